@@ -1,13 +1,16 @@
 use crate::{
     checkpoint::strategy::{CheckpointStrategy, NoCheckpointing},
     grads::Gradients,
+    graph::TensorHook,
+    ops::custom::custom_op,
     runtime::AutodiffClient,
     tensor::AutodiffTensor,
     AutodiffBridge,
 };
 use burn_common::sync_type::SyncType;
-use burn_tensor::backend::{AutodiffBackend, Backend};
+use burn_tensor::backend::{AutodiffBackend, Backend, CustomBackward, CustomForward, GradientHook};
 use core::marker::PhantomData;
+use std::sync::Arc;
 
 /// Enable auto-differentiation on a backend.
 ///
@@ -57,8 +60,10 @@ impl<B: Backend, C: CheckpointStrategy> AutodiffBackend for Autodiff<B, C> {
 
     fn backward<const D: usize>(tensor: AutodiffTensor<B, D>) -> Gradients {
         let client = tensor.node.client.clone();
+        let grads = AutodiffClient::backward(&client, tensor);
+        C::backward_done();
 
-        AutodiffClient::backward(&client, tensor)
+        grads
     }
 
     fn grad<const D: usize>(
@@ -91,6 +96,29 @@ impl<B: Backend, C: CheckpointStrategy> AutodiffBackend for Autodiff<B, C> {
         grads.register::<B, D>(tensor.node.id, grad);
     }
 
+    fn register_hook<const D: usize>(tensor: &AutodiffTensor<B, D>, hook: GradientHook<B, D>) {
+        if tensor.node.requirement.is_none() {
+            return;
+        }
+
+        tensor
+            .node
+            .client
+            .register_hook(tensor.node.id, Arc::new(TensorHook::new(hook)));
+    }
+
+    fn custom_op<const N: usize, const D_IN: usize, const D_OUT: usize, S>(
+        inputs: [AutodiffTensor<B, D_IN>; N],
+        forward: CustomForward<B, N, D_IN, D_OUT, S>,
+        backward: CustomBackward<B, N, D_IN, D_OUT, S>,
+        memory_bound: bool,
+    ) -> AutodiffTensor<B, D_OUT>
+    where
+        S: Clone + Send + 'static,
+    {
+        custom_op::<B, C, N, D_IN, D_OUT, S>(inputs, forward, backward, memory_bound)
+    }
+
     fn int_inner<const D: usize>(
         tensor: burn_tensor::ops::IntTensor<Self, D>,
     ) -> burn_tensor::ops::IntTensor<Self::InnerBackend, D> {
@@ -1,4 +1,4 @@
-use core::fmt::Debug;
+use core::{cell::Cell, fmt::Debug, mem::size_of};
 use std::sync::Arc;
 
 use burn_tensor::backend::Backend;
@@ -22,6 +22,17 @@ pub trait CheckpointStrategy: Clone + Copy + Debug + Default + Send + Sync + 'st
     ) where
         B2: Backend,
         A: IntoIterator<Item = &'a AutodiffTensor<B2, D2>>;
+
+    /// May modify the compute property of an operation once its output is computed
+    fn output_property<B: Backend, const D: usize>(
+        property: ComputingProperty,
+        _output: &B::FloatTensorPrimitive<D>,
+    ) -> ComputingProperty {
+        property
+    }
+
+    /// Called after a backward pass, which released the outputs kept by the forward pass
+    fn backward_done() {}
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -73,3 +84,142 @@ impl CheckpointStrategy for BalancedCheckpointing {
         }
     }
 }
+
+/// Checkpointing mode used by the [DynamicCheckpointing] strategy, selected at runtime with
+/// [with_checkpointing].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CheckpointingMode {
+    /// All operations are considered compute bound, as with [NoCheckpointing]
+    #[default]
+    None,
+    /// Operation properties are as they are marked, as with [BalancedCheckpointing]
+    Balanced,
+    /// The outputs of memory bound operations are kept in memory until their total size reaches
+    /// the given number of bytes, after which the outputs of memory bound operations are
+    /// recomputed during the backward pass. The budget is reset after each backward pass
+    MemoryBudget(usize),
+}
+
+#[derive(Clone, Copy, Default)]
+struct CheckpointingScope {
+    mode: CheckpointingMode,
+    num_bytes_kept: usize,
+}
+
+std::thread_local! {
+    static SCOPE: Cell<CheckpointingScope> = Cell::new(CheckpointingScope::default());
+}
+
+/// Restores the previous scope even if the function panics.
+struct ScopeGuard {
+    previous: CheckpointingScope,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        SCOPE.with(|scope| scope.set(self.previous));
+    }
+}
+
+/// Executes the function with the given checkpointing mode for the operations of backends using
+/// the [DynamicCheckpointing] strategy.
+///
+/// The mode only applies to the operations executed by the function on the current thread, so it
+/// can be used to mark the forward pass of a module, e.g. a transformer block, as a recomputation
+/// boundary. Scopes can be nested, and the memory budget of a
+/// [memory budget](CheckpointingMode::MemoryBudget) mode is shared by all the operations of its
+/// scope until a backward pass is executed on the current thread, after which the budget of the
+/// current scope is available again. Outside of any scope, the mode is [CheckpointingMode::None].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let x = with_checkpointing(CheckpointingMode::Balanced, || block.forward(x));
+/// ```
+pub fn with_checkpointing<O, F: FnOnce() -> O>(mode: CheckpointingMode, func: F) -> O {
+    let scope = CheckpointingScope {
+        mode,
+        num_bytes_kept: 0,
+    };
+    let _guard = ScopeGuard {
+        previous: SCOPE.with(|current| current.replace(scope)),
+    };
+
+    func()
+}
+
+/// Returns the checkpointing mode of the current thread.
+pub fn checkpointing_mode() -> CheckpointingMode {
+    SCOPE.with(|scope| scope.get().mode)
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// Operation properties depend on the [checkpointing mode](CheckpointingMode) selected at runtime
+/// with [with_checkpointing]
+pub struct DynamicCheckpointing {}
+
+impl CheckpointStrategy for DynamicCheckpointing {
+    /// An operation marked as memory bound is memory bound, unless checkpointing is disabled.
+    fn compute_property<R: RetroForward>(retro_forward: R) -> ComputingProperty {
+        match checkpointing_mode() {
+            CheckpointingMode::None => ComputingProperty::ComputeBound,
+            CheckpointingMode::Balanced | CheckpointingMode::MemoryBudget(_) => {
+                ComputingProperty::MemoryBound {
+                    retro_forward: Arc::new(retro_forward),
+                }
+            }
+        }
+    }
+
+    /// Parents are checkpointed when the operation may need to recompute itself.
+    fn checkpoint_parents<'a, B2, const D2: usize, A>(parents: A, builder: &mut CheckpointerBuilder)
+    where
+        B2: Backend,
+        A: IntoIterator<Item = &'a AutodiffTensor<B2, D2>>,
+    {
+        match checkpointing_mode() {
+            CheckpointingMode::None => {}
+            CheckpointingMode::Balanced | CheckpointingMode::MemoryBudget(_) => {
+                BalancedCheckpointing::checkpoint_parents(parents, builder)
+            }
+        }
+    }
+
+    /// With a memory budget, a memory bound operation is compute bound as long as its output
+    /// fits in the remaining budget.
+    fn output_property<B: Backend, const D: usize>(
+        property: ComputingProperty,
+        output: &B::FloatTensorPrimitive<D>,
+    ) -> ComputingProperty {
+        if !matches!(property, ComputingProperty::MemoryBound { .. }) {
+            return property;
+        }
+
+        SCOPE.with(|scope| {
+            let mut current = scope.get();
+            let budget = match current.mode {
+                CheckpointingMode::MemoryBudget(budget) => budget,
+                CheckpointingMode::None | CheckpointingMode::Balanced => return property,
+            };
+            let num_bytes = B::float_shape(output).num_elements() * size_of::<B::FloatElem>();
+
+            match current.num_bytes_kept + num_bytes <= budget {
+                true => {
+                    current.num_bytes_kept += num_bytes;
+                    scope.set(current);
+                    ComputingProperty::ComputeBound
+                }
+                false => property,
+            }
+        })
+    }
+
+    /// The outputs kept by the forward pass are released, so the memory budget is reset.
+    fn backward_done() {
+        SCOPE.with(|scope| {
+            let mut current = scope.get();
+            current.num_bytes_kept = 0;
+            scope.set(current);
+        })
+    }
+}
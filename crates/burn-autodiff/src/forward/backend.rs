@@ -0,0 +1,45 @@
+use super::{DualTensor, ForwardBridge};
+use burn_common::sync_type::SyncType;
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+
+/// Enable forward-mode auto-differentiation on a backend.
+///
+/// This works as a backend decorator, where each float tensor carries a tangent along with its
+/// value. The tangents are propagated by every operation, so a single forward pass computes the
+/// Jacobian-vector product of a function in the direction of the input tangents.
+///
+/// Use [dual](super::dual) to create an input with a tangent, and [tangent](super::tangent) to
+/// retrieve the tangent of an output.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ForwardAutodiff<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for ForwardAutodiff<B> {
+    type Device = B::Device;
+
+    type FullPrecisionBridge = ForwardBridge<B::FullPrecisionBridge>;
+
+    type FloatTensorPrimitive<const D: usize> = DualTensor<B, D>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> = B::IntTensorPrimitive<D>;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> = B::BoolTensorPrimitive<D>;
+
+    type QuantizedTensorPrimitive<const D: usize> = B::QuantizedTensorPrimitive<D>;
+
+    fn name() -> String {
+        format!("forward<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn sync(device: &B::Device, sync_type: SyncType) {
+        B::sync(device, sync_type)
+    }
+}
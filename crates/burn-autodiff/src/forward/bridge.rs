@@ -0,0 +1,47 @@
+use std::marker::PhantomData;
+
+use burn_tensor::{
+    backend::{Backend, BackendBridge},
+    ops::FloatTensor,
+    Device,
+};
+
+use super::{DualTensor, ForwardAutodiff};
+
+/// Enable forward-mode autodiff on a [backend bridge](BackendBridge).
+#[derive(Debug)]
+pub struct ForwardBridge<Bridge> {
+    _p: PhantomData<Bridge>,
+}
+
+impl<B, Bridge> BackendBridge<ForwardAutodiff<B>> for ForwardBridge<Bridge>
+where
+    B: Backend,
+    Bridge: BackendBridge<B> + 'static,
+{
+    type Target = ForwardAutodiff<Bridge::Target>;
+
+    fn into_target<const D: usize>(
+        tensor: FloatTensor<ForwardAutodiff<B>, D>,
+        device: Option<Device<Self::Target>>,
+    ) -> FloatTensor<Self::Target, D> {
+        DualTensor {
+            primal: Bridge::into_target(tensor.primal, device.clone()),
+            tangent: tensor
+                .tangent
+                .map(|tangent| Bridge::into_target(tangent, device)),
+        }
+    }
+
+    fn from_target<const D: usize>(
+        tensor: FloatTensor<Self::Target, D>,
+        device: Option<Device<ForwardAutodiff<B>>>,
+    ) -> FloatTensor<ForwardAutodiff<B>, D> {
+        DualTensor {
+            primal: Bridge::from_target(tensor.primal, device.clone()),
+            tangent: tensor
+                .tangent
+                .map(|tangent| Bridge::from_target(tangent, device)),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+mod backend;
+mod bridge;
+mod ops;
+mod tensor;
+
+pub use backend::*;
+pub use bridge::*;
+pub use tensor::*;
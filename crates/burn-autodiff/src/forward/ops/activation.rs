@@ -0,0 +1,45 @@
+use crate::forward::{DualTensor, ForwardAutodiff};
+use burn_tensor::{
+    backend::Backend,
+    ops::{ActivationOps, FloatTensor},
+};
+
+// The backward functions of element-wise activations multiply the gradient by the derivative, so
+// they also compute the tangent of the output when applied to the tangent of the input.
+impl<B: Backend> ActivationOps<ForwardAutodiff<B>> for ForwardAutodiff<B> {
+    fn gelu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor {
+            primal: B::gelu(tensor.primal.clone()),
+            tangent: tensor
+                .tangent
+                .map(|tangent| B::gelu_backward(tensor.primal, tangent)),
+        }
+    }
+
+    fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let primal = B::relu(tensor.primal);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::relu_backward(primal.clone(), tangent));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let primal = B::sigmoid(tensor.primal);
+        let tangent = tensor
+            .tangent
+            .map(|tangent| B::sigmoid_backward(primal.clone(), tangent));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn log_sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor {
+            primal: B::log_sigmoid(tensor.primal.clone()),
+            tangent: tensor
+                .tangent
+                .map(|tangent| B::log_sigmoid_backward(tensor.primal, tangent)),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use burn_tensor::{backend::Backend, ops::FloatTensor, Shape};
+
+use crate::forward::DualTensor;
+
+/// Applies a linear operation to the value and the tangent.
+pub(crate) fn linear<B, const D1: usize, const D2: usize, F>(
+    tensor: DualTensor<B, D1>,
+    op: F,
+) -> DualTensor<B, D2>
+where
+    B: Backend,
+    F: Fn(FloatTensor<B, D1>) -> FloatTensor<B, D2>,
+{
+    DualTensor {
+        primal: op(tensor.primal),
+        tangent: tensor.tangent.map(op),
+    }
+}
+
+/// Applies an element-wise function, where the tangent is multiplied by the derivative computed
+/// from the input and the output of the function.
+pub(crate) fn unary<B, const D: usize, F, G>(
+    tensor: DualTensor<B, D>,
+    func: F,
+    derivative: G,
+) -> DualTensor<B, D>
+where
+    B: Backend,
+    F: FnOnce(FloatTensor<B, D>) -> FloatTensor<B, D>,
+    G: FnOnce(FloatTensor<B, D>, FloatTensor<B, D>) -> FloatTensor<B, D>,
+{
+    match tensor.tangent {
+        Some(tangent) => {
+            let output = func(tensor.primal.clone());
+            let derivative = derivative(tensor.primal, output.clone());
+
+            DualTensor::new(output, B::float_mul(tangent, derivative))
+        }
+        None => DualTensor::constant(func(tensor.primal)),
+    }
+}
+
+/// Adds two tangents, where `None` is a zero tangent.
+pub(crate) fn add_tangents<B: Backend, const D: usize>(
+    lhs: Option<FloatTensor<B, D>>,
+    rhs: Option<FloatTensor<B, D>>,
+) -> Option<FloatTensor<B, D>> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(B::float_add(lhs, rhs)),
+        (lhs, None) => lhs,
+        (None, rhs) => rhs,
+    }
+}
+
+/// Broadcasts the tangent of an operand to the shape of the output, so that the tangents always
+/// have the same shape as the values.
+pub(crate) fn broadcast_tangent<B: Backend, const D: usize>(
+    tangent: FloatTensor<B, D>,
+    shape: &Shape<D>,
+) -> FloatTensor<B, D> {
+    match B::float_shape(&tangent) == *shape {
+        true => tangent,
+        false => B::float_expand(tangent, shape.clone()),
+    }
+}
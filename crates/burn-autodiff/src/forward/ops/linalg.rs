@@ -0,0 +1,256 @@
+use super::add_tangents;
+use crate::{
+    forward::{DualTensor, ForwardAutodiff},
+    ops::linalg::{check_qr_backward, eye, inverse_differences, primitive, tensor},
+};
+use burn_tensor::{
+    backend::Backend,
+    linalg,
+    ops::{FloatTensor, LinalgOps},
+    Tensor,
+};
+
+/// Symmetric part of the tangents, for the decompositions of symmetric matrices.
+///
+/// It matches the gradients of the reverse mode, which are symmetric as well.
+fn symmetric<B: Backend, const D: usize>(tangent: FloatTensor<B, D>) -> Tensor<B, D> {
+    let tangent = tensor::<B, D>(tangent);
+    tangent.clone().add(tangent.transpose()).mul_scalar(0.5)
+}
+
+/// Returns `tr(a^{-1} b)` for each matrix, with shape `[..., 1, 1]`.
+fn trace_inverse_mul<B: Backend, const D: usize>(a: Tensor<B, D>, b: Tensor<B, D>) -> Tensor<B, D> {
+    linalg::inverse(a)
+        .transpose()
+        .mul(b)
+        .sum_dim(D - 1)
+        .sum_dim(D - 2)
+}
+
+/// Diagonal of the matrices, with shape `[..., 1, n]`.
+fn diagonal<B: Backend, const D: usize>(m: Tensor<B, D>) -> Tensor<B, D> {
+    let size = m.dims()[D - 1];
+    let eye = eye::<B, D>(size, &m.device());
+
+    m.mul(eye).sum_dim(D - 2)
+}
+
+impl<B: Backend> LinalgOps<Self> for ForwardAutodiff<B> {
+    fn solve<const D: usize>(
+        a: FloatTensor<Self, D>,
+        b: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let x = B::solve(a.primal.clone(), b.primal);
+
+        // a dx = db - da x
+        let tangent = add_tangents::<B, D>(
+            b.tangent,
+            a.tangent
+                .map(|tangent| B::float_neg(B::float_matmul(tangent, x.clone()))),
+        )
+        .map(|tangent| B::solve(a.primal, tangent));
+
+        DualTensor { primal: x, tangent }
+    }
+
+    fn inverse<const D: usize>(a: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let inverse = B::inverse(a.primal);
+
+        // dy = -y da y
+        let tangent = a.tangent.map(|tangent| {
+            let tangent = B::float_matmul(inverse.clone(), tangent);
+            B::float_neg(B::float_matmul(tangent, inverse.clone()))
+        });
+
+        DualTensor {
+            primal: inverse,
+            tangent,
+        }
+    }
+
+    fn det<const D: usize>(a: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let det = B::det(a.primal.clone());
+
+        // d(det) = det tr(a^{-1} da), which is undefined (non-finite) for singular matrices
+        let tangent = a.tangent.map(|tangent| {
+            let trace = trace_inverse_mul(tensor::<B, D>(a.primal), tensor(tangent));
+            B::float_mul(primitive(trace), det.clone())
+        });
+
+        DualTensor {
+            primal: det,
+            tangent,
+        }
+    }
+
+    fn logdet<const D: usize>(a: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let tangent = a.tangent.map(|tangent| {
+            let trace = trace_inverse_mul(tensor::<B, D>(a.primal.clone()), tensor(tangent));
+            primitive(trace)
+        });
+
+        DualTensor {
+            primal: B::logdet(a.primal),
+            tangent,
+        }
+    }
+
+    fn cholesky<const D: usize>(a: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        let l = B::cholesky(a.primal);
+
+        // dl = l phi(l^{-1} da l^{-T}), where phi takes the lower triangular part and halves the
+        // diagonal
+        let tangent = a.tangent.map(|tangent| {
+            let l = tensor::<B, D>(l.clone());
+            let size = l.dims()[D - 1];
+            let half_diagonal = eye::<B, D>(size, &l.device())
+                .mul_scalar(-0.5)
+                .add_scalar(1.0);
+
+            let z = linalg::solve(l.clone(), symmetric(tangent));
+            let x = linalg::solve(l.clone(), z.transpose()).transpose();
+
+            primitive(l.matmul(x.tril(0).mul(half_diagonal)))
+        });
+
+        DualTensor { primal: l, tangent }
+    }
+
+    fn qr<const D: usize>(a: FloatTensor<Self, D>) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (q, r) = B::qr(a.primal);
+
+        let (q_tangent, r_tangent) = match a.tangent {
+            Some(tangent) => {
+                let (q, r) = (tensor::<B, D>(q.clone()), tensor::<B, D>(r.clone()));
+                check_qr_backward(&q, &r);
+
+                // x = q^T da r^{-1} is split into the skew-symmetric q^T dq and the upper
+                // triangular dr r^{-1}
+                let tangent = tensor::<B, D>(tangent).matmul(linalg::inverse(r.clone()));
+                let x = q.clone().transpose().matmul(tangent.clone());
+                let lower = x.clone().tril(-1);
+                let upper = x.sub(lower.clone()).add(lower.transpose());
+
+                let q_tangent = tangent.sub(q.matmul(upper.clone()));
+                let r_tangent = upper.matmul(r);
+
+                (Some(primitive(q_tangent)), Some(primitive(r_tangent)))
+            }
+            None => (None, None),
+        };
+
+        (
+            DualTensor {
+                primal: q,
+                tangent: q_tangent,
+            },
+            DualTensor {
+                primal: r,
+                tangent: r_tangent,
+            },
+        )
+    }
+
+    fn svd<const D: usize>(
+        a: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        let (u, s, vh) = B::svd(a.primal);
+
+        let (u_tangent, s_tangent, vh_tangent) = match a.tangent {
+            Some(tangent) => {
+                let (u, s, vh) = (
+                    tensor::<B, D>(u.clone()),
+                    tensor::<B, D>(s.clone()),
+                    tensor::<B, D>(vh.clone()),
+                );
+                let tangent = tensor::<B, D>(tangent);
+                let v = vh.transpose();
+                let s_transposed = s.clone().transpose();
+                let s_squared = s.clone().powf_scalar(2.0);
+                let f = inverse_differences(s_squared.clone().sub(s_squared.transpose()));
+
+                // p = u^T da v, where the singular values change with the diagonal of p
+                let tangent_v = tangent.clone().matmul(v.clone());
+                let tangent_u = tangent.transpose().matmul(u.clone());
+                let p = u.clone().transpose().matmul(tangent_v.clone());
+                let p_transposed = p.clone().transpose();
+                let s_tangent = diagonal(p.clone());
+
+                // du = u (f o (p s + s p^T)) + (I - u u^T) da v s^{-1}
+                let j = f.clone().mul(
+                    p.clone()
+                        .mul(s.clone())
+                        .add(p_transposed.clone().mul(s_transposed.clone())),
+                );
+                let projected = tangent_v.sub(u.clone().matmul(p.clone())).div(s.clone());
+                let u_tangent = u.matmul(j).add(projected);
+
+                // dv = v (f o (s p + p^T s)) + (I - v v^T) da^T u s^{-1}
+                let k = f.mul(p.mul(s_transposed).add(p_transposed.clone().mul(s.clone())));
+                let projected = tangent_u.sub(v.clone().matmul(p_transposed)).div(s);
+                let v_tangent = v.matmul(k).add(projected);
+
+                (
+                    Some(primitive(u_tangent)),
+                    Some(primitive(s_tangent)),
+                    Some(primitive(v_tangent.transpose())),
+                )
+            }
+            None => (None, None, None),
+        };
+
+        (
+            DualTensor {
+                primal: u,
+                tangent: u_tangent,
+            },
+            DualTensor {
+                primal: s,
+                tangent: s_tangent,
+            },
+            DualTensor {
+                primal: vh,
+                tangent: vh_tangent,
+            },
+        )
+    }
+
+    fn eigh<const D: usize>(
+        a: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (w, v) = B::eigh(a.primal);
+
+        let (w_tangent, v_tangent) = match a.tangent {
+            Some(tangent) => {
+                let (w, v) = (tensor::<B, D>(w.clone()), tensor::<B, D>(v.clone()));
+
+                // p = v^T da v, where dw = diag(p) and dv = v (f o p)
+                let p = v
+                    .clone()
+                    .transpose()
+                    .matmul(symmetric(tangent))
+                    .matmul(v.clone());
+                let f = inverse_differences(w.clone().sub(w.transpose()));
+                let v_tangent = v.matmul(f.mul(p.clone()));
+
+                (Some(primitive(diagonal(p))), Some(primitive(v_tangent)))
+            }
+            None => (None, None),
+        };
+
+        (
+            DualTensor {
+                primal: w,
+                tangent: w_tangent,
+            },
+            DualTensor {
+                primal: v,
+                tangent: v_tangent,
+            },
+        )
+    }
+}
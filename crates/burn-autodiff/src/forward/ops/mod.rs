@@ -0,0 +1,10 @@
+mod activation;
+mod base;
+mod bool_tensor;
+mod int_tensor;
+mod linalg;
+mod module;
+mod qtensor;
+mod tensor;
+
+pub(crate) use base::*;
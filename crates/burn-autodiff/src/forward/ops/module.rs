@@ -0,0 +1,248 @@
+use super::{add_tangents, broadcast_tangent, linear};
+use crate::forward::{DualTensor, ForwardAutodiff};
+
+use burn_tensor::{
+    backend::Backend,
+    ops::{
+        ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor, InterpolateOptions,
+        MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    },
+    Shape,
+};
+
+/// Applies a convolution, where the tangent is the sum of the convolution of each tangent with
+/// the values of the other operand, plus the tangent of the bias.
+fn conv<B, const D: usize, F>(
+    x: DualTensor<B, D>,
+    weight: DualTensor<B, D>,
+    bias: Option<DualTensor<B, 1>>,
+    func: F,
+) -> DualTensor<B, D>
+where
+    B: Backend,
+    F: Fn(FloatTensor<B, D>, FloatTensor<B, D>, Option<FloatTensor<B, 1>>) -> FloatTensor<B, D>,
+{
+    let (bias, bias_tangent) = match bias {
+        Some(bias) => (Some(bias.primal), bias.tangent),
+        None => (None, None),
+    };
+
+    let tangent = add_tangents::<B, D>(
+        x.tangent
+            .map(|tangent| func(tangent, weight.primal.clone(), None)),
+        weight
+            .tangent
+            .map(|tangent| func(x.primal.clone(), tangent, None)),
+    );
+
+    let primal = func(x.primal, weight.primal, bias);
+    let shape = B::float_shape(&primal);
+
+    // The bias is broadcast over the batch and the spatial dimensions
+    let bias_tangent = bias_tangent.map(|tangent| {
+        let mut dims = [1; D];
+        dims[1] = shape.dims[1];
+        B::float_reshape(tangent, Shape::new(dims))
+    });
+    let tangent = add_tangents::<B, D>(tangent, bias_tangent)
+        .map(|tangent| broadcast_tangent::<B, D>(tangent, &shape));
+
+    DualTensor { primal, tangent }
+}
+
+/// Returns the tangent of the values selected by a max pooling, where the indices are flattened
+/// over the spatial dimensions of the input.
+fn gather_pooled<B: Backend>(
+    tangent: FloatTensor<B, 4>,
+    indices: IntTensor<B, 4>,
+) -> FloatTensor<B, 4> {
+    let [batch_size, channels, height, width] = B::float_shape(&tangent).dims;
+    let shape = B::int_shape(&indices);
+    let [_, _, height_out, width_out] = shape.dims;
+
+    let tangent = B::float_reshape(tangent, Shape::new([batch_size, channels, height * width]));
+    let indices = B::int_reshape(
+        indices,
+        Shape::new([batch_size, channels, height_out * width_out]),
+    );
+
+    B::float_reshape(B::float_gather(2, tangent, indices), shape)
+}
+
+impl<B: Backend> ModuleOps<ForwardAutodiff<B>> for ForwardAutodiff<B> {
+    fn conv2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        conv(x, weight, bias, |x, weight, bias| {
+            B::conv2d(x, weight, bias, options.clone())
+        })
+    }
+
+    fn conv3d(
+        x: FloatTensor<Self, 5>,
+        weight: FloatTensor<Self, 5>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<3>,
+    ) -> FloatTensor<Self, 5> {
+        conv(x, weight, bias, |x, weight, bias| {
+            B::conv3d(x, weight, bias, options.clone())
+        })
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        conv(x, weight, bias, |x, weight, bias| {
+            B::conv_transpose2d(x, weight, bias, options.clone())
+        })
+    }
+
+    fn conv_transpose3d(
+        x: FloatTensor<Self, 5>,
+        weight: FloatTensor<Self, 5>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> FloatTensor<Self, 5> {
+        conv(x, weight, bias, |x, weight, bias| {
+            B::conv_transpose3d(x, weight, bias, options.clone())
+        })
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        linear(x, |x| {
+            B::avg_pool2d(x, kernel_size, stride, padding, count_include_pad)
+        })
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        linear(grad, |grad| {
+            B::avg_pool2d_backward(
+                x.primal.clone(),
+                grad,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            )
+        })
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        linear(x, |x| B::adaptive_avg_pool2d(x, output_size))
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        linear(grad, |grad| {
+            B::adaptive_avg_pool2d_backward(x.primal.clone(), grad)
+        })
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        match x.tangent {
+            Some(_) => {
+                Self::max_pool2d_with_indices(x, kernel_size, stride, padding, dilation).output
+            }
+            None => DualTensor::constant(B::max_pool2d(
+                x.primal,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            )),
+        }
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        let output = B::max_pool2d_with_indices(x.primal, kernel_size, stride, padding, dilation);
+        let tangent = x
+            .tangent
+            .map(|tangent| gather_pooled::<B>(tangent, output.indices.clone()));
+
+        MaxPool2dWithIndices::new(
+            DualTensor {
+                primal: output.output,
+                tangent,
+            },
+            output.indices,
+        )
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<B, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        MaxPool2dBackward::new(linear(output_grad, |grad| {
+            B::max_pool2d_with_indices_backward(
+                x.primal.clone(),
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                grad,
+                indices.clone(),
+            )
+            .x_grad
+        }))
+    }
+
+    fn interpolate(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        linear(x, |x| B::interpolate(x, output_size, options.clone()))
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        linear(grad, |grad| {
+            B::interpolate_backward(x.primal.clone(), grad, output_size, options.clone())
+        })
+    }
+}
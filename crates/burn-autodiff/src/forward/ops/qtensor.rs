@@ -0,0 +1,81 @@
+use burn_tensor::{
+    backend::Backend,
+    ops::{FloatTensor, QTensorOps, QuantizedTensor},
+    quantization::{QuantizationParametersPrimitive, QuantizationScheme},
+    Device, ElementConversion, Shape, TensorData,
+};
+
+use crate::{
+    forward::{DualTensor, ForwardAutodiff},
+    ops::qtensor::clipped_values,
+};
+
+/// Returns the quantization parameters of the inner backend.
+fn inner_qparams<B: Backend>(
+    qparams: QuantizationParametersPrimitive<ForwardAutodiff<B>>,
+) -> QuantizationParametersPrimitive<B> {
+    QuantizationParametersPrimitive {
+        scale: qparams.scale.primal,
+        offset: qparams.offset,
+    }
+}
+
+impl<B: Backend> QTensorOps<Self> for ForwardAutodiff<B> {
+    fn q_from_data<const D: usize>(
+        data: TensorData,
+        device: &Device<Self>,
+    ) -> QuantizedTensor<Self, D> {
+        B::q_from_data(data, device)
+    }
+
+    fn quantize<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self, D> {
+        // Quantization isn't differentiable, see `fake_quantize` for quantization-aware training
+        B::quantize(tensor.primal, scheme, inner_qparams(qparams))
+    }
+
+    fn dequantize<const D: usize>(tensor: QuantizedTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::dequantize(tensor))
+    }
+
+    fn fake_quantize<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> FloatTensor<Self, D> {
+        let qparams = inner_qparams(qparams);
+
+        // Straight-through estimator, where the tangent of the clipped values is zero
+        let tangent = tensor.tangent.map(|tangent| {
+            let clipped = clipped_values::<B, D>(tensor.primal.clone(), scheme, &qparams);
+            B::float_mask_fill(tangent, clipped, 0.elem())
+        });
+
+        DualTensor {
+            primal: B::fake_quantize(tensor.primal, scheme, qparams),
+            tangent,
+        }
+    }
+
+    fn q_shape<const D: usize>(tensor: &QuantizedTensor<Self, D>) -> Shape<D> {
+        B::q_shape(tensor)
+    }
+
+    fn q_device<const D: usize>(tensor: &QuantizedTensor<Self, D>) -> Device<Self> {
+        B::q_device(tensor)
+    }
+
+    fn q_reshape<const D1: usize, const D2: usize>(
+        tensor: QuantizedTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> QuantizedTensor<Self, D2> {
+        B::q_reshape(tensor, shape)
+    }
+
+    async fn q_into_data<const D: usize>(tensor: QuantizedTensor<Self, D>) -> TensorData {
+        B::q_into_data(tensor).await
+    }
+}
@@ -0,0 +1,713 @@
+use super::{add_tangents, broadcast_tangent, linear, unary};
+use crate::forward::{DualTensor, ForwardAutodiff};
+use crate::ops::cumulative::CumProdZeros;
+
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
+    Device, Distribution, ElementConversion, Shape, TensorData,
+};
+
+/// Returns the tangent of the values selected by the indices along the dimension, used by the
+/// operations where each output is a single input value (e.g., sort, max).
+fn gather_tangent<B: Backend, const D: usize>(
+    tangent: Option<FloatTensor<B, D>>,
+    dim: usize,
+    indices: IntTensor<B, D>,
+) -> Option<FloatTensor<B, D>> {
+    tangent.map(|tangent| B::float_gather(dim, tangent, indices))
+}
+
+impl<B: Backend> FloatTensorOps<Self> for ForwardAutodiff<B> {
+    fn float_from_data<const D: usize>(
+        data: TensorData,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_from_data(data, device))
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_random(shape, distribution, device))
+    }
+
+    fn float_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_zeros(shape, device))
+    }
+
+    fn float_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_ones(shape, device))
+    }
+
+    fn float_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: FloatElem<B>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_full(shape, fill_value, device))
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_empty(shape, device))
+    }
+
+    fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        B::float_shape(&tensor.primal)
+    }
+
+    async fn float_into_data<const D: usize>(tensor: FloatTensor<Self, D>) -> TensorData {
+        B::float_into_data(tensor.primal).await
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
+        B::float_device(&tensor.primal)
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        linear(tensor, |tensor| B::float_to_device(tensor, device))
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<B, D> {
+        B::float_into_int(tensor.primal)
+    }
+
+    fn float_detach<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor::constant(tensor.primal)
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let primal = B::float_add(lhs.primal, rhs.primal);
+        let shape = B::float_shape(&primal);
+        let tangent = add_tangents::<B, D>(lhs.tangent, rhs.tangent)
+            .map(|tangent| broadcast_tangent::<B, D>(tangent, &shape));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor {
+            primal: B::float_add_scalar(lhs.primal, rhs),
+            tangent: lhs.tangent,
+        }
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let primal = B::float_sub(lhs.primal, rhs.primal);
+        let shape = B::float_shape(&primal);
+        let tangent = match (lhs.tangent, rhs.tangent) {
+            (Some(lhs), Some(rhs)) => Some(B::float_sub(lhs, rhs)),
+            (lhs, None) => lhs,
+            (None, Some(rhs)) => Some(B::float_neg(rhs)),
+        };
+        let tangent = tangent.map(|tangent| broadcast_tangent::<B, D>(tangent, &shape));
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor {
+            primal: B::float_sub_scalar(lhs.primal, rhs),
+            tangent: lhs.tangent,
+        }
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        // d(lhs * rhs) = d(lhs) * rhs + lhs * d(rhs)
+        let tangent = add_tangents::<B, D>(
+            lhs.tangent
+                .map(|tangent| B::float_mul(tangent, rhs.primal.clone())),
+            rhs.tangent
+                .map(|tangent| B::float_mul(lhs.primal.clone(), tangent)),
+        );
+
+        DualTensor {
+            primal: B::float_mul(lhs.primal, rhs.primal),
+            tangent,
+        }
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        linear(lhs, |tensor| B::float_mul_scalar(tensor, rhs))
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let primal = B::float_div(lhs.primal, rhs.primal.clone());
+
+        // d(lhs / rhs) = d(lhs) / rhs - (lhs / rhs) * d(rhs) / rhs
+        let tangent = add_tangents::<B, D>(
+            lhs.tangent
+                .map(|tangent| B::float_div(tangent, rhs.primal.clone())),
+            rhs.tangent.map(|tangent| {
+                let tangent = B::float_mul(primal.clone(), tangent);
+                B::float_neg(B::float_div(tangent, rhs.primal.clone()))
+            }),
+        );
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        linear(lhs, |tensor| B::float_div_scalar(tensor, rhs))
+    }
+
+    fn float_remainder_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        // The remainder is a translation of the input, except at its discontinuities.
+        DualTensor {
+            primal: B::float_remainder_scalar(lhs.primal, rhs),
+            tangent: lhs.tangent,
+        }
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = add_tangents::<B, D>(
+            lhs.tangent
+                .map(|tangent| B::float_matmul(tangent, rhs.primal.clone())),
+            rhs.tangent
+                .map(|tangent| B::float_matmul(lhs.primal.clone(), tangent)),
+        );
+
+        DualTensor {
+            primal: B::float_matmul(lhs.primal, rhs.primal),
+            tangent,
+        }
+    }
+
+    fn float_neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        linear(tensor, B::float_neg)
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary(tensor, B::float_recip, |_, output| {
+            B::float_neg(B::float_mul(output.clone(), output))
+        })
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        linear(tensor, |tensor| B::float_swap_dims(tensor, dim1, dim2))
+    }
+
+    fn float_permute<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: [usize; D],
+    ) -> FloatTensor<Self, D> {
+        linear(tensor, |tensor| B::float_permute(tensor, axes))
+    }
+
+    fn float_flip<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: &[usize],
+    ) -> FloatTensor<Self, D> {
+        linear(tensor, |tensor| B::float_flip(tensor, axes))
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        linear(tensor, |tensor| B::float_reshape(tensor, shape.clone()))
+    }
+
+    fn float_expand<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        linear(tensor, |tensor| B::float_expand(tensor, shape.clone()))
+    }
+
+    fn float_repeat<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<Self, D> {
+        linear(tensor, |tensor| B::float_repeat(tensor, dim, times))
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<B, D>,
+    ) -> FloatTensor<Self, D> {
+        linear(tensor, |tensor| {
+            B::float_gather(dim, tensor, indices.clone())
+        })
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<B, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = match tensor.tangent.is_some() || value.tangent.is_some() {
+            true => Some(B::float_scatter(
+                dim,
+                tensor.tangent_or_zeros(),
+                indices.clone(),
+                value.tangent_or_zeros(),
+            )),
+            false => None,
+        };
+
+        DualTensor {
+            primal: B::float_scatter(dim, tensor.primal, indices, value.primal),
+            tangent,
+        }
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+    ) -> FloatTensor<Self, D> {
+        linear(tensor, |tensor| {
+            B::float_select(tensor, dim, indices.clone())
+        })
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let tangent = match tensor.tangent.is_some() || value.tangent.is_some() {
+            true => Some(B::float_select_assign(
+                tensor.tangent_or_zeros(),
+                dim,
+                indices.clone(),
+                value.tangent_or_zeros(),
+            )),
+            false => None,
+        };
+
+        DualTensor {
+            primal: B::float_select_assign(tensor.primal, dim, indices, value.primal),
+            tangent,
+        }
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [std::ops::Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        linear(tensor, |tensor| B::float_slice(tensor, ranges.clone()))
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [std::ops::Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        let tangent = match tensor.tangent.is_some() || value.tangent.is_some() {
+            true => Some(B::float_slice_assign(
+                tensor.tangent_or_zeros(),
+                ranges.clone(),
+                value.tangent_or_zeros(),
+            )),
+            false => None,
+        };
+
+        DualTensor {
+            primal: B::float_slice_assign(tensor.primal, ranges, value.primal),
+            tangent,
+        }
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<B, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let primal = B::float_mask_where(tensor.primal.clone(), mask.clone(), value.primal.clone());
+        let tangent = match tensor.tangent.is_some() || value.tangent.is_some() {
+            true => {
+                let shape = B::float_shape(&primal);
+                let tangent = broadcast_tangent::<B, D>(tensor.tangent_or_zeros(), &shape);
+                Some(B::float_mask_where(tangent, mask, value.tangent_or_zeros()))
+            }
+            false => None,
+        };
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<B, D>,
+        value: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        DualTensor {
+            primal: B::float_mask_fill(tensor.primal, mask.clone(), value),
+            tangent: tensor
+                .tangent
+                .map(|tangent| B::float_mask_fill(tangent, mask, 0.elem())),
+        }
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<B, D> {
+        B::float_equal(lhs.primal, rhs.primal)
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::float_equal_elem(lhs.primal, rhs)
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<B, D> {
+        B::float_greater(lhs.primal, rhs.primal)
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::float_greater_elem(lhs.primal, rhs)
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<B, D> {
+        B::float_greater_equal(lhs.primal, rhs.primal)
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::float_greater_equal_elem(lhs.primal, rhs)
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<B, D> {
+        B::float_lower(lhs.primal, rhs.primal)
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::float_lower_elem(lhs.primal, rhs)
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<B, D> {
+        B::float_lower_equal(lhs.primal, rhs.primal)
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<B>,
+    ) -> BoolTensor<B, D> {
+        B::float_lower_equal_elem(lhs.primal, rhs)
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        linear(tensor, B::float_sum)
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        linear(tensor, |tensor| B::float_sum_dim(tensor, dim))
+    }
+
+    fn float_mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        linear(tensor, B::float_mean)
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        linear(tensor, |tensor| B::float_mean_dim(tensor, dim))
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary(tensor, B::float_exp, |_, output| output)
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary(tensor, B::float_log, |input, _| B::float_recip(input))
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary(tensor, B::float_log1p, |input, _| {
+            B::float_recip(B::float_add_scalar(input, 1.elem()))
+        })
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let primal = B::float_powf(lhs.primal.clone(), rhs.primal.clone());
+
+        // d(lhs^rhs) = rhs * lhs^(rhs - 1) * d(lhs) + lhs^rhs * ln(lhs) * d(rhs)
+        let tangent = add_tangents::<B, D>(
+            lhs.tangent.map(|tangent| {
+                let exponent = B::float_sub_scalar(rhs.primal.clone(), 1.elem());
+                let derivative = B::float_powf(lhs.primal.clone(), exponent);
+                let derivative = B::float_mul(rhs.primal.clone(), derivative);
+                B::float_mul(tangent, derivative)
+            }),
+            rhs.tangent.map(|tangent| {
+                let derivative = B::float_mul(primal.clone(), B::float_log(lhs.primal.clone()));
+                B::float_mul(tangent, derivative)
+            }),
+        );
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        unary(
+            tensor,
+            |tensor| B::float_powf_scalar(tensor, value),
+            |input, _| {
+                let derivative = B::float_powf_scalar(input, value - 1.0);
+                B::float_mul_scalar(derivative, value.elem())
+            },
+        )
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary(tensor, B::float_sqrt, |_, output| {
+            B::float_recip(B::float_mul_scalar(output, 2.elem()))
+        })
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary(tensor, B::float_abs, |input, _| B::float_sign(input))
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary(tensor, B::float_cos, |input, _| {
+            B::float_neg(B::float_sin(input))
+        })
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary(tensor, B::float_sin, |input, _| B::float_cos(input))
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary(tensor, B::float_tanh, |_, output| {
+            let squared = B::float_mul(output.clone(), output);
+            B::float_add_scalar(B::float_neg(squared), 1.elem())
+        })
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // d(erf(x)) = 2 / sqrt(pi) * exp(-x^2)
+        unary(tensor, B::float_erf, |input, _| {
+            let squared = B::float_mul(input.clone(), input);
+            let exponential = B::float_exp(B::float_neg(squared));
+            B::float_mul_scalar(exponential, (2.0 / core::f64::consts::PI.sqrt()).elem())
+        })
+    }
+
+    fn float_sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        DualTensor::constant(B::float_sign(tensor.primal))
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let tangents = match tensors.iter().any(|tensor| tensor.tangent.is_some()) {
+            true => Some(
+                tensors
+                    .iter()
+                    .map(|tensor| tensor.tangent_or_zeros())
+                    .collect(),
+            ),
+            false => None,
+        };
+        let primals = tensors.into_iter().map(|tensor| tensor.primal).collect();
+
+        DualTensor {
+            primal: B::float_cat(primals, dim),
+            tangent: tangents.map(|tangents| B::float_cat(tangents, dim)),
+        }
+    }
+
+    fn float_argmax<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<B, D> {
+        B::float_argmax(tensor.primal, dim)
+    }
+
+    fn float_argmin<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<B, D> {
+        B::float_argmin(tensor.primal, dim)
+    }
+
+    fn float_max_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        Self::float_max_dim_with_indices(tensor, dim).0
+    }
+
+    fn float_max_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (primal, indices) = B::float_max_dim_with_indices(tensor.primal, dim);
+        let tangent = gather_tangent::<B, D>(tensor.tangent, dim, indices.clone());
+
+        (DualTensor { primal, tangent }, indices)
+    }
+
+    fn float_min_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        Self::float_min_dim_with_indices(tensor, dim).0
+    }
+
+    fn float_min_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (primal, indices) = B::float_min_dim_with_indices(tensor.primal, dim);
+        let tangent = gather_tangent::<B, D>(tensor.tangent, dim, indices.clone());
+
+        (DualTensor { primal, tangent }, indices)
+    }
+
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        match tensor.tangent {
+            Some(_) => Self::float_sort_with_indices(tensor, dim, descending).0,
+            None => DualTensor::constant(B::float_sort(tensor.primal, dim, descending)),
+        }
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (primal, indices) = B::float_sort_with_indices(tensor.primal, dim, descending);
+        let tangent = gather_tangent::<B, D>(tensor.tangent, dim, indices.clone());
+
+        (DualTensor { primal, tangent }, indices)
+    }
+
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::float_argsort(tensor.primal, dim, descending)
+    }
+
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        linear(tensor, |tensor| B::float_cumsum(tensor, dim))
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        let primal = B::float_cumprod(tensor.primal.clone(), dim);
+
+        let tangent = tensor.tangent.map(|tangent| {
+            let zeros = CumProdZeros::<B, D>::new(tensor.primal, dim);
+
+            // Before the first zero, the partial products are the outputs divided by the value
+            let before = B::float_cumsum(B::float_div(tangent.clone(), zeros.nonzero), dim);
+            let before = B::float_mul(before, primal.clone());
+            let before = B::float_mask_fill(before, zeros.from_first_zero, 0.elem());
+
+            // From the first zero, only the tangent of the zero contributes to the output
+            let zero = B::float_mask_fill(tangent, zeros.not_first_zero, 0.elem());
+            let zero = B::float_mul(B::float_cumsum(zero, dim), zeros.products);
+
+            B::float_add(before, zero)
+        });
+
+        DualTensor { primal, tangent }
+    }
+
+    fn float_cummax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (primal, indices) = B::float_cummax(tensor.primal, dim);
+        let tangent = gather_tangent::<B, D>(tensor.tangent, dim, indices.clone());
+
+        (DualTensor { primal, tangent }, indices)
+    }
+
+    fn float_cummin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (primal, indices) = B::float_cummin(tensor.primal, dim);
+        let tangent = gather_tangent::<B, D>(tensor.tangent, dim, indices.clone());
+
+        (DualTensor { primal, tangent }, indices)
+    }
+}
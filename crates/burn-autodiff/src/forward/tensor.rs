@@ -0,0 +1,80 @@
+use burn_tensor::{backend::Backend, ops::FloatTensor, Tensor, TensorPrimitive};
+
+use super::ForwardAutodiff;
+
+/// Float tensor primitive of the [forward autodiff](ForwardAutodiff) backend.
+///
+/// The tangent is the derivative of the tensor in the direction of the input tangents. It is
+/// `None` when the tensor doesn't depend on any input with a tangent, which is equivalent to a
+/// tangent filled with zeros without the cost of propagating it.
+#[derive(Debug, Clone)]
+pub struct DualTensor<B: Backend, const D: usize> {
+    /// The value of the tensor.
+    pub primal: FloatTensor<B, D>,
+    /// The tangent of the tensor, with the same shape as the value.
+    pub tangent: Option<FloatTensor<B, D>>,
+}
+
+impl<B: Backend, const D: usize> DualTensor<B, D> {
+    /// Create a tensor with the given tangent.
+    pub fn new(primal: FloatTensor<B, D>, tangent: FloatTensor<B, D>) -> Self {
+        Self {
+            primal,
+            tangent: Some(tangent),
+        }
+    }
+
+    /// Create a tensor with a zero tangent.
+    pub fn constant(primal: FloatTensor<B, D>) -> Self {
+        Self {
+            primal,
+            tangent: None,
+        }
+    }
+
+    /// Returns the tangent, filled with zeros when it isn't tracked.
+    pub fn tangent_or_zeros(&self) -> FloatTensor<B, D> {
+        match &self.tangent {
+            Some(tangent) => tangent.clone(),
+            None => B::float_zeros(B::float_shape(&self.primal), &B::float_device(&self.primal)),
+        }
+    }
+}
+
+/// Create a tensor of the [forward autodiff](ForwardAutodiff) backend from its value and its
+/// tangent, which is the direction of the derivatives.
+///
+/// # Panics
+///
+/// If the tangent doesn't have the same shape as the value.
+pub fn dual<B: Backend, const D: usize>(
+    primal: Tensor<B, D>,
+    tangent: Tensor<B, D>,
+) -> Tensor<ForwardAutodiff<B>, D> {
+    assert_eq!(
+        primal.shape(),
+        tangent.shape(),
+        "The tangent must have the same shape as the value"
+    );
+
+    Tensor::from_primitive(TensorPrimitive::Float(DualTensor::new(
+        primal.into_primitive().tensor(),
+        tangent.into_primitive().tensor(),
+    )))
+}
+
+/// Returns the value of a tensor of the [forward autodiff](ForwardAutodiff) backend.
+pub fn primal<B: Backend, const D: usize>(tensor: Tensor<ForwardAutodiff<B>, D>) -> Tensor<B, D> {
+    Tensor::from_primitive(TensorPrimitive::Float(
+        tensor.into_primitive().tensor().primal,
+    ))
+}
+
+/// Returns the tangent of a tensor of the [forward autodiff](ForwardAutodiff) backend.
+///
+/// The tangent is filled with zeros when the tensor doesn't depend on any input with a tangent.
+pub fn tangent<B: Backend, const D: usize>(tensor: Tensor<ForwardAutodiff<B>, D>) -> Tensor<B, D> {
+    let tensor = tensor.into_primitive().tensor();
+
+    Tensor::from_primitive(TensorPrimitive::Float(tensor.tangent_or_zeros()))
+}
@@ -0,0 +1,158 @@
+use burn_tensor::{
+    backend::{AutodiffBackend, Backend},
+    Tensor, TensorPrimitive,
+};
+
+use crate::{
+    forward::{dual, tangent, ForwardAutodiff},
+    Autodiff,
+};
+
+/// Computes the Jacobian-vector product of a function with forward-mode differentiation.
+///
+/// # Arguments
+///
+/// * `func` - The function to differentiate.
+/// * `input` - The point where the function is differentiated.
+/// * `direction` - The tangent of the input, with the same shape as the input.
+///
+/// # Returns
+///
+/// The output of the function and its derivative in the given direction.
+pub fn jvp<B, const D1: usize, const D2: usize, F>(
+    func: F,
+    input: Tensor<B, D1>,
+    direction: Tensor<B, D1>,
+) -> (Tensor<B, D2>, Tensor<B, D2>)
+where
+    B: Backend,
+    F: FnOnce(Tensor<ForwardAutodiff<B>, D1>) -> Tensor<ForwardAutodiff<B>, D2>,
+{
+    let output = func(dual(input, direction)).into_primitive().tensor();
+    let tangent = output.tangent_or_zeros();
+
+    (
+        Tensor::from_primitive(TensorPrimitive::Float(output.primal)),
+        Tensor::from_primitive(TensorPrimitive::Float(tangent)),
+    )
+}
+
+/// Computes the vector-Jacobian product of a function with reverse-mode differentiation.
+///
+/// # Arguments
+///
+/// * `func` - The function to differentiate.
+/// * `input` - The point where the function is differentiated.
+/// * `cotangent` - The vector multiplying the Jacobian, with the same shape as the output.
+///
+/// # Returns
+///
+/// The output of the function and the gradient of the output weighted by the cotangent.
+pub fn vjp<B, const D1: usize, const D2: usize, F>(
+    func: F,
+    input: Tensor<B::InnerBackend, D1>,
+    cotangent: Tensor<B::InnerBackend, D2>,
+) -> (Tensor<B::InnerBackend, D2>, Tensor<B::InnerBackend, D1>)
+where
+    B: AutodiffBackend,
+    F: FnOnce(Tensor<B, D1>) -> Tensor<B, D2>,
+{
+    let input = Tensor::<B, D1>::from_inner(input).require_grad();
+    let output = func(input.clone());
+
+    let grads = output
+        .clone()
+        .mul(Tensor::from_inner(cotangent))
+        .sum()
+        .backward();
+    let grad = input
+        .grad(&grads)
+        .unwrap_or_else(|| input.inner().zeros_like());
+
+    (output.inner(), grad)
+}
+
+/// Computes the Jacobian matrix of a function with forward-mode differentiation.
+///
+/// The Jacobian is computed column by column, with a Jacobian-vector product for each element of
+/// the input.
+///
+/// # Arguments
+///
+/// * `func` - The function to differentiate.
+/// * `input` - The point where the function is differentiated.
+///
+/// # Returns
+///
+/// The Jacobian of shape `[m, n]`, where `m` and `n` are the number of elements of the output and
+/// the input. The elements are ordered as in the flattened tensors.
+pub fn jacobian<B, const D1: usize, const D2: usize, F>(
+    func: F,
+    input: Tensor<B, D1>,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+    F: Fn(Tensor<ForwardAutodiff<B>, D1>) -> Tensor<ForwardAutodiff<B>, D2>,
+{
+    let shape = input.shape();
+    let size = shape.num_elements();
+    let device = input.device();
+
+    let columns = (0..size)
+        .map(|index| {
+            let direction = Tensor::<B, 1>::one_hot(index, size, &device).reshape(shape.clone());
+            let (_, tangent) = jvp(&func, input.clone(), direction);
+            let size_out = tangent.shape().num_elements();
+
+            tangent.reshape([size_out])
+        })
+        .collect();
+
+    Tensor::stack(columns, 1)
+}
+
+/// Computes the Hessian matrix of a scalar function with forward-over-reverse differentiation.
+///
+/// The gradient of the function is computed by the [autodiff](Autodiff) backend, and
+/// differentiated by the [forward autodiff](ForwardAutodiff) backend in the direction of each
+/// element of the input.
+///
+/// # Arguments
+///
+/// * `func` - The function to differentiate, returning a single element.
+/// * `input` - The point where the function is differentiated.
+///
+/// # Returns
+///
+/// The Hessian of shape `[n, n]`, where `n` is the number of elements of the input. The elements
+/// are ordered as in the flattened input.
+pub fn hessian<B, const D: usize, F>(func: F, input: Tensor<B, D>) -> Tensor<B, 2>
+where
+    B: Backend,
+    F: Fn(Tensor<Autodiff<ForwardAutodiff<B>>, D>) -> Tensor<Autodiff<ForwardAutodiff<B>>, 1>,
+{
+    let shape = input.shape();
+    let size = shape.num_elements();
+    let device = input.device();
+
+    let columns = (0..size)
+        .map(|index| {
+            let direction = Tensor::<B, 1>::one_hot(index, size, &device).reshape(shape.clone());
+            let input = Tensor::<Autodiff<ForwardAutodiff<B>>, D>::from_inner(dual(
+                input.clone(),
+                direction,
+            ))
+            .require_grad();
+
+            let grads = func(input.clone()).backward();
+            let column = match input.grad(&grads) {
+                Some(grad) => tangent(grad),
+                None => Tensor::zeros(shape.clone(), &device),
+            };
+
+            column.reshape([size])
+        })
+        .collect();
+
+    Tensor::stack(columns, 1)
+}
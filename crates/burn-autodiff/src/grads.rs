@@ -62,6 +62,16 @@ impl Gradients {
             .map(|tensor| tensor.into_primitive().tensor())
     }
 
+    /// Removes the grad tensor of a node from the container.
+    pub(crate) fn remove_node<B: Backend, const D: usize>(
+        &mut self,
+        node_id: NodeID,
+    ) -> Option<TensorPrimitive<B, D>> {
+        self.container
+            .remove::<B, D>(&node_id.value)
+            .map(|tensor| tensor.into_primitive().tensor())
+    }
+
     /// Gets a grad tensor from the container.
     pub fn get<B: Backend, const D: usize>(
         &self,
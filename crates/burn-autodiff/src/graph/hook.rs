@@ -0,0 +1,33 @@
+use super::NodeID;
+use crate::grads::Gradients;
+use burn_tensor::backend::{Backend, GradientHook};
+use std::{collections::HashMap, sync::Arc};
+
+/// Function applied to the gradient of a node during the backward pass.
+pub trait Hook: Send + Sync {
+    /// Replaces the gradient of the node with the result of the hook, if it was computed.
+    fn apply(&self, node: NodeID, grads: &mut Gradients);
+    /// Address of the hook function, used to avoid registering the same hook twice on a node.
+    fn address(&self) -> usize;
+}
+
+pub type HookRef = Arc<dyn Hook>;
+pub type NodeHooks = HashMap<NodeID, Vec<HookRef>>;
+
+/// [Hook](Hook) calling a [gradient hook](GradientHook) of the tensor API.
+#[derive(new)]
+pub struct TensorHook<B: Backend, const D: usize> {
+    func: GradientHook<B, D>,
+}
+
+impl<B: Backend, const D: usize> Hook for TensorHook<B, D> {
+    fn apply(&self, node: NodeID, grads: &mut Gradients) {
+        if let Some(grad) = grads.remove_node::<B, D>(node) {
+            grads.register::<B, D>(node, (self.func)(grad));
+        }
+    }
+
+    fn address(&self) -> usize {
+        Arc::as_ptr(&self.func) as *const () as usize
+    }
+}
@@ -1,9 +1,11 @@
 mod base;
+mod hook;
 mod node;
 mod requirement;
 
 pub mod traversal;
 
 pub use base::*;
+pub use hook::*;
 pub use node::*;
 pub use requirement::*;
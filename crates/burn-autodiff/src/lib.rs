@@ -14,6 +14,10 @@ extern crate alloc;
 
 /// Checkpoint module.
 pub mod checkpoint;
+/// Forward-mode autodiff module.
+pub mod forward;
+/// Functional differentiation module.
+pub mod functional;
 /// Gradients module.
 pub mod grads;
 /// Operation module.
@@ -32,6 +36,7 @@ pub(crate) mod runtime;
 
 pub use backend::*;
 pub use bridge::*;
+pub use forward::ForwardAutodiff;
 
 #[cfg(feature = "export_tests")]
 mod tests;
@@ -125,6 +125,7 @@ impl<BO, B, C, const D: usize, const N: usize> OpsPrep<BO, B, (), C, D, N, Compu
 where
     B: Backend,
     BO: Backward<B, D, N, State = ()>,
+    C: CheckpointStrategy,
 {
     /// Prepare a stateless operation.
     pub fn stateless(
@@ -193,6 +194,7 @@ where
     B: Backend,
     S: Clone + Send + std::fmt::Debug + 'static,
     BO: Backward<B, D, N, State = S>,
+    C: CheckpointStrategy,
 {
     /// Finish the preparation of a tracked operation and returns the output tensor.
     pub fn finish(
@@ -200,12 +202,9 @@ where
         state: S,
         output: <B as Backend>::FloatTensorPrimitive<D>,
     ) -> AutodiffTensor<B, D> {
-        let output = AutodiffTensor::from_parents(
-            output,
-            &self.nodes,
-            self.requirement,
-            self.compute_property,
-        );
+        let compute_property = C::output_property::<B, D>(self.compute_property, &output);
+        let output =
+            AutodiffTensor::from_parents(output, &self.nodes, self.requirement, compute_property);
         let parents = self.nodes.map(|node| node.clone_if_require_grad());
         let ops = Ops::new(parents, output.node.clone(), state);
 
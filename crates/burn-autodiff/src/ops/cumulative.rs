@@ -0,0 +1,134 @@
+use super::{unary, Backward, Ops};
+use crate::{checkpoint::base::Checkpointer, grads::Gradients};
+use burn_tensor::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor},
+    ElementConversion, Shape,
+};
+
+/// Cumulative sum of the values in reverse order along the dimension.
+fn reverse_cumsum<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+    dim: usize,
+) -> FloatTensor<B, D> {
+    let tensor = B::float_cumsum(B::float_flip(tensor, &[dim]), dim);
+    B::float_flip(tensor, &[dim])
+}
+
+/// The zero values of the input of a cumulative product along the dimension.
+///
+/// The partial products can't be recovered by dividing the output by the input values once a zero
+/// is reached, so the values before the first zero and the first zero are handled separately. The
+/// following values have no effect on the output, since the products all include the first zero.
+pub(crate) struct CumProdZeros<B: Backend, const D: usize> {
+    /// The input values before the first zero, and one from the first zero.
+    pub(crate) nonzero: FloatTensor<B, D>,
+    /// True from the first zero.
+    pub(crate) from_first_zero: BoolTensor<B, D>,
+    /// True everywhere except at the first zero.
+    pub(crate) not_first_zero: BoolTensor<B, D>,
+    /// Cumulative product where the first zero is replaced by one, i.e., the product of all the
+    /// other values from the first zero.
+    pub(crate) products: FloatTensor<B, D>,
+}
+
+impl<B: Backend, const D: usize> CumProdZeros<B, D> {
+    pub(crate) fn new(input: FloatTensor<B, D>, dim: usize) -> Self {
+        let zeros = B::float_equal_elem(input.clone(), 0.elem());
+        let num_zeros = B::float_cumsum(B::bool_into_float(zeros.clone()), dim);
+
+        // Twice the number of zeros so far, minus one at the zeros, is one only at the first zero
+        let first_zero = B::float_sub(
+            B::float_mul_scalar(num_zeros.clone(), 2.elem()),
+            B::bool_into_float(zeros),
+        );
+        let first_zero = B::float_equal_elem(first_zero, 1.elem());
+        let from_first_zero = B::float_not_equal_elem(num_zeros, 0.elem());
+
+        Self {
+            nonzero: B::float_mask_fill(input.clone(), from_first_zero.clone(), 1.elem()),
+            from_first_zero,
+            products: B::float_cumprod(
+                B::float_mask_fill(input, first_zero.clone(), 1.elem()),
+                dim,
+            ),
+            not_first_zero: B::bool_not(first_zero),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct CumSum;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for CumSum {
+    type State = usize;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let dim = ops.state;
+
+        // Each value contributes to all the following outputs along the dimension
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            reverse_cumsum::<B, D>(grad, dim)
+        });
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct CumProd;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for CumProd {
+    type State = (FloatTensor<B, D>, FloatTensor<B, D>, usize);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (input, output, dim) = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let zeros = CumProdZeros::<B, D>::new(input, dim);
+
+            // Before the first zero, the partial products are the outputs divided by the value
+            let grad_before = reverse_cumsum::<B, D>(B::float_mul(grad.clone(), output), dim);
+            let grad_before = B::float_div(grad_before, zeros.nonzero);
+            let grad_before = B::float_mask_fill(grad_before, zeros.from_first_zero, 0.elem());
+
+            // The first zero receives the products of all the other values, and the following
+            // values receive no gradient
+            let grad_zero = reverse_cumsum::<B, D>(B::float_mul(grad, zeros.products), dim);
+            let grad_zero = B::float_mask_fill(grad_zero, zeros.not_first_zero, 0.elem());
+
+            B::float_add(grad_before, grad_zero)
+        });
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct CumMaxMin;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for CumMaxMin {
+    type State = (B::IntTensorPrimitive<D>, Shape<D>, usize);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let (indices, shape, dim) = ops.state;
+            let device = B::float_device(&grad);
+            let zeros = B::float_zeros(shape, &device);
+
+            // Each output selects a single input value, which receives the sum of the gradients
+            B::float_scatter(dim, zeros, indices, grad)
+        });
+    }
+}
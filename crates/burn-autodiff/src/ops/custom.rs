@@ -0,0 +1,180 @@
+use core::{array, marker::PhantomData, mem::size_of};
+
+use crate::{
+    checkpoint::{
+        base::Checkpointer, retro_forward::RetroForward, state::BackwardStates,
+        strategy::CheckpointStrategy,
+    },
+    grads::Gradients,
+    graph::NodeID,
+    ops::{Backward, Ops, OpsKind},
+    tensor::AutodiffTensor,
+};
+use burn_tensor::backend::{Backend, CustomBackward, CustomForward};
+use std::sync::{Arc, Mutex};
+
+struct Custom<const D_IN: usize, S> {
+    state: PhantomData<S>,
+}
+
+impl<const D_IN: usize, S> core::fmt::Debug for Custom<D_IN, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Custom")
+    }
+}
+
+/// Slot filled with the saved state when the output of a memory bound operation is recomputed.
+type RecomputedState<S> = Arc<Mutex<Option<S>>>;
+
+#[derive(Clone)]
+enum SavedState<S> {
+    /// The state returned by the forward pass.
+    Kept(S),
+    /// The state of a memory bound operation, recomputed during the backward pass. It is taken
+    /// from the slot when the output was recomputed by the retro forward, so the forward function
+    /// is called only once.
+    Recomputed(RecomputedState<S>),
+}
+
+#[derive(Clone)]
+struct CustomState<B: Backend, const N: usize, const D_IN: usize, const D_OUT: usize, S> {
+    inputs: [NodeID; N],
+    saved: SavedState<S>,
+    forward: CustomForward<B, N, D_IN, D_OUT, S>,
+    backward: CustomBackward<B, N, D_IN, D_OUT, S>,
+}
+
+impl<B: Backend, const N: usize, const D_IN: usize, const D_OUT: usize, S> core::fmt::Debug
+    for CustomState<B, N, D_IN, D_OUT, S>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CustomState")
+            .field("inputs", &self.inputs)
+            .finish()
+    }
+}
+
+struct RetroCustom<B: Backend, const N: usize, const D_IN: usize, const D_OUT: usize, S> {
+    inputs: [NodeID; N],
+    saved: Option<RecomputedState<S>>,
+    forward: CustomForward<B, N, D_IN, D_OUT, S>,
+}
+
+impl<B: Backend, const N: usize, const D_IN: usize, const D_OUT: usize, S> core::fmt::Debug
+    for RetroCustom<B, N, D_IN, D_OUT, S>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RetroCustom")
+            .field("inputs", &self.inputs)
+            .finish()
+    }
+}
+
+impl<B: Backend, const N: usize, const D_IN: usize, const D_OUT: usize, S> RetroForward
+    for RetroCustom<B, N, D_IN, D_OUT, S>
+where
+    S: Send + 'static,
+{
+    fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+        let inputs = self
+            .inputs
+            .map(|input| states.get_state::<B::FloatTensorPrimitive<D_IN>>(&input));
+        let (out, saved) = (self.forward)(inputs);
+
+        if let Some(slot) = &self.saved {
+            *slot.lock().unwrap() = Some(saved);
+        }
+
+        states.save(out_node, out)
+    }
+}
+
+impl<B: Backend, const N: usize, const D_IN: usize, const D_OUT: usize, S> Backward<B, D_OUT, N>
+    for Custom<D_IN, S>
+where
+    S: Clone + Send + 'static,
+{
+    type State = CustomState<B, N, D_IN, D_OUT, S>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, N>,
+        grads: &mut Gradients,
+        checkpointer: &mut Checkpointer,
+    ) {
+        let inputs = ops
+            .state
+            .inputs
+            .map(|input| checkpointer.retrieve_node_output(input));
+        let saved = match ops.state.saved {
+            SavedState::Kept(saved) => saved,
+            SavedState::Recomputed(slot) => {
+                let recomputed = slot.lock().unwrap().take();
+                match recomputed {
+                    Some(saved) => saved,
+                    None => (ops.state.forward)(inputs.clone()).1,
+                }
+            }
+        };
+        let grad = grads.consume::<B, D_OUT>(&ops.node);
+        let grads_inputs = (ops.state.backward)(inputs, saved, grad);
+
+        for (parent, grad) in ops.parents.into_iter().zip(grads_inputs) {
+            if let (Some(parent), Some(grad)) = (parent, grad) {
+                grads.register::<B, D_IN>(parent.id, grad);
+            }
+        }
+    }
+}
+
+/// Applies an operation with a custom backward function.
+pub(crate) fn custom_op<B, C, const N: usize, const D_IN: usize, const D_OUT: usize, S>(
+    inputs: [AutodiffTensor<B, D_IN>; N],
+    forward: CustomForward<B, N, D_IN, D_OUT, S>,
+    backward: CustomBackward<B, N, D_IN, D_OUT, S>,
+    memory_bound: bool,
+) -> AutodiffTensor<B, D_OUT>
+where
+    B: Backend,
+    C: CheckpointStrategy,
+    S: Clone + Send + 'static,
+{
+    let nodes = array::from_fn(|i| inputs[i].node.clone());
+    let custom = Custom::<D_IN, S> { state: PhantomData };
+    // Empty states, e.g. `()`, are kept since they don't need to be recomputed.
+    let recomputed: Option<RecomputedState<S>> = match memory_bound && size_of::<S>() > 0 {
+        true => Some(Arc::new(Mutex::new(None))),
+        false => None,
+    };
+    let prep = match memory_bound {
+        true => custom
+            .prepare::<C>(nodes)
+            .memory_bound()
+            .retro_forward(RetroCustom::<B, N, D_IN, D_OUT, S> {
+                inputs: array::from_fn(|i| inputs[i].node.id),
+                saved: recomputed.clone(),
+                forward: forward.clone(),
+            })
+            .parents(inputs.iter())
+            .stateful(),
+        false => custom.prepare::<C>(nodes).compute_bound().stateful(),
+    };
+
+    match prep {
+        OpsKind::Tracked(mut prep) => {
+            let input_ids = array::from_fn(|i| prep.checkpoint(&inputs[i]));
+            let (output, saved) = forward(inputs.map(|input| input.primitive));
+            let state = CustomState {
+                inputs: input_ids,
+                saved: match recomputed {
+                    Some(slot) => SavedState::Recomputed(slot),
+                    None => SavedState::Kept(saved),
+                },
+                forward,
+                backward,
+            };
+            prep.finish(state, output)
+        }
+        OpsKind::UnTracked(prep) => prep.finish(forward(inputs.map(|input| input.primitive)).0),
+    }
+}
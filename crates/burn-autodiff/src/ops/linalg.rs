@@ -0,0 +1,526 @@
+use crate::{
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    ops::{binary, unary, Backward, Ops, OpsKind},
+    Autodiff,
+};
+use burn_tensor::{
+    backend::Backend,
+    linalg,
+    ops::{FloatTensor, LinalgOps},
+    Tensor, TensorPrimitive,
+};
+
+pub(crate) fn tensor<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> Tensor<B, D> {
+    Tensor::from_primitive(TensorPrimitive::Float(tensor))
+}
+
+pub(crate) fn primitive<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> FloatTensor<B, D> {
+    tensor.into_primitive().tensor()
+}
+
+/// Identity matrix that can be broadcast to the batched matrices.
+pub(crate) fn eye<B: Backend, const D: usize>(size: usize, device: &B::Device) -> Tensor<B, D> {
+    Tensor::<B, 2>::eye(size, device).unsqueeze()
+}
+
+/// Element-wise inverse of the differences, with zeros on the diagonal.
+///
+/// The values are infinite for repeated (singular or eigen) values, where the gradient of the
+/// vectors is undefined.
+pub(crate) fn inverse_differences<B: Backend, const D: usize>(
+    differences: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let size = differences.dims()[D - 1];
+    let eye = eye::<B, D>(size, &differences.device());
+
+    differences
+        .add(eye.clone())
+        .recip()
+        .mul(eye.neg().add_scalar(1.0))
+}
+
+/// Returns `x r^{-T}` for the upper triangular matrices `r`.
+fn mul_inverse_transpose<B: Backend, const D: usize>(
+    x: Tensor<B, D>,
+    r: Tensor<B, D>,
+) -> Tensor<B, D> {
+    linalg::solve(r, x.transpose()).transpose()
+}
+
+/// Returns the lower triangular part of `m` with the strictly lower part copied to the upper part.
+fn copy_lower_to_upper<B: Backend, const D: usize>(m: Tensor<B, D>) -> Tensor<B, D> {
+    m.clone().tril(0).add(m.tril(-1).transpose())
+}
+
+#[derive(Debug)]
+struct Solve;
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for Solve {
+    type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 2>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (a, x) = ops.state;
+        let a_transposed = tensor::<B, D>(a).transpose();
+        let a_transposed_rhs = a_transposed.clone();
+
+        // x = a^{-1} b, so the gradient of b is a^{-T} g and the gradient of a is -g_b x^T
+        binary::<B, D, D, D, _, _>(
+            ops.parents,
+            ops.node,
+            grads,
+            |grad| {
+                let grad_b = linalg::solve(a_transposed, tensor(grad));
+                primitive(grad_b.matmul(tensor::<B, D>(x).transpose()).neg())
+            },
+            |grad| primitive(linalg::solve(a_transposed_rhs, tensor(grad))),
+        );
+    }
+}
+
+#[derive(Debug)]
+struct Inverse;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Inverse {
+    type State = FloatTensor<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let output = tensor::<B, D>(ops.state).transpose();
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            primitive(output.clone().matmul(tensor(grad)).matmul(output).neg())
+        });
+    }
+}
+
+#[derive(Debug)]
+struct Det;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Det {
+    type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (a, det) = ops.state;
+
+        // The gradient is undefined (non-finite) for singular matrices
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let inverse = linalg::inverse(tensor::<B, D>(a)).transpose();
+            primitive(inverse.mul(tensor::<B, D>(grad).mul(tensor(det))))
+        });
+    }
+}
+
+#[derive(Debug)]
+struct Logdet;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Logdet {
+    type State = FloatTensor<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let a = ops.state;
+
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let inverse = linalg::inverse(tensor::<B, D>(a)).transpose();
+            primitive(inverse.mul(tensor(grad)))
+        });
+    }
+}
+
+#[derive(Debug)]
+struct Cholesky;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Cholesky {
+    type State = FloatTensor<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let l = tensor::<B, D>(ops.state);
+
+        // grad_a = sym(l^{-T} phi(l^T grad) l^{-1}), where phi takes the lower triangular part
+        // and halves the diagonal
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let size = l.dims()[D - 1];
+            let half_diagonal = eye::<B, D>(size, &l.device())
+                .mul_scalar(-0.5)
+                .add_scalar(1.0);
+            let l_transposed = l.clone().transpose();
+
+            let phi = l_transposed
+                .clone()
+                .matmul(tensor(grad))
+                .tril(0)
+                .mul(half_diagonal);
+            let z = linalg::solve(l_transposed.clone(), phi);
+            let s = linalg::solve(l_transposed, z.transpose()).transpose();
+
+            primitive(s.clone().add(s.transpose()).mul_scalar(0.5))
+        });
+    }
+}
+
+/// Checks that the gradient of the QR decomposition is defined for the shape of the input.
+pub(crate) fn check_qr_backward<B: Backend, const D: usize>(q: &Tensor<B, D>, r: &Tensor<B, D>) {
+    assert!(
+        q.dims()[D - 2] >= r.dims()[D - 1],
+        "The gradient of the QR decomposition is only supported for matrices with at least as \
+         many rows as columns"
+    );
+}
+
+#[derive(Debug)]
+struct QrQ;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for QrQ {
+    type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (q, r) = ops.state;
+        let (q, r) = (tensor::<B, D>(q), tensor::<B, D>(r));
+        check_qr_backward(&q, &r);
+
+        // grad_a = (grad_q + q copyltu(-grad_q^T q)) r^{-T}
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let grad = tensor::<B, D>(grad);
+            let m = grad.clone().transpose().matmul(q.clone()).neg();
+            let grad = grad.add(q.matmul(copy_lower_to_upper(m)));
+
+            primitive(mul_inverse_transpose(grad, r))
+        });
+    }
+}
+
+#[derive(Debug)]
+struct QrR;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for QrR {
+    type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (q, r) = ops.state;
+        let (q, r) = (tensor::<B, D>(q), tensor::<B, D>(r));
+        check_qr_backward(&q, &r);
+
+        // grad_a = q copyltu(r grad_r^T) r^{-T}
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let m = r.clone().matmul(tensor::<B, D>(grad).transpose());
+            let grad = q.matmul(copy_lower_to_upper(m));
+
+            primitive(mul_inverse_transpose(grad, r))
+        });
+    }
+}
+
+#[derive(Debug)]
+struct SvdU;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for SvdU {
+    type State = (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (u, s, vh) = ops.state;
+        let (u, s, vh) = (tensor::<B, D>(u), tensor::<B, D>(s), tensor::<B, D>(vh));
+
+        // grad_a = u (f o (u^T grad_u - grad_u^T u)) s vh + (I - u u^T) grad_u s^{-1} vh
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let grad = tensor::<B, D>(grad);
+            let s_squared = s.clone().powf_scalar(2.0);
+            let f = inverse_differences(s_squared.clone().sub(s_squared.transpose()));
+
+            let ut_grad = u.clone().transpose().matmul(grad.clone());
+            let j = f.mul(ut_grad.clone().sub(ut_grad.clone().transpose()));
+            let projected = grad.sub(u.clone().matmul(ut_grad)).div(s.clone());
+            let grad = u.matmul(j.mul(s)).add(projected);
+
+            primitive(grad.matmul(vh))
+        });
+    }
+}
+
+#[derive(Debug)]
+struct SvdS;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for SvdS {
+    type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (u, vh) = ops.state;
+
+        // grad_a = u diag(grad_s) vh
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let u = tensor::<B, D>(u).mul(tensor(grad));
+            primitive(u.matmul(tensor(vh)))
+        });
+    }
+}
+
+#[derive(Debug)]
+struct SvdVh;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for SvdVh {
+    type State = (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (u, s, vh) = ops.state;
+        let (u, s, vh) = (tensor::<B, D>(u), tensor::<B, D>(s), tensor::<B, D>(vh));
+
+        // grad_a = u s (f o (v^T grad_v - grad_v^T v)) vh + u s^{-1} grad_v^T (I - v v^T)
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let grad = tensor::<B, D>(grad);
+            let s = s.transpose();
+            let s_squared = s.clone().powf_scalar(2.0);
+            let f = inverse_differences(s_squared.clone().transpose().sub(s_squared));
+
+            let grad_v = grad.clone().matmul(vh.clone().transpose());
+            let k = f.mul(grad_v.clone().transpose().sub(grad_v.clone()));
+            let projected = grad.sub(grad_v.matmul(vh.clone())).div(s.clone());
+            let grad = k.mul(s).matmul(vh).add(projected);
+
+            primitive(u.matmul(grad))
+        });
+    }
+}
+
+#[derive(Debug)]
+struct EighW;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for EighW {
+    type State = FloatTensor<B, D>;
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let v = tensor::<B, D>(ops.state);
+
+        // grad_a = v diag(grad_w) v^T
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            primitive(v.clone().mul(tensor(grad)).matmul(v.transpose()))
+        });
+    }
+}
+
+#[derive(Debug)]
+struct EighV;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for EighV {
+    type State = (FloatTensor<B, D>, FloatTensor<B, D>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (w, v) = ops.state;
+        let (w, v) = (tensor::<B, D>(w), tensor::<B, D>(v));
+
+        // grad_a = sym(v (f o (v^T grad_v)) v^T), since the input matrices are symmetric
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let f = inverse_differences(w.clone().sub(w.transpose()));
+            let grad = f.mul(v.clone().transpose().matmul(tensor(grad)));
+            let grad = v.clone().matmul(grad).matmul(v.transpose());
+
+            primitive(grad.clone().add(grad.transpose()).mul_scalar(0.5))
+        });
+    }
+}
+
+// The decompositions with multiple outputs register a node for each output, since the gradient of
+// the input is the sum of the contributions of each output.
+impl<B: Backend, C: CheckpointStrategy> LinalgOps<Self> for Autodiff<B, C> {
+    fn solve<const D: usize>(
+        a: FloatTensor<Self, D>,
+        b: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        match Solve
+            .prepare::<C>([a.node, b.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::solve(a.primitive.clone(), b.primitive);
+                prep.finish((a.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::solve(a.primitive, b.primitive)),
+        }
+    }
+
+    fn inverse<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        match Inverse
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::inverse(tensor.primitive);
+                prep.finish(output.clone(), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::inverse(tensor.primitive)),
+        }
+    }
+
+    fn det<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        match Det.prepare::<C>([tensor.node]).compute_bound().stateful() {
+            OpsKind::Tracked(prep) => {
+                let output = B::det(tensor.primitive.clone());
+                prep.finish((tensor.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::det(tensor.primitive)),
+        }
+    }
+
+    fn logdet<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        match Logdet
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::logdet(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::logdet(tensor.primitive)),
+        }
+    }
+
+    fn cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        match Cholesky
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::cholesky(tensor.primitive);
+                prep.finish(output.clone(), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::cholesky(tensor.primitive)),
+        }
+    }
+
+    fn qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (q, r) = B::qr(tensor.primitive);
+        let state = (q.clone(), r.clone());
+
+        let q = match QrQ
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(state.clone(), q),
+            OpsKind::UnTracked(prep) => prep.finish(q),
+        };
+        let r = match QrR.prepare::<C>([tensor.node]).compute_bound().stateful() {
+            OpsKind::Tracked(prep) => prep.finish(state, r),
+            OpsKind::UnTracked(prep) => prep.finish(r),
+        };
+
+        (q, r)
+    }
+
+    fn svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        let (u, s, vh) = B::svd(tensor.primitive);
+        let state = (u.clone(), s.clone(), vh.clone());
+
+        let u = match SvdU
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(state.clone(), u),
+            OpsKind::UnTracked(prep) => prep.finish(u),
+        };
+        let s = match SvdS
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((state.0.clone(), state.2.clone()), s),
+            OpsKind::UnTracked(prep) => prep.finish(s),
+        };
+        let vh = match SvdVh.prepare::<C>([tensor.node]).compute_bound().stateful() {
+            OpsKind::Tracked(prep) => prep.finish(state, vh),
+            OpsKind::UnTracked(prep) => prep.finish(vh),
+        };
+
+        (u, s, vh)
+    }
+
+    fn eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        let (w, v) = B::eigh(tensor.primitive);
+
+        let w_output = match EighW
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(v.clone(), w.clone()),
+            OpsKind::UnTracked(prep) => prep.finish(w.clone()),
+        };
+        let v_output = match EighV.prepare::<C>([tensor.node]).compute_bound().stateful() {
+            OpsKind::Tracked(prep) => prep.finish((w, v.clone()), v),
+            OpsKind::UnTracked(prep) => prep.finish(v),
+        };
+
+        (w_output, v_output)
+    }
+}
@@ -4,10 +4,13 @@ mod base;
 mod bool_tensor;
 mod int_tensor;
 mod module;
-mod qtensor;
 mod tensor;
 
+pub(crate) mod cumulative;
+pub(crate) mod custom;
+pub(crate) mod linalg;
 pub(crate) mod maxmin;
+pub(crate) mod qtensor;
 pub(crate) mod sort;
 
 pub use backward::*;
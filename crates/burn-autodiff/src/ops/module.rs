@@ -1186,6 +1186,301 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate adaptive avg pool2d backward.");
     }
 
+    fn avg_pool3d(
+        x: AutodiffTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> AutodiffTensor<B, 5> {
+        #[derive(Debug)]
+        struct AvgPool3D;
+
+        impl<B: Backend> Backward<B, 5, 1> for AvgPool3D {
+            type State = (NodeID, [usize; 3], [usize; 3], [usize; 3], bool);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+                let (x_state, kernel_size, stride, padding, count_include_pad) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::avg_pool3d_backward(
+                        x,
+                        grad,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                    );
+                    grads.register::<B, 5>(node.id, grad);
+                }
+            }
+        }
+
+        match AvgPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                prep.finish(
+                    (x_state, kernel_size, stride, padding, count_include_pad),
+                    B::avg_pool3d(
+                        x.primitive.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::avg_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+            )),
+        }
+    }
+
+    fn avg_pool3d_backward(
+        _x: AutodiffTensor<B, 5>,
+        _grad: AutodiffTensor<B, 5>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _count_include_pad: bool,
+    ) -> AutodiffTensor<B, 5> {
+        panic!("Can't differentiate avg pool 3d backward.");
+    }
+
+    fn adaptive_avg_pool3d(
+        x: AutodiffTensor<B, 5>,
+        output_size: [usize; 3],
+    ) -> AutodiffTensor<B, 5> {
+        #[derive(Debug)]
+        struct AdaptiveAvgPool3D;
+
+        impl<B: Backend> Backward<B, 5, 1> for AdaptiveAvgPool3D {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_parent] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+                let state = checkpointer.retrieve_node_output(ops.state);
+
+                if let Some(node) = node_parent {
+                    let grad = B::adaptive_avg_pool3d_backward(state, grad);
+                    grads.register::<B, 5>(node.id, grad);
+                }
+            }
+        }
+
+        match AdaptiveAvgPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                prep.finish(x_state, B::adaptive_avg_pool3d(x.primitive, output_size))
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::adaptive_avg_pool3d(x.primitive, output_size))
+            }
+        }
+    }
+
+    fn adaptive_avg_pool3d_backward(
+        _x: AutodiffTensor<B, 5>,
+        _grad: AutodiffTensor<B, 5>,
+    ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive<5> {
+        panic!("Can't differentiate adaptive avg pool3d backward.");
+    }
+
+    fn max_pool3d(
+        x: AutodiffTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> AutodiffTensor<B, 5> {
+        match MaxPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                prep.finish(
+                    (
+                        x_state,
+                        output.indices,
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                    ),
+                    output.output,
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::max_pool3d(
+                x.primitive,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+            )),
+        }
+    }
+
+    fn max_pool3d_with_indices(
+        x: AutodiffTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<Self> {
+        match MaxPool3D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+
+                let output_tensor = prep.finish(
+                    (
+                        x_state,
+                        output.indices.clone(),
+                        kernel_size,
+                        stride,
+                        padding,
+                        dilation,
+                    ),
+                    output.output,
+                );
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output =
+                    B::max_pool3d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool3dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn max_pool3d_with_indices_backward(
+        _x: AutodiffTensor<B, 5>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        _output_grad: AutodiffTensor<B, 5>,
+        _indices: IntTensor<B, 5>,
+    ) -> MaxPool3dBackward<Self> {
+        panic!("Can't differentiate max pool3d with indices backward.");
+    }
+
+    fn adaptive_max_pool1d(x: AutodiffTensor<B, 3>, output_size: usize) -> AutodiffTensor<B, 3> {
+        Self::adaptive_max_pool1d_with_indices(x, output_size).output
+    }
+
+    fn adaptive_max_pool1d_with_indices(
+        x: AutodiffTensor<B, 3>,
+        output_size: usize,
+    ) -> MaxPool1dWithIndices<Self> {
+        match AdaptiveMaxPool1D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output = B::adaptive_max_pool1d_with_indices(x.primitive, output_size);
+                let output_tensor = prep.finish((x_state, output.indices.clone()), output.output);
+
+                MaxPool1dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output = B::adaptive_max_pool1d_with_indices(x.primitive, output_size);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool1dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn adaptive_max_pool1d_with_indices_backward(
+        _x: AutodiffTensor<B, 3>,
+        _output_grad: AutodiffTensor<B, 3>,
+        _indices: IntTensor<B, 3>,
+    ) -> MaxPool1dBackward<Self> {
+        panic!("Can't differentiate adaptive max pool1d with indices backward.");
+    }
+
+    fn adaptive_max_pool2d(
+        x: AutodiffTensor<B, 4>,
+        output_size: [usize; 2],
+    ) -> AutodiffTensor<B, 4> {
+        Self::adaptive_max_pool2d_with_indices(x, output_size).output
+    }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: AutodiffTensor<B, 4>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        match AdaptiveMaxPool2D
+            .prepare::<C>([x.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let output = B::adaptive_max_pool2d_with_indices(x.primitive, output_size);
+                let output_tensor = prep.finish((x_state, output.indices.clone()), output.output);
+
+                MaxPool2dWithIndices::new(output_tensor, output.indices)
+            }
+            OpsKind::UnTracked(prep) => {
+                let output = B::adaptive_max_pool2d_with_indices(x.primitive, output_size);
+                let output_tensor = prep.finish(output.output);
+
+                MaxPool2dWithIndices::new(output_tensor, output.indices)
+            }
+        }
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        _x: AutodiffTensor<B, 4>,
+        _output_grad: AutodiffTensor<B, 4>,
+        _indices: IntTensor<B, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        panic!("Can't differentiate adaptive max pool2d with indices backward.");
+    }
+
     fn interpolate(
         x: AutodiffTensor<B, 4>,
         output_size: [usize; 2],
@@ -1239,6 +1534,69 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive<4> {
         panic!("Can't differentiate interpolate backward.");
     }
+
+    fn grid_sample_2d(
+        x: AutodiffTensor<B, 4>,
+        grid: AutodiffTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> AutodiffTensor<B, 4> {
+        #[derive(Debug)]
+        struct GridSample2D;
+
+        impl<B: Backend> Backward<B, 4, 2> for GridSample2D {
+            type State = (NodeID, NodeID, GridSampleOptions);
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_x, node_grid] = ops.parents;
+                let grad = grads.consume::<B, 4>(&ops.node);
+
+                let (x_state, grid_state, options) = ops.state;
+                let x = checkpointer.retrieve_node_output(x_state);
+                let grid = checkpointer.retrieve_node_output(grid_state);
+
+                let backward = B::grid_sample_2d_backward(x, grid, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 4>(node.id, backward.x_grad)
+                }
+                if let Some(node) = node_grid {
+                    grads.register::<B, 4>(node.id, backward.grid_grad)
+                }
+            }
+        }
+
+        match GridSample2D
+            .prepare::<C>([x.node.clone(), grid.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let x_state = prep.checkpoint(&x);
+                let grid_state = prep.checkpoint(&grid);
+                prep.finish(
+                    (x_state, grid_state, options.clone()),
+                    B::grid_sample_2d(x.primitive, grid.primitive, options),
+                )
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::grid_sample_2d(x.primitive, grid.primitive, options))
+            }
+        }
+    }
+
+    fn grid_sample_2d_backward(
+        _x: AutodiffTensor<B, 4>,
+        _grid: AutodiffTensor<B, 4>,
+        _grad: AutodiffTensor<B, 4>,
+        _options: GridSampleOptions,
+    ) -> GridSample2dBackward<Autodiff<B, C>> {
+        panic!("Can't differentiate grid sample 2d backward.");
+    }
 }
 
 #[derive(Debug)]
@@ -1313,3 +1671,93 @@ impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
         }
     }
 }
+
+#[derive(Debug)]
+struct MaxPool3D;
+
+impl<B: Backend> Backward<B, 5, 1> for MaxPool3D {
+    type State = (
+        NodeID,
+        IntTensor<B, 5>,
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+        [usize; 3],
+    );
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        checkpointer: &mut Checkpointer,
+    ) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B, 5>(&ops.node);
+        let (x_state, indices, kernel_size, stride, padding, dilation) = ops.state;
+        let x = checkpointer.retrieve_node_output(x_state);
+
+        if let Some(node) = node_parent {
+            let grad = B::max_pool3d_with_indices_backward(
+                x,
+                kernel_size,
+                stride,
+                padding,
+                dilation,
+                grad,
+                indices,
+            );
+
+            grads.register::<B, 5>(node.id, grad.x_grad);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AdaptiveMaxPool1D;
+
+impl<B: Backend> Backward<B, 3, 1> for AdaptiveMaxPool1D {
+    type State = (NodeID, IntTensor<B, 3>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        checkpointer: &mut Checkpointer,
+    ) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B, 3>(&ops.node);
+        let (x_state, indices) = ops.state;
+        let x = checkpointer.retrieve_node_output(x_state);
+
+        if let Some(node) = node_parent {
+            let grad = B::adaptive_max_pool1d_with_indices_backward(x, grad, indices);
+
+            grads.register::<B, 3>(node.id, grad.x_grad);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AdaptiveMaxPool2D;
+
+impl<B: Backend> Backward<B, 4, 1> for AdaptiveMaxPool2D {
+    type State = (NodeID, IntTensor<B, 4>);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        checkpointer: &mut Checkpointer,
+    ) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B, 4>(&ops.node);
+        let (x_state, indices) = ops.state;
+        let x = checkpointer.retrieve_node_output(x_state);
+
+        if let Some(node) = node_parent {
+            let grad = B::adaptive_max_pool2d_with_indices_backward(x, grad, indices);
+
+            grads.register::<B, 4>(node.id, grad.x_grad);
+        }
+    }
+}
@@ -1,30 +1,151 @@
 use burn_tensor::{
     backend::Backend,
-    ops::{FloatTensor, QTensorOps, QuantizedTensor},
-    quantization::{QuantizationParametersPrimitive, QuantizationScheme},
-    Device, Shape, TensorData,
+    ops::{BoolTensor, FloatTensor, QTensorOps, QuantizedTensor},
+    quantization::{QuantizationGroups, QuantizationParametersPrimitive, QuantizationScheme},
+    Device, ElementConversion, Shape, TensorData,
 };
 
-use crate::{checkpoint::strategy::CheckpointStrategy, Autodiff};
+use crate::{
+    checkpoint::{base::Checkpointer, strategy::CheckpointStrategy},
+    grads::Gradients,
+    ops::{unary, Backward, Ops, OpsKind},
+    tensor::AutodiffTensor,
+    Autodiff,
+};
+
+/// Returns the quantization parameters of the inner backend.
+fn inner_qparams<B: Backend, C: CheckpointStrategy>(
+    qparams: QuantizationParametersPrimitive<Autodiff<B, C>>,
+) -> QuantizationParametersPrimitive<B> {
+    QuantizationParametersPrimitive {
+        scale: qparams.scale.primitive,
+        offset: qparams.offset,
+    }
+}
+
+/// Reshape the quantization parameters so that they can be broadcast to the tensor values.
+fn broadcast_params<B: Backend, const D: usize>(
+    params: FloatTensor<B, 1>,
+    groups: Option<QuantizationGroups>,
+    shape: &Shape<D>,
+) -> FloatTensor<B, D> {
+    match groups {
+        None => B::float_reshape(params, Shape::new([1; D])),
+        Some(QuantizationGroups::Channel { axis }) => {
+            let mut dims = [1; D];
+            dims[axis] = shape.dims[axis];
+            B::float_reshape(params, Shape::new(dims))
+        }
+        Some(QuantizationGroups::Block { size }) => {
+            let num_blocks = shape.num_elements() / size;
+            let params = B::float_reshape(params, Shape::new([num_blocks, 1]));
+            let params = B::float_expand(params, Shape::new([num_blocks, size]));
+            B::float_reshape(params, shape.clone())
+        }
+    }
+}
+
+/// Returns the mask of the values outside of the quantization range, which are clipped by the
+/// quantization.
+pub(crate) fn clipped_values<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+    scheme: &QuantizationScheme,
+    qparams: &QuantizationParametersPrimitive<B>,
+) -> BoolTensor<B, D> {
+    let groups = scheme.groups();
+    let shape = B::float_shape(&tensor);
+
+    // Quantized range `[a, b]`
+    let (a, b) = match scheme.is_affine() {
+        true => scheme.dtype().affine_range(),
+        false => scheme.dtype().symmetric_range(),
+    };
+
+    // Input range `[(a - offset) * scale, (b - offset) * scale]`
+    let scale = broadcast_params(qparams.scale.clone(), groups, &shape);
+    let (lower, upper) = match &qparams.offset {
+        Some(offset) => {
+            let offset = broadcast_params(B::int_into_float(offset.clone()), groups, &shape);
+            let offset = B::float_neg(offset);
+            (
+                B::float_mul(B::float_add_scalar(offset.clone(), a.elem()), scale.clone()),
+                B::float_mul(B::float_add_scalar(offset, b.elem()), scale),
+            )
+        }
+        None => (
+            B::float_mul_scalar(scale.clone(), a.elem()),
+            B::float_mul_scalar(scale, b.elem()),
+        ),
+    };
+
+    // The value is in range when `(x - lower) * (upper - x) >= 0`
+    let distance = B::float_mul(
+        B::float_sub(tensor.clone(), lower),
+        B::float_sub(upper, tensor),
+    );
+    B::float_lower_elem(distance, 0.elem())
+}
 
 impl<B: Backend, C: CheckpointStrategy> QTensorOps<Self> for Autodiff<B, C> {
     fn q_from_data<const D: usize>(
-        _data: TensorData,
-        _device: &Device<Self>,
+        data: TensorData,
+        device: &Device<Self>,
     ) -> QuantizedTensor<Self, D> {
-        todo!()
+        B::q_from_data(data, device)
     }
 
     fn quantize<const D: usize>(
-        _tensor: FloatTensor<Self, D>,
-        _scheme: &QuantizationScheme,
-        _qparams: QuantizationParametersPrimitive<Self>,
+        tensor: FloatTensor<Self, D>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
     ) -> QuantizedTensor<Self, D> {
-        todo!() // required for QAT
+        // Quantization isn't differentiable, see `fake_quantize` for quantization-aware training
+        B::quantize(tensor.primitive, scheme, inner_qparams(qparams))
+    }
+
+    fn dequantize<const D: usize>(tensor: QuantizedTensor<Self, D>) -> FloatTensor<Self, D> {
+        AutodiffTensor::new(B::dequantize(tensor))
     }
 
-    fn dequantize<const D: usize>(_tensor: QuantizedTensor<Self, D>) -> FloatTensor<Self, D> {
-        todo!()
+    fn fake_quantize<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct FakeQuantize;
+
+        // Straight-through estimator, where the gradient of the clipped values is zero
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for FakeQuantize {
+            type State = BoolTensor<B, D>;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mask_fill(grad, ops.state, 0.elem())
+                });
+            }
+        }
+
+        let qparams = inner_qparams(qparams);
+
+        match FakeQuantize
+            .prepare::<C>([tensor.node])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let clipped = clipped_values(tensor.primitive.clone(), scheme, &qparams);
+                prep.finish(clipped, B::fake_quantize(tensor.primitive, scheme, qparams))
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::fake_quantize(tensor.primitive, scheme, qparams))
+            }
+        }
     }
 
     fn q_shape<const D: usize>(tensor: &QuantizedTensor<Self, D>) -> Shape<D> {
@@ -1,8 +1,9 @@
 use crate::{
     checkpoint::builder::CheckpointerBuilder,
     grads::Gradients,
-    graph::StepBoxed,
+    graph::{HookRef, StepBoxed},
     tensor::{AutodiffTensor, NodeRefCount},
+    NodeID,
 };
 use burn_tensor::backend::Backend;
 
@@ -10,6 +11,8 @@ use burn_tensor::backend::Backend;
 pub trait AutodiffClient: Send + Clone {
     /// Register a new step.
     fn register(&self, node_id: NodeRefCount, step: StepBoxed, actions: CheckpointerBuilder);
+    /// Register a hook applied to the gradient of a node during backpropagation.
+    fn register_hook(&self, node_id: NodeID, hook: HookRef);
     /// Call backpropagation from the given tensor.
     fn backward<B: Backend, const D: usize>(&self, tensor: AutodiffTensor<B, D>) -> Gradients;
 }
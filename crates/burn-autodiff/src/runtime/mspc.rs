@@ -2,7 +2,7 @@ use super::{server::AutodiffServer, AutodiffClient};
 use crate::{
     checkpoint::builder::CheckpointerBuilder,
     grads::Gradients,
-    graph::StepBoxed,
+    graph::{HookRef, StepBoxed},
     tensor::{AutodiffTensor, NodeRefCount},
     NodeID,
 };
@@ -22,6 +22,10 @@ enum Message {
         step: StepBoxed,
         actions: CheckpointerBuilder,
     },
+    RegisterHook {
+        node_id: NodeID,
+        hook: HookRef,
+    },
     Backward {
         node_id: NodeID,
         grads: Gradients,
@@ -46,6 +50,7 @@ impl ChannelClient {
                         step,
                         actions,
                     } => server.register(node_id, step, actions),
+                    Message::RegisterHook { node_id, hook } => server.register_hook(node_id, hook),
                     Message::Backward {
                         node_id,
                         grads,
@@ -73,6 +78,12 @@ impl AutodiffClient for ChannelClient {
             .unwrap()
     }
 
+    fn register_hook(&self, node_id: NodeID, hook: HookRef) {
+        self.sender
+            .send(Message::RegisterHook { node_id, hook })
+            .unwrap()
+    }
+
     fn backward<B: Backend, const D: usize>(&self, root: AutodiffTensor<B, D>) -> Gradients {
         let node_id = root.node.id;
         let grads = Gradients::new::<B, D>(root.node, root.primitive);
@@ -2,8 +2,9 @@ use super::{server::AutodiffServer, AutodiffClient};
 use crate::{
     checkpoint::builder::CheckpointerBuilder,
     grads::Gradients,
-    graph::StepBoxed,
+    graph::{HookRef, StepBoxed},
     tensor::{AutodiffTensor, NodeRefCount},
+    NodeID,
 };
 use burn_tensor::backend::Backend;
 
@@ -31,18 +32,28 @@ impl AutodiffClient for MutexClient {
         server_new.register(node_id, step, actions);
         *server = Some(server_new);
     }
+    fn register_hook(&self, node_id: NodeID, hook: HookRef) {
+        SERVER
+            .lock()
+            .get_or_insert_with(AutodiffServer::default)
+            .register_hook(node_id, hook);
+    }
     fn backward<B: Backend, const D: usize>(&self, root: AutodiffTensor<B, D>) -> Gradients {
-        let mut server = SERVER.lock();
         let node_id = root.node.id;
         let grads = Gradients::new::<B, D>(root.node, root.primitive);
 
-        if let Some(server) = server.as_mut() {
-            return server.backward(grads, node_id);
-        }
+        let (tape, checkpointer, hooks) = SERVER
+            .lock()
+            .get_or_insert_with(AutodiffServer::default)
+            .prepare_backward(node_id);
 
-        let mut server_new = AutodiffServer::default();
-        let gradients = server_new.backward(grads, node_id);
-        *server = Some(server_new);
+        // The lock is released while executing the steps, since an inner autodiff backend
+        // registers the operations of the backward pass on the same server.
+        let gradients = AutodiffServer::execute_steps(tape, grads, checkpointer, hooks);
+
+        if let Some(server) = SERVER.lock().as_mut() {
+            server.cleanup();
+        }
 
         gradients
     }
@@ -2,7 +2,7 @@ use super::memory_management::GraphMemoryManagement;
 use crate::{
     checkpoint::{base::Checkpointer, builder::CheckpointerBuilder},
     grads::Gradients,
-    graph::{traversal::BreadthFirstSearch, StepBoxed},
+    graph::{traversal::BreadthFirstSearch, HookRef, NodeHooks, StepBoxed},
     tensor::NodeRefCount,
     NodeID,
 };
@@ -13,6 +13,7 @@ pub struct AutodiffServer {
     steps: HashMap<NodeID, StepBoxed>,
     actions_builder: HashMap<NodeID, CheckpointerBuilder>,
     memory_management: GraphMemoryManagement,
+    hooks: NodeHooks,
 }
 
 impl AutodiffServer {
@@ -26,26 +27,54 @@ impl AutodiffServer {
         self.actions_builder.insert(node_id, actions);
     }
 
+    /// Registers a hook applied to the gradient of the given node during the backward pass.
+    ///
+    /// Nothing is registered if the same hook is already registered on the node.
+    pub fn register_hook(&mut self, node_id: NodeID, hook: HookRef) {
+        let hooks = self.hooks.entry(node_id).or_default();
+
+        if !hooks.iter().any(|item| item.address() == hook.address()) {
+            hooks.push(hook);
+        }
+    }
+
     pub fn backward(&mut self, grads: Gradients, node_id: NodeID) -> Gradients {
+        let (tape, checkpointer, hooks) = self.prepare_backward(node_id);
+        let gradients = Self::execute_steps(tape, grads, checkpointer, hooks);
+        self.cleanup();
+
+        gradients
+    }
+
+    /// Removes the steps required to compute the gradients of the given node from the graph.
+    ///
+    /// The returned tape can be executed with [execute_steps](Self::execute_steps) without
+    /// borrowing the server, which lets the backward pass register new steps, as done when
+    /// nesting autodiff backends to compute higher-order gradients.
+    pub fn prepare_backward(
+        &mut self,
+        node_id: NodeID,
+    ) -> (Vec<Vec<StepBoxed>>, Checkpointer, NodeHooks) {
         let step = self.steps.remove(&node_id).expect(
             "Node should have a step registered, did you forget to call \
              `Tensor::register_grad` on the tensor where you need gradients?",
         );
         let builder = self.actions_builder.remove(&node_id).unwrap();
 
-        let (tape, builder) = self.build_tape(node_id, step, builder);
+        let (tape, builder, hooks) = self.build_tape(node_id, step, builder);
         let checkpointer = builder.build(&self.steps);
 
-        let gradients = Self::execute_steps(tape, grads, checkpointer);
+        (tape, checkpointer, hooks)
+    }
 
-        // Cleanup
+    /// Frees the nodes that can't be used in a backward pass anymore.
+    pub fn cleanup(&mut self) {
         self.memory_management
             .free_unavailable_nodes(|node_id: &NodeID| {
                 self.steps.remove(node_id);
                 self.actions_builder.remove(node_id);
+                self.hooks.remove(node_id);
             });
-
-        gradients
     }
 
     fn build_tape(
@@ -53,14 +82,21 @@ impl AutodiffServer {
         node: NodeID,
         node_step: StepBoxed,
         mut builder: CheckpointerBuilder,
-    ) -> (Vec<Vec<StepBoxed>>, CheckpointerBuilder) {
+    ) -> (Vec<Vec<StepBoxed>>, CheckpointerBuilder, NodeHooks) {
         let mut tape = (0..node_step.depth())
             .map(|_| Vec::with_capacity(1))
             .collect::<Vec<_>>();
+        let mut hooks = NodeHooks::new();
 
         BreadthFirstSearch.traverse(node, node_step, &mut self.steps, |id, step| {
             self.memory_management.consume_node(id);
 
+            // Only the hooks of the nodes in the graph are taken, since consumed nodes can't be
+            // part of another graph.
+            if let Some(node_hooks) = self.hooks.remove(&id) {
+                hooks.insert(id, node_hooks);
+            }
+
             let depth = step.depth();
             if depth == 0 {
                 return;
@@ -75,20 +111,38 @@ impl AutodiffServer {
             }
         });
 
-        (tape, builder)
+        (tape, builder, hooks)
     }
 
-    fn execute_steps(
+    pub fn execute_steps(
         tape: Vec<Vec<StepBoxed>>,
         mut grads: Gradients,
         mut checkpointer: Checkpointer,
+        mut hooks: NodeHooks,
     ) -> Gradients {
+        let mut apply_hooks = |node_id: NodeID, grads: &mut Gradients| {
+            for hook in hooks.remove(&node_id).into_iter().flatten() {
+                hook.apply(node_id, grads);
+            }
+        };
+
         tape.into_iter().rev().for_each(|steps| {
-            steps
-                .into_iter()
-                .for_each(|step| step.step(&mut grads, &mut checkpointer))
+            steps.into_iter().for_each(|step| {
+                // The gradient of a node is complete once all the steps of its children are
+                // executed, which is the case before executing its own step.
+                apply_hooks(step.node(), &mut grads);
+                step.step(&mut grads, &mut checkpointer)
+            })
         });
 
+        // The remaining hooks are the ones of the leaves, whose root steps have a depth of zero
+        // and aren't added to the tape.
+        for (node_id, node_hooks) in hooks.drain() {
+            for hook in node_hooks {
+                hook.apply(node_id, &mut grads);
+            }
+        }
+
         #[cfg(feature = "export_tests")]
         // For checkpointing tests
         assert!(checkpointer.is_empty());
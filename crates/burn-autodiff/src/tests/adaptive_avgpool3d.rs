@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool3d;
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_adaptive_avg_pool3d_simple() {
+        let device = Default::default();
+        let shape_x = Shape::new([1, 1, 3, 5, 4]);
+        let x = TestAutodiffTensor::from_data(
+            TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                .reshape(shape_x)
+                .into_data(),
+            &device,
+        )
+        .require_grad();
+        let x_grad_expected = TestTensor::<5>::from_floats(
+            [[[
+                [
+                    [0.1250, 0.1250, 0.1250, 0.1250],
+                    [0.2083, 0.2083, 0.2083, 0.2083],
+                    [0.0833, 0.0833, 0.0833, 0.0833],
+                    [0.2083, 0.2083, 0.2083, 0.2083],
+                    [0.1250, 0.1250, 0.1250, 0.1250],
+                ],
+                [
+                    [0.2500, 0.2500, 0.2500, 0.2500],
+                    [0.4167, 0.4167, 0.4167, 0.4167],
+                    [0.1667, 0.1667, 0.1667, 0.1667],
+                    [0.4167, 0.4167, 0.4167, 0.4167],
+                    [0.2500, 0.2500, 0.2500, 0.2500],
+                ],
+                [
+                    [0.1250, 0.1250, 0.1250, 0.1250],
+                    [0.2083, 0.2083, 0.2083, 0.2083],
+                    [0.0833, 0.0833, 0.0833, 0.0833],
+                    [0.2083, 0.2083, 0.2083, 0.2083],
+                    [0.1250, 0.1250, 0.1250, 0.1250],
+                ],
+            ]]],
+            &device,
+        );
+
+        let output = adaptive_avg_pool3d(x.clone(), [2, 3, 2]);
+        let grads = output.backward();
+        let x_grad_actual = x.grad(&grads).unwrap();
+
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.into_data(), 3);
+    }
+}
@@ -0,0 +1,26 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_max_pool1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_max_pool1d;
+
+    #[test]
+    fn test_adaptive_max_pool1d_overlapping_windows() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats(
+            [[[0.2380, 0.5442, 0.3700, 0.6039, 0.6257, 0.0655, 0.0132]]],
+            &device,
+        )
+        .require_grad();
+        let x_grad_expected =
+            TestAutodiffTensor::<3>::from_floats([[[0.0, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0]]], &device);
+
+        let output = adaptive_max_pool1d(x.clone(), 3);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_max_pool2d;
+
+    #[test]
+    fn test_adaptive_max_pool2d_dyn_filter_size() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats(
+            [[[
+                [0.6391, 0.1506, 0.6349, 0.8680, 0.5232, 0.7413, 0.6714],
+                [0.0640, 0.7582, 0.5911, 0.3013, 0.0310, 0.8655, 0.4727],
+                [0.7188, 0.8788, 0.7141, 0.9211, 0.3950, 0.8009, 0.4446],
+                [0.9356, 0.8789, 0.0975, 0.1360, 0.2170, 0.9655, 0.4362],
+                [0.6266, 0.3010, 0.5072, 0.3859, 0.3509, 0.5851, 0.5843],
+            ]]],
+            &device,
+        )
+        .require_grad();
+        let x_grad_expected = TestAutodiffTensor::<4>::from_floats(
+            [[[
+                [0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0],
+                [2.0, 1.0, 0.0, 0.0, 0.0, 4.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            ]]],
+            &device,
+        );
+
+        let output = adaptive_max_pool2d(x.clone(), [3, 4]);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
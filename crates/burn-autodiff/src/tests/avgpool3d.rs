@@ -0,0 +1,116 @@
+#[burn_tensor_testgen::testgen(ad_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_avg_pool3d_count_include_pad() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [2, 3, 2],
+            padding: [1, 1, 0],
+            stride: [1, 2, 2],
+            depth: 3,
+            height: 4,
+            width: 5,
+            count_include_pad: true,
+        };
+
+        let plane = [
+            [0.1667, 0.1667, 0.1667, 0.1667, 0.0000],
+            [0.3333, 0.3333, 0.3333, 0.3333, 0.0000],
+            [0.1667, 0.1667, 0.1667, 0.1667, 0.0000],
+            [0.1667, 0.1667, 0.1667, 0.1667, 0.0000],
+        ];
+        test.assert_output(TestTensor::from_floats(
+            [[[plane, plane, plane]]],
+            &Default::default(),
+        ));
+    }
+
+    #[test]
+    fn test_avg_pool3d_count_exclude_pad() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: [2, 3, 2],
+            padding: [1, 1, 0],
+            stride: [1, 2, 2],
+            depth: 3,
+            height: 4,
+            width: 5,
+            count_include_pad: false,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[
+                [
+                    [0.3750, 0.3750, 0.3750, 0.3750, 0.0000],
+                    [0.6250, 0.6250, 0.6250, 0.6250, 0.0000],
+                    [0.2500, 0.2500, 0.2500, 0.2500, 0.0000],
+                    [0.2500, 0.2500, 0.2500, 0.2500, 0.0000],
+                ],
+                [
+                    [0.2500, 0.2500, 0.2500, 0.2500, 0.0000],
+                    [0.4167, 0.4167, 0.4167, 0.4167, 0.0000],
+                    [0.1667, 0.1667, 0.1667, 0.1667, 0.0000],
+                    [0.1667, 0.1667, 0.1667, 0.1667, 0.0000],
+                ],
+                [
+                    [0.3750, 0.3750, 0.3750, 0.3750, 0.0000],
+                    [0.6250, 0.6250, 0.6250, 0.6250, 0.0000],
+                    [0.2500, 0.2500, 0.2500, 0.2500, 0.0000],
+                    [0.2500, 0.2500, 0.2500, 0.2500, 0.0000],
+                ],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    struct AvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        depth: usize,
+        height: usize,
+        width: usize,
+        count_include_pad: bool,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, x_grad: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let device = Default::default();
+            let x = TestAutodiffTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape(shape_x)
+                    .into_data(),
+                &device,
+            )
+            .require_grad();
+            let output = avg_pool3d(
+                x.clone(),
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.count_include_pad,
+            );
+            let grads = output.backward();
+            let x_grad_actual = x.grad(&grads).unwrap();
+
+            x_grad
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.into_data(), 3);
+        }
+    }
+}
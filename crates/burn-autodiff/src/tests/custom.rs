@@ -0,0 +1,175 @@
+#[burn_tensor_testgen::testgen(ad_custom)]
+mod tests {
+    use super::*;
+    use burn_autodiff::checkpoint::strategy::BalancedCheckpointing;
+    use burn_tensor::{backend::AutodiffBackend, CustomOp, Tensor, TensorData};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    type TestCheckpointBackend = burn_autodiff::Autodiff<TestBackend, BalancedCheckpointing>;
+
+    fn mul<B: AutodiffBackend>() -> CustomOp<B, 2, 2, 2> {
+        CustomOp::new(
+            |[lhs, rhs]| (lhs * rhs, ()),
+            |[lhs, rhs], (), grad| [Some(grad.clone() * rhs), Some(grad * lhs)],
+        )
+    }
+
+    /// The exponential, whose output is saved for the backward pass. The number of calls of the
+    /// forward function is counted.
+    fn exp<B: AutodiffBackend>(
+        num_forward: Arc<AtomicUsize>,
+    ) -> CustomOp<B, 1, 1, 1, Tensor<B::InnerBackend, 1>> {
+        CustomOp::new(
+            move |[x]| {
+                num_forward.fetch_add(1, Ordering::Relaxed);
+                let y = x.exp();
+                (y.clone(), y)
+            },
+            |[_x], y, grad| [Some(grad * y)],
+        )
+    }
+
+    #[test]
+    fn should_use_custom_backward() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0], &device).require_grad();
+
+        // The derivative of x^2 is replaced by 1.
+        let op = CustomOp::<TestAutodiffBackend, 1, 1, 1>::new(
+            |[x]| (x.clone() * x, ()),
+            |[_x], (), grad| [Some(grad)],
+        );
+        let y = op.apply([x.clone()]);
+        let grads = (y.clone() * 2.0).sum().backward();
+
+        y.into_data()
+            .assert_eq(&TensorData::from([1.0, 4.0, 9.0]), false);
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 2.0, 2.0]), false);
+    }
+
+    #[test]
+    fn should_register_gradients_of_tracked_inputs_only() {
+        let device = Default::default();
+        let lhs =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let rhs = TestAutodiffTensor::<2>::from_floats([[5.0, 6.0], [7.0, 8.0]], &device);
+
+        let y = mul::<TestAutodiffBackend>().apply([lhs.clone(), rhs.clone()]);
+        let grads = y.exp().sum().backward();
+
+        let expected = (lhs.clone() * rhs.clone()).exp() * rhs.clone();
+        lhs.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+        assert!(rhs.grad(&grads).is_none());
+    }
+
+    #[test]
+    fn should_recompute_memory_bound_custom_op_with_checkpointing() {
+        let device = Default::default();
+        let lhs =
+            Tensor::<TestCheckpointBackend, 2>::from_floats([[1.0, -2.0], [0.5, 4.0]], &device)
+                .require_grad();
+        let rhs =
+            Tensor::<TestCheckpointBackend, 2>::from_floats([[3.0, 1.0], [2.0, -1.0]], &device)
+                .require_grad();
+
+        let y = mul::<TestCheckpointBackend>()
+            .memory_bound()
+            .apply([lhs.clone().exp(), rhs.clone()]);
+        let grads = (y.clone() * y).sum().backward();
+
+        // d/dlhs (e^lhs * rhs)^2 = 2 (e^lhs rhs)^2 and d/drhs = 2 e^(2 lhs) rhs
+        let product = lhs.clone().exp() * rhs.clone();
+        let expected_lhs = product.clone() * product.clone() * 2.0;
+        let expected_rhs = lhs.clone().exp() * product * 2.0;
+
+        lhs.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected_lhs.into_data(), 3);
+        rhs.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected_rhs.into_data(), 3);
+    }
+
+    #[test]
+    fn should_pass_saved_state_to_backward() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_floats([0.0, 1.0, -2.0], &device).require_grad();
+        let num_forward = Arc::new(AtomicUsize::new(0));
+
+        let y = exp::<TestAutodiffBackend>(num_forward.clone()).apply([x.clone()]);
+        let grads = y.sum().backward();
+
+        // The gradient is computed from the saved output, without recomputing it.
+        assert_eq!(num_forward.load(Ordering::Relaxed), 1);
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&x.clone().exp().into_data(), 3);
+    }
+
+    #[test]
+    fn should_recompute_saved_state_of_memory_bound_custom_op_with_checkpointing() {
+        let device = Default::default();
+        let x = Tensor::<TestCheckpointBackend, 1>::from_floats([0.0, 1.0, -2.0], &device)
+            .require_grad();
+        let num_forward = Arc::new(AtomicUsize::new(0));
+
+        let y = exp::<TestCheckpointBackend>(num_forward.clone())
+            .memory_bound()
+            .apply([x.clone()]);
+        let grads = (y.clone() * y).sum().backward();
+
+        // d/dx (e^x)^2 = 2 e^(2x), with the saved output recomputed during the backward pass. The
+        // output and the saved state are recomputed by the same call of the forward function.
+        assert_eq!(num_forward.load(Ordering::Relaxed), 2);
+        let expected = x.clone().mul_scalar(2.0).exp().mul_scalar(2.0);
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_unsqueezed_inputs_of_lower_rank() {
+        let device = Default::default();
+        let x =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let weight =
+            TestAutodiffTensor::<2>::from_floats([[0.5, -1.0], [2.0, 1.0]], &device).require_grad();
+        let bias = TestAutodiffTensor::<1>::from_floats([1.0, -1.0], &device).require_grad();
+
+        // y = x @ w + b, with the bias unsqueezed to the rank of the other inputs.
+        let linear = CustomOp::<TestAutodiffBackend, 3, 2, 2>::new(
+            |[x, weight, bias]| (x.matmul(weight) + bias, ()),
+            |[x, weight, _bias], (), grad| {
+                [
+                    Some(grad.clone().matmul(weight.transpose())),
+                    Some(x.transpose().matmul(grad.clone())),
+                    Some(grad.sum_dim(0)),
+                ]
+            },
+        );
+        let y = linear.apply([x.clone(), weight.clone(), bias.clone().unsqueeze()]);
+        let grads = y.clone().sum().backward();
+
+        y.into_data()
+            .assert_approx_eq(&TensorData::from([[5.5, 0.0], [10.5, 0.0]]), 3);
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-0.5, 3.0], [-0.5, 3.0]]), 3);
+        bias.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.0, 2.0]), 3);
+    }
+}
@@ -0,0 +1,97 @@
+#[burn_tensor_testgen::testgen(ad_dynamic_checkpoint)]
+mod tests {
+    use super::*;
+    use burn_autodiff::checkpoint::strategy::{
+        checkpointing_mode, with_checkpointing, CheckpointingMode, DynamicCheckpointing,
+    };
+    use burn_tensor::{backend::Backend, CustomOp, Tensor, TensorData};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    type TestDynamicBackend = burn_autodiff::Autodiff<TestBackend, DynamicCheckpointing>;
+
+    fn block<B: Backend>(x: Tensor<B, 2>, y: Tensor<B, 2>) -> Tensor<B, 2> {
+        let z = (x.clone() * y.clone()).exp() + x.clone().matmul(y.clone());
+        z.tanh() * x - y.sigmoid()
+    }
+
+    fn gradients(mode: CheckpointingMode) -> (TensorData, TensorData) {
+        let device = Default::default();
+        let x = Tensor::<TestDynamicBackend, 2>::from_floats([[0.5, -1.0], [0.2, 0.3]], &device)
+            .require_grad();
+        let y = Tensor::<TestDynamicBackend, 2>::from_floats([[1.0, 0.1], [-0.4, 0.6]], &device)
+            .require_grad();
+
+        let z = block(x.clone(), y.clone());
+        let z = with_checkpointing(mode, || block(z.clone(), y.clone()));
+        let grads = block(z, y.clone()).sum().backward();
+
+        (
+            x.grad(&grads).unwrap().into_data(),
+            y.grad(&grads).unwrap().into_data(),
+        )
+    }
+
+    #[test]
+    fn should_compute_same_gradients_with_all_modes() {
+        let (x_grad, y_grad) = gradients(CheckpointingMode::None);
+
+        for mode in [
+            CheckpointingMode::Balanced,
+            CheckpointingMode::MemoryBudget(0),
+            CheckpointingMode::MemoryBudget(32),
+            CheckpointingMode::MemoryBudget(usize::MAX),
+        ] {
+            let (x_grad_mode, y_grad_mode) = gradients(mode);
+
+            x_grad_mode.assert_approx_eq(&x_grad, 3);
+            y_grad_mode.assert_approx_eq(&y_grad, 3);
+        }
+    }
+
+    #[test]
+    fn should_restore_mode_after_scope() {
+        assert_eq!(checkpointing_mode(), CheckpointingMode::None);
+
+        with_checkpointing(CheckpointingMode::Balanced, || {
+            with_checkpointing(CheckpointingMode::MemoryBudget(64), || {
+                assert_eq!(checkpointing_mode(), CheckpointingMode::MemoryBudget(64));
+            });
+            assert_eq!(checkpointing_mode(), CheckpointingMode::Balanced);
+        });
+
+        assert_eq!(checkpointing_mode(), CheckpointingMode::None);
+    }
+
+    #[test]
+    fn should_reset_memory_budget_after_backward() {
+        let device = Default::default();
+        let num_forward = Arc::new(AtomicUsize::new(0));
+        let counter = num_forward.clone();
+        let exp = CustomOp::<TestDynamicBackend, 1, 1, 1>::new(
+            move |[x]| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                (x.exp(), ())
+            },
+            |[x], (), grad| [Some(grad * x.exp())],
+        )
+        .memory_bound();
+        let x =
+            Tensor::<TestDynamicBackend, 1>::from_floats([0.0, 1.0, -2.0], &device).require_grad();
+
+        // The budget only fits the output of the custom operation.
+        let budget = 3 * core::mem::size_of::<<TestBackend as Backend>::FloatElem>();
+
+        with_checkpointing(CheckpointingMode::MemoryBudget(budget), || {
+            for _ in 0..2 {
+                num_forward.store(0, Ordering::Relaxed);
+
+                let y = exp.apply([x.clone()]);
+                let _grads = (y.clone() * y).sum().backward();
+
+                // The output is kept during each forward pass, so it isn't recomputed.
+                assert_eq!(num_forward.load(Ordering::Relaxed), 1);
+            }
+        });
+    }
+}
@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(ad_fake_quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        quantization::{QuantizationParameters, QuantizationScheme, QuantizationType},
+        Int, Tensor, TensorData,
+    };
+
+    #[test]
+    fn should_diff_fake_quantize_symmetric() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_data([-1.8, -1.0, 0.0, 0.5, 2.0], &device).require_grad();
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8);
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.01], &device),
+            offset: None,
+        };
+
+        let tensor_2 = tensor_1.clone().fake_quantize(&scheme, qparams);
+        let grads = tensor_2.clone().mul_scalar(2.0).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        tensor_2
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.27, -1.0, 0.0, 0.5, 1.27]), 3);
+        grad_1
+            .to_data()
+            .assert_eq(&TensorData::from([0.0, 2.0, 2.0, 2.0, 0.0]), false);
+    }
+
+    #[test]
+    fn should_diff_fake_quantize_affine() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_data([-1.5, -1.3, 1.0, 1.2], &device).require_grad();
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+        // Input range `[-1.38, 1.17]`
+        let qparams = QuantizationParameters {
+            scale: Tensor::from_floats([0.01], &device),
+            offset: Some(Tensor::<TestAutodiffBackend, 1, Int>::from_ints(
+                [10],
+                &device,
+            )),
+        };
+
+        let tensor_2 = tensor_1.clone().fake_quantize(&scheme, qparams);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_eq(&TensorData::from([0.0, 1.0, 1.0, 0.0]), false);
+    }
+}
@@ -0,0 +1,156 @@
+#[burn_tensor_testgen::testgen(ad_forward)]
+mod tests {
+    use super::*;
+    use burn_autodiff::forward::{dual, primal, tangent};
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    type TestForwardBackend = burn_autodiff::ForwardAutodiff<TestBackend>;
+    type TestForwardTensor<const D: usize> = Tensor<TestForwardBackend, D>;
+
+    #[test]
+    fn should_propagate_tangents_of_element_wise_ops() {
+        let device = Default::default();
+        let x = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+        let v = TestTensor::<1>::from_floats([1.0, 0.0, -1.0], &device);
+        let x = dual(x, v);
+
+        let y = x.clone() * x.clone() + x.sin();
+
+        primal(y.clone())
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.8415, 4.9093, 9.1411]), 3);
+        tangent(y)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.5403, 0.0, -5.0100]), 3);
+    }
+
+    #[test]
+    fn should_have_zero_tangent_for_constants() {
+        let device = Default::default();
+        let x = TestForwardTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        let y = x.clone().exp() * x;
+
+        tangent(y)
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 0.0], [0.0, 0.0]]), false);
+    }
+
+    #[test]
+    fn should_broadcast_tangents() {
+        let device = Default::default();
+        let x = TestTensor::<2>::from_floats([[1.0, 2.0]], &device);
+        let v = TestTensor::<2>::from_floats([[1.0, -1.0]], &device);
+        let x = dual(x, v);
+        let c = TestForwardTensor::<2>::from_floats([[0.0, 0.0], [1.0, 1.0]], &device);
+
+        let y = c - x;
+
+        tangent(y)
+            .into_data()
+            .assert_eq(&TensorData::from([[-1.0, 1.0], [-1.0, 1.0]]), false);
+    }
+
+    #[test]
+    fn should_propagate_tangents_of_matmul() {
+        let device = Default::default();
+        let x = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let v = TestTensor::<2>::from_floats([[1.0, 0.0], [0.0, 2.0]], &device);
+        let w = TestTensor::<2>::from_floats([[1.0, 1.0], [0.0, 1.0]], &device);
+        let u = TestTensor::<2>::from_floats([[0.0, 1.0], [1.0, 0.0]], &device);
+        let x = dual(x, v);
+        let w = dual(w, u);
+
+        let y = x.matmul(w);
+
+        tangent(y)
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 2.0], [4.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_gather_tangents_of_sort() {
+        let device = Default::default();
+        let x = TestTensor::<1>::from_floats([3.0, 1.0, 2.0], &device);
+        let v = TestTensor::<1>::from_floats([10.0, 20.0, 30.0], &device);
+
+        let y = dual(x, v).sort(0);
+
+        primal(y.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0, 3.0]), false);
+        tangent(y)
+            .into_data()
+            .assert_eq(&TensorData::from([20.0, 30.0, 10.0]), false);
+    }
+
+    #[test]
+    fn should_propagate_tangents_of_cumprod_with_zeros() {
+        let device = Default::default();
+        let x = TestTensor::<1>::from_floats([2.0, 0.0, 3.0, 4.0], &device);
+        let v = TestTensor::<1>::from_floats([1.0, 1.0, 1.0, 1.0], &device);
+
+        let y = dual(x, v).cumprod(0);
+
+        primal(y.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 0.0, 0.0, 0.0]), false);
+        tangent(y)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 2.0, 6.0, 24.0]), 5);
+    }
+
+    #[test]
+    fn should_propagate_tangents_of_det_and_inverse() {
+        let device = Default::default();
+        let a = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let b = TestTensor::<2>::from_floats([[2.0, 0.0], [0.0, 4.0]], &device);
+        let eye = TestTensor::<2>::eye(2, &device);
+
+        let det = linalg::det(dual(a, eye.clone()));
+        let inverse = linalg::inverse(dual(b, eye));
+
+        tangent(det)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[5.0]]), 3);
+        tangent(inverse)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[-0.25, 0.0], [0.0, -0.0625]]), 3);
+    }
+
+    #[test]
+    fn should_propagate_tangents_of_symmetric_decompositions() {
+        let device = Default::default();
+        let a = TestTensor::<2>::from_floats([[4.0, 2.0], [2.0, 3.0]], &device);
+        let b = TestTensor::<2>::from_floats([[2.0, 1.0], [1.0, 2.0]], &device);
+        let eye = TestTensor::<2>::eye(2, &device);
+        let v = TestTensor::<2>::from_floats([[1.0, 0.0], [0.0, 0.0]], &device);
+
+        let l = linalg::cholesky(dual(a, eye));
+        let (w, _) = linalg::eigh(dual(b, v));
+
+        tangent(l)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.25, 0.0], [-0.125, 0.4419]]), 3);
+        tangent(w)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.5, 0.5]]), 3);
+    }
+
+    #[test]
+    fn should_reconstruct_tangents_of_qr_and_svd() {
+        let device = Default::default();
+        let a = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]], &device);
+        let v = TestTensor::<2>::from_floats([[1.0, -1.0], [0.5, 2.0], [0.0, 1.0]], &device);
+
+        let (q, r) = linalg::qr(dual(a.clone(), v.clone()));
+        let (u, s, vh) = linalg::svd(dual(a, v.clone()));
+
+        tangent(q.matmul(r))
+            .into_data()
+            .assert_approx_eq(&v.clone().into_data(), 3);
+        tangent((u * s).matmul(vh))
+            .into_data()
+            .assert_approx_eq(&v.into_data(), 3);
+    }
+}
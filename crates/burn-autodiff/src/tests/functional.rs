@@ -0,0 +1,105 @@
+#[burn_tensor_testgen::testgen(ad_functional)]
+mod tests {
+    use super::*;
+    use burn_autodiff::functional::{hessian, jacobian, jvp, vjp};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_compute_jvp() {
+        let device = Default::default();
+        let x = TestTensor::<1>::from_floats([1.0, 2.0], &device);
+        let v = TestTensor::<1>::from_floats([1.0, -1.0], &device);
+
+        let (y, y_tangent) = jvp(|x| x.powf_scalar(3.0), x, v);
+
+        y.into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 8.0]), 3);
+        y_tangent
+            .into_data()
+            .assert_approx_eq(&TensorData::from([3.0, -12.0]), 3);
+    }
+
+    #[test]
+    fn should_compute_vjp() {
+        let device = Default::default();
+        let x = TestTensor::<1>::from_floats([1.0, 2.0], &device);
+        let u = TestTensor::<1>::from_floats([1.0, 2.0], &device);
+
+        let (y, x_grad) = vjp(|x: TestAutodiffTensor<1>| x.powf_scalar(3.0), x, u);
+
+        y.into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 8.0]), 3);
+        x_grad
+            .into_data()
+            .assert_approx_eq(&TensorData::from([3.0, 24.0]), 3);
+    }
+
+    #[test]
+    fn should_match_jvp_and_vjp() {
+        let device = Default::default();
+        let x = TestTensor::<2>::from_floats([[0.5, -1.0], [2.0, 1.5]], &device);
+        let v = TestTensor::<2>::from_floats([[1.0, 2.0], [-1.0, 0.5]], &device);
+        let u = TestTensor::<2>::from_floats([[0.3, -0.7], [1.0, 2.0]], &device);
+
+        // <u, J v> = <J^T u, v>
+        let (_, y_tangent) = jvp(|x| x.clone().matmul(x.tanh()), x.clone(), v.clone());
+        let (_, x_grad) = vjp(
+            |x: TestAutodiffTensor<2>| x.clone().matmul(x.tanh()),
+            x,
+            u.clone(),
+        );
+
+        (u * y_tangent)
+            .sum()
+            .into_data()
+            .assert_approx_eq(&(x_grad * v).sum().into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_jacobian() {
+        let device = Default::default();
+        let x = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+
+        let jacobian = jacobian(|x| x.clone() * x.sum(), x);
+
+        jacobian.into_data().assert_approx_eq(
+            &TensorData::from([[7.0, 1.0, 1.0], [2.0, 8.0, 2.0], [3.0, 3.0, 9.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_compute_hessian() {
+        let device = Default::default();
+        let x = TestTensor::<1>::from_floats([1.0, -1.0, 2.0], &device);
+
+        let hessian = hessian(
+            |x| x.clone().powf_scalar(3.0).sum() + x.sum().powf_scalar(2.0),
+            x,
+        );
+
+        hessian.into_data().assert_approx_eq(
+            &TensorData::from([[8.0, 2.0, 2.0], [2.0, -4.0, 2.0], [2.0, 2.0, 14.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_compute_hessian_of_matrix_input() {
+        let device = Default::default();
+        let x = TestTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+
+        // f(x) = sum(x x), where the Hessian is constant
+        let hessian = hessian(|x| x.clone().matmul(x).sum(), x);
+
+        hessian.into_data().assert_approx_eq(
+            &TensorData::from([
+                [2.0, 1.0, 1.0, 0.0],
+                [1.0, 0.0, 2.0, 1.0],
+                [1.0, 2.0, 0.0, 1.0],
+                [0.0, 1.0, 1.0, 2.0],
+            ]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,164 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{affine_grid_2d, grid_sample_2d};
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+
+    #[test]
+    fn test_grid_sample_2d_bilinear_zeros() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(
+                GridSampleMode::Bilinear,
+                GridSamplePaddingMode::Zeros,
+                false,
+            ),
+        };
+
+        test.assert_grads(
+            [[
+                [
+                    [0.56, 0.135, 0.595, 0.07],
+                    [0.0, 0.165, 0.385, 0.0],
+                    [0.0, 0.0, 0.1725, 0.4975],
+                ],
+                [
+                    [0.56, 0.135, 0.595, 0.07],
+                    [0.0, 0.165, 0.385, 0.0],
+                    [0.0, 0.0, 0.1725, 0.4975],
+                ],
+            ]],
+            [[
+                [[0.8, 0.525], [4.15, 3.825], [-17.1, -1.35]],
+                [[0.0, 0.0], [-2.0125, -14.2875], [-0.525, 5.775]],
+            ]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_2d_bilinear_border() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(
+                GridSampleMode::Bilinear,
+                GridSamplePaddingMode::Border,
+                false,
+            ),
+        };
+
+        test.assert_grads(
+            [[
+                [
+                    [1.0, 0.135, 1.115, 0.2],
+                    [0.325, 0.165, 0.385, 0.0],
+                    [0.675, 0.0, 0.3, 1.7],
+                ],
+                [
+                    [1.0, 0.135, 1.115, 0.2],
+                    [0.325, 0.165, 0.385, 0.0],
+                    [0.675, 0.0, 0.3, 1.7],
+                ],
+            ]],
+            [[
+                [[0.0, 0.0], [4.15, 3.825], [0.0, 0.0]],
+                [[0.0, 7.5], [-3.5, 0.0], [-1.5, 0.0]],
+            ]],
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_2d_bilinear_reflection_align_corners() {
+        let test = GridSampleTestCase {
+            options: GridSampleOptions::new(
+                GridSampleMode::Bilinear,
+                GridSamplePaddingMode::Reflection,
+                true,
+            ),
+        };
+
+        test.assert_grads(
+            [[
+                [
+                    [0.68, 0.225, 1.0725, 0.0225],
+                    [0.4725, 0.5225, 0.6725, 0.2325],
+                    [0.2475, 0.2025, 0.78, 0.87],
+                ],
+                [
+                    [0.68, 0.225, 1.0725, 0.0225],
+                    [0.4725, 0.5225, 0.6725, 0.2325],
+                    [0.2475, 0.2025, 0.78, 0.87],
+                ],
+            ]],
+            [[
+                [[-0.75, 5.0], [2.325, 2.725], [-0.1875, 0.875]],
+                [[0.75, 5.0], [-2.1562, 2.75], [-0.3375, -1.6312]],
+            ]],
+        );
+    }
+
+    #[test]
+    fn test_affine_grid_2d() {
+        let device = Default::default();
+        let theta =
+            TestAutodiffTensor::<3>::from_floats([[[0.8, -0.3, 0.1], [0.2, 1.1, -0.4]]], &device)
+                .require_grad();
+
+        let grid = affine_grid_2d(theta.clone(), [1, 1, 2, 3], true);
+        let grads = grid.backward();
+
+        // Each output is a linear combination of the base coordinates (x, y, 1).
+        let theta_grad_expected =
+            TestAutodiffTensor::<3>::from_floats([[[0.0, 0.0, 6.0], [0.0, 0.0, 6.0]]], &device);
+        let theta_grad = theta.grad(&grads).unwrap();
+        theta_grad_expected
+            .to_data()
+            .assert_approx_eq(&theta_grad.to_data(), 3);
+    }
+
+    struct GridSampleTestCase {
+        options: GridSampleOptions,
+    }
+
+    impl GridSampleTestCase {
+        fn assert_grads(self, x_grad: [[[[f32; 4]; 3]; 2]; 1], grid_grad: [[[[f32; 2]; 3]; 2]; 1]) {
+            let device = Default::default();
+            let x = TestAutodiffTensor::<4>::from_floats(
+                [[
+                    [
+                        [0.0, 1.0, 2.0, 3.0],
+                        [4.0, 5.0, 6.0, 7.0],
+                        [8.0, 9.0, 10.0, 11.0],
+                    ],
+                    [
+                        [0.5, -1.0, 2.0, 0.25],
+                        [1.5, 0.0, -0.5, 3.0],
+                        [2.5, 1.0, 0.75, -2.0],
+                    ],
+                ]],
+                &device,
+            )
+            .require_grad();
+            let grid = TestAutodiffTensor::<4>::from_floats(
+                [[
+                    [[-0.9, -0.8], [0.1, -0.3], [1.2, 0.7]],
+                    [[-1.3, 0.45], [0.6, 0.95], [0.35, -1.1]],
+                ]],
+                &device,
+            )
+            .require_grad();
+
+            let output = grid_sample_2d(x.clone(), grid.clone(), self.options);
+            let grads = output.backward();
+
+            let x_grad_expected = TestAutodiffTensor::<4>::from_floats(x_grad, &device);
+            let grid_grad_expected = TestAutodiffTensor::<4>::from_floats(grid_grad, &device);
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let grid_grad_actual = grid.grad(&grads).unwrap();
+
+            x_grad_expected
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+            grid_grad_expected
+                .to_data()
+                .assert_approx_eq(&grid_grad_actual.to_data(), 3);
+        }
+    }
+}
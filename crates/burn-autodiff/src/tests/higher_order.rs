@@ -0,0 +1,102 @@
+#[burn_tensor_testgen::testgen(ad_higher_order)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::max_pool2d, Tensor, TensorData};
+
+    type TestDoubleAutodiffBackend = burn_autodiff::Autodiff<TestAutodiffBackend>;
+    type TestDoubleAutodiffTensor<const D: usize> = Tensor<TestDoubleAutodiffBackend, D>;
+
+    #[test]
+    fn should_diff_gradients_of_cubic() {
+        let device = Default::default();
+        let x_inner =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, -1.0]], &device).require_grad();
+        let x = TestDoubleAutodiffTensor::from_inner(x_inner.clone()).require_grad();
+
+        let y = x.clone() * x.clone() * x.clone();
+        let grads = y.sum().backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        let grads = x_grad.clone().sum().backward();
+        let x_grad_grad = x_inner.grad(&grads).unwrap();
+
+        x_grad
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 12.0], [27.0, 3.0]]), false);
+        x_grad_grad
+            .into_data()
+            .assert_eq(&TensorData::from([[6.0, 12.0], [18.0, -6.0]]), false);
+    }
+
+    #[test]
+    fn should_compute_hessian_vector_product() {
+        let device = Default::default();
+        let x_inner =
+            TestAutodiffTensor::<1>::from_floats([0.5, -1.0, 2.0], &device).require_grad();
+        let v = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, -1.0], &device);
+        let x = TestDoubleAutodiffTensor::from_inner(x_inner.clone()).require_grad();
+
+        let grads = x.clone().tanh().sum().backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        let grads = (x_grad.clone() * v).sum().backward();
+        let hvp = x_inner.grad(&grads).unwrap();
+
+        x_grad
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.7864, 0.4200, 0.0707]), 3);
+        hvp.into_data()
+            .assert_approx_eq(&TensorData::from([-0.7269, 1.2794, 0.1362]), 3);
+    }
+
+    #[test]
+    fn should_diff_gradient_penalty() {
+        let device = Default::default();
+        let x_inner = TestAutodiffTensor::<2>::from_floats([[2.0, 3.0]], &device).require_grad();
+        let w_inner = TestAutodiffTensor::<2>::from_floats([[0.5, -1.0]], &device).require_grad();
+        let x = TestDoubleAutodiffTensor::from_inner(x_inner.clone()).require_grad();
+        let w = TestDoubleAutodiffTensor::from_inner(w_inner.clone()).require_grad();
+
+        let y = w.clone() * x.clone() * x.clone();
+        let grads = y.sum().backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        let penalty = (x_grad.clone() * x_grad).sum();
+        let grads = penalty.backward();
+        let x_grad_penalty = x_inner.grad(&grads).unwrap();
+        let w_grad_penalty = w_inner.grad(&grads).unwrap();
+
+        x_grad_penalty
+            .into_data()
+            .assert_eq(&TensorData::from([[4.0, 24.0]]), false);
+        w_grad_penalty
+            .into_data()
+            .assert_eq(&TensorData::from([[16.0, -72.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_first_order_with_untracked_inner_tensors() {
+        let device = Default::default();
+        let x_inner = TestAutodiffTensor::<1>::from_floats([1.0, 2.0], &device);
+        let x = TestDoubleAutodiffTensor::from_inner(x_inner).require_grad();
+
+        let grads = (x.clone() * x.clone()).sum().backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        x_grad
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 4.0]), false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't differentiate max pool2d with indices backward.")]
+    fn should_panic_when_diff_gradients_of_pooling() {
+        let device = Default::default();
+        let x_inner = TestAutodiffTensor::<4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device)
+            .require_grad();
+        let x = TestDoubleAutodiffTensor::from_inner(x_inner).require_grad();
+
+        let y = max_pool2d(x, [2, 2], [1, 1], [0, 0], [1, 1]);
+        y.sum().backward();
+    }
+}
@@ -0,0 +1,93 @@
+#[burn_tensor_testgen::testgen(ad_hooks)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn should_modify_gradient_of_leaf() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0], &device).require_grad();
+        x.register_hook(|grad| grad.neg());
+
+        let y = x.clone() * x.clone();
+        let grads = y.sum().backward();
+
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([-2.0, -4.0, -6.0]), false);
+    }
+
+    #[test]
+    fn should_propagate_modified_gradient_of_intermediate_tensor() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0], &device).require_grad();
+
+        let y = x.clone().exp();
+        y.register_hook(|grad| grad.zeros_like());
+        let z = y * 2.0 + x.clone();
+        let grads = z.sum().backward();
+
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 1.0, 1.0]), false);
+    }
+
+    #[test]
+    fn should_call_hook_with_accumulated_gradient() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_floats([1.0, 2.0], &device).require_grad();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let y = x.clone() * 3.0;
+        let calls_hook = calls.clone();
+        y.register_hook(move |grad| {
+            calls_hook.lock().unwrap().push(grad.clone().into_data());
+            grad
+        });
+        let z = y.clone() * 2.0 + y.clone() * y;
+        let grads = z.sum().backward();
+
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        calls[0].assert_eq(&TensorData::from([8.0, 14.0]), false);
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([24.0, 42.0]), false);
+    }
+
+    #[test]
+    fn should_call_hooks_registered_multiple_times_in_order() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_floats([1.0, 2.0], &device).require_grad();
+        let add_one = |grad: Tensor<_, 1>| grad.add_scalar(1.0);
+        x.register_hook(add_one);
+        x.register_hook(|grad| grad.mul_scalar(2.0));
+        x.register_hook(add_one);
+
+        let grads = (x.clone() * 3.0).sum().backward();
+
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([9.0, 9.0]), false);
+    }
+
+    #[test]
+    fn should_ignore_hooks_of_untracked_tensors() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::<1>::from_floats([1.0, 2.0], &device).require_grad();
+        let c = TestAutodiffTensor::<1>::from_floats([3.0, 4.0], &device);
+        c.register_hook(|_grad| panic!("Should not be called"));
+
+        let grads = (x.clone() * c).sum().backward();
+
+        x.grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([3.0, 4.0]), false);
+    }
+}
@@ -0,0 +1,159 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn should_diff_solve() {
+        let device = Default::default();
+        let a =
+            TestAutodiffTensor::<2>::from_floats([[3.0, 1.0], [1.0, 2.0]], &device).require_grad();
+        let b = TestAutodiffTensor::from_floats([[9.0], [8.0]], &device).require_grad();
+
+        let x = linalg::solve(a.clone(), b.clone());
+        let grads = x.sum().backward();
+
+        let grad_a = a.grad(&grads).unwrap();
+        let grad_b = b.grad(&grads).unwrap();
+
+        grad_a
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[-0.4, -0.6], [-0.8, -1.2]]), 3);
+        grad_b
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.2], [0.4]]), 3);
+    }
+
+    #[test]
+    fn should_diff_inverse() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[4.0, 7.0], [2.0, 6.0]], &device).require_grad();
+
+        let tensor_2 = linalg::inverse(tensor_1.clone());
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.04, -0.08], [-0.03, 0.06]]), 3);
+    }
+
+    #[test]
+    fn should_diff_det_and_logdet() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let grads = linalg::det(tensor_1.clone()).sum().backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[4.0, -3.0], [-2.0, 1.0]]), 3);
+
+        let grads = linalg::logdet(tensor_1.clone()).sum().backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[-2.0, 1.5], [1.0, -0.5]]), 3);
+    }
+
+    #[test]
+    fn should_diff_cholesky() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[4.0, 2.0], [2.0, 3.0]], &device).require_grad();
+
+        let tensor_2 = linalg::cholesky(tensor_1.clone());
+        let grads = tensor_2.sum().backward();
+
+        // The gradient is symmetric, since the input matrices are symmetric
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1.to_data().assert_approx_eq(
+            &TensorData::from([[0.213388, 0.073223], [0.073223, 0.353553]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_qr_reconstruction() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[2.0, -1.0], [1.0, 3.0], [0.5, 1.0]], &device)
+                .require_grad();
+
+        let (q, r) = linalg::qr(tensor_1.clone());
+        let grads = q.matmul(r).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 1.0], [1.0, 1.0], [1.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_svd_singular_values() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[3.0, 0.0], [0.0, -2.0]], &device).require_grad();
+
+        let (_u, s, _vh) = linalg::svd(tensor_1.clone());
+        let grads = s.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 0.0], [0.0, -1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_svd_reconstruction() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]], &device)
+                .require_grad();
+
+        let (u, s, vh) = linalg::svd(tensor_1.clone());
+        let grads = u.mul(s).matmul(vh).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_eigh() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[2.0, 1.0], [1.0, 2.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::from_floats([[1.0, 2.0]], &device);
+
+        let (w, _v) = linalg::eigh(tensor_1.clone());
+        let grads = w.mul(weights).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[1.5, 0.5], [0.5, 1.5]]), 3);
+    }
+
+    #[test]
+    fn should_diff_eigh_reconstruction() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_floats(
+            [[2.0, 1.0, 0.5], [1.0, 3.0, 0.0], [0.5, 0.0, 1.0]],
+            &device,
+        )
+        .require_grad();
+
+        let (w, v) = linalg::eigh(tensor_1.clone());
+        let grads = v.clone().mul(w).matmul(v.transpose()).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1.to_data().assert_approx_eq(
+            &TensorData::from([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0], [1.0, 1.0, 1.0]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,60 @@
+#[burn_tensor_testgen::testgen(ad_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::max_pool3d;
+
+    #[test]
+    fn test_max_pool3d_with_padding_and_stride() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats(
+            [[[
+                [
+                    [0.3238, 0.1508, 0.6509, 0.0724],
+                    [0.5359, 0.3657, 0.0580, 0.5074],
+                    [0.0375, 0.4336, 0.0699, 0.0907],
+                ],
+                [
+                    [0.4245, 0.8269, 0.1238, 0.2232],
+                    [0.6274, 0.9477, 0.5771, 0.3967],
+                    [0.9763, 0.0466, 0.8585, 0.2896],
+                ],
+                [
+                    [0.1443, 0.1178, 0.3085, 0.8161],
+                    [0.1807, 0.5816, 0.6389, 0.3724],
+                    [0.5477, 0.0628, 0.0596, 0.2060],
+                ],
+            ]]],
+            &device,
+        )
+        .require_grad();
+        let x_grad_expected = TestAutodiffTensor::<5>::from_floats(
+            [[[
+                [
+                    [0.0, 0.0, 1.0, 0.0],
+                    [2.0, 0.0, 0.0, 2.0],
+                    [0.0, 1.0, 0.0, 0.0],
+                ],
+                [
+                    [0.0, 0.0, 0.0, 0.0],
+                    [1.0, 2.0, 0.0, 1.0],
+                    [1.0, 0.0, 0.0, 0.0],
+                ],
+                [
+                    [0.0, 0.0, 0.0, 1.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ],
+            ]]],
+            &device,
+        );
+
+        let output = max_pool3d(x.clone(), [2, 2, 2], [2, 1, 2], [1, 0, 1], [1, 1, 1]);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+}
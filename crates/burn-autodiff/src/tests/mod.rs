@@ -3,10 +3,14 @@
 mod abs;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_avgpool3d;
+mod adaptive_maxpool1d;
+mod adaptive_maxpool2d;
 mod add;
 mod aggregation;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod backward;
 mod bridge;
 mod broadcast;
@@ -21,14 +25,24 @@ mod conv_transpose2d;
 mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
+mod custom;
 mod div;
+mod dynamic_checkpoint;
 mod erf;
 mod exp;
 mod expand;
+mod fake_quantize;
 mod flip;
+mod forward;
+mod functional;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod grid_sample;
+mod higher_order;
+mod hooks;
+mod linalg;
 mod log;
 mod log1p;
 mod log_sigmoid;
@@ -37,6 +51,7 @@ mod matmul;
 mod maxmin;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod memory_management;
 mod mul;
 mod multithread;
@@ -52,6 +67,7 @@ mod reshape;
 mod select;
 mod sigmoid;
 mod sign;
+mod signal;
 mod sin;
 mod slice;
 mod softmax;
@@ -72,7 +88,13 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_gradients!();
         burn_autodiff::testgen_bridge!();
         burn_autodiff::testgen_checkpoint!();
+        burn_autodiff::testgen_ad_dynamic_checkpoint!();
         burn_autodiff::testgen_memory_management!();
+        burn_autodiff::testgen_ad_higher_order!();
+        burn_autodiff::testgen_ad_forward!();
+        burn_autodiff::testgen_ad_functional!();
+        burn_autodiff::testgen_ad_hooks!();
+        burn_autodiff::testgen_ad_custom!();
 
         // Activation
         burn_autodiff::testgen_ad_relu!();
@@ -87,12 +109,18 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_conv_transpose3d!();
         burn_autodiff::testgen_ad_max_pool1d!();
         burn_autodiff::testgen_ad_max_pool2d!();
+        burn_autodiff::testgen_ad_max_pool3d!();
         burn_autodiff::testgen_ad_avg_pool1d!();
         burn_autodiff::testgen_ad_avg_pool2d!();
+        burn_autodiff::testgen_ad_avg_pool3d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_adaptive_avg_pool3d!();
+        burn_autodiff::testgen_ad_adaptive_max_pool1d!();
+        burn_autodiff::testgen_ad_adaptive_max_pool2d!();
         burn_autodiff::testgen_module_backward!();
         burn_autodiff::testgen_ad_nearest_interpolate!();
+        burn_autodiff::testgen_ad_grid_sample!();
 
         // Tensor
         burn_autodiff::testgen_ad_complex!();
@@ -133,6 +161,10 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_sign!();
         burn_autodiff::testgen_ad_expand!();
         burn_autodiff::testgen_ad_sort!();
+        burn_autodiff::testgen_ad_cumulative!();
+        burn_autodiff::testgen_ad_linalg!();
+        burn_autodiff::testgen_ad_signal!();
         burn_autodiff::testgen_ad_repeat!();
+        burn_autodiff::testgen_ad_fake_quantize!();
     };
 }
@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(ad_signal)]
+mod tests {
+    use super::*;
+    use burn_tensor::{signal, TensorData};
+
+    #[test]
+    fn should_diff_rfft() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+
+        let (re, _im) = signal::rfft(tensor_1.clone(), 0);
+        let grads = re.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([3.0, 0.0, 1.0, 0.0]), 3);
+    }
+
+    #[test]
+    fn should_diff_fft_power_spectrum() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+
+        // The energy of the spectrum is n times the energy of the signal (Parseval).
+        let (re, im) = signal::fft(tensor_1.clone(), tensor_1.zeros_like(), 0);
+        let grads = (re.powf_scalar(2.0) + im.powf_scalar(2.0)).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([8.0, 16.0, 24.0, 32.0]), 3);
+    }
+
+    #[test]
+    fn should_diff_stft_reconstruction() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_floats(
+            [[1.0, -2.0, 0.5, 3.0, 1.0, 0.0, -1.0, 2.0, 1.5, -0.5]],
+            &device,
+        )
+        .require_grad();
+        let window = signal::hann_window(4, true, &device);
+
+        let (re, im) = signal::stft(tensor_1.clone(), 4, 1, Some(window.clone()), true);
+        let output = signal::istft(re, im, 4, 1, Some(window), true, Some(10));
+        let grads = output.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        grad_1.to_data().assert_approx_eq(
+            &TensorData::from([[1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0]]),
+            3,
+        );
+    }
+}
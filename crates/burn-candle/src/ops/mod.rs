@@ -3,6 +3,7 @@ mod base;
 mod bool_tensor;
 mod candle_utils;
 mod int_tensor;
+mod linalg;
 mod module;
 mod qtensor;
 mod tensor;
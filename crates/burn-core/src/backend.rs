@@ -10,6 +10,9 @@ pub use burn_autodiff as autodiff;
 #[cfg(feature = "autodiff")]
 pub use burn_autodiff::Autodiff;
 
+#[cfg(feature = "autodiff")]
+pub use burn_autodiff::ForwardAutodiff;
+
 #[cfg(feature = "wgpu")]
 pub use burn_wgpu as wgpu;
 
@@ -1,7 +1,7 @@
 use crate as burn;
 
-use crate::{config::Config, tensor::Tensor};
-use burn_tensor::backend::Backend;
+use crate::{config::Config, module::AutodiffModule, optim::GradientsParams, tensor::Tensor};
+use burn_tensor::backend::{AutodiffBackend, Backend};
 
 /// Gradient Clipping provides a way to mitigate exploding gradients
 #[derive(Config)]
@@ -11,6 +11,9 @@ pub enum GradientClippingConfig {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients of all parameters by their global norm.
+    GlobalNorm(f32),
 }
 
 impl GradientClippingConfig {
@@ -23,6 +26,7 @@ impl GradientClippingConfig {
         match self {
             GradientClippingConfig::Value(val) => GradientClipping::Value(*val),
             GradientClippingConfig::Norm(val) => GradientClipping::Norm(*val),
+            GradientClippingConfig::GlobalNorm(val) => GradientClipping::GlobalNorm(*val),
         }
     }
 }
@@ -37,6 +41,12 @@ pub enum GradientClipping {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients of all parameters by their global norm.
+    ///
+    /// The L2 norm is computed over all the gradients as if they were concatenated into a single
+    /// vector, and they are all scaled by the same factor when it exceeds the threshold.
+    GlobalNorm(f32),
 }
 
 impl GradientClipping {
@@ -49,10 +59,60 @@ impl GradientClipping {
     /// # Returns
     ///
     /// The clipped gradient.
+    ///
+    /// # Panics
+    ///
+    /// If the clipping is by [global norm](GradientClipping::GlobalNorm), which requires the
+    /// gradients of all parameters, use
+    /// [clip_gradients_global_norm](GradientClipping::clip_gradients_global_norm) instead.
     pub fn clip_gradient<B: Backend, const D: usize>(&self, grad: Tensor<B, D>) -> Tensor<B, D> {
         match self {
             GradientClipping::Value(threshold) => self.clip_by_value(grad, *threshold),
             GradientClipping::Norm(max_norm) => self.clip_by_norm(grad, *max_norm),
+            GradientClipping::GlobalNorm(_) => panic!(
+                "Can't clip a single gradient by the global norm, use `clip_gradients_global_norm` to clip the gradients of all parameters."
+            ),
+        }
+    }
+
+    /// Clip the gradients of all the parameters of a module by their global norm.
+    ///
+    /// Like PyTorch's `clip_grad_norm_`, the gradients are scaled by
+    /// `max_norm / (global_norm + 1e-6)` when the global norm exceeds `max_norm`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_norm` - The maximum global norm.
+    /// * `module` - The module whose parameters the gradients belong to.
+    /// * `grads` - The gradients to clip.
+    ///
+    /// # Returns
+    ///
+    /// The clipped gradients, along with their global L2 norm before clipping.
+    pub fn clip_gradients_global_norm<B: AutodiffBackend, M: AutodiffModule<B>>(
+        max_norm: f32,
+        module: &M,
+        grads: GradientsParams,
+    ) -> (GradientsParams, f32) {
+        let norm = grads.global_norm(module);
+        let scale = max_norm / (norm + 1e-6);
+
+        if scale < 1.0 {
+            (grads.scale(scale, module), norm)
+        } else {
+            (grads, norm)
+        }
+    }
+
+    /// The gradient clipping with its threshold multiplied by a factor, used to clip the
+    /// gradients of a [scaled loss](GradientsParams::loss_scale).
+    pub(crate) fn scale(&self, factor: f32) -> Self {
+        match self {
+            GradientClipping::Value(threshold) => GradientClipping::Value(threshold * factor),
+            GradientClipping::Norm(max_norm) => GradientClipping::Norm(max_norm * factor),
+            GradientClipping::GlobalNorm(max_norm) => {
+                GradientClipping::GlobalNorm(max_norm * factor)
+            }
         }
     }
 
@@ -97,8 +157,9 @@ impl GradientClipping {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tensor::Tensor;
-    use crate::TestBackend;
+    use crate::nn::LinearConfig;
+    use crate::tensor::{Tensor, TensorData};
+    use crate::{TestAutodiffBackend, TestBackend};
 
     #[test]
     fn test_clip_by_value() {
@@ -135,4 +196,61 @@ mod tests {
             assert!(value <= 0.88);
         }
     }
+
+    #[test]
+    #[should_panic = "Can't clip a single gradient by the global norm"]
+    fn test_clip_gradient_by_global_norm_should_panic() {
+        let gradient: Tensor<TestBackend, 1> =
+            Tensor::from_floats([0.6294, 0.0940], &Default::default());
+
+        GradientClipping::GlobalNorm(1.0).clip_gradient(gradient);
+    }
+
+    #[test]
+    fn test_clip_by_global_norm() {
+        let device = Default::default();
+        let layer = LinearConfig::new(2, 2).init::<TestAutodiffBackend>(&device);
+        let weight_id = layer.weight.id.clone();
+        let bias_id = layer.bias.as_ref().unwrap().id.clone();
+
+        let mut grads = GradientsParams::new();
+        grads.register::<TestBackend, 2>(
+            weight_id.clone(),
+            Tensor::from_floats([[3.0, 0.0], [0.0, 0.0]], &device),
+        );
+        grads.register::<TestBackend, 1>(bias_id.clone(), Tensor::from_floats([0.0, 4.0], &device));
+
+        let (grads, norm) = GradientClipping::clip_gradients_global_norm(1.0, &layer, grads);
+
+        assert!((norm - 5.0).abs() < 1e-4);
+        let weight_grad = grads.get::<TestBackend, 2>(&weight_id).unwrap();
+        let bias_grad = grads.get::<TestBackend, 1>(&bias_id).unwrap();
+        weight_grad
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.6, 0.0], [0.0, 0.0]]), 3);
+        bias_grad
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.8]), 3);
+    }
+
+    #[test]
+    fn test_clip_by_global_norm_below_threshold() {
+        let device = Default::default();
+        let layer = LinearConfig::new(2, 2).init::<TestAutodiffBackend>(&device);
+        let weight_id = layer.weight.id.clone();
+
+        let mut grads = GradientsParams::new();
+        grads.register::<TestBackend, 2>(
+            weight_id.clone(),
+            Tensor::from_floats([[0.3, 0.0], [0.4, 0.0]], &device),
+        );
+
+        let (grads, norm) = GradientClipping::clip_gradients_global_norm(1.0, &layer, grads);
+
+        assert!((norm - 0.5).abs() < 1e-4);
+        let weight_grad = grads.get::<TestBackend, 2>(&weight_id).unwrap();
+        weight_grad
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.3, 0.0], [0.4, 0.0]]), 3);
+    }
 }
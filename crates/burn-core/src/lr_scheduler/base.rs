@@ -16,4 +16,15 @@ pub trait LrScheduler<B: Backend>: Send + Sync {
 
     /// Load the state of the scheduler as a [record](Record).
     fn load_record(self, record: Self::Record) -> Self;
+
+    /// The name of the validation metric driving the scheduler, if any.
+    ///
+    /// When a name is returned, the learner [reports](LrScheduler::report_metric) the mean value
+    /// of the metric at the end of each validation epoch.
+    fn metric_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Report the value of the [metric](LrScheduler::metric_name) driving the scheduler.
+    fn report_metric(&mut self, _value: f64) {}
 }
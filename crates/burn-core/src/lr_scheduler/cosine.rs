@@ -100,6 +100,99 @@ impl<B: Backend> LrScheduler<B> for CosineAnnealingLrScheduler {
     }
 }
 
+/// The configuration for creating a Cosine Annealing learning rate scheduler with warm restarts, as described in
+/// [SGDR: Stochastic Gradient Descent with Warm Restarts](https://arxiv.org/abs/1608.03983).
+///
+/// This scheduler starts at a learning rate `initial_lr`, then decreases the learning rate down to `min_lr` by
+/// following a cosine function during `num_iters` iterations, before restarting at `initial_lr`. The length of each
+/// period is the length of the previous one multiplied by `period_mult`.
+#[derive(Config)]
+pub struct CosineAnnealingWarmRestartsLrSchedulerConfig {
+    // The initial learning rate.
+    initial_lr: LearningRate,
+    // The final learning rate of each period.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+    // The number of iterations of the first period.
+    num_iters: usize,
+    // The factor by which the number of iterations of the period is multiplied after each restart.
+    #[config(default = 1)]
+    period_mult: usize,
+}
+
+impl CosineAnnealingWarmRestartsLrSchedulerConfig {
+    /// Initializes a [Cosine learning rate scheduler with warm restarts](CosineAnnealingWarmRestartsLrScheduler).
+    ///
+    /// # Panics
+    /// This function panics if `initial_lr` is not between 0 and 1, if `min_lr` is not between 0 and `initial_lr`,
+    /// or if `num_iters` or `period_mult` are 0.
+    pub fn init(&self) -> CosineAnnealingWarmRestartsLrScheduler {
+        assert!(
+            self.initial_lr > 0. && self.initial_lr <= 1.,
+            "Initial learning rate must be greater than 0 and at most 1"
+        );
+        assert!(
+            self.min_lr >= 0.0 && self.min_lr <= self.initial_lr,
+            "Minimum learning rate must be at least 0 and at most equal to the initial learning rate"
+        );
+        assert!(
+            self.num_iters > 0,
+            "Number of iterations must be at least 1"
+        );
+        assert!(self.period_mult > 0, "Period multiplier must be at least 1");
+
+        CosineAnnealingWarmRestartsLrScheduler {
+            min_lr: self.min_lr,
+            max_lr: self.initial_lr,
+            period_mult: self.period_mult,
+            period: self.num_iters,
+            current_iter: 0,
+        }
+    }
+}
+
+/// A Cosine Annealing learning rate scheduler with warm restarts.
+///
+/// See [CosineAnnealingWarmRestartsLrSchedulerConfig] for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct CosineAnnealingWarmRestartsLrScheduler {
+    min_lr: LearningRate,
+    max_lr: LearningRate,
+    period_mult: usize,
+    // The number of iterations of the current period.
+    period: usize,
+    // The iteration in the current period.
+    current_iter: usize,
+}
+
+impl<B: Backend> LrScheduler<B> for CosineAnnealingWarmRestartsLrScheduler {
+    type Record = (usize, usize);
+
+    fn step(&mut self) -> LearningRate {
+        if self.current_iter >= self.period {
+            self.current_iter = 0;
+            self.period *= self.period_mult;
+        }
+
+        let lr = self.min_lr
+            + 0.5
+                * (self.max_lr - self.min_lr)
+                * (1.0
+                    + (self.current_iter as f64 / self.period as f64 * std::f64::consts::PI).cos());
+        self.current_iter += 1;
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (self.period, self.current_iter)
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        (self.period, self.current_iter) = record;
+        self
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -171,4 +264,34 @@ mod test {
             "Learning rate should be reset after the specified number of iterations"
         );
     }
+
+    #[test]
+    #[should_panic = "Period multiplier must be at least 1"]
+    fn config_period_mult_too_low() {
+        CosineAnnealingWarmRestartsLrSchedulerConfig::new(0.5, 10)
+            .with_period_mult(0)
+            .init();
+    }
+
+    #[test]
+    fn test_warm_restarts_lr_change() {
+        const INITIAL_LR: LearningRate = 0.5;
+
+        let mut scheduler = CosineAnnealingWarmRestartsLrSchedulerConfig::new(INITIAL_LR, 2)
+            .with_period_mult(2)
+            .init();
+
+        let lrs = (0..7)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect::<Vec<_>>();
+
+        // The first period lasts 2 iterations and the second one 4 iterations.
+        let expected = [0.5, 0.25, 0.5, 0.426777, 0.25, 0.073223, 0.5];
+        for (lr, expected) in lrs.iter().zip(expected) {
+            assert!(
+                (lr - expected).abs() < 1e-6,
+                "Expected {expected}, got {lr}"
+            );
+        }
+    }
 }
@@ -13,6 +13,21 @@ pub mod exponential;
 /// Cosine learning rate scheduler
 pub mod cosine;
 
+/// Step learning rate schedulers
+pub mod step;
+
+/// Warmup learning rate scheduler
+pub mod warmup;
+
+/// One-cycle learning rate scheduler
+pub mod one_cycle;
+
+/// Reduce on plateau learning rate scheduler
+pub mod reduce_on_plateau;
+
+/// Sequential learning rate scheduler
+pub mod sequential;
+
 mod base;
 
 pub use base::*;
@@ -0,0 +1,154 @@
+use super::LrScheduler;
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a one-cycle learning rate scheduler, as described in
+/// [Super-Convergence](https://arxiv.org/abs/1708.07120).
+///
+/// This scheduler starts at a learning rate `max_lr / div_factor`, increases the learning rate up to `max_lr` during
+/// the first `pct_start` fraction of the `num_iters` iterations, then decreases it down to
+/// `max_lr / (div_factor * final_div_factor)` during the remaining iterations, both following a cosine function.
+#[derive(Config)]
+pub struct OneCycleLrSchedulerConfig {
+    // The maximum learning rate.
+    max_lr: LearningRate,
+    // The total number of iterations of the cycle.
+    num_iters: usize,
+    // The fraction of the iterations spent increasing the learning rate.
+    #[config(default = 0.3)]
+    pct_start: f64,
+    // The initial learning rate is `max_lr / div_factor`.
+    #[config(default = 25.0)]
+    div_factor: f64,
+    // The final learning rate is the initial learning rate divided by `final_div_factor`.
+    #[config(default = 1e4)]
+    final_div_factor: f64,
+}
+
+impl OneCycleLrSchedulerConfig {
+    /// Initializes a [one-cycle learning rate scheduler](OneCycleLrScheduler).
+    ///
+    /// # Panics
+    /// This function panics if `max_lr` is not between 0 and 1, if `num_iters` is lower than 2 or if `pct_start`
+    /// is not between 0 and 1.
+    pub fn init(&self) -> OneCycleLrScheduler {
+        assert!(
+            self.max_lr > 0. && self.max_lr <= 1.,
+            "Max learning rate must be greater than 0 and at most 1"
+        );
+        assert!(
+            self.num_iters > 1,
+            "Number of iterations must be at least 2"
+        );
+        assert!(
+            self.pct_start > 0. && self.pct_start < 1.,
+            "Percentage of the increasing phase must be greater than 0 and lower than 1"
+        );
+
+        let initial_lr = self.max_lr / self.div_factor;
+        // Both phases last at least one iteration.
+        let warmup_iters =
+            ((self.pct_start * self.num_iters as f64) as usize).clamp(1, self.num_iters - 1);
+
+        OneCycleLrScheduler {
+            initial_lr,
+            max_lr: self.max_lr,
+            final_lr: initial_lr / self.final_div_factor,
+            warmup_iters,
+            num_iters: self.num_iters,
+            current_iter: 0,
+        }
+    }
+}
+
+/// A one-cycle learning rate scheduler.
+///
+/// See [OneCycleLrSchedulerConfig] for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct OneCycleLrScheduler {
+    initial_lr: LearningRate,
+    max_lr: LearningRate,
+    final_lr: LearningRate,
+    warmup_iters: usize,
+    num_iters: usize,
+    current_iter: usize,
+}
+
+impl<B: Backend> LrScheduler<B> for OneCycleLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let iter = self.current_iter.min(self.num_iters - 1);
+        self.current_iter += 1;
+
+        if iter < self.warmup_iters {
+            let progress = iter as f64 / self.warmup_iters as f64;
+            cosine_annealing(self.initial_lr, self.max_lr, progress)
+        } else {
+            let progress = (iter - self.warmup_iters) as f64
+                / (self.num_iters - 1 - self.warmup_iters).max(1) as f64;
+            cosine_annealing(self.max_lr, self.final_lr, progress)
+        }
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.current_iter
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.current_iter = record;
+        self
+    }
+}
+
+/// Interpolate from `start` to `end` following a cosine function, `progress` being between 0 and 1.
+fn cosine_annealing(start: LearningRate, end: LearningRate, progress: f64) -> LearningRate {
+    end + 0.5 * (start - end) * (1.0 + (progress * std::f64::consts::PI).cos())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Max learning rate must be greater than 0 and at most 1"]
+    fn config_max_lr_too_high() {
+        OneCycleLrSchedulerConfig::new(1.5, 10).init();
+    }
+
+    #[test]
+    #[should_panic = "Percentage of the increasing phase must be greater than 0 and lower than 1"]
+    fn config_pct_start_too_high() {
+        OneCycleLrSchedulerConfig::new(0.5, 10)
+            .with_pct_start(1.0)
+            .init();
+    }
+
+    #[test]
+    fn test_lr_change() {
+        const MAX_LR: LearningRate = 0.5;
+        const NUM_ITERS: usize = 11;
+
+        let mut scheduler = OneCycleLrSchedulerConfig::new(MAX_LR, NUM_ITERS)
+            .with_pct_start(0.5)
+            .with_div_factor(10.0)
+            .with_final_div_factor(5.0)
+            .init();
+
+        let lrs = (0..NUM_ITERS + 1)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect::<Vec<_>>();
+
+        assert!((lrs[0] - 0.05).abs() < 1e-10);
+        assert!(lrs[..5].windows(2).all(|pair| pair[0] < pair[1]));
+        assert!((lrs[5] - MAX_LR).abs() < 1e-10);
+        assert!(lrs[5..11].windows(2).all(|pair| pair[0] > pair[1]));
+        assert!((lrs[10] - 0.01).abs() < 1e-10);
+        assert_eq!(
+            lrs[11], lrs[10],
+            "Learning rate should remain constant after the cycle"
+        );
+    }
+}
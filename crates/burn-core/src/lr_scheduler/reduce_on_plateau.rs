@@ -0,0 +1,190 @@
+use super::LrScheduler;
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a reduce on plateau learning rate scheduler.
+///
+/// This scheduler starts at a learning rate `initial_lr`, then multiplies the learning rate by `factor` when the
+/// validation metric `metric_name` hasn't improved for more than `patience` epochs. The learner reports the mean
+/// value of the metric at the end of each validation epoch, so the metric must be registered as a validation metric.
+#[derive(Config)]
+pub struct ReduceOnPlateauLrSchedulerConfig {
+    // The initial learning rate.
+    initial_lr: LearningRate,
+    // The name of the validation metric to monitor.
+    metric_name: String,
+    // The factor by which the learning rate is multiplied when the metric stops improving.
+    #[config(default = 0.1)]
+    factor: f64,
+    // The number of epochs without improvement after which the learning rate is reduced.
+    #[config(default = 10)]
+    patience: usize,
+    // The minimum relative change of the metric to be considered an improvement.
+    #[config(default = 1e-4)]
+    threshold: f64,
+    // The lower bound of the learning rate.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+    // Whether the metric should be maximized instead of minimized.
+    #[config(default = false)]
+    maximize: bool,
+}
+
+impl ReduceOnPlateauLrSchedulerConfig {
+    /// Initializes a [reduce on plateau learning rate scheduler](ReduceOnPlateauLrScheduler).
+    ///
+    /// # Panics
+    /// This function panics if `initial_lr` is not between 0 and 1, if `factor` is not between 0 and 1 or if
+    /// `min_lr` is not between 0 and `initial_lr`.
+    pub fn init(&self) -> ReduceOnPlateauLrScheduler {
+        assert!(
+            self.initial_lr > 0. && self.initial_lr <= 1.,
+            "Initial learning rate must be greater than 0 and at most 1"
+        );
+        assert!(
+            self.factor > 0. && self.factor < 1.,
+            "Factor must be greater than 0 and lower than 1"
+        );
+        assert!(
+            self.min_lr >= 0.0 && self.min_lr <= self.initial_lr,
+            "Minimum learning rate must be at least 0 and at most equal to the initial learning rate"
+        );
+
+        ReduceOnPlateauLrScheduler {
+            metric_name: self.metric_name.clone(),
+            factor: self.factor,
+            patience: self.patience,
+            threshold: self.threshold,
+            min_lr: self.min_lr,
+            maximize: self.maximize,
+            lr: self.initial_lr,
+            best: None,
+            num_bad_epochs: 0,
+        }
+    }
+}
+
+/// A reduce on plateau learning rate scheduler.
+///
+/// See [ReduceOnPlateauLrSchedulerConfig] for more information.
+#[derive(Clone, Debug)]
+pub struct ReduceOnPlateauLrScheduler {
+    metric_name: String,
+    factor: f64,
+    patience: usize,
+    threshold: f64,
+    min_lr: LearningRate,
+    maximize: bool,
+    lr: LearningRate,
+    best: Option<f64>,
+    num_bad_epochs: usize,
+}
+
+impl ReduceOnPlateauLrScheduler {
+    fn is_improvement(&self, value: f64, best: f64) -> bool {
+        match self.maximize {
+            true => value > best + best.abs() * self.threshold,
+            false => value < best - best.abs() * self.threshold,
+        }
+    }
+}
+
+impl<B: Backend> LrScheduler<B> for ReduceOnPlateauLrScheduler {
+    type Record = (LearningRate, Option<f64>, usize);
+
+    fn step(&mut self) -> LearningRate {
+        self.lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (self.lr, self.best, self.num_bad_epochs)
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        (self.lr, self.best, self.num_bad_epochs) = record;
+        self
+    }
+
+    fn metric_name(&self) -> Option<&str> {
+        Some(&self.metric_name)
+    }
+
+    fn report_metric(&mut self, value: f64) {
+        match self.best {
+            Some(best) if !self.is_improvement(value, best) => self.num_bad_epochs += 1,
+            _ => {
+                self.best = Some(value);
+                self.num_bad_epochs = 0;
+            }
+        }
+
+        if self.num_bad_epochs > self.patience {
+            self.lr = f64::max(self.lr * self.factor, self.min_lr);
+            self.num_bad_epochs = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Factor must be greater than 0 and lower than 1"]
+    fn config_factor_too_high() {
+        ReduceOnPlateauLrSchedulerConfig::new(0.5, "Loss".to_string())
+            .with_factor(1.0)
+            .init();
+    }
+
+    #[test]
+    fn test_lr_reduced_after_patience() {
+        let mut scheduler = ReduceOnPlateauLrSchedulerConfig::new(0.8, "Loss".to_string())
+            .with_factor(0.5)
+            .with_patience(1)
+            .init();
+
+        let mut lrs = Vec::new();
+        for value in [1.0, 0.9, 0.9, 0.95, 0.8, 0.85, 0.85] {
+            lrs.push(LrScheduler::<TestBackend>::step(&mut scheduler));
+            LrScheduler::<TestBackend>::report_metric(&mut scheduler, value);
+        }
+        lrs.push(LrScheduler::<TestBackend>::step(&mut scheduler));
+
+        assert_eq!(lrs, vec![0.8, 0.8, 0.8, 0.8, 0.4, 0.4, 0.4, 0.2]);
+    }
+
+    #[test]
+    fn test_maximize_and_min_lr() {
+        let mut scheduler = ReduceOnPlateauLrSchedulerConfig::new(0.8, "Accuracy".to_string())
+            .with_factor(0.5)
+            .with_patience(0)
+            .with_min_lr(0.3)
+            .with_maximize(true)
+            .init();
+
+        for value in [50.0, 60.0, 55.0, 58.0] {
+            LrScheduler::<TestBackend>::report_metric(&mut scheduler, value);
+        }
+
+        assert_eq!(LrScheduler::<TestBackend>::step(&mut scheduler), 0.3);
+    }
+
+    #[test]
+    fn test_resume() {
+        let config = ReduceOnPlateauLrSchedulerConfig::new(0.8, "Loss".to_string())
+            .with_factor(0.5)
+            .with_patience(1);
+        let mut scheduler = config.init();
+        LrScheduler::<TestBackend>::report_metric(&mut scheduler, 1.0);
+        LrScheduler::<TestBackend>::report_metric(&mut scheduler, 1.0);
+
+        let record = LrScheduler::<TestBackend>::to_record(&scheduler);
+        let mut scheduler = LrScheduler::<TestBackend>::load_record(config.init(), record);
+        LrScheduler::<TestBackend>::report_metric(&mut scheduler, 1.0);
+
+        assert_eq!(LrScheduler::<TestBackend>::step(&mut scheduler), 0.4);
+    }
+}
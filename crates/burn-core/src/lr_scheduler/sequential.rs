@@ -0,0 +1,170 @@
+use super::LrScheduler;
+use crate::LearningRate;
+use burn_tensor::backend::Backend;
+
+/// A learning rate scheduler chaining two schedulers.
+///
+/// The first scheduler is used during the first `milestone` iterations, then the second scheduler is used, starting
+/// from its own first iteration. More schedulers can be chained with [then](SequentialLrScheduler::then), the
+/// milestones always being counted from the start of the training.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // Linear warmup during 1000 iterations, then cosine annealing during 9000 iterations.
+/// let scheduler = SequentialLrScheduler::new(
+///     LinearLrSchedulerConfig::new(1e-5, 1e-3, 1000).init(),
+///     1000,
+///     CosineAnnealingLrSchedulerConfig::new(1e-3, 9000).init(),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct SequentialLrScheduler<S1, S2> {
+    first: S1,
+    second: S2,
+    milestone: usize,
+    current_iter: usize,
+}
+
+impl<S1, S2> SequentialLrScheduler<S1, S2> {
+    /// Create a scheduler using `first` during the first `milestone` iterations, then `second`.
+    pub fn new(first: S1, milestone: usize, second: S2) -> Self {
+        Self {
+            first,
+            second,
+            milestone,
+            current_iter: 0,
+        }
+    }
+
+    /// Chain another scheduler, used from the iteration `milestone` counted from the start of the training.
+    ///
+    /// # Panics
+    /// This function panics if `milestone` is lower than the milestone of the current last scheduler.
+    pub fn then<S3>(self, milestone: usize, next: S3) -> SequentialLrScheduler<Self, S3> {
+        assert!(milestone >= self.milestone, "Milestones must be increasing");
+
+        SequentialLrScheduler::new(self, milestone, next)
+    }
+}
+
+impl<B: Backend, S1: LrScheduler<B>, S2: LrScheduler<B>> LrScheduler<B>
+    for SequentialLrScheduler<S1, S2>
+{
+    type Record = (S1::Record, S2::Record, usize);
+
+    fn step(&mut self) -> LearningRate {
+        let lr = match self.current_iter < self.milestone {
+            true => self.first.step(),
+            false => self.second.step(),
+        };
+        self.current_iter += 1;
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (
+            self.first.to_record(),
+            self.second.to_record(),
+            self.current_iter,
+        )
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        let (first, second, current_iter) = record;
+        self.first = self.first.load_record(first);
+        self.second = self.second.load_record(second);
+        self.current_iter = current_iter;
+        self
+    }
+
+    fn metric_name(&self) -> Option<&str> {
+        match self.current_iter < self.milestone {
+            true => self.first.metric_name(),
+            false => self.second.metric_name(),
+        }
+    }
+
+    fn report_metric(&mut self, value: f64) {
+        match self.current_iter < self.milestone {
+            true => self.first.report_metric(value),
+            false => self.second.report_metric(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lr_scheduler::constant::ConstantLr;
+    use crate::lr_scheduler::cosine::CosineAnnealingLrSchedulerConfig;
+    use crate::lr_scheduler::linear::LinearLrSchedulerConfig;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Milestones must be increasing"]
+    fn milestones_not_increasing() {
+        SequentialLrScheduler::new(ConstantLr::new(0.1), 4, ConstantLr::new(0.2))
+            .then(2, ConstantLr::new(0.3));
+    }
+
+    #[test]
+    fn test_chained_schedulers() {
+        let mut scheduler =
+            SequentialLrScheduler::new(ConstantLr::new(0.1), 2, ConstantLr::new(0.2))
+                .then(3, ConstantLr::new(0.3));
+
+        let lrs = (0..5)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect::<Vec<_>>();
+
+        assert_eq!(lrs, vec![0.1, 0.1, 0.2, 0.3, 0.3]);
+    }
+
+    #[test]
+    fn test_warmup_then_cosine() {
+        let mut scheduler = SequentialLrScheduler::new(
+            LinearLrSchedulerConfig::new(0.1, 0.5, 4).init(),
+            4,
+            CosineAnnealingLrSchedulerConfig::new(0.5, 4).init(),
+        );
+
+        let lrs = (0..8)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect::<Vec<_>>();
+
+        assert!(lrs[..4].windows(2).all(|pair| pair[0] < pair[1]));
+        let mut cosine = CosineAnnealingLrSchedulerConfig::new(0.5, 4).init();
+        assert_eq!(
+            lrs[4],
+            LrScheduler::<TestBackend>::step(&mut cosine),
+            "The cosine scheduler should start at its first iteration"
+        );
+        assert!(lrs[4..].windows(2).all(|pair| pair[0] > pair[1]));
+    }
+
+    #[test]
+    fn test_resume() {
+        let init = || {
+            SequentialLrScheduler::new(
+                LinearLrSchedulerConfig::new(0.1, 0.5, 4).init(),
+                4,
+                CosineAnnealingLrSchedulerConfig::new(0.5, 4).init(),
+            )
+        };
+        let mut scheduler = init();
+        let mut expected = init();
+        for _ in 0..5 {
+            LrScheduler::<TestBackend>::step(&mut scheduler);
+            LrScheduler::<TestBackend>::step(&mut expected);
+        }
+
+        let record = LrScheduler::<TestBackend>::to_record(&scheduler);
+        let mut scheduler = LrScheduler::<TestBackend>::load_record(init(), record);
+
+        assert_eq!(
+            LrScheduler::<TestBackend>::step(&mut scheduler),
+            LrScheduler::<TestBackend>::step(&mut expected)
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use super::LrScheduler;
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a step learning rate scheduler.
+///
+/// This scheduler starts at a learning rate `initial_lr`, then multiplies the learning rate by `gamma` every
+/// `step_size` iterations.
+#[derive(Config)]
+pub struct StepLrSchedulerConfig {
+    // The initial learning rate.
+    initial_lr: LearningRate,
+    // The number of iterations between two decays of the learning rate.
+    step_size: usize,
+    // The factor by which the learning rate is multiplied at each decay.
+    #[config(default = 0.1)]
+    gamma: f64,
+}
+
+impl StepLrSchedulerConfig {
+    /// Initializes a [step learning rate scheduler](StepLrScheduler).
+    ///
+    /// # Panics
+    /// This function panics if `initial_lr` is not between 0 and 1, if `step_size` is 0 or if `gamma` is not
+    /// between 0 and 1.
+    pub fn init(&self) -> StepLrScheduler {
+        assert!(
+            self.initial_lr > 0. && self.initial_lr <= 1.,
+            "Initial learning rate must be greater than 0 and at most 1"
+        );
+        assert!(self.step_size > 0, "Step size must be at least 1");
+        assert!(
+            self.gamma > 0. && self.gamma <= 1.,
+            "Gamma must be greater than 0 and at most 1"
+        );
+
+        StepLrScheduler {
+            initial_lr: self.initial_lr,
+            step_size: self.step_size,
+            gamma: self.gamma,
+            current_iter: 0,
+        }
+    }
+}
+
+/// A step learning rate scheduler.
+///
+/// See [StepLrSchedulerConfig] for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct StepLrScheduler {
+    initial_lr: LearningRate,
+    step_size: usize,
+    gamma: f64,
+    current_iter: usize,
+}
+
+impl<B: Backend> LrScheduler<B> for StepLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let num_decays = self.current_iter / self.step_size;
+        self.current_iter += 1;
+
+        self.initial_lr * self.gamma.powi(num_decays as i32)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.current_iter
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.current_iter = record;
+        self
+    }
+}
+
+/// The configuration for creating a multi-step learning rate scheduler.
+///
+/// This scheduler starts at a learning rate `initial_lr`, then multiplies the learning rate by `gamma` each time
+/// the number of iterations reaches one of the `milestones`.
+#[derive(Config)]
+pub struct MultiStepLrSchedulerConfig {
+    // The initial learning rate.
+    initial_lr: LearningRate,
+    // The iterations at which the learning rate is decayed, in increasing order.
+    milestones: Vec<usize>,
+    // The factor by which the learning rate is multiplied at each decay.
+    #[config(default = 0.1)]
+    gamma: f64,
+}
+
+impl MultiStepLrSchedulerConfig {
+    /// Initializes a [multi-step learning rate scheduler](MultiStepLrScheduler).
+    ///
+    /// # Panics
+    /// This function panics if `initial_lr` is not between 0 and 1, if the `milestones` are not increasing or if
+    /// `gamma` is not between 0 and 1.
+    pub fn init(&self) -> MultiStepLrScheduler {
+        assert!(
+            self.initial_lr > 0. && self.initial_lr <= 1.,
+            "Initial learning rate must be greater than 0 and at most 1"
+        );
+        assert!(
+            self.milestones.windows(2).all(|pair| pair[0] < pair[1]),
+            "Milestones must be strictly increasing"
+        );
+        assert!(
+            self.gamma > 0. && self.gamma <= 1.,
+            "Gamma must be greater than 0 and at most 1"
+        );
+
+        MultiStepLrScheduler {
+            initial_lr: self.initial_lr,
+            milestones: self.milestones.clone(),
+            gamma: self.gamma,
+            current_iter: 0,
+        }
+    }
+}
+
+/// A multi-step learning rate scheduler.
+///
+/// See [MultiStepLrSchedulerConfig] for more information.
+#[derive(Clone, Debug)]
+pub struct MultiStepLrScheduler {
+    initial_lr: LearningRate,
+    milestones: Vec<usize>,
+    gamma: f64,
+    current_iter: usize,
+}
+
+impl<B: Backend> LrScheduler<B> for MultiStepLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let num_decays = self
+            .milestones
+            .iter()
+            .take_while(|milestone| **milestone <= self.current_iter)
+            .count();
+        self.current_iter += 1;
+
+        self.initial_lr * self.gamma.powi(num_decays as i32)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.current_iter
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.current_iter = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Step size must be at least 1"]
+    fn config_step_size_too_low() {
+        StepLrSchedulerConfig::new(0.5, 0).init();
+    }
+
+    #[test]
+    #[should_panic = "Milestones must be strictly increasing"]
+    fn config_milestones_not_increasing() {
+        MultiStepLrSchedulerConfig::new(0.5, vec![10, 5]).init();
+    }
+
+    #[test]
+    fn test_step_lr_change() {
+        let mut scheduler = StepLrSchedulerConfig::new(0.8, 2).with_gamma(0.5).init();
+
+        let lrs = (0..6)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect::<Vec<_>>();
+
+        assert_eq!(lrs, vec![0.8, 0.8, 0.4, 0.4, 0.2, 0.2]);
+    }
+
+    #[test]
+    fn test_multi_step_lr_change() {
+        let mut scheduler = MultiStepLrSchedulerConfig::new(0.8, vec![1, 4])
+            .with_gamma(0.5)
+            .init();
+
+        let lrs = (0..6)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect::<Vec<_>>();
+
+        assert_eq!(lrs, vec![0.8, 0.4, 0.4, 0.4, 0.2, 0.2]);
+    }
+
+    #[test]
+    fn test_multi_step_lr_resume() {
+        let config = MultiStepLrSchedulerConfig::new(0.8, vec![2]).with_gamma(0.5);
+        let mut scheduler = config.init();
+        LrScheduler::<TestBackend>::step(&mut scheduler);
+        LrScheduler::<TestBackend>::step(&mut scheduler);
+
+        let record = LrScheduler::<TestBackend>::to_record(&scheduler);
+        let mut scheduler = LrScheduler::<TestBackend>::load_record(config.init(), record);
+
+        assert_eq!(LrScheduler::<TestBackend>::step(&mut scheduler), 0.4);
+    }
+}
@@ -0,0 +1,135 @@
+use super::LrScheduler;
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a linear warmup learning rate scheduler.
+///
+/// This scheduler wraps another scheduler and multiplies its learning rate by a factor increasing linearly from
+/// `start_factor` to 1 during the first `num_iters` iterations. The wrapped scheduler is stepped from the first
+/// iteration, use a [sequential scheduler](super::sequential::SequentialLrScheduler) to only start it after the
+/// warmup.
+#[derive(Config)]
+pub struct WarmupLrSchedulerConfig {
+    // The number of warmup iterations.
+    num_iters: usize,
+    // The factor of the learning rate before the first iteration.
+    #[config(default = 0.0)]
+    start_factor: f64,
+}
+
+impl WarmupLrSchedulerConfig {
+    /// Initializes a [warmup learning rate scheduler](WarmupLrScheduler) wrapping the given scheduler.
+    ///
+    /// # Panics
+    /// This function panics if `start_factor` is not between 0 and 1.
+    pub fn init<S>(&self, scheduler: S) -> WarmupLrScheduler<S> {
+        assert!(
+            self.start_factor >= 0. && self.start_factor <= 1.,
+            "Start factor must be at least 0 and at most 1"
+        );
+
+        WarmupLrScheduler {
+            scheduler,
+            num_iters: self.num_iters,
+            start_factor: self.start_factor,
+            current_iter: 0,
+        }
+    }
+}
+
+/// A linear warmup learning rate scheduler.
+///
+/// See [WarmupLrSchedulerConfig] for more information.
+#[derive(Clone, Debug)]
+pub struct WarmupLrScheduler<S> {
+    scheduler: S,
+    num_iters: usize,
+    start_factor: f64,
+    current_iter: usize,
+}
+
+impl<B: Backend, S: LrScheduler<B>> LrScheduler<B> for WarmupLrScheduler<S> {
+    type Record = (S::Record, usize);
+
+    fn step(&mut self) -> LearningRate {
+        let lr = self.scheduler.step();
+
+        if self.current_iter >= self.num_iters {
+            return lr;
+        }
+
+        self.current_iter += 1;
+        let progress = self.current_iter as f64 / self.num_iters as f64;
+
+        lr * (self.start_factor + (1.0 - self.start_factor) * progress)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (self.scheduler.to_record(), self.current_iter)
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        let (scheduler, current_iter) = record;
+        self.scheduler = self.scheduler.load_record(scheduler);
+        self.current_iter = current_iter;
+        self
+    }
+
+    fn metric_name(&self) -> Option<&str> {
+        self.scheduler.metric_name()
+    }
+
+    fn report_metric(&mut self, value: f64) {
+        self.scheduler.report_metric(value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lr_scheduler::constant::ConstantLr;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Start factor must be at least 0 and at most 1"]
+    fn config_start_factor_too_high() {
+        WarmupLrSchedulerConfig::new(10)
+            .with_start_factor(1.5)
+            .init(ConstantLr::new(0.5));
+    }
+
+    #[test]
+    fn test_lr_change() {
+        let mut scheduler = WarmupLrSchedulerConfig::new(4).init(ConstantLr::new(0.8));
+
+        let lrs = (0..6)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect::<Vec<_>>();
+
+        assert_lrs(&lrs, &[0.2, 0.4, 0.6, 0.8, 0.8, 0.8]);
+    }
+
+    #[test]
+    fn test_start_factor() {
+        let mut scheduler = WarmupLrSchedulerConfig::new(2)
+            .with_start_factor(0.5)
+            .init(ConstantLr::new(0.8));
+
+        let lrs = (0..3)
+            .map(|_| LrScheduler::<TestBackend>::step(&mut scheduler))
+            .collect::<Vec<_>>();
+
+        assert_lrs(&lrs, &[0.6, 0.8, 0.8]);
+    }
+
+    fn assert_lrs(lrs: &[LearningRate], expected: &[LearningRate]) {
+        assert_eq!(lrs.len(), expected.len());
+        for (lr, expected) in lrs.iter().zip(expected) {
+            assert!(
+                (lr - expected).abs() < 1e-10,
+                "Expected {expected}, got {lr}"
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ParamId, Quantizer};
+use super::{ParamId, Quantizer, RunningState};
 use crate::{
     record::Record,
     tensor::backend::{AutodiffBackend, Backend},
@@ -211,8 +211,19 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
 
 /// Module visitor trait.
 pub trait ModuleVisitor<B: Backend> {
+    /// Called before visiting the submodule with the given name, which is the field name for
+    /// structs, the variant name for enums and the index for collections.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after visiting the submodule with the given name.
+    fn exit_module(&mut self, _name: &str) {}
     /// Visit a float tensor in the module.
     fn visit_float<const D: usize>(&mut self, _id: &ParamId, _tensor: &Tensor<B, D>) {}
+    /// Visit a [running state](RunningState) in the module.
+    ///
+    /// The default implementation [visits](ModuleVisitor::visit_float) its value.
+    fn visit_running_state<const D: usize>(&mut self, state: &RunningState<Tensor<B, D>>) {
+        self.visit_float(state.id(), &state.value())
+    }
     /// Visit an int tensor in the module.
     fn visit_int<const D: usize>(&mut self, _id: &ParamId, _tensor: &Tensor<B, D, Int>) {}
     /// Visit a bool tensor in the module.
@@ -225,6 +236,15 @@ pub trait ModuleMapper<B: Backend> {
     fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
         tensor
     }
+    /// Map a [running state](RunningState) in the module.
+    ///
+    /// The default implementation [maps its value](RunningState::map_value) in place.
+    fn map_running_state<const D: usize>(
+        &mut self,
+        state: RunningState<Tensor<B, D>>,
+    ) -> RunningState<Tensor<B, D>> {
+        state.map_value(self)
+    }
     /// Map an int tensor in the module.
     fn map_int<const D: usize>(
         &mut self,
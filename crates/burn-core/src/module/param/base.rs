@@ -1,6 +1,8 @@
 use super::ParamId;
 use alloc::boxed::Box;
 use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 use burn_common::stub::RwLock;
 use core::cell::OnceCell;
 use core::ops::Deref;
@@ -41,8 +43,15 @@ pub struct Param<T: Parameter> {
     /// when the lock is actually useful, waiting for the initialization to be completed before
     /// returning the value.
     initialization: Option<RwLock<Option<Uninitialized<T>>>>,
+    /// Hooks called with each value taken by the parameter, see [ParamHook].
+    hooks: Vec<ParamHook<T>>,
 }
 
+/// A function called with each value taken by a [parameter](Param), e.g. after an optimizer
+/// step, used to register [gradient hooks](crate::tensor::Tensor::register_hook) on all the
+/// values of the parameter.
+pub type ParamHook<T> = Arc<dyn Fn(&T) + Send + Sync>;
+
 impl<T: Parameter> core::fmt::Display for Param<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str(format!("Param: {}", self.id).as_str())
@@ -91,6 +100,7 @@ impl<T: Parameter> Param<T> {
             id,
             state: OnceCell::from(value),
             initialization: None,
+            hooks: Vec::new(),
         }
     }
 
@@ -107,6 +117,7 @@ impl<T: Parameter> Param<T> {
                 device,
                 is_require_grad,
             }))),
+            hooks: Vec::new(),
         }
     }
 
@@ -124,6 +135,7 @@ impl<T: Parameter> Param<T> {
                 let tensor = state.initialize();
 
                 *result = None;
+                self.hooks.iter().for_each(|hook| hook(&tensor));
 
                 tensor
             })
@@ -145,15 +157,38 @@ impl<T: Parameter> Param<T> {
     }
 
     /// Execute the given function on the inner value.
-    pub fn map<F: Fn(T) -> T>(self, func: F) -> Self {
+    pub fn map<F: FnOnce(T) -> T>(self, func: F) -> Self {
+        let hooks = self.hooks.clone();
         let (id, tensor) = self.consume();
         let tensor = func(tensor);
 
-        Self {
-            id,
-            state: OnceCell::from(tensor),
-            initialization: None,
+        Self::initialized(id, tensor).with_hooks(hooks)
+    }
+
+    /// Create a new parameter with the given id and value, keeping the hooks of the current
+    /// parameter.
+    pub(crate) fn replace(self, id: ParamId, value: T) -> Self {
+        Self::initialized(id, value).with_hooks(self.hooks)
+    }
+
+    /// Add a hook called with the current value of the parameter, if initialized, and with
+    /// each new value it takes.
+    pub(crate) fn add_hook(mut self, hook: ParamHook<T>) -> Self {
+        if let Some(value) = self.state.get() {
+            hook(value);
+        }
+
+        self.hooks.push(hook);
+        self
+    }
+
+    fn with_hooks(mut self, hooks: Vec<ParamHook<T>>) -> Self {
+        if let Some(value) = self.state.get() {
+            hooks.iter().for_each(|hook| hook(value));
         }
+
+        self.hooks = hooks;
+        self
     }
 
     /// The device on which the parameter is or will be initialized.
@@ -235,7 +270,10 @@ impl<T: Parameter> Param<T> {
 
 impl<T: Parameter> Clone for Param<T> {
     fn clone(&self) -> Self {
-        Param::initialized(self.id.clone(), self.val())
+        let mut param = Param::initialized(self.id.clone(), self.val());
+        // The value is the same, so the hooks are already registered on it.
+        param.hooks = self.hooks.clone();
+        param
     }
 }
 
@@ -255,6 +293,7 @@ impl<T: Parameter> Deref for Param<T> {
             let tensor = state.initialize();
 
             *result = None;
+            self.hooks.iter().for_each(|hook| hook(&tensor));
 
             tensor
         })
@@ -80,6 +80,30 @@ where
     }
 }
 
+/// Buffer to name the elements of a collection with their index, without allocating on every
+/// traversal.
+#[derive(Default)]
+struct IndexBuffer {
+    digits: [u8; 20],
+}
+
+impl IndexBuffer {
+    /// Returns the decimal representation of the index.
+    fn name(&mut self, mut index: usize) -> &str {
+        let mut start = self.digits.len();
+        loop {
+            start -= 1;
+            self.digits[start] = b'0' + (index % 10) as u8;
+            index /= 10;
+            if index == 0 {
+                break;
+            }
+        }
+
+        core::str::from_utf8(&self.digits[start..]).unwrap()
+    }
+}
+
 impl<T, B> Module<B> for Vec<T>
 where
     T: Module<B> + Debug + Send + Clone,
@@ -97,8 +121,12 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        let mut buffer = IndexBuffer::default();
+        self.iter().enumerate().for_each(|(i, module)| {
+            let name = buffer.name(i);
+            visitor.enter_module(name);
             module.visit(visitor);
+            visitor.exit_module(name);
         });
     }
 
@@ -197,8 +225,12 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        let mut buffer = IndexBuffer::default();
+        self.iter().enumerate().for_each(|(i, module)| {
+            let name = buffer.name(i);
+            visitor.enter_module(name);
             module.visit(visitor);
+            visitor.exit_module(name);
         });
     }
 
@@ -288,7 +320,11 @@ macro_rules! impl_module_tuple {
             }
 
             fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-                $(self.$i.visit(visitor);)*
+                $(
+                    visitor.enter_module(stringify!($i));
+                    self.$i.visit(visitor);
+                    visitor.exit_module(stringify!($i));
+                )*
             }
 
             fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
@@ -347,6 +383,7 @@ impl_module_tuple!([L0, L1, L2, L3, L4, L5, L6, L7, L8, L9][0, 1, 2, 3, 4, 5, 6,
 mod tests {
     use super::*;
     use crate::TestBackend;
+    use alloc::string::ToString;
 
     #[test]
     fn dont_override_constant_module_when_loading_record() {
@@ -366,4 +403,14 @@ mod tests {
 
         assert_eq!(loaded, module);
     }
+
+    #[test]
+    fn index_buffer_names_elements_with_their_index() {
+        let mut buffer = IndexBuffer::default();
+
+        assert_eq!(buffer.name(0), "0");
+        assert_eq!(buffer.name(42), "42");
+        assert_eq!(buffer.name(7), "7");
+        assert_eq!(buffer.name(usize::MAX), usize::MAX.to_string());
+    }
 }
@@ -49,6 +49,7 @@ pub struct RunningState<V> {
     id: ParamId,
     values: Arc<Mutex<HashMap<ThreadId, V>>>,
     value: Arc<Mutex<V>>,
+    num_averaged: Arc<Mutex<Option<usize>>>,
 }
 
 // Implement display for the module
@@ -73,19 +74,11 @@ impl<const D: usize, B: Backend> Module<B> for RunningState<Tensor<B, D>> {
     type Record = Param<Tensor<B, D>>;
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        let tensor = self.value.lock().unwrap();
-
-        visitor.visit_float(&self.id, &tensor)
+        visitor.visit_running_state(self)
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        let mut tensor = self.value.lock().unwrap();
-        let tensor_out = mapper.map_float(&self.id, tensor.clone());
-
-        *tensor = tensor_out;
-        core::mem::drop(tensor);
-
-        self
+        mapper.map_running_state(self)
     }
 
     fn into_record(self) -> Self::Record {
@@ -140,6 +133,7 @@ impl<const D: usize, B: Backend> RunningState<Tensor<B, D>> {
             id: ParamId::new(),
             values: Arc::new(Mutex::new(HashMap::new())),
             value: Arc::new(Mutex::new(value)),
+            num_averaged: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -149,6 +143,7 @@ impl<const D: usize, B: Backend> RunningState<Tensor<B, D>> {
             id,
             values: Arc::new(Mutex::new(HashMap::new())),
             value: Arc::new(Mutex::new(value)),
+            num_averaged: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -159,9 +154,27 @@ impl<const D: usize, B: Backend> RunningState<Tensor<B, D>> {
             id: record.id,
             values: Arc::new(Mutex::new(HashMap::new())),
             value: Arc::new(Mutex::new(tensor)),
+            num_averaged: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// The id of the running state.
+    pub fn id(&self) -> &ParamId {
+        &self.id
+    }
+
+    /// Map the value of the state in place with the [mapper](ModuleMapper::map_float), so the
+    /// clones of the state share the mapped value.
+    pub fn map_value<M: ModuleMapper<B> + ?Sized>(self, mapper: &mut M) -> Self {
+        let mut tensor = self.value.lock().unwrap();
+        let tensor_out = mapper.map_float(&self.id, tensor.clone());
+
+        *tensor = tensor_out;
+        core::mem::drop(tensor);
+
+        self
+    }
+
     /// Update the value on the current thread.
     pub fn update(&self, value: Tensor<B, D>) {
         let thread_id = get_thread_current_id();
@@ -174,6 +187,36 @@ impl<const D: usize, B: Backend> RunningState<Tensor<B, D>> {
         map.insert(thread_id, value);
     }
 
+    /// Update the value on the current thread with the moving average of the observed values,
+    /// `value * (1 - momentum) + observed * momentum`.
+    ///
+    /// After a [reset](RunningState::reset_average), the momentum of the `n`-th update is
+    /// `1 / n` instead, so that the value is the cumulative average of the observed values.
+    pub fn update_average(&self, observed: Tensor<B, D>, momentum: f64) {
+        let momentum = match self.num_averaged.lock().unwrap().as_mut() {
+            Some(num_averaged) => {
+                *num_averaged += 1;
+                1.0 / *num_averaged as f64
+            }
+            None => momentum,
+        };
+        let value = self.value_sync().to_device(&observed.device());
+
+        self.update(
+            value
+                .mul_scalar(1.0 - momentum)
+                .add(observed.mul_scalar(momentum)),
+        );
+    }
+
+    /// Reset the [moving average](RunningState::update_average) of the state, which becomes
+    /// the cumulative average of the next observed values.
+    ///
+    /// The current value is discarded at the next update.
+    pub fn reset_average(&self) {
+        *self.num_averaged.lock().unwrap() = Some(0);
+    }
+
     /// Get the current value,
     ///
     /// # Note
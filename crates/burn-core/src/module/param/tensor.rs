@@ -7,8 +7,8 @@ use crate::tensor::{
     backend::{AutodiffBackend, Backend},
     Tensor,
 };
-use alloc::{format, string::ToString, vec::Vec};
-use burn_tensor::{Bool, Float, Int, TensorData};
+use alloc::{format, string::ToString, sync::Arc, vec::Vec};
+use burn_tensor::{backend::GradientHook, Bool, Float, Int, TensorData, TensorPrimitive};
 
 impl<B: Backend, const D: usize> Parameter for Tensor<B, D, Float> {
     type Device = B::Device;
@@ -84,6 +84,38 @@ impl<B: Backend, const D: usize> Param<Tensor<B, D>> {
     }
 }
 
+impl<B: AutodiffBackend, const D: usize> Param<Tensor<B, D>> {
+    /// Register a hook called with the gradient of the parameter during the backward pass.
+    ///
+    /// Unlike [Tensor::register_hook], the hook stays registered when the parameter value is
+    /// updated, e.g. by an optimizer, so it is called during every backward pass where the
+    /// parameter is used. The gradient returned by the hook is the one collected for the
+    /// parameter in the [gradients](AutodiffBackend::Gradients).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Clip the gradient of the weights of a layer.
+    /// linear.weight = linear.weight.register_hook(|grad| grad.clamp(-1.0, 1.0));
+    /// ```
+    pub fn register_hook<F>(self, hook: F) -> Self
+    where
+        F: Fn(Tensor<B::InnerBackend, D>) -> Tensor<B::InnerBackend, D> + Send + Sync + 'static,
+    {
+        // The same gradient hook is registered on all the values, so registering it again on an
+        // unchanged value has no effect.
+        let hook: GradientHook<B::InnerBackend, D> = Arc::new(move |grad| {
+            hook(Tensor::from_primitive(TensorPrimitive::Float(grad)))
+                .into_primitive()
+                .tensor()
+        });
+
+        self.add_hook(Arc::new(move |tensor: &Tensor<B, D>| {
+            B::register_hook(&tensor.clone().into_primitive().tensor(), hook.clone())
+        }))
+    }
+}
+
 impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
     type Record = Param<Tensor<B, D>>;
 
@@ -92,10 +124,9 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        let (id, tensor) = self.consume();
-        let value = mapper.map_float(&id, tensor);
+        let id = self.id.clone();
 
-        Self::initialized(id, value)
+        Param::map(self, |tensor| mapper.map_float(&id, tensor))
     }
 
     fn into_record(self) -> Self::Record {
@@ -116,7 +147,7 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
         // Make sure we load the record with the same autodiff setting.
         new_value = new_value.set_require_grad(expected_require_grad);
 
-        Self::initialized(new_id, new_value)
+        self.replace(new_id, new_value)
     }
 
     fn to_device(self, device: &<B as Backend>::Device) -> Self {
@@ -360,4 +391,24 @@ mod tests {
         assert!(!no_grad_is_require_grad);
         assert!(with_default_is_require_grad);
     }
+
+    #[test]
+    fn test_hook_registered_on_new_values() {
+        let device = Default::default();
+        let param = Param::from_tensor(Tensor::<TestAutodiffBackend, 1>::ones([2], &device))
+            .register_hook(|grad| grad.mul_scalar(2.0));
+
+        // Update the value like an optimizer, then keep the same value.
+        let param = param
+            .map(|tensor| Tensor::from_inner(tensor.inner().add_scalar(1.0)).require_grad())
+            .set_require_grad(true);
+
+        let grads = param.val().mul_scalar(3.0).sum().backward();
+
+        param
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([6.0, 6.0]), false);
+    }
 }
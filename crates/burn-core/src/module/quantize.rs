@@ -16,8 +16,81 @@ pub struct Quantizer<C: Calibration> {
 
 impl<B: Backend, C: Calibration> ModuleMapper<B> for Quantizer<C> {
     fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
-        let range = self.calibration.compute_range(&tensor);
+        let range = self.scheme.compute_range(&self.calibration, &tensor);
         let qparams = self.scheme.compute_q_params(range);
         tensor.quantize(&self.scheme, qparams)
     }
 }
+
+/// Describes how to fake-quantize the parameters of a module for quantization-aware training.
+///
+/// The trainable parameters are quantized and dequantized in floating point precision, so that
+/// the forward pass models the quantization error. The gradients are propagated to the original
+/// parameters with a straight-through estimator. The states that aren't trainable (e.g., running
+/// statistics) are left unchanged.
+///
+/// The fake-quantized module should only be used for the forward pass of a training step, while
+/// the gradients are collected and applied to the original module. Once trained, the weights are
+/// quantized with a [Quantizer] using the same scheme.
+///
+/// ```rust, ignore
+/// let output = model.clone().map(&mut fake_quantizer).forward(batch);
+/// let grads = GradientsParams::from_grads(loss.backward(), &model);
+/// model = optim.step(lr, model, grads);
+/// ```
+pub struct FakeQuantizer<C: Calibration> {
+    /// The calibration method used in quantization.
+    pub calibration: C,
+    /// The quantization scheme.
+    pub scheme: QuantizationScheme,
+}
+
+impl<B: Backend, C: Calibration> ModuleMapper<B> for FakeQuantizer<C> {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if !tensor.is_require_grad() {
+            return tensor;
+        }
+
+        let range = self
+            .scheme
+            .compute_range(&self.calibration, &tensor.clone().detach());
+        let qparams = self.scheme.compute_q_params(range);
+        tensor.fake_quantize(&self.scheme, qparams)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        optim::GradientsParams,
+        tensor::quantization::{MinMaxCalibration, QuantizationType},
+        TestAutodiffBackend,
+    };
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn fake_quantizer_should_propagate_gradients_to_module_params() {
+        let device = Default::default();
+        let layer: Linear<TestAutodiffBackend> =
+            LinearConfig::new(8, 4).with_bias(true).init(&device);
+        let mut fake_quantizer = FakeQuantizer {
+            calibration: MinMaxCalibration {},
+            scheme: QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8),
+        };
+
+        let fake_quantized = layer.clone().map(&mut fake_quantizer);
+        let input = Tensor::random([2, 8], Distribution::Default, &device);
+        let loss = fake_quantized.forward(input).sum();
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+
+        assert_eq!(grads.len(), 2);
+        fake_quantized
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq_diff(&layer.weight.val().into_data(), 0.01);
+    }
+}
@@ -0,0 +1,167 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::{backend::Backend, Int, Tensor};
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Cosine embedding loss](CosineEmbeddingLoss).
+#[derive(Config, Debug)]
+pub struct CosineEmbeddingLossConfig {
+    /// The margin below which the cosine similarity of dissimilar pairs isn't penalized.
+    #[config(default = 0.0)]
+    pub margin: f32,
+}
+
+impl CosineEmbeddingLossConfig {
+    /// Initialize [Cosine embedding loss](CosineEmbeddingLoss).
+    pub fn init(&self) -> CosineEmbeddingLoss {
+        self.assertions();
+        CosineEmbeddingLoss {
+            margin: self.margin,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            (-1.0..=1.).contains(&self.margin),
+            "Margin of cosine embedding loss should be in interval [-1, 1]. Got {}",
+            self.margin
+        );
+    }
+}
+
+/// Calculate the cosine embedding loss, measuring whether two inputs are similar or dissimilar
+/// using their cosine similarity.
+///
+/// The loss of each pair of inputs is given by
+///
+/// ```text
+/// L(x1, x2, y) = 1 - cos(x1, x2)                  if y = 1
+/// L(x1, x2, y) = max(0, cos(x1, x2) - margin)     if y = -1
+/// ```
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct CosineEmbeddingLoss {
+    /// The margin for the dissimilar pairs.
+    pub margin: f32,
+}
+
+impl ModuleDisplay for CosineEmbeddingLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("margin", &self.margin).optional()
+    }
+}
+
+impl CosineEmbeddingLoss {
+    /// Compute the criterion on the input tensors, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[batch_size, dim]`
+    /// - input2: `[batch_size, dim]`
+    /// - targets: `[batch_size]`, with values 1 for similar pairs and -1 for dissimilar pairs
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        input1: Tensor<B, 2>,
+        input2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(input1, input2, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[batch_size, dim]`
+    /// - input2: `[batch_size, dim]`
+    /// - targets: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        input1: Tensor<B, 2>,
+        input2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        assert!(
+            input1.dims() == input2.dims(),
+            "Shape of input1 ({:?}) should correspond to the shape of input2 ({:?}).",
+            input1.dims(),
+            input2.dims()
+        );
+        let [batch_size, _] = input1.dims();
+
+        let similarity = cosine_similarity(input1, input2).reshape([batch_size]);
+        let dissimilar_loss = similarity.clone().sub_scalar(self.margin).clamp_min(0.);
+        let similar_loss = similarity.neg() + 1.;
+
+        dissimilar_loss.mask_where(targets.equal_elem(1), similar_loss)
+    }
+}
+
+/// The cosine similarity along the last dimension, the norms being bounded by a small epsilon to
+/// avoid a division by zero.
+fn cosine_similarity<B: Backend>(x1: Tensor<B, 2>, x2: Tensor<B, 2>) -> Tensor<B, 2> {
+    const EPSILON: f32 = 1e-8;
+
+    let dot = (x1.clone() * x2.clone()).sum_dim(1);
+    let norm1 = x1.powf_scalar(2.).sum_dim(1).sqrt().clamp_min(EPSILON);
+    let norm2 = x2.powf_scalar(2.).sum_dim(1).sqrt().clamp_min(EPSILON);
+
+    dot / (norm1 * norm2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_cosine_embedding_loss() {
+        let device = Default::default();
+        let input1 =
+            Tensor::<TestBackend, 2>::from_data([[1.0, 0.0], [1.0, 0.0], [1.0, 1.0]], &device);
+        let input2 =
+            Tensor::<TestBackend, 2>::from_data([[1.0, 1.0], [0.0, 1.0], [2.0, 2.0]], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([1, -1, -1], &device);
+
+        let loss = CosineEmbeddingLossConfig::new().with_margin(0.5).init();
+        let loss_no_reduction =
+            loss.forward_no_reduction(input1.clone(), input2.clone(), targets.clone());
+        let loss_mean = loss.forward(input1, input2, targets, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.292893, 0.0, 0.5]), 5);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.264298]), 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = CosineEmbeddingLossConfig::new().with_margin(0.5).init();
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "CosineEmbeddingLoss {margin: 0.5}"
+        );
+    }
+}
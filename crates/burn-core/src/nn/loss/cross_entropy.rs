@@ -4,6 +4,8 @@ use crate::module::{Content, DisplaySettings, ModuleDisplay};
 use crate::tensor::activation::log_softmax;
 use crate::tensor::{backend::Backend, Bool, Int, Tensor};
 use crate::{config::Config, module::Module};
+
+use super::mask::tokens_mask;
 use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -35,6 +37,13 @@ pub struct CrossEntropyLossConfig {
     ///
     #[config(default = true)]
     pub logits: bool,
+
+    /// Create cross-entropy ignoring the targets equal to the given index, which doesn't need
+    /// to be a valid class, e.g. `-100`.
+    ///
+    /// Unlike the pad tokens, which only have a zero loss, the ignored targets don't count in the
+    /// mean of the loss.
+    pub ignore_index: Option<i64>,
 }
 
 impl CrossEntropyLossConfig {
@@ -49,6 +58,7 @@ impl CrossEntropyLossConfig {
                 .map(|e| Tensor::<B, 1>::from_floats(e.as_slice(), device)),
             smoothing: self.smoothing,
             logits: self.logits,
+            ignore_index: self.ignore_index,
         }
     }
 
@@ -83,6 +93,8 @@ pub struct CrossEntropyLoss<B: Backend> {
     pub smoothing: Option<f32>,
     /// Use logits as input.
     pub logits: bool,
+    /// Target index to ignore in the loss calculation.
+    pub ignore_index: Option<i64>,
 }
 
 impl<B: Backend> ModuleDisplay for CrossEntropyLoss<B> {
@@ -104,6 +116,7 @@ impl<B: Backend> ModuleDisplay for CrossEntropyLoss<B> {
             .add("weights", &self.weights)
             .add("smoothing", &self.smoothing)
             .add("logits", &self.logits)
+            .add("ignore_index", &self.ignore_index)
             .optional()
     }
 }
@@ -124,12 +137,158 @@ impl<B: Backend> CrossEntropyLoss<B> {
     /// - targets: `[batch_size]`
     pub fn forward(&self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
         Self::assertions(logits.clone(), targets.clone());
+        if self.ignore_index.is_some() {
+            return self.forward_nd(logits, targets);
+        }
         match self.smoothing {
             Some(alpha) => self.forward_smoothed(logits, targets, alpha),
             _ => self.forward_default(logits, targets),
         }
     }
 
+    /// Compute the criterion on the input tensor with the classes along the second dimension,
+    /// e.g. the per-pixel logits of a segmentation model.
+    ///
+    /// The loss is the mean over the targets which aren't ignored, weighted by the class weights.
+    /// Like with [forward](CrossEntropyLoss::forward), the pad tokens have a zero loss but count in
+    /// the mean, while the targets equal to the
+    /// [ignore index](CrossEntropyLossConfig::ignore_index) don't.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_classes, d_1, ..., d_k]`
+    /// - targets: `[batch_size, d_1, ..., d_k]`
+    /// - output: `[1]`
+    pub fn forward_nd<const D: usize, const D2: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D2, Int>,
+    ) -> Tensor<B, 1> {
+        Self::assertions_nd(&logits, &targets);
+        let num_classes = logits.dims()[1];
+        let device = logits.device();
+
+        let log_probs = self.log_probs(logits);
+        let ignored = tokens_mask(&targets, self.ignore_index);
+        let masked = tokens_mask(&targets, self.pad_indices().chain(self.ignore_index));
+        // The ignored targets could be out of the range of the classes.
+        let targets = match &masked {
+            Some(mask) => targets.mask_fill(mask.clone(), 0),
+            None => targets,
+        };
+
+        let weights = match &self.weights {
+            Some(weights) => weights
+                .clone()
+                .gather(0, targets.clone().flatten::<1>(0, D2 - 1))
+                .reshape(targets.dims()),
+            None => Tensor::ones(targets.dims(), &device),
+        };
+        // Only the ignored targets don't count in the mean.
+        let total = match ignored {
+            Some(mask) => weights.clone().mask_fill(mask, 0),
+            None => weights.clone(),
+        };
+        let weights = match &masked {
+            Some(mask) => weights.mask_fill(mask.clone(), 0),
+            None => weights,
+        };
+
+        let loss = log_probs
+            .clone()
+            .gather(1, targets.unsqueeze_dim(1))
+            .squeeze::<D2>(1)
+            * weights;
+
+        let loss = match self.smoothing {
+            Some(alpha) => {
+                // The smoothed targets put `alpha / num_classes` on each class.
+                let log_probs = match &self.weights {
+                    Some(class_weights) => {
+                        let shape = log_probs.dims();
+                        log_probs
+                            * class_weights
+                                .clone()
+                                .reshape(Self::class_shape::<D>(num_classes))
+                                .expand(shape)
+                    }
+                    None => log_probs,
+                };
+                let uniform = log_probs.sum_dim(1).squeeze::<D2>(1);
+                let uniform = match masked {
+                    Some(mask) => uniform.mask_fill(mask, 0),
+                    None => uniform,
+                };
+                loss * (1. - alpha) + uniform * (alpha / num_classes as f32)
+            }
+            None => loss,
+        };
+
+        loss.sum().neg() / total.sum()
+    }
+
+    /// Compute the criterion on the input tensor with probability targets (soft labels) and the
+    /// classes along the second dimension.
+    ///
+    /// The loss is the mean over all the elements except the classes, the class weights
+    /// multiplying the target probabilities. The label smoothing is applied to the targets.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_classes, d_1, ..., d_k]`
+    /// - targets: `[batch_size, num_classes, d_1, ..., d_k]`
+    /// - output: `[1]`
+    pub fn forward_probs<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, 1> {
+        assert!(
+            logits.dims() == targets.dims(),
+            "Shape of targets ({:?}) should correspond to the shape of logits ({:?}).",
+            targets.dims(),
+            logits.dims()
+        );
+        let num_classes = logits.dims()[1];
+
+        let targets = match self.smoothing {
+            Some(alpha) => targets * (1. - alpha) + alpha / num_classes as f32,
+            None => targets,
+        };
+        let loss = self.log_probs(logits) * targets;
+        let loss = match &self.weights {
+            Some(weights) => {
+                let shape = loss.dims();
+                loss * weights
+                    .clone()
+                    .reshape(Self::class_shape::<D>(num_classes))
+                    .expand(shape)
+            }
+            None => loss,
+        };
+
+        loss.sum_dim(1).mean().neg()
+    }
+
+    fn log_probs<const D: usize>(&self, logits: Tensor<B, D>) -> Tensor<B, D> {
+        if self.logits {
+            log_softmax(logits, 1)
+        } else {
+            logits.log()
+        }
+    }
+
+    /// The shape broadcasting the class weights along the second dimension.
+    fn class_shape<const D: usize>(num_classes: usize) -> [usize; D] {
+        let mut shape = [1; D];
+        shape[1] = num_classes;
+        shape
+    }
+
+    fn pad_indices(&self) -> impl Iterator<Item = i64> + '_ {
+        self.pad_tokens.iter().flatten().map(|token| *token as i64)
+    }
+
     fn forward_smoothed(
         &self,
         logits: Tensor<B, 2>,
@@ -201,16 +360,7 @@ impl<B: Backend> CrossEntropyLoss<B> {
     }
 
     fn padding_mask(&self, targets: &Tensor<B, 1, Int>) -> Option<Tensor<B, 1, Bool>> {
-        let mut mask = None;
-        if let Some(pad_tokens) = &self.pad_tokens {
-            let mut res = targets.clone().equal_elem(pad_tokens[0] as i64).int();
-            for x in pad_tokens {
-                res = res + targets.clone().equal_elem(*x as i64).int();
-            }
-            mask = Some(res.greater_elem(0));
-        }
-
-        mask
+        tokens_mask(targets, self.pad_indices())
     }
 
     fn apply_mask_1d(mut tensor: Tensor<B, 1>, mask: Option<Tensor<B, 1, Bool>>) -> Tensor<B, 1> {
@@ -230,6 +380,22 @@ impl<B: Backend> CrossEntropyLoss<B> {
         tensor
     }
 
+    fn assertions_nd<const D: usize, const D2: usize>(
+        logits: &Tensor<B, D>,
+        targets: &Tensor<B, D2, Int>,
+    ) {
+        let logits_dims = logits.dims();
+        let targets_dims = targets.dims();
+        assert!(
+            D == D2 + 1
+                && logits_dims[0] == targets_dims[0]
+                && logits_dims[2..] == targets_dims[1..],
+            "Shape of targets ({:?}) should correspond to the shape of logits ({:?}) without the class dimension.",
+            targets_dims,
+            logits_dims
+        );
+    }
+
     fn assertions(logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) {
         let [logits_height, _] = logits.dims();
         let [targets_height] = targets.dims();
@@ -436,6 +602,170 @@ mod tests {
         loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
     }
 
+    #[test]
+    fn test_cross_entropy_loss_with_ignore_index() {
+        let (logits, _, _) = setup!();
+        let device = Default::default();
+        let targets =
+            Tensor::<TestBackend, 1, Int>::from_data(TensorData::from([2, -100, 4, 1]), &device);
+        let kept = Tensor::<TestBackend, 1, Int>::from_data(TensorData::from([0, 2, 3]), &device);
+
+        let loss_1 = CrossEntropyLossConfig::new()
+            .with_ignore_index(Some(-100))
+            .init(&device)
+            .forward(logits.clone(), targets);
+        // The ignored target doesn't count in the mean.
+        let loss_2 = CrossEntropyLossConfig::new().init(&device).forward(
+            logits.select(0, kept),
+            Tensor::from_data(TensorData::from([2, 4, 1]), &device),
+        );
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_ignore_index_counts_pad_tokens() {
+        let (logits, targets, _) = setup_padded!();
+        let device = Default::default();
+        let config = CrossEntropyLossConfig::new()
+            .with_weights(Some(vec![1.0, 2., 3., 4., 5.]))
+            .with_pad_tokens(Some(vec![1, 2]));
+
+        // The pad tokens count in the mean whether an index is ignored or not.
+        let loss_1 = config
+            .clone()
+            .with_ignore_index(Some(-100))
+            .init(&device)
+            .forward(logits.clone(), targets.clone());
+        let loss_2 = config.init(&device).forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_ignore_index_and_weights() {
+        let (logits, _, targets_logits) = setup!();
+        let device = Default::default();
+        let weights = vec![1.0, 2., 3., 4., 5.];
+        let targets =
+            Tensor::<TestBackend, 1, Int>::from_data(TensorData::from([2, 0, 4, 1]), &device);
+
+        let loss_1 = CrossEntropyLossConfig::new()
+            .with_weights(Some(weights.clone()))
+            .with_ignore_index(Some(0))
+            .init(&device)
+            .forward(logits.clone(), targets);
+        let ignored = Tensor::<TestBackend, 2>::from_data(
+            TensorData::from([[1.0], [0.0], [1.0], [1.0]]),
+            &device,
+        );
+        let loss_2 = log_softmax(logits, 1)
+            * targets_logits
+            * ignored.repeat(1, 5)
+            * Tensor::<TestBackend, 1>::from_floats(weights.as_slice(), &device)
+                .unsqueeze()
+                .repeat(0, 4);
+        let loss_2 = loss_2.sum().neg() / (3. + 5. + 2.);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_nd() {
+        let [batch_size, num_classes, length] = [2, 4, 3];
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 3>::random(
+            [batch_size, num_classes, length],
+            Distribution::Normal(0., 1.0),
+            &device,
+        );
+        let targets = Tensor::<TestBackend, 2, Int>::from_data(
+            TensorData::from([[0, 3, 1], [2, 2, 0]]),
+            &device,
+        );
+        let config = CrossEntropyLossConfig::new()
+            .with_weights(Some(vec![1.0, 2., 3., 4.]))
+            .with_smoothing(Some(0.1));
+
+        let loss_1 = config
+            .init(&device)
+            .forward_nd(logits.clone(), targets.clone());
+        // Same loss with the classes along the last dimension, flattened.
+        let loss_2 = config.init(&device).forward(
+            logits
+                .swap_dims(1, 2)
+                .reshape([batch_size * length, num_classes]),
+            targets.reshape([batch_size * length]),
+        );
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_nd_with_ignore_index() {
+        let device = Default::default();
+        let logits =
+            Tensor::<TestBackend, 4>::random([2, 3, 2, 2], Distribution::Normal(0., 1.0), &device);
+        let targets = Tensor::<TestBackend, 3, Int>::from_data(
+            TensorData::from([[[0, 255], [1, 2]], [[255, 255], [2, 1]]]),
+            &device,
+        );
+
+        let loss = CrossEntropyLossConfig::new()
+            .with_ignore_index(Some(255))
+            .init(&device)
+            .forward_nd(logits.clone(), targets.clone());
+
+        // Mean of the negative log-probabilities of the 5 targets which aren't ignored.
+        let log_probs = log_softmax(logits, 1).into_data();
+        let log_probs = log_probs.as_slice::<f32>().unwrap();
+        let index = |b: usize, c: usize, h: usize, w: usize| ((b * 3 + c) * 2 + h) * 2 + w;
+        let expected = -(log_probs[index(0, 0, 0, 0)]
+            + log_probs[index(0, 1, 1, 0)]
+            + log_probs[index(0, 2, 1, 1)]
+            + log_probs[index(1, 2, 1, 0)]
+            + log_probs[index(1, 1, 1, 1)])
+            / 5.;
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([expected]), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_probs() {
+        let (logits, targets, targets_logits) = setup!();
+        let device = Default::default();
+        let config = CrossEntropyLossConfig::new().with_smoothing(Some(0.05));
+
+        let loss_1 = config
+            .init(&device)
+            .forward_probs(logits.clone(), targets_logits);
+        let loss_2 = config.init(&device).forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_probs_nd() {
+        let device = Default::default();
+        let logits =
+            Tensor::<TestBackend, 3>::random([2, 3, 2], Distribution::Normal(0., 1.0), &device);
+        let targets = Tensor::<TestBackend, 3>::from_data(
+            TensorData::from([
+                [[0.2, 1.0], [0.5, 0.0], [0.3, 0.0]],
+                [[0.0, 0.1], [0.0, 0.1], [1.0, 0.8]],
+            ]),
+            &device,
+        );
+
+        let loss = CrossEntropyLossConfig::new()
+            .init(&device)
+            .forward_probs(logits.clone(), targets.clone());
+        let expected = (log_softmax(logits, 1) * targets).sum().neg() / 4.;
+
+        loss.into_data().assert_approx_eq(&expected.into_data(), 3);
+    }
+
     #[test]
     fn display() {
         let config = CrossEntropyLossConfig::new()
@@ -445,7 +775,7 @@ mod tests {
 
         assert_eq!(
             alloc::format!("{}", loss),
-            "CrossEntropyLoss {pad_tokens: None, weights: Tensor {rank: 1, shape: [3]}, smoothing: 0.5, logits: true}"
+            "CrossEntropyLoss {pad_tokens: None, weights: Tensor {rank: 1, shape: [3]}, smoothing: 0.5, logits: true, ignore_index: None}"
         );
     }
 }
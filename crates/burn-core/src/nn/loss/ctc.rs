@@ -0,0 +1,439 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::{backend::Backend, Bool, Int, Tensor};
+use crate::{config::Config, module::Module};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::Reduction;
+
+/// The log-probability of the impossible alignments.
+///
+/// A finite value is used instead of `-inf` so that the gradients of the impossible alignments
+/// are zero instead of NaN.
+const NEG_INF: f32 = -1e30;
+
+/// Configuration to create a [Connectionist Temporal Classification loss](CtcLoss).
+#[derive(Config, Debug)]
+pub struct CtcLossConfig {
+    /// The index of the blank label.
+    #[config(default = 0)]
+    pub blank: usize,
+    /// Replace the infinite losses of the impossible alignments, when an input is too short to
+    /// be aligned with its target, by zero.
+    #[config(default = false)]
+    pub zero_infinity: bool,
+}
+
+impl CtcLossConfig {
+    /// Initialize [Connectionist Temporal Classification loss](CtcLoss).
+    pub fn init(&self) -> CtcLoss {
+        CtcLoss {
+            blank: self.blank,
+            zero_infinity: self.zero_infinity,
+        }
+    }
+}
+
+/// Calculate the Connectionist Temporal Classification loss, as described in
+/// [Connectionist Temporal Classification: Labelling Unsegmented Sequence Data with Recurrent Neural Networks](https://www.cs.toronto.edu/~graves/icml_2006.pdf).
+///
+/// The loss is the negative log-likelihood of the target sequence, summed over all the
+/// alignments of the target with the input. It is computed with the forward algorithm, in the
+/// log space, over the target labels interleaved with blanks. Each step of the dynamic
+/// programming processes all the batch at once, so that the gradients are computed by
+/// the autodiff backend.
+///
+/// The impossible alignments, e.g. when an input is shorter than its target, have a very large
+/// loss, which can be replaced by zero with [zero_infinity](CtcLossConfig::zero_infinity).
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct CtcLoss {
+    /// The index of the blank label.
+    pub blank: usize,
+    /// Replace the losses of the impossible alignments by zero.
+    pub zero_infinity: bool,
+}
+
+impl ModuleDisplay for CtcLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("blank", &self.blank)
+            .add("zero_infinity", &self.zero_infinity)
+            .optional()
+    }
+}
+
+impl CtcLoss {
+    /// Compute the criterion on the input tensor, then reduce to a single loss value.
+    ///
+    /// With `Reduction::Mean` or `Reduction::Auto`, the loss of each sequence is divided by the
+    /// length of its target before computing the mean over the batch.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, max_input_length, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss =
+            self.forward_no_reduction(log_probs, targets, input_lengths, target_lengths.clone());
+
+        match reduction {
+            Reduction::Mean | Reduction::Auto => {
+                (loss / target_lengths.clamp_min(1).float()).mean()
+            }
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// The log-probabilities are usually the output of a
+    /// [log_softmax](crate::tensor::activation::log_softmax) over the classes. The targets are
+    /// padded to the length of the longest one, the padding values being ignored, and shouldn't
+    /// contain the blank label.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, max_input_length, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        Self::assertions(&log_probs, &targets, &input_lengths, &target_lengths);
+
+        let [batch_size, max_input_length, num_classes] = log_probs.dims();
+        let [_, max_target_length] = targets.dims();
+        let num_states = 2 * max_target_length + 1;
+        let device = log_probs.device();
+        let blank = self.blank as i64;
+
+        // The padding values could be out of the range of the classes.
+        let targets = targets.clamp(0, num_classes as i64 - 1);
+        let states = Self::interleave_blanks(targets, blank);
+
+        // The log-probability of the label of each state at each time step.
+        let emissions = log_probs.gather(
+            2,
+            states
+                .clone()
+                .unsqueeze_dim::<3>(1)
+                .expand([batch_size, max_input_length, num_states]),
+        );
+
+        // A state can be reached from two states before when it isn't a blank or a repeated label.
+        let states_before = Self::shift_states(
+            states.clone(),
+            2,
+            Tensor::full([batch_size, 2], blank, &device),
+        );
+        let no_skip = (states.clone().not_equal_elem(blank).int()
+            * states.not_equal(states_before).int())
+        .equal_elem(0);
+
+        // Before the first time step, the alignments start at the first state.
+        let mut alpha = Tensor::cat(
+            vec![
+                Tensor::zeros([batch_size, 1], &device),
+                Tensor::full([batch_size, num_states - 1], NEG_INF, &device),
+            ],
+            1,
+        );
+
+        for t in 0..max_input_length {
+            let emission = emissions
+                .clone()
+                .slice([0..batch_size, t..t + 1, 0..num_states])
+                .reshape([batch_size, num_states]);
+
+            let alpha_prev = Self::shift_alpha(alpha.clone(), 1);
+            let alpha_skip =
+                Self::shift_alpha(alpha.clone(), 2).mask_fill(no_skip.clone(), NEG_INF);
+            let alpha_next = log_sum_exp(vec![alpha.clone(), alpha_prev, alpha_skip]) + emission;
+
+            // The sequences shorter than the current time step keep their final values.
+            let finished: Tensor<B, 2, Bool> = input_lengths
+                .clone()
+                .lower_equal_elem(t as i64)
+                .reshape([batch_size, 1])
+                .expand([batch_size, num_states]);
+            alpha = alpha_next.mask_where(finished, alpha);
+        }
+
+        // The alignments end at the last label or at the final blank.
+        let last_state = target_lengths
+            .clone()
+            .mul_scalar(2)
+            .reshape([batch_size, 1]);
+        let alpha_last = alpha.clone().gather(1, last_state.clone());
+        let alpha_last_label = alpha
+            .gather(1, last_state.sub_scalar(1).clamp_min(0))
+            .mask_fill(
+                target_lengths.equal_elem(0).reshape([batch_size, 1]),
+                NEG_INF,
+            );
+
+        let loss = log_sum_exp(vec![alpha_last, alpha_last_label])
+            .neg()
+            .reshape([batch_size]);
+
+        match self.zero_infinity {
+            true => {
+                let is_impossible = loss.clone().greater_elem(-NEG_INF / 2.);
+                loss.mask_fill(is_impossible, 0.)
+            }
+            false => loss,
+        }
+    }
+
+    /// Interleave the targets with blanks, starting and ending with a blank.
+    fn interleave_blanks<B: Backend>(targets: Tensor<B, 2, Int>, blank: i64) -> Tensor<B, 2, Int> {
+        let [batch_size, max_target_length] = targets.dims();
+        let device = targets.device();
+
+        let blanks = Tensor::full([batch_size, max_target_length], blank, &device);
+        let states = Tensor::stack::<3>(vec![blanks, targets], 2)
+            .reshape([batch_size, 2 * max_target_length]);
+
+        Tensor::cat(
+            vec![states, Tensor::full([batch_size, 1], blank, &device)],
+            1,
+        )
+    }
+
+    /// Shift the states by `n` positions, the first positions being filled with `fill`.
+    fn shift_states<B: Backend>(
+        states: Tensor<B, 2, Int>,
+        n: usize,
+        fill: Tensor<B, 2, Int>,
+    ) -> Tensor<B, 2, Int> {
+        let [batch_size, num_states] = states.dims();
+
+        if n >= num_states {
+            return fill.slice([0..batch_size, 0..num_states]);
+        }
+
+        Tensor::cat(
+            vec![fill, states.slice([0..batch_size, 0..num_states - n])],
+            1,
+        )
+    }
+
+    /// Shift the log-probabilities of the states by `n` positions, the first positions being
+    /// impossible.
+    fn shift_alpha<B: Backend>(alpha: Tensor<B, 2>, n: usize) -> Tensor<B, 2> {
+        let [batch_size, num_states] = alpha.dims();
+        let device = alpha.device();
+
+        if n >= num_states {
+            return Tensor::full([batch_size, num_states], NEG_INF, &device);
+        }
+
+        Tensor::cat(
+            vec![
+                Tensor::full([batch_size, n], NEG_INF, &device),
+                alpha.slice([0..batch_size, 0..num_states - n]),
+            ],
+            1,
+        )
+    }
+
+    fn assertions<B: Backend>(
+        log_probs: &Tensor<B, 3>,
+        targets: &Tensor<B, 2, Int>,
+        input_lengths: &Tensor<B, 1, Int>,
+        target_lengths: &Tensor<B, 1, Int>,
+    ) {
+        let [log_probs_batch_size, _, _] = log_probs.dims();
+        let [targets_batch_size, _] = targets.dims();
+        let [input_lengths_size] = input_lengths.dims();
+        let [target_lengths_size] = target_lengths.dims();
+        assert!(
+            log_probs_batch_size == targets_batch_size
+                && log_probs_batch_size == input_lengths_size
+                && log_probs_batch_size == target_lengths_size,
+            "Batch size of log_probs ({}), targets ({}), input_lengths ({}) and target_lengths ({}) should be the same.",
+            log_probs_batch_size,
+            targets_batch_size,
+            input_lengths_size,
+            target_lengths_size
+        );
+    }
+}
+
+/// The element-wise `log(sum(exp(x)))` of the given tensors, computed in a numerically stable way.
+fn log_sum_exp<B: Backend>(tensors: Vec<Tensor<B, 2>>) -> Tensor<B, 2> {
+    let max = tensors
+        .iter()
+        .cloned()
+        .reduce(|a, b| a.max_pair(b))
+        .expect("At least one tensor");
+
+    let sum = tensors
+        .into_iter()
+        .map(|tensor| (tensor - max.clone()).exp())
+        .reduce(|a, b| a + b)
+        .expect("At least one tensor");
+
+    max + sum.log()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    fn log_probs<B: Backend, const D: usize>(
+        probs: [[[f32; 2]; D]; 2],
+        device: &B::Device,
+    ) -> Tensor<B, 3> {
+        Tensor::<B, 3>::from_data(probs, device).log()
+    }
+
+    #[test]
+    fn test_ctc_loss() {
+        let device = Default::default();
+        // The second sequence only has one time step, the second one being padding.
+        let log_probs = log_probs::<TestBackend, 2>(
+            [[[0.4, 0.6], [0.3, 0.7]], [[0.4, 0.6], [0.5, 0.5]]],
+            &device,
+        );
+        let targets = Tensor::<TestBackend, 2, Int>::from_data([[1, 1], [1, 0]], &device);
+        let input_lengths = Tensor::<TestBackend, 1, Int>::from_data([2, 1], &device);
+        let target_lengths = Tensor::<TestBackend, 1, Int>::from_data([1, 1], &device);
+
+        let ctc = CtcLossConfig::new().init();
+        let loss_no_reduction = ctc.forward_no_reduction(
+            log_probs.clone(),
+            targets.clone(),
+            input_lengths.clone(),
+            target_lengths.clone(),
+        );
+        let loss_sum = ctc.forward(
+            log_probs,
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Sum,
+        );
+
+        // The alignments of the first sequence are (1, 1), (blank, 1) and (1, blank):
+        // -log(0.6 * 0.7 + 0.4 * 0.7 + 0.6 * 0.3)
+        let expected = TensorData::from([0.127833, 0.510826]);
+        loss_no_reduction.into_data().assert_approx_eq(&expected, 5);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.638659]), 5);
+    }
+
+    #[test]
+    fn test_ctc_loss_repeated_labels() {
+        let device = Default::default();
+        let log_probs =
+            Tensor::<TestBackend, 3>::from_data([[[0.4, 0.6], [0.3, 0.7], [0.2, 0.8]]], &device)
+                .log();
+        let targets = Tensor::<TestBackend, 2, Int>::from_data([[1, 1]], &device);
+        let input_lengths = Tensor::<TestBackend, 1, Int>::from_data([3], &device);
+        let target_lengths = Tensor::<TestBackend, 1, Int>::from_data([2], &device);
+
+        let loss = CtcLossConfig::new().init().forward(
+            log_probs,
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Mean,
+        );
+
+        // The only alignment is (1, blank, 1): -log(0.6 * 0.3 * 0.8) / 2
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.968971]), 5);
+    }
+
+    #[test]
+    fn test_ctc_loss_zero_infinity() {
+        let device = Default::default();
+        let log_probs = log_probs::<TestBackend, 2>(
+            [[[0.4, 0.6], [0.3, 0.7]], [[0.4, 0.6], [0.3, 0.7]]],
+            &device,
+        );
+        let targets = Tensor::<TestBackend, 2, Int>::from_data([[1, 1], [1, 0]], &device);
+        let input_lengths = Tensor::<TestBackend, 1, Int>::from_data([2, 2], &device);
+        let target_lengths = Tensor::<TestBackend, 1, Int>::from_data([2, 1], &device);
+
+        let loss = CtcLossConfig::new()
+            .with_zero_infinity(true)
+            .init()
+            .forward_no_reduction(log_probs, targets, input_lengths, target_lengths);
+
+        // The repeated labels need a blank in between, so 2 time steps are too short.
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.127833]), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ctc_loss_gradients() {
+        type TestAutodiffTensor<const D: usize, K = crate::tensor::Float> =
+            Tensor<crate::TestAutodiffBackend, D, K>;
+
+        let device = Default::default();
+        // The log of the probabilities [[0.4, 0.6], [0.3, 0.7]].
+        let log_probs = TestAutodiffTensor::<3>::from_data(
+            [[[-0.916291, -0.510826], [-1.203973, -0.356675]]],
+            &device,
+        )
+        .require_grad();
+        let targets = TestAutodiffTensor::<2, Int>::from_data([[1]], &device);
+        let input_lengths = TestAutodiffTensor::<1, Int>::from_data([2], &device);
+        let target_lengths = TestAutodiffTensor::<1, Int>::from_data([1], &device);
+
+        let loss = CtcLossConfig::new().init().forward(
+            log_probs.clone(),
+            targets,
+            input_lengths,
+            target_lengths,
+            Reduction::Sum,
+        );
+        let grads = loss.backward();
+        let grads_log_probs = log_probs.grad(&grads).unwrap();
+
+        // Minus the posterior probability of each label at each time step.
+        let expected = TensorData::from([[[-0.318182, -0.681818], [-0.204545, -0.795455]]]);
+        grads_log_probs.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = CtcLossConfig::new().init();
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "CtcLoss {blank: 0, zero_infinity: false}"
+        );
+    }
+}
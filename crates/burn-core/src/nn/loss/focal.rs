@@ -0,0 +1,246 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::activation::{log_sigmoid, sigmoid};
+use crate::tensor::{backend::Backend, Int, Tensor};
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Focal loss](FocalLoss) using the [init function](FocalLossConfig::init).
+#[derive(Config, Debug)]
+pub struct FocalLossConfig {
+    /// The focusing parameter, reducing the loss of the well classified elements.
+    ///
+    /// Gamma = 0 would be the same as the binary cross-entropy.
+    #[config(default = 2.0)]
+    pub gamma: f32,
+
+    /// The weight of the positive targets, the negative targets being weighted by `1 - alpha`.
+    pub alpha: Option<f32>,
+
+    /// Treat the inputs as logits, applying a sigmoid activation when computing the loss.
+    #[config(default = true)]
+    pub logits: bool,
+}
+
+impl FocalLossConfig {
+    /// Initialize [Focal loss](FocalLoss).
+    pub fn init(&self) -> FocalLoss {
+        self.assertions();
+        FocalLoss {
+            gamma: self.gamma,
+            alpha: self.alpha,
+            logits: self.logits,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.gamma >= 0.,
+            "Gamma of focal loss must be a non-negative number."
+        );
+        if let Some(alpha) = self.alpha {
+            assert!(
+                (0.0..=1.).contains(&alpha),
+                "Alpha of focal loss should be in interval [0, 1]. Got {}",
+                alpha
+            );
+        }
+    }
+}
+
+/// Calculate the binary focal loss from the input logits and the targets, as described in
+/// [Focal Loss for Dense Object Detection](https://arxiv.org/abs/1708.02002).
+///
+/// The loss of each element is given by
+///
+/// ```text
+/// L(p, t) = -alpha_t * (1 - p_t)^gamma * log(p_t)
+/// ```
+///
+/// where `p_t` is the predicted probability of the target, and `alpha_t` is `alpha` for the
+/// positive targets and `1 - alpha` for the negative ones.
+///
+/// Should be created using [FocalLossConfig]
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct FocalLoss {
+    /// The focusing parameter.
+    pub gamma: f32,
+    /// The weight of the positive targets.
+    pub alpha: Option<f32>,
+    /// Use logits as input.
+    pub logits: bool,
+}
+
+impl ModuleDisplay for FocalLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("gamma", &self.gamma)
+            .add("alpha", &self.alpha)
+            .add("logits", &self.logits)
+            .optional()
+    }
+}
+
+impl FocalLoss {
+    /// Compute the criterion on the input tensor, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[1]`
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[...dims]`
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D, Int>,
+    ) -> Tensor<B, D> {
+        assert!(
+            predictions.dims() == targets.dims(),
+            "Shape of targets ({:?}) should correspond to the shape of predictions ({:?}).",
+            targets.dims(),
+            predictions.dims()
+        );
+        let targets = targets.float();
+
+        let (probs, log_probs, log_probs_neg) = if self.logits {
+            // Numerically stable by using `log_sigmoid(x)` and `log(1 - sigmoid(x)) = log_sigmoid(-x)`
+            (
+                sigmoid(predictions.clone()),
+                log_sigmoid(predictions.clone()),
+                log_sigmoid(predictions.neg()),
+            )
+        } else {
+            (
+                predictions.clone(),
+                predictions.clone().log(),
+                (predictions.neg() + 1.).log(),
+            )
+        };
+
+        // The probability of the target, p_t = p * t + (1 - p) * (1 - t)
+        let probs_target =
+            probs.clone() * targets.clone() + (probs.neg() + 1.) * (targets.clone().neg() + 1.);
+        // - (t * log(p) + (1 - t) * log(1 - p))
+        let cross_entropy =
+            (log_probs * targets.clone() + log_probs_neg * (targets.clone().neg() + 1.)).neg();
+        let loss = cross_entropy * (probs_target.neg() + 1.).powf_scalar(self.gamma);
+
+        match self.alpha {
+            // alpha_t = alpha * t + (1 - alpha) * (1 - t)
+            Some(alpha) => loss * (targets * (2. * alpha - 1.) + (1. - alpha)),
+            None => loss,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::BinaryCrossEntropyLossConfig;
+    use crate::tensor::{Distribution, TensorData};
+    use crate::TestBackend;
+
+    #[test]
+    fn test_focal_loss() {
+        let device = Default::default();
+        let probs = Tensor::<TestBackend, 1>::from_data([0.9, 0.3], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([1, 0], &device);
+
+        let focal = FocalLossConfig::new().with_logits(false).init();
+        let loss_no_reduction = focal.forward_no_reduction(probs.clone(), targets.clone());
+        let loss = focal.forward(probs.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = focal.forward(probs, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.00105361, 0.03210075]), 5);
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.01657718]), 5);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.03315436]), 5);
+    }
+
+    #[test]
+    fn test_focal_loss_with_alpha() {
+        let device = Default::default();
+        let probs = Tensor::<TestBackend, 1>::from_data([0.9, 0.3], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([1, 0], &device);
+
+        let loss = FocalLossConfig::new()
+            .with_alpha(Some(0.25))
+            .with_logits(false)
+            .init()
+            .forward_no_reduction(probs, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.00026340, 0.02407556]), 5);
+    }
+
+    #[test]
+    fn test_focal_loss_without_focusing_is_binary_cross_entropy() {
+        let device = Default::default();
+        let logits =
+            Tensor::<TestBackend, 2>::random([4, 3], Distribution::Normal(0., 1.), &device);
+        let targets = Tensor::<TestBackend, 2, Int>::from_data(
+            [[0, 1, 1], [1, 0, 0], [0, 0, 1], [1, 1, 0]],
+            &device,
+        );
+
+        let loss = FocalLossConfig::new().with_gamma(0.0).init().forward(
+            logits.clone(),
+            targets.clone(),
+            Reduction::Mean,
+        );
+        let expected = BinaryCrossEntropyLossConfig::new()
+            .with_logits(true)
+            .init(&device)
+            .forward(logits, targets);
+
+        loss.into_data().assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn display() {
+        let loss = FocalLossConfig::new()
+            .with_gamma(1.5)
+            .with_alpha(Some(0.25))
+            .init();
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "FocalLoss {gamma: 1.5, alpha: 0.25, logits: true}"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Kullback-Leibler divergence loss](KLDivLoss).
+#[derive(Config, Debug)]
+pub struct KLDivLossConfig {
+    /// Whether the targets are given as log-probabilities instead of probabilities.
+    #[config(default = false)]
+    pub log_target: bool,
+}
+
+impl KLDivLossConfig {
+    /// Initialize [Kullback-Leibler divergence loss](KLDivLoss).
+    pub fn init(&self) -> KLDivLoss {
+        KLDivLoss {
+            log_target: self.log_target,
+        }
+    }
+}
+
+/// Calculate the Kullback-Leibler divergence between the target distribution and the predicted
+/// distribution.
+///
+/// The predictions are log-probabilities, e.g. the output of a
+/// [log_softmax](crate::tensor::activation::log_softmax), and the loss of each element is given by
+///
+/// ```text
+/// L(p, t) = t * (log(t) - p)
+/// ```
+///
+/// where the elements with a target probability of 0 don't contribute to the loss.
+///
+/// `Reduction::Mean` averages the loss over all the elements. The divergence of each
+/// distribution is obtained with `Reduction::Sum` divided by the number of distributions.
+///
+/// See also: <https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence>
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct KLDivLoss {
+    /// Whether the targets are given as log-probabilities.
+    pub log_target: bool,
+}
+
+impl ModuleDisplay for KLDivLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("log_target", &self.log_target).optional()
+    }
+}
+
+impl KLDivLoss {
+    /// Compute the loss element-wise for the predictions and targets, then reduce
+    /// to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - output: \[1\]
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the predictions and targets.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: [...dims]
+    /// - targets: [...dims]
+    /// - output: [...dims]
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        if self.log_target {
+            return targets.clone().exp() * (targets - predictions);
+        }
+
+        let is_zero = targets.clone().lower_equal_elem(0.0);
+        // The masked elements would be NaN since 0 * log(0) isn't defined.
+        let log_targets = targets.clone().mask_fill(is_zero.clone(), 1.0).log();
+        let loss = targets * (log_targets - predictions);
+        loss.mask_fill(is_zero, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_kl_div_loss() {
+        let device = Default::default();
+        let predictions = TestTensor::<2>::from_data([[0.25, 0.75], [0.5, 0.5]], &device).log();
+        let targets = TestTensor::<2>::from_data([[0.5, 0.5], [1.0, 0.0]], &device);
+
+        let kl_div = KLDivLossConfig::new().init();
+
+        let loss_sum = kl_div.forward(predictions.clone(), targets.clone(), Reduction::Sum);
+        let loss = kl_div.forward(predictions.clone(), targets.clone(), Reduction::Auto);
+        let loss_no_reduction = kl_div.forward_no_reduction(predictions, targets);
+
+        let expected = TensorData::from([[0.346574, -0.202733], [0.693147, 0.0]]);
+        loss_no_reduction.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.209247]);
+        loss.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.836988]);
+        loss_sum.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_kl_div_loss_log_target() {
+        let device = Default::default();
+        let predictions = TestTensor::<1>::from_data([0.25, 0.75], &device).log();
+        let targets = TestTensor::<1>::from_data([0.5, 0.5], &device);
+
+        let loss = KLDivLossConfig::new().with_log_target(true).init().forward(
+            predictions.clone(),
+            targets.clone().log(),
+            Reduction::Sum,
+        );
+        let expected = KLDivLossConfig::new()
+            .init()
+            .forward(predictions, targets, Reduction::Sum);
+
+        loss.into_data().assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = KLDivLossConfig::new().init();
+
+        assert_eq!(alloc::format!("{}", loss), "KLDivLoss {log_target: false}");
+    }
+}
@@ -0,0 +1,95 @@
+use crate as burn;
+
+use crate::nn::loss::reduction::Reduction;
+
+use crate::module::Module;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// Calculate the mean absolute error loss from the input logits and the targets.
+#[derive(Module, Clone, Debug)]
+pub struct L1Loss;
+
+impl Default for L1Loss {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl L1Loss {
+    /// Create the criterion.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [...dims]
+    /// - targets: [...dims]
+    /// - output: [1]
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(logits, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        logits.sub(targets).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_l1_loss() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 2>::from_data(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0]]),
+            &device,
+        );
+
+        let targets = Tensor::<TestBackend, 2>::from_data(
+            TensorData::from([[2.0, 1.0], [3.0, 2.0]]),
+            &device,
+        );
+
+        let l1 = L1Loss::new();
+        let loss_no_reduction = l1.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = l1.forward(logits.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = l1.forward(logits, targets, Reduction::Sum);
+
+        let expected = TensorData::from([[1.0, 1.0], [0.0, 2.0]]);
+        loss_no_reduction.into_data().assert_eq(&expected, false);
+
+        let expected = TensorData::from([1.0]);
+        loss.into_data().assert_eq(&expected, false);
+
+        let expected = TensorData::from([4.0]);
+        loss_sum.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn display() {
+        let loss = L1Loss::new();
+        assert_eq!(alloc::format!("{}", loss), "L1Loss");
+    }
+}
@@ -0,0 +1,142 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::{backend::Backend, Int, Tensor};
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Margin ranking loss](MarginRankingLoss).
+#[derive(Config, Debug)]
+pub struct MarginRankingLossConfig {
+    /// The margin by which the inputs should be ranked.
+    #[config(default = 0.0)]
+    pub margin: f32,
+}
+
+impl MarginRankingLossConfig {
+    /// Initialize [Margin ranking loss](MarginRankingLoss).
+    pub fn init(&self) -> MarginRankingLoss {
+        MarginRankingLoss {
+            margin: self.margin,
+        }
+    }
+}
+
+/// Calculate the margin ranking loss, measuring whether the inputs are ranked as the targets.
+///
+/// The loss of each pair of inputs is given by
+///
+/// ```text
+/// L(x1, x2, y) = max(0, -y * (x1 - x2) + margin)
+/// ```
+///
+/// where `y` is 1 when `x1` should be ranked higher than `x2`, and -1 otherwise.
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct MarginRankingLoss {
+    /// The margin by which the inputs should be ranked.
+    pub margin: f32,
+}
+
+impl ModuleDisplay for MarginRankingLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("margin", &self.margin).optional()
+    }
+}
+
+impl MarginRankingLoss {
+    /// Compute the criterion on the input tensors, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[...dims]`
+    /// - input2: `[...dims]`
+    /// - targets: `[...dims]`, with values 1 or -1
+    /// - output: `[1]`
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        input1: Tensor<B, D>,
+        input2: Tensor<B, D>,
+        targets: Tensor<B, D, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(input1, input2, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - input1: `[...dims]`
+    /// - input2: `[...dims]`
+    /// - targets: `[...dims]`
+    /// - output: `[...dims]`
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        input1: Tensor<B, D>,
+        input2: Tensor<B, D>,
+        targets: Tensor<B, D, Int>,
+    ) -> Tensor<B, D> {
+        assert!(
+            input1.dims() == input2.dims() && input1.dims() == targets.dims(),
+            "Shapes of input1 ({:?}), input2 ({:?}) and targets ({:?}) should be the same.",
+            input1.dims(),
+            input2.dims(),
+            targets.dims()
+        );
+
+        ((input1 - input2) * targets.float().neg())
+            .add_scalar(self.margin)
+            .clamp_min(0.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_margin_ranking_loss() {
+        let device = Default::default();
+        let input1 = Tensor::<TestBackend, 1>::from_data([1.0, 2.0, 0.5], &device);
+        let input2 = Tensor::<TestBackend, 1>::from_data([2.0, 1.0, 0.0], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([1, 1, -1], &device);
+
+        let loss = MarginRankingLossConfig::new().with_margin(0.5).init();
+        let loss_no_reduction =
+            loss.forward_no_reduction(input1.clone(), input2.clone(), targets.clone());
+        let loss_sum = loss.forward(input1, input2, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.5, 0.0, 1.0]), 5);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.5]), 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = MarginRankingLossConfig::new().with_margin(0.5).init();
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "MarginRankingLoss {margin: 0.5}"
+        );
+    }
+}
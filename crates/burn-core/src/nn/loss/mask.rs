@@ -0,0 +1,14 @@
+use crate::tensor::{backend::Backend, Bool, Int, Tensor};
+
+/// Returns the mask of the targets equal to any of the given tokens, e.g. the pad tokens, or
+/// `None` if there are no tokens.
+pub(crate) fn tokens_mask<B: Backend, const D: usize>(
+    targets: &Tensor<B, D, Int>,
+    tokens: impl IntoIterator<Item = i64>,
+) -> Option<Tensor<B, D, Bool>> {
+    tokens
+        .into_iter()
+        .map(|token| targets.clone().equal_elem(token).int())
+        .reduce(|count, equal| count + equal)
+        .map(|count| count.greater_elem(0))
+}
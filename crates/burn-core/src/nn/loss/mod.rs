@@ -1,11 +1,30 @@
 mod binary_cross_entropy;
+mod cosine_embedding;
 mod cross_entropy;
+mod ctc;
+mod focal;
 mod huber;
+mod kl_div;
+mod l1;
+mod margin_ranking;
+mod mask;
 mod mse;
+mod nll;
 mod reduction;
+mod smooth_l1;
+mod triplet;
 
 pub use binary_cross_entropy::*;
+pub use cosine_embedding::*;
 pub use cross_entropy::*;
+pub use ctc::*;
+pub use focal::*;
 pub use huber::*;
+pub use kl_div::*;
+pub use l1::*;
+pub use margin_ranking::*;
 pub use mse::*;
+pub use nll::*;
 pub use reduction::*;
+pub use smooth_l1::*;
+pub use triplet::*;
@@ -0,0 +1,249 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::{backend::Backend, Bool, Int, Tensor};
+use crate::{config::Config, module::Module};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use super::mask::tokens_mask;
+use super::Reduction;
+
+/// Configuration to create a [Negative log-likelihood loss](NllLoss) using the [init function](NllLossConfig::init).
+#[derive(Config, Debug)]
+pub struct NllLossConfig {
+    /// Create padded negative log-likelihood.
+    ///
+    /// Prevents pad tokens from impacting loss calculation.
+    pub pad_tokens: Option<Vec<usize>>,
+
+    /// Create weighted negative log-likelihood.
+    ///
+    /// The loss of a specific sample is multiplied by the weight of its target class.
+    ///
+    /// # Pre-conditions
+    ///   - The order of the weight vector should correspond to the label integer assignment.
+    pub weights: Option<Vec<f32>>,
+}
+
+impl NllLossConfig {
+    /// Initialize [Negative log-likelihood loss](NllLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> NllLoss<B> {
+        self.assertions();
+        NllLoss {
+            pad_tokens: self.pad_tokens.clone(),
+            weights: self
+                .weights
+                .as_ref()
+                .map(|e| Tensor::<B, 1>::from_floats(e.as_slice(), device)),
+        }
+    }
+
+    fn assertions(&self) {
+        if let Some(weights) = self.weights.as_ref() {
+            assert!(
+                weights.iter().all(|e| e > &0.),
+                "Weights of negative log-likelihood have to be positive."
+            );
+        }
+    }
+}
+
+/// Calculate the negative log-likelihood loss from the input log-probabilities and the targets.
+///
+/// Combined with a [log_softmax](crate::tensor::activation::log_softmax), this is equal to the
+/// [cross-entropy loss](super::CrossEntropyLoss).
+///
+/// Should be created using [NllLossConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct NllLoss<B: Backend> {
+    /// Pad tokens to ignore in the loss calculation.
+    pub pad_tokens: Option<Vec<usize>>,
+    /// Weights of the classes.
+    pub weights: Option<Tensor<B, 1>>,
+}
+
+impl<B: Backend> ModuleDisplay for NllLoss<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let pad_tokens = if let Some(pad_tokens) = &self.pad_tokens {
+            alloc::format!("Vec<0..{}>", pad_tokens.len())
+        } else {
+            "None".to_string()
+        };
+
+        content
+            .add("pad_tokens", &pad_tokens)
+            .add("weights", &self.weights)
+            .optional()
+    }
+}
+
+impl<B: Backend> NllLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// With `Reduction::Mean` or `Reduction::Auto`, the sum of the losses is divided by the sum
+    /// of the weights of the targets which aren't pad tokens.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(log_probs, targets.clone());
+
+        match reduction {
+            Reduction::Mean | Reduction::Auto => {
+                let [batch_size] = targets.dims();
+                let weights = match &self.weights {
+                    Some(weights) => weights.clone().gather(0, targets.clone()),
+                    None => Tensor::ones([batch_size], &loss.device()),
+                };
+                let weights = Self::apply_mask(weights, self.padding_mask(&targets));
+                loss.sum() / weights.sum()
+            }
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        Self::assertions(&log_probs, &targets);
+        let [batch_size] = targets.dims();
+
+        let mask = self.padding_mask(&targets);
+        let loss = log_probs
+            .gather(1, targets.clone().reshape([batch_size, 1]))
+            .reshape([batch_size])
+            .neg();
+        let loss = match &self.weights {
+            Some(weights) => loss * weights.clone().gather(0, targets),
+            None => loss,
+        };
+
+        Self::apply_mask(loss, mask)
+    }
+
+    fn padding_mask(&self, targets: &Tensor<B, 1, Int>) -> Option<Tensor<B, 1, Bool>> {
+        tokens_mask(
+            targets,
+            self.pad_tokens.iter().flatten().map(|token| *token as i64),
+        )
+    }
+
+    fn apply_mask(mut tensor: Tensor<B, 1>, mask: Option<Tensor<B, 1, Bool>>) -> Tensor<B, 1> {
+        if let Some(mask) = mask {
+            tensor = tensor.mask_fill(mask, 0);
+        }
+
+        tensor
+    }
+
+    fn assertions(log_probs: &Tensor<B, 2>, targets: &Tensor<B, 1, Int>) {
+        let [log_probs_height, _] = log_probs.dims();
+        let [targets_height] = targets.dims();
+        assert!(
+            log_probs_height == targets_height,
+            "Shape of targets ({}) should correspond to outer shape of log_probs ({}).",
+            targets_height,
+            log_probs_height
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    use alloc::vec;
+
+    fn setup() -> (Tensor<TestBackend, 2>, Tensor<TestBackend, 1, Int>) {
+        let device = Default::default();
+        let log_probs =
+            Tensor::<TestBackend, 2>::from_data([[0.1, 0.7, 0.2], [0.5, 0.25, 0.25]], &device)
+                .log();
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([1, 0], &device);
+
+        (log_probs, targets)
+    }
+
+    #[test]
+    fn test_nll_loss() {
+        let (log_probs, targets) = setup();
+        let loss = NllLossConfig::new().init(&Default::default());
+
+        let loss_no_reduction = loss.forward_no_reduction(log_probs.clone(), targets.clone());
+        let loss_mean = loss.forward(log_probs.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = loss.forward(log_probs, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.356675, 0.693147]), 5);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.524911]), 5);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.049822]), 5);
+    }
+
+    #[test]
+    fn test_nll_loss_with_weights() {
+        let (log_probs, targets) = setup();
+        let loss = NllLossConfig::new()
+            .with_weights(Some(vec![1.0, 2.0, 3.0]))
+            .init(&Default::default());
+
+        let loss = loss.forward(log_probs, targets, Reduction::Mean);
+
+        // (2 * 0.356675 + 1 * 0.693147) / (2 + 1)
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.468832]), 5);
+    }
+
+    #[test]
+    fn test_nll_loss_with_pad_token() {
+        let (log_probs, targets) = setup();
+        let loss = NllLossConfig::new()
+            .with_pad_tokens(Some(vec![0]))
+            .init(&Default::default());
+
+        let loss = loss.forward(log_probs, targets, Reduction::Mean);
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([0.356675]), 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = NllLossConfig::new().init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "NllLoss {pad_tokens: None, weights: None}"
+        );
+    }
+}
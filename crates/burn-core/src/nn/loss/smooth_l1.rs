@@ -0,0 +1,168 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Smooth L1 loss](SmoothL1Loss).
+#[derive(Config, Debug)]
+pub struct SmoothL1LossConfig {
+    /// The bound where the loss function changes from quadratic to linear behaviour.
+    #[config(default = 1.0)]
+    pub beta: f32,
+}
+
+impl SmoothL1LossConfig {
+    /// Initialize [Smooth L1 loss](SmoothL1Loss).
+    pub fn init(&self) -> SmoothL1Loss {
+        self.assertions();
+        SmoothL1Loss { beta: self.beta }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.beta >= 0., // This also tests for normality
+            "Beta for Smooth L1 loss must be a non-negative number."
+        );
+    }
+}
+
+/// Calculate the Smooth L1 loss between the inputs and the target.
+///
+/// The loss for each element of the residuals `r = targets - predictions` is given by
+///
+/// ```text
+/// L(r) = 0.5 * r^2 / b   if |r| < b
+/// L(r) = |r| - 0.5 * b   if |r| >= b
+/// ```
+///
+/// where `b` is the configured `beta`. This is the [Huber loss](super::HuberLoss) divided by
+/// `beta`, so that the linear part always has a slope of 1. When `beta` is 0, this is equal to
+/// the [L1 loss](super::L1Loss).
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct SmoothL1Loss {
+    /// The bound where the loss function changes from quadratic to linear behaviour.
+    pub beta: f32,
+}
+
+impl ModuleDisplay for SmoothL1Loss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content.add("beta", &self.beta).optional()
+    }
+}
+
+impl SmoothL1Loss {
+    /// Compute the loss element-wise for the predictions and targets, then reduce
+    /// to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - output: \[1\]
+    pub fn forward<const D: usize, B: Backend>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the predictions and targets.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: [...dims]
+    /// - targets: [...dims]
+    /// - output: [...dims]
+    pub fn forward_no_reduction<const D: usize, B: Backend>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let residuals = targets - predictions;
+        let abs = residuals.clone().abs();
+
+        if self.beta == 0.0 {
+            return abs;
+        }
+
+        let is_large = abs.clone().greater_equal_elem(self.beta);
+        let outside = abs.sub_scalar(0.5 * self.beta);
+        let inside = residuals.powf_scalar(2.).mul_scalar(0.5 / self.beta);
+        inside.mask_where(is_large, outside)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_smooth_l1_loss() {
+        let predict = TensorData::from([-2., -0.5, 0., 0.3, 1.]);
+        let targets = TensorData::from([0., 0., 0., 0., 0.]);
+
+        let device = Default::default();
+
+        let predict = TestTensor::<1>::from_data(predict, &device);
+        let targets = TestTensor::<1>::from_data(targets, &device);
+
+        let smooth_l1 = SmoothL1LossConfig::new().init();
+
+        let loss_sum = smooth_l1.forward(predict.clone(), targets.clone(), Reduction::Sum);
+        let loss = smooth_l1.forward(predict.clone(), targets.clone(), Reduction::Auto);
+        let loss_no_reduction = smooth_l1.forward_no_reduction(predict, targets);
+
+        let expected = TensorData::from([1.5, 0.125, 0., 0.045, 0.5]);
+        loss_no_reduction.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([0.434]);
+        loss.into_data().assert_approx_eq(&expected, 5);
+
+        let expected = TensorData::from([2.17]);
+        loss_sum.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_smooth_l1_loss_zero_beta() {
+        let device = Default::default();
+        let predict = TestTensor::<1>::from_data([-2., 0.5], &device);
+        let targets = TestTensor::<1>::from_data([0., 0.], &device);
+
+        let loss = SmoothL1LossConfig::new()
+            .with_beta(0.0)
+            .init()
+            .forward_no_reduction(predict, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([2.0, 0.5]), 5);
+    }
+
+    #[test]
+    fn display() {
+        let loss = SmoothL1LossConfig::new().with_beta(0.5).init();
+
+        assert_eq!(alloc::format!("{}", loss), "SmoothL1Loss {beta: 0.5}");
+    }
+}
@@ -0,0 +1,220 @@
+use crate as burn;
+
+use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::tensor::{backend::Backend, Tensor};
+use crate::{config::Config, module::Module};
+
+use super::Reduction;
+
+/// Configuration to create a [Triplet margin loss](TripletMarginLoss).
+#[derive(Config, Debug)]
+pub struct TripletMarginLossConfig {
+    /// The margin by which the negative should be further from the anchor than the positive.
+    #[config(default = 1.0)]
+    pub margin: f32,
+    /// The norm degree of the pairwise distance.
+    #[config(default = 2.0)]
+    pub p: f32,
+    /// A small constant added to the differences to avoid a null distance.
+    #[config(default = 1e-6)]
+    pub epsilon: f32,
+    /// Use the distance between the positive and the negative when it's smaller than the distance
+    /// between the anchor and the negative, as described in
+    /// [Learning local feature descriptors with triplets and shallow convolutional neural networks](https://bmva-archive.org.uk/bmvc/2016/papers/paper119/index.html).
+    #[config(default = false)]
+    pub swap: bool,
+}
+
+impl TripletMarginLossConfig {
+    /// Initialize [Triplet margin loss](TripletMarginLoss).
+    pub fn init(&self) -> TripletMarginLoss {
+        self.assertions();
+        TripletMarginLoss {
+            margin: self.margin,
+            p: self.p,
+            epsilon: self.epsilon,
+            swap: self.swap,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.margin >= 0.,
+            "Margin of triplet margin loss must be a non-negative number."
+        );
+        assert!(
+            self.p > 0.,
+            "Norm degree of triplet margin loss must be a positive number."
+        );
+    }
+}
+
+/// Calculate the triplet margin loss, measuring whether the anchors are closer to the positive
+/// samples than to the negative samples.
+///
+/// The loss of each triplet is given by
+///
+/// ```text
+/// L(a, p, n) = max(0, d(a, p) - d(a, n) + margin)
+/// ```
+///
+/// where `d(x, y) = ||x - y + epsilon||_p` is the pairwise distance.
+#[derive(Module, Debug, Clone)]
+#[module(custom_display)]
+pub struct TripletMarginLoss {
+    /// The margin between the distances.
+    pub margin: f32,
+    /// The norm degree of the pairwise distance.
+    pub p: f32,
+    /// A small constant added to the differences.
+    pub epsilon: f32,
+    /// Use the distance between the positive and the negative when it's smaller.
+    pub swap: bool,
+}
+
+impl ModuleDisplay for TripletMarginLoss {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("margin", &self.margin)
+            .add("p", &self.p)
+            .add("epsilon", &self.epsilon)
+            .add("swap", &self.swap)
+            .optional()
+    }
+}
+
+impl TripletMarginLoss {
+    /// Compute the criterion on the input tensors, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - anchor: `[batch_size, dim]`
+    /// - positive: `[batch_size, dim]`
+    /// - negative: `[batch_size, dim]`
+    /// - output: `[1]`
+    pub fn forward<B: Backend>(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(anchor, positive, negative);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensors without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - anchor: `[batch_size, dim]`
+    /// - positive: `[batch_size, dim]`
+    /// - negative: `[batch_size, dim]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction<B: Backend>(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        assert!(
+            anchor.dims() == positive.dims() && anchor.dims() == negative.dims(),
+            "Shapes of anchor ({:?}), positive ({:?}) and negative ({:?}) should be the same.",
+            anchor.dims(),
+            positive.dims(),
+            negative.dims()
+        );
+        let [batch_size, _] = anchor.dims();
+
+        let distance_positive = self.distance(anchor.clone(), positive.clone());
+        let mut distance_negative = self.distance(anchor, negative.clone());
+
+        if self.swap {
+            let distance_swap = self.distance(positive, negative);
+            distance_negative = distance_negative.min_pair(distance_swap);
+        }
+
+        (distance_positive - distance_negative)
+            .add_scalar(self.margin)
+            .clamp_min(0.)
+            .reshape([batch_size])
+    }
+
+    fn distance<B: Backend>(&self, x1: Tensor<B, 2>, x2: Tensor<B, 2>) -> Tensor<B, 2> {
+        (x1 - x2)
+            .add_scalar(self.epsilon)
+            .abs()
+            .powf_scalar(self.p)
+            .sum_dim(1)
+            .powf_scalar(1. / self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let device = Default::default();
+        let anchor = Tensor::<TestBackend, 2>::from_data([[0.0, 0.0], [0.0, 0.0]], &device);
+        let positive = Tensor::<TestBackend, 2>::from_data([[3.0, 4.0], [1.0, 0.0]], &device);
+        let negative = Tensor::<TestBackend, 2>::from_data([[0.0, 1.0], [3.0, 0.0]], &device);
+
+        let loss = TripletMarginLossConfig::new().init();
+        let loss_no_reduction =
+            loss.forward_no_reduction(anchor.clone(), positive.clone(), negative.clone());
+        let loss_mean = loss.forward(anchor, positive, negative, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&TensorData::from([5.0, 0.0]), 4);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.5]), 4);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_swap() {
+        let device = Default::default();
+        let anchor = Tensor::<TestBackend, 2>::from_data([[0.0, 0.0]], &device);
+        let positive = Tensor::<TestBackend, 2>::from_data([[2.0, 0.0]], &device);
+        let negative = Tensor::<TestBackend, 2>::from_data([[3.0, 0.0]], &device);
+
+        let loss = TripletMarginLossConfig::new()
+            .with_swap(true)
+            .init()
+            .forward_no_reduction(anchor, positive, negative);
+
+        // The positive is closer to the negative (1) than the anchor (3).
+        loss.into_data()
+            .assert_approx_eq(&TensorData::from([2.0]), 4);
+    }
+
+    #[test]
+    fn display() {
+        let loss = TripletMarginLossConfig::new()
+            .with_margin(0.5)
+            .with_p(1.5)
+            .with_epsilon(0.25)
+            .init();
+
+        assert_eq!(
+            alloc::format!("{}", loss),
+            "TripletMarginLoss {margin: 0.5, p: 1.5, epsilon: 0.25, swap: false}"
+        );
+    }
+}
@@ -26,6 +26,7 @@ mod norm;
 mod padding;
 mod pos_encoding;
 mod prelu;
+mod quantize;
 mod relu;
 mod rnn;
 mod rope_encoding;
@@ -44,6 +45,7 @@ pub use norm::*;
 pub use padding::*;
 pub use pos_encoding::*;
 pub use prelu::*;
+pub use quantize::*;
 pub use relu::*;
 pub use rnn::*;
 pub use rope_encoding::*;
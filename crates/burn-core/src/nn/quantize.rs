@@ -0,0 +1,375 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Ignored, Module, RunningState};
+use crate::tensor::backend::Backend;
+use crate::tensor::quantization::{
+    Calibration, CalibrationRange, EntropyCalibration, MinMaxCalibration, MovingAverageCalibration,
+    PercentileCalibration, QuantizationScheme,
+};
+use crate::tensor::Tensor;
+
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Calibration method used to observe the range of the activations.
+#[derive(Config, Debug, PartialEq)]
+pub enum ActivationCalibration {
+    /// Uses the min and max values observed over all batches (see [MinMaxCalibration]).
+    MinMax,
+    /// Uses the moving average of the min and max values of each batch
+    /// (see [MovingAverageCalibration]).
+    MovingAverage {
+        /// Momentum used to update the running range.
+        momentum: f64,
+    },
+    /// Uses the moving average of the percentiles of the values of each batch
+    /// (see [PercentileCalibration]).
+    Percentile {
+        /// The percentile of the upper bound of the range.
+        percentile: f64,
+        /// Momentum used to update the running range.
+        momentum: f64,
+    },
+    /// Uses the moving average of the threshold minimizing the KL divergence of each batch
+    /// (see [EntropyCalibration]).
+    Entropy {
+        /// The number of bins of the histogram.
+        num_bins: usize,
+        /// The number of quantized bins.
+        num_quantized_bins: usize,
+        /// Momentum used to update the running range.
+        momentum: f64,
+    },
+}
+
+impl ActivationCalibration {
+    /// Update the running range with the range of the tensor.
+    pub fn update_range<B: Backend, const D: usize>(
+        &self,
+        range: Option<CalibrationRange<B>>,
+        tensor: &Tensor<B, D>,
+    ) -> CalibrationRange<B> {
+        match self {
+            ActivationCalibration::MinMax => MinMaxCalibration {}.update_range(range, tensor),
+            ActivationCalibration::MovingAverage { momentum } => MovingAverageCalibration {
+                momentum: *momentum,
+            }
+            .update_range(range, tensor),
+            ActivationCalibration::Percentile {
+                percentile,
+                momentum,
+            } => PercentileCalibration {
+                percentile: *percentile,
+                momentum: *momentum,
+            }
+            .update_range(range, tensor),
+            ActivationCalibration::Entropy {
+                num_bins,
+                num_quantized_bins,
+                momentum,
+            } => EntropyCalibration {
+                num_bins: *num_bins,
+                num_quantized_bins: *num_quantized_bins,
+                momentum: *momentum,
+            }
+            .update_range(range, tensor),
+        }
+    }
+}
+
+/// Configuration to create a [QuantizeActivation](QuantizeActivation) layer using the
+/// [init function](QuantizeActivationConfig::init).
+#[derive(Config, Debug)]
+pub struct QuantizeActivationConfig {
+    /// The quantization scheme of the activations. Only per-tensor schemes are supported.
+    pub scheme: QuantizationScheme,
+    /// The calibration method used to observe the range of the activations.
+    #[config(default = "ActivationCalibration::MinMax")]
+    pub calibration: ActivationCalibration,
+    /// If true, the range of the activations is observed instead of quantizing them.
+    #[config(default = true)]
+    pub calibrate: bool,
+}
+
+/// Quantizes the activations with the range observed on a calibration dataset (static
+/// quantization).
+///
+/// The layer has two modes:
+/// - Calibration: the range of each input is accumulated in the running state and the input is
+///   returned unchanged. Feeding a representative dataset through the model calibrates all the
+///   activation ranges. During training (quantization-aware training), the input is also
+///   fake-quantized with the observed range.
+/// - Quantization: the input is quantized with the parameters of the calibrated range, so that the
+///   following operations (e.g., matrix multiplication with quantized weights) can be performed
+///   on quantized values.
+///
+/// The observed range is part of the record, so a calibrated model can be saved and loaded with
+/// [calibrate](QuantizeActivationConfig::calibrate) disabled for inference. Whether activations
+/// were observed isn't recorded, so calibrating a loaded module starts over from the first
+/// observed activations.
+///
+/// Should be created with [QuantizeActivationConfig].
+#[derive(Module, Debug)]
+pub struct QuantizeActivation<B: Backend> {
+    /// The observed minimum value of the activations.
+    pub min: RunningState<Tensor<B, 1>>,
+    /// The observed maximum value of the activations.
+    pub max: RunningState<Tensor<B, 1>>,
+    /// The quantization scheme of the activations.
+    pub scheme: Ignored<QuantizationScheme>,
+    /// The calibration method used to observe the range of the activations.
+    pub calibration: Ignored<ActivationCalibration>,
+    /// If true, the range of the activations is observed instead of quantizing them.
+    pub calibrate: bool,
+    /// Whether activations were observed, i.e., whether the running range is initialized.
+    observed: Ignored<Arc<AtomicBool>>,
+}
+
+impl QuantizeActivationConfig {
+    /// Initialize a new [quantize activation](QuantizeActivation) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the quantization scheme is not per-tensor.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> QuantizeActivation<B> {
+        assert!(
+            self.scheme.groups().is_none(),
+            "Activations only support per-tensor quantization, got {:?}",
+            self.scheme
+        );
+
+        QuantizeActivation {
+            min: RunningState::new(Tensor::zeros([1], device)),
+            max: RunningState::new(Tensor::zeros([1], device)),
+            scheme: Ignored(self.scheme.clone()),
+            calibration: Ignored(self.calibration.clone()),
+            calibrate: self.calibrate,
+            observed: Ignored(Arc::new(AtomicBool::new(false))),
+        }
+    }
+}
+
+impl<B: Backend> QuantizeActivation<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// See [QuantizeActivation](QuantizeActivation) for more information.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if self.calibrate {
+            let range = self.observe(&input);
+
+            // Quantization-aware training models the quantization error of the activations
+            if B::ad_enabled() {
+                let qparams = self.scheme.0.compute_q_params(range);
+                return input.fake_quantize(&self.scheme.0, qparams);
+            }
+
+            return input;
+        }
+
+        let device = input.device();
+        let range = CalibrationRange {
+            min: self.min.value().to_device(&device),
+            max: self.max.value().to_device(&device),
+        };
+        let qparams = self.scheme.0.compute_q_params(range);
+
+        input.quantize(&self.scheme.0, qparams)
+    }
+
+    /// Returns the observed range of the activations, or `None` if no activations were observed.
+    pub fn range(&self) -> Option<CalibrationRange<B>> {
+        if !self.observed.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        Some(CalibrationRange {
+            min: self.min.value_sync(),
+            max: self.max.value_sync(),
+        })
+    }
+
+    fn observe<const D: usize>(&self, input: &Tensor<B, D>) -> CalibrationRange<B> {
+        let device = input.device();
+        let range = self.range().map(|range| CalibrationRange {
+            min: range.min.to_device(&device),
+            max: range.max.to_device(&device),
+        });
+        let range = self
+            .calibration
+            .0
+            .update_range(range, &input.clone().detach());
+
+        self.min.update(range.min.clone().detach());
+        self.max.update(range.max.clone().detach());
+        self.observed.store(true, Ordering::Relaxed);
+
+        range
+    }
+}
+
+/// Wraps a module with [QuantizeActivation] layers observing and quantizing its input and output
+/// activations, e.g., to model their quantization error during quantization-aware training
+/// without changing the module.
+///
+/// Should be created with [QuantizeActivationConfig::wrap].
+///
+/// ```rust, ignore
+/// let linear = QuantizeActivationConfig::new(scheme).wrap(linear, &device);
+/// let output = linear.forward(input, Linear::forward);
+/// ```
+#[derive(Module, Debug)]
+pub struct QuantizeActivations<B: Backend, M> {
+    /// The wrapped module.
+    pub module: M,
+    /// Observes and quantizes the input activations.
+    pub input: QuantizeActivation<B>,
+    /// Observes and quantizes the output activations.
+    pub output: QuantizeActivation<B>,
+}
+
+impl QuantizeActivationConfig {
+    /// Wrap the module with [quantize activation](QuantizeActivation) layers on its input and
+    /// output activations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the quantization scheme is not per-tensor.
+    pub fn wrap<B: Backend, M>(&self, module: M, device: &B::Device) -> QuantizeActivations<B, M> {
+        QuantizeActivations {
+            module,
+            input: self.init(device),
+            output: self.init(device),
+        }
+    }
+}
+
+impl<B: Backend, M> QuantizeActivations<B, M> {
+    /// Applies the forward function of the wrapped module between the quantization of its input
+    /// and output activations.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input tensor.
+    /// * `forward` - The forward function of the wrapped module (e.g., `Linear::forward`).
+    pub fn forward<const D_IN: usize, const D_OUT: usize, F>(
+        &self,
+        input: Tensor<B, D_IN>,
+        forward: F,
+    ) -> Tensor<B, D_OUT>
+    where
+        F: FnOnce(&M, Tensor<B, D_IN>) -> Tensor<B, D_OUT>,
+    {
+        let output = forward(&self.module, self.input.forward(input));
+
+        self.output.forward(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Initializer, Linear, LinearConfig};
+    use crate::tensor::quantization::QuantizationType;
+    use crate::tensor::{DType, TensorData};
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[test]
+    fn quantize_activation_should_observe_range_when_calibrating() {
+        let device = Default::default();
+        let module = QuantizeActivationConfig::new(QuantizationScheme::PerTensorAffine(
+            QuantizationType::QInt8,
+        ))
+        .init::<TestBackend>(&device);
+        assert!(module.range().is_none());
+
+        let input = Tensor::<TestBackend, 2>::from_floats([[-1.0, 0.5], [0.0, 2.0]], &device);
+        let output = module.forward(input.clone());
+        module.forward(Tensor::from_floats([[-0.5, 3.0]], &device));
+
+        output.into_data().assert_eq(&input.into_data(), true);
+        let range = module.range().unwrap();
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([-1.0]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([3.0]), false);
+    }
+
+    #[test]
+    fn quantize_activation_should_quantize_with_calibrated_range() {
+        let device = Default::default();
+        let mut module = QuantizeActivationConfig::new(QuantizationScheme::PerTensorSymmetric(
+            QuantizationType::QInt8,
+        ))
+        .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 1>::from_floats([-1.8, -1.0, 0.0, 0.5], &device);
+        module.forward(input.clone());
+
+        module.calibrate = false;
+        let output = module.forward(input.clone());
+
+        assert!(matches!(output.to_data().dtype, DType::QFloat(_)));
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&input.into_data(), 0.01);
+    }
+
+    #[test]
+    #[should_panic = "Activations only support per-tensor quantization"]
+    fn quantize_activation_should_panic_with_per_channel_scheme() {
+        QuantizeActivationConfig::new(QuantizationScheme::PerChannelSymmetric {
+            dtype: QuantizationType::QInt8,
+            axis: 0,
+        })
+        .init::<TestBackend>(&Default::default());
+    }
+
+    #[test]
+    fn quantize_activations_should_observe_ranges_of_wrapped_module_during_training() {
+        let device = Default::default();
+        let linear = LinearConfig::new(2, 3)
+            .with_initializer(Initializer::Constant { value: 1.0 })
+            .init::<TestAutodiffBackend>(&device);
+        let linear = QuantizeActivationConfig::new(QuantizationScheme::PerTensorAffine(
+            QuantizationType::QInt8,
+        ))
+        .wrap(linear, &device);
+
+        let output = linear.forward(
+            Tensor::from_floats([[1.0, -2.0], [2.0, 1.0]], &device),
+            Linear::forward,
+        );
+        let grads = output.sum().backward();
+        linear.forward(Tensor::from_floats([[3.0, 0.5]], &device), Linear::forward);
+
+        let input_range = linear.input.range().unwrap();
+        let output_range = linear.output.range().unwrap();
+        assert!(linear.module.weight.grad(&grads).is_some());
+        input_range
+            .min
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-2.0]), 3);
+        input_range
+            .max
+            .into_data()
+            .assert_approx_eq(&TensorData::from([3.0]), 3);
+        output_range
+            .min
+            .into_data()
+            .assert_approx_eq_diff(&TensorData::from([0.0]), 0.05);
+        output_range
+            .max
+            .into_data()
+            .assert_approx_eq_diff(&TensorData::from([4.5]), 0.05);
+    }
+}
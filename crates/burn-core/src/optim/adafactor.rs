@@ -0,0 +1,329 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::MixedPrecision;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::{backend::Backend, ops::FullPrecisionBackend};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Adafactor configuration.
+#[derive(Config)]
+pub struct AdafactorConfig {
+    /// Regularization constant added to the squared gradients.
+    #[config(default = 1e-30)]
+    epsilon_1: f32,
+    /// Lower bound of the parameters scale when `scale_parameter` is enabled.
+    #[config(default = 1e-3)]
+    epsilon_2: f32,
+    /// Threshold of the root mean square of the update, above which it is clipped.
+    #[config(default = 1.0)]
+    clip_threshold: f32,
+    /// Exponent of the decay rate of the second moment estimates, the decay at step `t` being
+    /// `1 - t^decay_rate`.
+    #[config(default = -0.8)]
+    decay_rate: f32,
+    /// Coefficient of the first moment estimates, which are not kept when `None`.
+    beta_1: Option<f32>,
+    /// Decoupled weight decay.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// If true, the learning rate is multiplied by the root mean square of the parameters.
+    #[config(default = true)]
+    scale_parameter: bool,
+    /// If true, the learning rate provided to the optimizer is ignored and the relative step
+    /// size `min(1e-2, 1 / sqrt(t))` is used instead.
+    #[config(default = false)]
+    relative_step: bool,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Adafactor optimizer as described in the paper
+/// [Adafactor: Adaptive Learning Rates with Sublinear Memory Cost](https://arxiv.org/abs/1804.04235).
+///
+/// The second moment estimates of the parameters with at least two dimensions are factored
+/// into the moving averages of their rows and columns, so the memory used by the optimizer
+/// grows with the sum of the last two dimensions instead of their product.
+#[derive(Clone)]
+pub struct Adafactor<B: Backend> {
+    epsilon_1: f32,
+    epsilon_2: f32,
+    clip_threshold: f32,
+    decay_rate: f32,
+    beta_1: Option<f32>,
+    weight_decay: f32,
+    scale_parameter: bool,
+    relative_step: bool,
+    _phantom: PhantomData<B>,
+}
+
+/// Adafactor state.
+///
+/// The second moment estimates are either factored in `row` and `column` for the tensors with
+/// at least two dimensions, or kept in `moment_2` for the others.
+#[derive(Record, Clone, new)]
+pub struct AdafactorState<B: Backend, const D: usize> {
+    time: usize,
+    row: Option<Tensor<B, D>>,
+    column: Option<Tensor<B, D>>,
+    moment_2: Option<Tensor<B, D>>,
+    moment_1: Option<Tensor<B, D>>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Adafactor<B> {
+    type State<const D: usize> = AdafactorState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut state = state.unwrap_or_else(|| AdafactorState::new(0, None, None, None, None));
+        state.time += 1;
+
+        let time = state.time as f64;
+        let lr = match self.relative_step {
+            true => f64::min(1e-2, 1.0 / time.sqrt()),
+            false => lr,
+        };
+        let lr = match self.scale_parameter {
+            true => root_mean_square(tensor.clone())
+                .clamp_min(self.epsilon_2)
+                .mul_scalar(lr),
+            false => Tensor::from_floats([lr as f32], &tensor.device()),
+        };
+
+        let beta_2 = 1.0 - (state.time as f32).powf(self.decay_rate);
+        let grad_squared = grad.clone().powf_scalar(2.0).add_scalar(self.epsilon_1);
+
+        let update = if D >= 2 {
+            let row = moving_average(
+                state.row.take(),
+                grad_squared.clone().mean_dim(D - 1),
+                beta_2,
+            );
+            let column = moving_average(state.column.take(), grad_squared.mean_dim(D - 2), beta_2);
+
+            // The inverse square root of the second moment estimates is approximated by the
+            // outer product of the normalized rows and the columns.
+            let row_factor = row.clone().div(row.clone().mean_dim(D - 2)).sqrt().recip();
+            let column_factor = column.clone().sqrt().recip();
+
+            state.row = Some(row);
+            state.column = Some(column);
+
+            grad.mul(row_factor).mul(column_factor)
+        } else {
+            let moment_2 = moving_average(state.moment_2.take(), grad_squared, beta_2);
+            let update = grad.div(moment_2.clone().sqrt());
+
+            state.moment_2 = Some(moment_2);
+            update
+        };
+
+        let clip = root_mean_square(update.clone())
+            .div_scalar(self.clip_threshold)
+            .clamp_min(1.0);
+        let mut update = update.div(clip.unsqueeze()).mul(lr.clone().unsqueeze());
+
+        if let Some(beta_1) = self.beta_1 {
+            let moment_1 = match state.moment_1.take() {
+                Some(moment_1) => moment_1
+                    .mul_scalar(beta_1)
+                    .add(update.mul_scalar(1.0 - beta_1)),
+                None => update.mul_scalar(1.0 - beta_1),
+            };
+            update = moment_1.clone();
+            state.moment_1 = Some(moment_1);
+        }
+
+        let mut tensor = tensor;
+        if self.weight_decay != 0.0 {
+            let decay = tensor
+                .clone()
+                .mul(lr.unsqueeze())
+                .mul_scalar(self.weight_decay);
+            tensor = tensor - decay;
+        }
+
+        (tensor - update, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.row = state.row.map(|tensor| tensor.to_device(device));
+        state.column = state.column.map(|tensor| tensor.to_device(device));
+        state.moment_2 = state.moment_2.map(|tensor| tensor.to_device(device));
+        state.moment_1 = state.moment_1.map(|tensor| tensor.to_device(device));
+        state
+    }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: penalty as f32,
+            ..self.clone()
+        })
+    }
+}
+
+fn root_mean_square<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 1> {
+    tensor.powf_scalar(2.0).mean().sqrt()
+}
+
+fn moving_average<B: Backend, const D: usize>(
+    average: Option<Tensor<B, D>>,
+    value: Tensor<B, D>,
+    beta: f32,
+) -> Tensor<B, D> {
+    match average {
+        Some(average) => average.mul_scalar(beta).add(value.mul_scalar(1.0 - beta)),
+        None => value.mul_scalar(1.0 - beta),
+    }
+}
+
+impl AdafactorConfig {
+    /// Initialize Adafactor optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Adafactor<B::InnerBackend>, M, B> {
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize Adafactor optimizer with full precision master weights, for
+    /// [mixed precision](MixedPrecision) training.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<MixedPrecision<Adafactor<FullPrecisionBackend<B::InnerBackend>>>, M, B>
+    {
+        let mut optim = OptimizerAdaptor::from(MixedPrecision::new(self.optimizer()));
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> Adafactor<B> {
+        Adafactor {
+            epsilon_1: self.epsilon_1,
+            epsilon_2: self.epsilon_2,
+            clip_threshold: self.clip_threshold,
+            decay_rate: self.decay_rate,
+            beta_1: self.beta_1,
+            weight_decay: self.weight_decay,
+            scale_parameter: self.scale_parameter,
+            relative_step: self.relative_step,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+
+    #[test]
+    fn test_adafactor_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = AdafactorConfig::new().with_beta_1(Some(0.9)).init();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(
+                optimizer.to_record(),
+                std::env::temp_dir().as_path().join("test_optim_adafactor"),
+            )
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = AdafactorConfig::new()
+            .init::<TestAutodiffBackend, nn::Linear<TestAutodiffBackend>>()
+            .load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_adafactor_factored_second_moments() {
+        let device = Default::default();
+        let optimizer: Adafactor<TestBackend> = AdafactorConfig::new()
+            .with_scale_parameter(false)
+            .with_clip_threshold(1e6)
+            .optimizer();
+        let tensor = Tensor::<TestBackend, 2>::zeros([2, 2], &device);
+
+        // At the first step, the factored estimates are the row and column means of the squared
+        // gradients.
+        let grad = Tensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let (tensor, state) = optimizer.step(0.1, tensor, grad, None);
+
+        let state = state.unwrap();
+        state
+            .row
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.5], [12.5]]), 4);
+        state
+            .column
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[5.0, 10.0]]), 4);
+
+        // The update is grad / sqrt(row / mean(row) * column).
+        tensor.into_data().assert_approx_eq(
+            &TensorData::from([[-0.077459, -0.109544], [-0.103923, -0.097979]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn test_adafactor_update_clipping() {
+        let device = Default::default();
+        let optimizer: Adafactor<TestBackend> = AdafactorConfig::new()
+            .with_scale_parameter(false)
+            .optimizer();
+        let tensor = Tensor::<TestBackend, 1>::zeros([2], &device);
+
+        // The update grad / |grad| has a root mean square of one, which is not clipped.
+        let grad = Tensor::from_floats([2.0, -0.5], &device);
+        let (tensor, _state) = optimizer.step(0.1, tensor, grad, None);
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-0.1, 0.1]), 4);
+    }
+}
@@ -9,8 +9,9 @@ use super::{
 };
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::MixedPrecision;
 use crate::tensor::{backend::AutodiffBackend, Tensor};
-use burn_tensor::backend::Backend;
+use burn_tensor::{backend::Backend, ops::FullPrecisionBackend};
 
 /// AdaGrad configuration.
 #[derive(Config)]
@@ -72,6 +73,13 @@ impl<B: Backend> SimpleOptimizer<B> for AdaGrad<B> {
         state.lr_decay = state.lr_decay.to_device(device);
         state
     }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: WeightDecay::from_penalty(penalty),
+            ..self.clone()
+        })
+    }
 }
 
 impl AdaGradConfig {
@@ -83,19 +91,38 @@ impl AdaGradConfig {
     pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
         &self,
     ) -> OptimizerAdaptor<AdaGrad<B::InnerBackend>, M, B> {
-        let optim = AdaGrad {
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize AdaGrad optimizer with full precision master weights, for
+    /// [mixed precision](MixedPrecision) training.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<MixedPrecision<AdaGrad<FullPrecisionBackend<B::InnerBackend>>>, M, B>
+    {
+        let mut optim = OptimizerAdaptor::from(MixedPrecision::new(self.optimizer()));
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> AdaGrad<B> {
+        AdaGrad {
             lr_decay: LrDecay {
                 lr_decay: self.lr_decay,
                 epsilon: self.epsilon,
             },
             weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
-        };
-
-        let mut optim = OptimizerAdaptor::from(optim);
-        if let Some(config) = &self.grad_clipping {
-            optim = optim.with_grad_clipping(config.init());
         }
-        optim
     }
 }
 
@@ -7,8 +7,9 @@ use std::marker::PhantomData;
 use super::SimpleOptimizer;
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::MixedPrecision;
 use crate::tensor::{backend::AutodiffBackend, Tensor};
-use burn_tensor::{backend::Backend, ElementConversion};
+use burn_tensor::{backend::Backend, ops::FullPrecisionBackend, ElementConversion};
 
 /// AdamW configuration.
 #[derive(Config)]
@@ -22,6 +23,10 @@ pub struct AdamWConfig {
     /// A value required for numerical stability.
     #[config(default = 1e-5)]
     epsilon: f32,
+    /// Use the maximum of the past second moment estimates, as described in the paper
+    /// [On the Convergence of Adam and Beyond](https://arxiv.org/abs/1904.09237).
+    #[config(default = false)]
+    amsgrad: bool,
     /// Weight decay config.
     #[config(default = 1e-4)]
     weight_decay: f32,
@@ -76,6 +81,13 @@ impl<B: Backend> SimpleOptimizer<B> for AdamW<B> {
         state.momentum = state.momentum.to_device(device);
         state
     }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: penalty as f32,
+            ..self.clone()
+        })
+    }
 }
 
 impl AdamWConfig {
@@ -87,21 +99,40 @@ impl AdamWConfig {
     pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
         &self,
     ) -> OptimizerAdaptor<AdamW<B::InnerBackend>, M, B> {
-        let optim = AdamW {
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize AdamW optimizer with full precision master weights, for
+    /// [mixed precision](MixedPrecision) training.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<MixedPrecision<AdamW<FullPrecisionBackend<B::InnerBackend>>>, M, B> {
+        let mut optim = OptimizerAdaptor::from(MixedPrecision::new(self.optimizer()));
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> AdamW<B> {
+        AdamW {
             momentum: AdaptiveMomentumW {
                 beta_1: self.beta_1,
                 beta_2: self.beta_2,
                 epsilon: self.epsilon,
+                amsgrad: self.amsgrad,
             },
             weight_decay: self.weight_decay,
             _phantom: Default::default(),
-        };
-
-        let mut optim = OptimizerAdaptor::from(optim);
-        if let Some(config) = &self.grad_clipping {
-            optim = optim.with_grad_clipping(config.init());
         }
-        optim
     }
 }
 
@@ -111,6 +142,11 @@ pub struct AdaptiveMomentumWState<B: Backend, const D: usize> {
     time: usize,
     moment_1: Tensor<B, D>,
     moment_2: Tensor<B, D>,
+    // The records saved before AMSGrad was supported don't have the field. It only defaults with
+    // self-describing formats (e.g., named MessagePack), the old records saved with a bincode
+    // recorder (e.g., `BinFileRecorder`) can't be loaded.
+    #[serde(default)]
+    max_moment_2: Option<Tensor<B, D>>,
 }
 
 #[derive(Clone)]
@@ -118,6 +154,7 @@ struct AdaptiveMomentumW {
     beta_1: f32,
     beta_2: f32,
     epsilon: f32,
+    amsgrad: bool,
 }
 
 impl AdaptiveMomentumW {
@@ -126,7 +163,7 @@ impl AdaptiveMomentumW {
         grad: Tensor<B, D>,
         state: Option<AdaptiveMomentumWState<B, D>>,
     ) -> (Tensor<B, D>, AdaptiveMomentumWState<B, D>) {
-        let state = if let Some(mut state) = state {
+        let mut state = if let Some(mut state) = state {
             // Update first moment estimate.
             let factor = 1.0 - self.beta_1;
             state.moment_1 = state
@@ -154,9 +191,17 @@ impl AdaptiveMomentumW {
             let factor = 1.0 - self.beta_2;
             let moment_2 = grad.powf_scalar(2.0).mul_scalar(factor);
 
-            AdaptiveMomentumWState::new(1, moment_1, moment_2)
+            AdaptiveMomentumWState::new(1, moment_1, moment_2, None)
         };
 
+        if self.amsgrad {
+            // Keep the maximum of the second moment estimates.
+            state.max_moment_2 = Some(match state.max_moment_2.take() {
+                Some(max_moment_2) => max_moment_2.max_pair(state.moment_2.clone()),
+                None => state.moment_2.clone(),
+            });
+        }
+
         let time: i32 = (state.time as i32).elem();
 
         // Compute bias-corrected first and second moment estimates.
@@ -166,8 +211,9 @@ impl AdaptiveMomentumW {
             .div_scalar(1f32 - self.beta_1.powi(time));
 
         let moment_2_corrected = state
-            .moment_2
+            .max_moment_2
             .clone()
+            .unwrap_or_else(|| state.moment_2.clone())
             .div_scalar(1f32 - self.beta_2.powi(time));
 
         // Compute update delta. This still needs to be scaled by the learning rate.
@@ -176,7 +222,12 @@ impl AdaptiveMomentumW {
 
         (
             update_delta,
-            AdaptiveMomentumWState::new(state.time, state.moment_1, state.moment_2),
+            AdaptiveMomentumWState::new(
+                state.time,
+                state.moment_1,
+                state.moment_2,
+                state.max_moment_2,
+            ),
         )
     }
 }
@@ -194,6 +245,7 @@ impl<B: Backend, const D: usize> AdaptiveMomentumWState<B, D> {
     pub fn to_device(mut self, device: &B::Device) -> Self {
         self.moment_1 = self.moment_1.to_device(device);
         self.moment_2 = self.moment_2.to_device(device);
+        self.max_moment_2 = self.max_moment_2.map(|tensor| tensor.to_device(device));
         self
     }
 }
@@ -203,6 +255,8 @@ mod tests {
     use super::*;
     use crate::module::{Module, Param};
     use crate::optim::{GradientsParams, Optimizer};
+    #[cfg(feature = "std")]
+    use crate::record::NamedMpkBytesRecorder;
     use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
     use crate::tensor::{Distribution, Tensor, TensorData};
     use crate::{nn, TestAutodiffBackend, TestBackend};
@@ -356,6 +410,57 @@ mod tests {
         assert!(!state_updated.weight.to_data().as_slice::<f32>().unwrap()[0].is_nan());
     }
 
+    #[test]
+    fn test_adamw_amsgrad_uses_max_second_moment() {
+        let device = Default::default();
+        let optimizer: AdamW<TestBackend> = AdamWConfig::new()
+            .with_epsilon(1e-8)
+            .with_weight_decay(0.0)
+            .with_amsgrad(true)
+            .optimizer();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.0], &device);
+
+        // The second moment estimate decreases with a zero gradient, but its maximum is used.
+        let grad = Tensor::from_floats([1.0], &device);
+        let (tensor, state) = optimizer.step(1.0, tensor, grad, None);
+        let grad = Tensor::from_floats([0.0], &device);
+        let (tensor, _state) = optimizer.step(1.0, tensor, grad, state);
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.669723]), 4);
+    }
+
+    /// The adaptive momentum state before AMSGrad was supported.
+    #[derive(Record, Clone, new)]
+    struct AdaptiveMomentumWStateWithoutMax<B: Backend, const D: usize> {
+        time: usize,
+        moment_1: Tensor<B, D>,
+        moment_2: Tensor<B, D>,
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_adamw_load_record_without_max_second_moment() {
+        let device = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+        let state = AdaptiveMomentumWStateWithoutMax::<TestBackend, 1>::new(
+            2,
+            Tensor::from_floats([0.1, 0.2], &device),
+            Tensor::from_floats([0.3, 0.4], &device),
+        );
+        let bytes = recorder.record(state, ()).unwrap();
+
+        let state: AdaptiveMomentumWState<TestBackend, 1> = recorder.load(bytes, &device).unwrap();
+
+        assert_eq!(state.time, 2);
+        assert!(state.max_moment_2.is_none());
+        state
+            .moment_2
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.3, 0.4]), 3);
+    }
+
     fn given_linear_layer(weight: TensorData, bias: TensorData) -> nn::Linear<TestAutodiffBackend> {
         let device = Default::default();
         let record = nn::LinearRecord {
@@ -377,6 +482,7 @@ mod tests {
                 beta_1: config.beta_1,
                 beta_2: config.beta_2,
                 epsilon: config.epsilon,
+                amsgrad: config.amsgrad,
             },
             weight_decay: config.weight_decay,
             _phantom: Default::default(),
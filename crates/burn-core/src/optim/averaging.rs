@@ -0,0 +1,369 @@
+use crate as burn;
+
+use crate::{
+    config::Config,
+    module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, ParamId, RunningState},
+};
+use burn_tensor::{backend::AutodiffBackend, container::TensorContainer, Tensor};
+use core::marker::PhantomData;
+
+/// Configuration to create an [exponential moving average](AveragedModule) of the weights of a
+/// module.
+#[derive(Config)]
+pub struct EmaConfig {
+    /// The decay of the moving average, the weight of the average at each update.
+    #[config(default = 0.999)]
+    pub decay: f64,
+    /// If true, the decay at the update `t` is `min(decay, (1 + t) / (10 + t))`, so that the
+    /// average doesn't stay close to the initial weights during the first updates.
+    #[config(default = true)]
+    pub warmup: bool,
+}
+
+impl EmaConfig {
+    /// Initialize the exponential moving average of the weights of the given module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        module: M,
+    ) -> AveragedModule<B, M> {
+        AveragedModule::new(
+            module,
+            Averaging::Exponential {
+                decay: self.decay,
+                warmup: self.warmup,
+            },
+        )
+    }
+}
+
+/// The record of an [averaged module](AveragedModule), made of the record of the module and the
+/// number of updates.
+pub type AveragedModuleRecord<B, M> = (<M as Module<B>>::Record, usize);
+
+#[derive(Clone, Debug)]
+enum Averaging {
+    Exponential { decay: f64, warmup: bool },
+    Uniform,
+}
+
+/// A copy of a module whose weights are averaged over the training.
+///
+/// The average is [updated](AveragedModule::update) with the weights of the trained module,
+/// usually after each optimizer step for an [exponential moving average](EmaConfig), or at the
+/// end of each epoch for [stochastic weight averaging](AveragedModule::uniform). All the float
+/// tensors are averaged, including the running statistics of the normalization layers. With
+/// stochastic weight averaging, these statistics should be
+/// [reset](AveragedModule::reset_running_stats) and re-estimated with forward passes on the
+/// training data before the averaged module is used.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut ema = EmaConfig::new().with_decay(0.99).init(model.clone());
+///
+/// for item in dataloader.iter() {
+///     let grads = model.forward(item).backward();
+///     let grads = GradientsParams::from_grads(grads, &model);
+///     model = optim.step(lr, model, grads);
+///     ema.update(&model);
+/// }
+///
+/// let model_valid = ema.valid();
+/// ```
+#[derive(Clone)]
+pub struct AveragedModule<B: AutodiffBackend, M: AutodiffModule<B>> {
+    module: M,
+    averaging: Averaging,
+    num_updates: usize,
+    backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> AveragedModule<B, M> {
+    fn new(module: M, averaging: Averaging) -> Self {
+        Self {
+            // The running states are copied, they would otherwise be shared with the given module.
+            module: module.map(&mut RunningStatesCopy),
+            averaging,
+            num_updates: 0,
+            backend: PhantomData,
+        }
+    }
+
+    /// Create the uniform average of the weights of the given module, as used by
+    /// [stochastic weight averaging](https://arxiv.org/abs/1803.05407).
+    ///
+    /// The weights of the given module are replaced by the ones of the first update.
+    pub fn uniform(module: M) -> Self {
+        Self::new(module, Averaging::Uniform)
+    }
+
+    /// Update the average with the weights of the given module, which must have the same
+    /// parameters as the averaged module.
+    pub fn update(&mut self, module: &M) {
+        let mut collector = ParamsCollector::<B>::new();
+        module.visit(&mut collector);
+
+        let mut mapper = ParamsAveraging::<B> {
+            params: collector.params,
+            decay: self.decay(),
+            backend: PhantomData,
+        };
+        self.module = self.module.clone().map(&mut mapper);
+        self.num_updates += 1;
+    }
+
+    /// Reset the running statistics of the normalization layers of the averaged module.
+    ///
+    /// The statistics are then the cumulative average of the ones of the next forward passes in
+    /// training mode, i.e. the momentum of the `n`-th forward pass is `1 / n`.
+    pub fn reset_running_stats(&self) {
+        self.module.visit(&mut RunningStatesReset);
+    }
+
+    /// The weight of the current average for the next update.
+    fn decay(&self) -> f64 {
+        let num_updates = self.num_updates as f64;
+
+        match self.averaging {
+            Averaging::Exponential { decay, warmup } => match warmup {
+                true => f64::min(decay, (1.0 + num_updates) / (10.0 + num_updates)),
+                false => decay,
+            },
+            Averaging::Uniform => num_updates / (num_updates + 1.0),
+        }
+    }
+
+    /// The number of updates of the average.
+    pub fn num_updates(&self) -> usize {
+        self.num_updates
+    }
+
+    /// The averaged module.
+    pub fn module(&self) -> &M {
+        &self.module
+    }
+
+    /// Consume the average and return the averaged module.
+    pub fn into_module(self) -> M {
+        self.module
+    }
+
+    /// The averaged module without autodiff, to be used for validation.
+    pub fn valid(&self) -> M::InnerModule {
+        self.module.valid()
+    }
+
+    /// Convert the average into a [record](AveragedModuleRecord).
+    pub fn into_record(self) -> AveragedModuleRecord<B, M> {
+        (self.module.into_record(), self.num_updates)
+    }
+
+    /// Load the average from a [record](AveragedModuleRecord).
+    pub fn load_record(mut self, record: AveragedModuleRecord<B, M>) -> Self {
+        let (module, num_updates) = record;
+        self.module = self.module.load_record(module);
+        self.num_updates = num_updates;
+        self
+    }
+}
+
+struct RunningStatesCopy;
+
+impl<B: AutodiffBackend> ModuleMapper<B> for RunningStatesCopy {
+    fn map_running_state<const D: usize>(
+        &mut self,
+        state: RunningState<Tensor<B, D>>,
+    ) -> RunningState<Tensor<B, D>> {
+        RunningState::with_id(state.id().clone(), state.value_sync())
+    }
+}
+
+struct RunningStatesReset;
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for RunningStatesReset {
+    fn visit_running_state<const D: usize>(&mut self, state: &RunningState<Tensor<B, D>>) {
+        state.reset_average();
+    }
+}
+
+struct ParamsCollector<B: AutodiffBackend> {
+    params: TensorContainer<ParamId>,
+    backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ParamsCollector<B> {
+    fn new() -> Self {
+        Self {
+            params: TensorContainer::new(),
+            backend: PhantomData,
+        }
+    }
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for ParamsCollector<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params
+            .register::<B::InnerBackend, D>(id.clone(), tensor.clone().inner());
+    }
+}
+
+struct ParamsAveraging<B: AutodiffBackend> {
+    params: TensorContainer<ParamId>,
+    decay: f64,
+    backend: PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleMapper<B> for ParamsAveraging<B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let value: Tensor<B::InnerBackend, D> = match self.params.remove(id) {
+            Some(value) => value,
+            None => return tensor,
+        };
+
+        let is_require_grad = tensor.is_require_grad();
+        let average = tensor.inner();
+        let value = value.to_device(&average.device());
+        let average = average
+            .mul_scalar(self.decay)
+            .add(value.mul_scalar(1.0 - self.decay));
+
+        let mut tensor = Tensor::from_inner(average);
+        if is_require_grad {
+            tensor = tensor.require_grad();
+        }
+        tensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{BatchNormConfig, Linear, LinearConfig};
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::TensorData;
+
+    fn shifted(linear: &Linear<TestAutodiffBackend>, value: f32) -> Linear<TestAutodiffBackend> {
+        let mut linear = linear.clone();
+        linear.weight = linear.weight.map(|weight| weight.add_scalar(value));
+        linear
+    }
+
+    fn assert_weight_shift(
+        averaged: &Linear<TestAutodiffBackend>,
+        linear: &Linear<TestAutodiffBackend>,
+        value: f32,
+    ) {
+        let expected: Tensor<TestBackend, 2> = linear.weight.val().inner().add_scalar(value);
+        averaged
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn test_ema_update() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+        let mut ema = EmaConfig::new()
+            .with_decay(0.9)
+            .with_warmup(false)
+            .init(linear.clone());
+
+        ema.update(&shifted(&linear, 1.0));
+        assert_weight_shift(ema.module(), &linear, 0.1);
+
+        ema.update(&shifted(&linear, 1.0));
+        assert_weight_shift(ema.module(), &linear, 0.19);
+        assert_eq!(ema.num_updates(), 2);
+    }
+
+    #[test]
+    fn test_ema_warmup() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+        let mut ema = EmaConfig::new().with_decay(0.9).init(linear.clone());
+
+        // The decay of the first update is 1 / 10.
+        ema.update(&shifted(&linear, 1.0));
+        assert_weight_shift(ema.module(), &linear, 0.9);
+    }
+
+    #[test]
+    fn test_uniform_average() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+        let mut swa = AveragedModule::uniform(linear.clone());
+
+        update_with_shifts(&mut swa, &linear, &[3.0, 1.0, 2.0]);
+        assert_weight_shift(swa.module(), &linear, 2.0);
+    }
+
+    #[test]
+    fn test_averaged_module_record() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+        let mut swa = AveragedModule::uniform(linear.clone());
+        update_with_shifts(&mut swa, &linear, &[1.0, 3.0]);
+
+        let record = swa.clone().into_record();
+        let mut swa_loaded = AveragedModule::uniform(linear.clone()).load_record(record);
+        assert_eq!(swa_loaded.num_updates(), 2);
+
+        swa.update(&shifted(&linear, 5.0));
+        swa_loaded.update(&shifted(&linear, 5.0));
+        assert_weight_shift(swa_loaded.module(), &linear, 3.0);
+        swa_loaded
+            .module()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&swa.module().weight.val().into_data(), 5);
+    }
+
+    #[test]
+    fn test_reset_running_stats() {
+        let device = Default::default();
+        let batch_norm = BatchNormConfig::new(2).init::<TestAutodiffBackend, 0>(&device);
+        let mut swa = AveragedModule::uniform(batch_norm.clone());
+        swa.update(&batch_norm);
+        swa.reset_running_stats();
+
+        for input in [[[1.0, 0.0], [3.0, 4.0]], [[5.0, 2.0], [7.0, 2.0]]] {
+            swa.module()
+                .forward(Tensor::<TestAutodiffBackend, 2>::from_floats(
+                    input, &device,
+                ));
+        }
+
+        // The statistics are the average of the ones of both batches.
+        let module = swa.module();
+        module
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([4.0, 2.0]), 5);
+        module
+            .running_var
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 2.0]), 5);
+
+        // The statistics of the trained module are left unchanged.
+        batch_norm
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.0]), 5);
+    }
+
+    fn update_with_shifts(
+        averaged: &mut AveragedModule<TestAutodiffBackend, Linear<TestAutodiffBackend>>,
+        linear: &Linear<TestAutodiffBackend>,
+        values: &[f32],
+    ) {
+        for value in values {
+            averaged.update(&shifted(linear, *value));
+        }
+    }
+}
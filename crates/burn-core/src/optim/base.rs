@@ -1,4 +1,4 @@
-use super::GradientsParams;
+use super::{GradientsParams, ParamGroup, ParamGroupError};
 use crate::module::AutodiffModule;
 use crate::record::Record;
 use crate::tensor::backend::AutodiffBackend;
@@ -17,6 +17,27 @@ where
     /// The updated module is returned.
     fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M;
 
+    /// The global L2 norm of the gradients before clipping, computed during the last step.
+    ///
+    /// Only available when the gradients are clipped by their
+    /// [global norm](crate::grad_clipping::GradientClipping::GlobalNorm).
+    fn grad_norm(&self) -> Option<f64> {
+        None
+    }
+
+    /// Add a [parameter group](ParamGroup) optimized with its own settings.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer, or an error if it doesn't support the parameter groups, which is the
+    /// default, or the settings of the group.
+    fn with_param_group(self, _group: ParamGroup) -> Result<Self, ParamGroupError>
+    where
+        Self: Sized,
+    {
+        Err(ParamGroupError::Unsupported)
+    }
+
     /// Get the current state of the optimizer as a [record](Record).
     fn to_record(&self) -> Self::Record;
 
@@ -33,6 +33,12 @@ impl<B: Backend> WeightDecay<B> {
         }
     }
 
+    /// Creates a new [weight decay](WeightDecay) with the given L2 penalty, or `None` if the
+    /// penalty is zero.
+    pub fn from_penalty(penalty: f64) -> Option<Self> {
+        (penalty != 0.0).then(|| Self::new(&WeightDecayConfig::new(penalty)))
+    }
+
     /// Transforms a gradient.
     ///
     /// # Arguments
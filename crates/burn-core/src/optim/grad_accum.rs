@@ -30,10 +30,36 @@ impl<M> GradientsAccumulator<M> {
 
 impl<M> GradientsAccumulator<M> {
     /// Accumulate the given gradients for each parameter in the given module.
-    pub fn accumulate<B: AutodiffBackend>(&mut self, module: &M, grads: GradientsParams)
+    ///
+    /// The gradients computed from [scaled losses](GradientsParams::loss_scale) are accumulated
+    /// with the smallest scale, so that they don't overflow.
+    pub fn accumulate<B: AutodiffBackend>(&mut self, module: &M, mut grads: GradientsParams)
     where
         M: AutodiffModule<B>,
     {
+        match (self.grads.loss_scale(), grads.loss_scale()) {
+            _ if self.grads.is_empty() => {
+                self.grads = match grads.loss_scale() {
+                    Some(scale) => GradientsParams::new().with_loss_scale(scale),
+                    None => GradientsParams::new(),
+                };
+            }
+            (None, None) => {}
+            (current, new) => {
+                let current = current.unwrap_or(1.0);
+                let new = new.unwrap_or(1.0);
+
+                if new < current {
+                    let accumulated = core::mem::take(&mut self.grads);
+                    self.grads = accumulated
+                        .scale(new / current, module)
+                        .with_loss_scale(new);
+                } else {
+                    grads = grads.scale(current / new, module).with_loss_scale(current);
+                }
+            }
+        }
+
         let mut visitor = ModuleGradsAccumulator::<M>::new(&mut self.grads, grads);
         module.visit(&mut visitor);
     }
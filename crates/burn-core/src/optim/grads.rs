@@ -1,17 +1,21 @@
 use burn_tensor::{
     backend::{AutodiffBackend, Backend},
     container::TensorContainer,
-    Tensor,
+    ElementConversion, Tensor,
 };
 
 use crate::module::{AutodiffModule, ParamId};
 
-use super::visitor::{GradientsParamsChangeDevice, GradientsParamsConverter};
+use super::visitor::{
+    GradientsParamsChangeDevice, GradientsParamsConverter, GradientsParamsIsFinite,
+    GradientsParamsScale, GradientsParamsSquaredNorm,
+};
 
 /// Data type that contains gradients for parameters.
 #[derive(Default)]
 pub struct GradientsParams {
     container: TensorContainer<ParamId>,
+    loss_scale: Option<f32>,
 }
 
 impl GradientsParams {
@@ -53,6 +57,24 @@ impl GradientsParams {
         self.container.register(id, value)
     }
 
+    /// The scale of the loss the gradients were computed from, when they haven't been divided
+    /// by it yet.
+    pub fn loss_scale(&self) -> Option<f32> {
+        self.loss_scale
+    }
+
+    /// Set the scale of the loss the gradients were computed from, e.g., by a
+    /// [gradient scaler](crate::optim::GradScaler).
+    ///
+    /// The gradients are divided by the scale during the
+    /// [simple optimizer step](crate::optim::SimpleOptimizer::step_scaled), after they are cast
+    /// to the precision of the optimizer, so that small gradients don't underflow in half
+    /// precision.
+    pub fn with_loss_scale(mut self, loss_scale: f32) -> Self {
+        self.loss_scale = Some(loss_scale);
+        self
+    }
+
     /// The number of gradients tensors registered.
     pub fn len(&self) -> usize {
         self.container.len()
@@ -74,6 +96,45 @@ impl GradientsParams {
         self
     }
 
+    /// Compute the L2 norm of the gradients of the given [module](AutodiffModule), as if they were
+    /// all concatenated into a single vector.
+    pub fn global_norm<B: AutodiffBackend, M: AutodiffModule<B>>(&self, module: &M) -> f32 {
+        let mut visitor = GradientsParamsSquaredNorm::<M, B>::new(self, None);
+        module.visit(&mut visitor);
+
+        match visitor.squared_norm {
+            Some(squared_norm) => squared_norm.sqrt().into_scalar().elem::<f32>(),
+            None => 0.0,
+        }
+    }
+
+    /// Check that the gradients of the given [module](AutodiffModule) don't contain any inf or
+    /// NaN values.
+    pub fn is_finite<B: AutodiffBackend, M: AutodiffModule<B>>(&self, module: &M) -> bool {
+        let mut visitor = GradientsParamsIsFinite::<M, B>::new(self, None);
+        module.visit(&mut visitor);
+
+        match visitor.differences {
+            Some(differences) => differences.into_scalar().elem::<f32>() == 0.0,
+            None => true,
+        }
+    }
+
+    /// Multiply each tensor gradients registered for the given [module](AutodiffModule) by a
+    /// factor.
+    ///
+    /// The multiplication is computed in full precision, then the gradients are cast back to the
+    /// precision of the backend.
+    pub fn scale<B: AutodiffBackend, M: AutodiffModule<B>>(
+        mut self,
+        factor: f32,
+        module: &M,
+    ) -> Self {
+        let mut visitor = GradientsParamsScale::<M, B>::new(factor, &mut self);
+        module.visit(&mut visitor);
+        self
+    }
+
     /// Extract each tensor gradients for the given [module](AutodiffModule).
     pub fn from_grads<B: AutodiffBackend, M: AutodiffModule<B>>(
         grads: B::Gradients,
@@ -0,0 +1,262 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::MixedPrecision;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::{backend::Backend, ops::FullPrecisionBackend, ElementConversion};
+
+/// LAMB configuration.
+#[derive(Config)]
+pub struct LambConfig {
+    /// Parameter for LAMB.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for LAMB.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-6)]
+    epsilon: f32,
+    /// Decoupled weight decay, included in the update before computing the trust ratio.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// LAMB optimizer as described in the paper
+/// [Large Batch Optimization for Deep Learning: Training BERT in 76 minutes](https://arxiv.org/abs/1904.00962).
+///
+/// The Adam update of each parameter tensor is scaled by a layer-wise trust ratio, the norm of
+/// the parameter divided by the norm of the update, which keeps training stable with large
+/// batch sizes.
+#[derive(Clone)]
+pub struct Lamb<B: Backend> {
+    momentum: LambMomentum,
+    weight_decay: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// LAMB state.
+#[derive(Record, Clone, new)]
+pub struct LambState<B: Backend, const D: usize> {
+    time: usize,
+    moment_1: Tensor<B, D>,
+    moment_2: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lamb<B> {
+    type State<const D: usize> = LambState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let (update, state) = self.momentum.transform(grad, state);
+        let update = update.add(tensor.clone().mul_scalar(self.weight_decay));
+
+        let tensor_norm = tensor.clone().powf_scalar(2.0).sum().sqrt();
+        let update_norm = update.clone().powf_scalar(2.0).sum().sqrt();
+
+        // The trust ratio is one when either norm is zero.
+        let trust_ratio = tensor_norm
+            .clone()
+            .div(update_norm.clone())
+            .mask_fill(tensor_norm.lower_equal_elem(0.0), 1.0)
+            .mask_fill(update_norm.lower_equal_elem(0.0), 1.0);
+
+        let delta = update.mul(trust_ratio.unsqueeze()).mul_scalar(lr);
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.moment_1 = state.moment_1.to_device(device);
+        state.moment_2 = state.moment_2.to_device(device);
+        state
+    }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: penalty as f32,
+            ..self.clone()
+        })
+    }
+}
+
+impl LambConfig {
+    /// Initialize LAMB optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Lamb<B::InnerBackend>, M, B> {
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize LAMB optimizer with full precision master weights, for
+    /// [mixed precision](MixedPrecision) training.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<MixedPrecision<Lamb<FullPrecisionBackend<B::InnerBackend>>>, M, B> {
+        let mut optim = OptimizerAdaptor::from(MixedPrecision::new(self.optimizer()));
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> Lamb<B> {
+        Lamb {
+            momentum: LambMomentum {
+                beta_1: self.beta_1,
+                beta_2: self.beta_2,
+                epsilon: self.epsilon,
+            },
+            weight_decay: self.weight_decay,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct LambMomentum {
+    beta_1: f32,
+    beta_2: f32,
+    epsilon: f32,
+}
+
+impl LambMomentum {
+    fn transform<B: Backend, const D: usize>(
+        &self,
+        grad: Tensor<B, D>,
+        state: Option<LambState<B, D>>,
+    ) -> (Tensor<B, D>, LambState<B, D>) {
+        let state = match state {
+            Some(mut state) => {
+                state.moment_1 = state
+                    .moment_1
+                    .mul_scalar(self.beta_1)
+                    .add(grad.clone().mul_scalar(1.0 - self.beta_1));
+                state.moment_2 = state
+                    .moment_2
+                    .mul_scalar(self.beta_2)
+                    .add(grad.powf_scalar(2.0).mul_scalar(1.0 - self.beta_2));
+                state.time += 1;
+
+                state
+            }
+            None => {
+                let moment_1 = grad.clone().mul_scalar(1.0 - self.beta_1);
+                let moment_2 = grad.powf_scalar(2.0).mul_scalar(1.0 - self.beta_2);
+
+                LambState::new(1, moment_1, moment_2)
+            }
+        };
+
+        let time = (state.time as i32).elem();
+        let moment_1_corrected = state
+            .moment_1
+            .clone()
+            .div_scalar(1f32 - self.beta_1.powi(time));
+        let moment_2_corrected = state
+            .moment_2
+            .clone()
+            .div_scalar(1f32 - self.beta_2.powi(time));
+
+        let update = moment_1_corrected.div(moment_2_corrected.sqrt().add_scalar(self.epsilon));
+
+        (update, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+
+    #[test]
+    fn test_lamb_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = LambConfig::new().init();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(
+                optimizer.to_record(),
+                std::env::temp_dir().as_path().join("test_optim_lamb"),
+            )
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = LambConfig::new()
+            .init::<TestAutodiffBackend, nn::Linear<TestAutodiffBackend>>()
+            .load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lamb_optimizer_with_numbers() {
+        let device = Default::default();
+        let optimizer: Lamb<TestBackend> = LambConfig::new().with_epsilon(1e-8).optimizer();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([3.0, 4.0], &device);
+
+        // The Adam update of the first step is the sign of the gradient, of norm sqrt(2), and is
+        // scaled by the trust ratio 5 / sqrt(2).
+        let grad = Tensor::from_floats([1.0, -2.0], &device);
+        let (tensor, _state) = optimizer.step(0.1, tensor, grad, None);
+
+        let delta = 0.5 / 2.0f32.sqrt();
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([3.0 - delta, 4.0 + delta]), 4);
+    }
+
+    #[test]
+    fn test_lamb_optimizer_zero_parameters() {
+        let device = Default::default();
+        let optimizer: Lamb<TestBackend> = LambConfig::new().with_epsilon(1e-8).optimizer();
+        let tensor = Tensor::<TestBackend, 1>::zeros([2], &device);
+
+        // The trust ratio is one when the parameters are zero.
+        let grad = Tensor::from_floats([1.0, -2.0], &device);
+        let (tensor, _state) = optimizer.step(0.1, tensor, grad, None);
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-0.1, 0.1]), 4);
+    }
+}
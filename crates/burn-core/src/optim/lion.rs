@@ -0,0 +1,196 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::MixedPrecision;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::{backend::Backend, ops::FullPrecisionBackend};
+
+/// Lion configuration.
+#[derive(Config)]
+pub struct LionConfig {
+    /// Coefficient used to interpolate the momentum and the gradient for the update.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Coefficient used to update the momentum.
+    #[config(default = 0.99)]
+    beta_2: f32,
+    /// Decoupled weight decay.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Lion optimizer as described in the paper
+/// [Symbolic Discovery of Optimization Algorithms](https://arxiv.org/abs/2302.06675).
+///
+/// The update is the sign of an interpolation between the momentum and the gradient, so all the
+/// parameters are updated by the same magnitude. A smaller learning rate than for
+/// [AdamW](super::AdamW) is usually required.
+#[derive(Clone)]
+pub struct Lion<B: Backend> {
+    beta_1: f32,
+    beta_2: f32,
+    weight_decay: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// Lion state.
+#[derive(Record, Clone, new)]
+pub struct LionState<B: Backend, const D: usize> {
+    momentum: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lion<B> {
+    type State<const D: usize> = LionState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let momentum = match state {
+            Some(state) => state.momentum,
+            None => grad.zeros_like(),
+        };
+
+        let update = momentum
+            .clone()
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1))
+            .sign();
+        let update = update.add(tensor.clone().mul_scalar(self.weight_decay));
+
+        let momentum = momentum
+            .mul_scalar(self.beta_2)
+            .add(grad.mul_scalar(1.0 - self.beta_2));
+
+        (
+            tensor - update.mul_scalar(lr),
+            Some(LionState::new(momentum)),
+        )
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: penalty as f32,
+            ..self.clone()
+        })
+    }
+}
+
+impl LionConfig {
+    /// Initialize Lion optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Lion<B::InnerBackend>, M, B> {
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize Lion optimizer with full precision master weights, for
+    /// [mixed precision](MixedPrecision) training.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<MixedPrecision<Lion<FullPrecisionBackend<B::InnerBackend>>>, M, B> {
+        let mut optim = OptimizerAdaptor::from(MixedPrecision::new(self.optimizer()));
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> Lion<B> {
+        Lion {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            weight_decay: self.weight_decay,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+
+    #[test]
+    fn test_lion_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = LionConfig::new().init();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(
+                optimizer.to_record(),
+                std::env::temp_dir().as_path().join("test_optim_lion"),
+            )
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = LionConfig::new()
+            .init::<TestAutodiffBackend, nn::Linear<TestAutodiffBackend>>()
+            .load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lion_optimizer_with_numbers() {
+        let device = Default::default();
+        let optimizer: Lion<TestBackend> = LionConfig::new().with_weight_decay(0.5).optimizer();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, -2.0], &device);
+
+        let grad = Tensor::from_floats([0.5, -0.1], &device);
+        let (tensor, state) = optimizer.step(0.1, tensor, grad, None);
+        tensor
+            .clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.85, -1.8]), 5);
+
+        // The momentum outweighs the small negative gradient of the first parameter.
+        let grad = Tensor::from_floats([-0.01, 0.3], &device);
+        let (tensor, _state) = optimizer.step(0.1, tensor, grad, state);
+        tensor
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.7075, -1.81]), 5);
+    }
+}
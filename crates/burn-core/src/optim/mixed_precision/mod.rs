@@ -0,0 +1,5 @@
+mod optimizer;
+mod scaler;
+
+pub use optimizer::*;
+pub use scaler::*;
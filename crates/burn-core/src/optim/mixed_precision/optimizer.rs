@@ -0,0 +1,233 @@
+use crate::{
+    optim::SimpleOptimizer,
+    record::{PrecisionSettings, Record},
+    LearningRate,
+};
+use burn_tensor::{backend::Backend, ops::FullPrecisionBackend, Device, Tensor};
+
+/// Wrapper around a [simple optimizer](SimpleOptimizer) that keeps full precision master weights.
+///
+/// The parameters of the model and their gradients can be in half precision, but the wrapped
+/// optimizer updates a full precision copy of each parameter, so that small updates are not lost
+/// to rounding. The model parameters are then replaced by the updated master weights cast back
+/// to the precision of the backend.
+///
+/// The optimizers can be initialized with master weights using their `init_mixed_precision`
+/// function, e.g. [AdamConfig::init_mixed_precision](crate::optim::AdamConfig::init_mixed_precision).
+#[derive(Clone, new)]
+pub struct MixedPrecision<O> {
+    optim: O,
+}
+
+/// State of the [mixed precision](MixedPrecision) optimizer.
+#[derive(Clone)]
+pub struct MixedPrecisionState<B: Backend, S, const D: usize> {
+    master: Tensor<FullPrecisionBackend<B>, D>,
+    state: Option<S>,
+}
+
+impl<B, O> SimpleOptimizer<B> for MixedPrecision<O>
+where
+    B: Backend,
+    O: SimpleOptimizer<FullPrecisionBackend<B>>,
+{
+    type State<const D: usize> = MixedPrecisionState<B, O::State<D>, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step_full_precision(lr, tensor, grad.into_full_precision(), state)
+    }
+
+    fn step_scaled<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        loss_scale: f32,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        // The gradient is unscaled in full precision, where it doesn't underflow.
+        let grad = grad.into_full_precision().div_scalar(loss_scale);
+        self.step_full_precision(lr, tensor, grad, state)
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        let device = full_precision_device::<B>(device);
+
+        state.master = state.master.to_device(&device);
+        state.state = state.state.map(|state| O::to_device(state, &device));
+        state
+    }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        SimpleOptimizer::<FullPrecisionBackend<B>>::with_weight_decay(&self.optim, penalty)
+            .map(Self::new)
+    }
+}
+
+impl<O> MixedPrecision<O> {
+    fn step_full_precision<B, const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<FullPrecisionBackend<B>, D>,
+        state: Option<MixedPrecisionState<B, O::State<D>, D>>,
+    ) -> (Tensor<B, D>, Option<MixedPrecisionState<B, O::State<D>, D>>)
+    where
+        B: Backend,
+        O: SimpleOptimizer<FullPrecisionBackend<B>>,
+    {
+        let (master, state) = match state {
+            Some(state) => (state.master, state.state),
+            None => (tensor.into_full_precision(), None),
+        };
+
+        let (master, state) = self.optim.step(lr, master, grad, state);
+        let tensor = Tensor::from_full_precision(master.clone());
+
+        (tensor, Some(MixedPrecisionState { master, state }))
+    }
+}
+
+impl<B, S, const D: usize> Record<B> for MixedPrecisionState<B, S, D>
+where
+    B: Backend,
+    S: Record<FullPrecisionBackend<B>>,
+{
+    type Item<PS: PrecisionSettings> = (
+        <Tensor<FullPrecisionBackend<B>, D> as Record<FullPrecisionBackend<B>>>::Item<PS>,
+        <Option<S> as Record<FullPrecisionBackend<B>>>::Item<PS>,
+    );
+
+    fn into_item<PS: PrecisionSettings>(self) -> Self::Item<PS> {
+        (
+            Record::<FullPrecisionBackend<B>>::into_item::<PS>(self.master),
+            Record::<FullPrecisionBackend<B>>::into_item::<PS>(self.state),
+        )
+    }
+
+    fn from_item<PS: PrecisionSettings>(item: Self::Item<PS>, device: &B::Device) -> Self {
+        let device = full_precision_device::<B>(device);
+        let (master, state) = item;
+
+        Self {
+            master: Record::<FullPrecisionBackend<B>>::from_item::<PS>(master, &device),
+            state: Record::<FullPrecisionBackend<B>>::from_item::<PS>(state, &device),
+        }
+    }
+}
+
+/// The device of the full precision backend matching the given device.
+fn full_precision_device<B: Backend>(device: &B::Device) -> Device<FullPrecisionBackend<B>> {
+    // The precision bridge is the only link between the devices of both backends.
+    Tensor::<B, 1>::zeros([1], device)
+        .into_full_precision()
+        .device()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Module;
+    use crate::optim::{AdamConfig, GradScalerConfig, GradientsParams, Optimizer, SgdConfig};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::Distribution;
+    use crate::{nn, TestAutodiffBackend};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+
+    #[test]
+    fn test_mixed_precision_same_as_wrapped_optimizer() {
+        let device = Default::default();
+        let mut linear = nn::LinearConfig::new(6, 6).init(&device);
+        let mut linear_mixed = linear.clone();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = AdamConfig::new().init();
+        let mut optimizer_mixed = AdamConfig::new().init_mixed_precision();
+
+        for _ in 0..3 {
+            let grads = linear.forward(x.clone()).backward();
+            let grads = GradientsParams::from_grads(grads, &linear);
+            linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+            let grads = linear_mixed.forward(x.clone()).backward();
+            let grads = GradientsParams::from_grads(grads, &linear_mixed);
+            linear_mixed = optimizer_mixed.step(LEARNING_RATE, linear_mixed, grads);
+        }
+
+        let state = linear.into_record();
+        let state_mixed = linear_mixed.into_record();
+        state_mixed
+            .weight
+            .to_data()
+            .assert_approx_eq(&state.weight.to_data(), 5);
+        state_mixed
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&state.bias.unwrap().to_data(), 5);
+    }
+
+    #[test]
+    fn test_mixed_precision_unscales_gradients_of_scaled_loss() {
+        let device = Default::default();
+        let mut linear = nn::LinearConfig::new(6, 6).init(&device);
+        let mut linear_mixed = linear.clone();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = AdamConfig::new().init();
+        let mut optimizer_mixed = AdamConfig::new().init_mixed_precision();
+        let mut scaler = GradScalerConfig::new().with_init_scale(1024.0).init();
+
+        for _ in 0..3 {
+            let grads = linear.forward(x.clone()).sum().backward();
+            let grads = GradientsParams::from_grads(grads, &linear);
+            linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+            let loss = scaler.scale_loss(linear_mixed.forward(x.clone()).sum());
+            let grads = GradientsParams::from_grads(loss.backward(), &linear_mixed);
+            linear_mixed = scaler.step(&mut optimizer_mixed, LEARNING_RATE, linear_mixed, grads);
+        }
+
+        linear_mixed
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&linear.weight.val().into_data(), 5);
+    }
+
+    #[test]
+    fn test_mixed_precision_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = SgdConfig::new().init_mixed_precision();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(
+                optimizer.to_record(),
+                std::env::temp_dir()
+                    .as_path()
+                    .join("test_optim_mixed_precision"),
+            )
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = SgdConfig::new()
+            .init_mixed_precision::<TestAutodiffBackend, nn::Linear<TestAutodiffBackend>>();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+}
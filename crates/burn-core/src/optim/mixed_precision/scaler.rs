@@ -0,0 +1,246 @@
+use crate as burn;
+
+use crate::{
+    config::Config,
+    module::AutodiffModule,
+    optim::{adaptor::OptimizerAdaptor, GradientsParams, Optimizer, SimpleOptimizer},
+    LearningRate,
+};
+use burn_tensor::{
+    backend::{AutodiffBackend, Backend},
+    Tensor,
+};
+
+/// Configuration to create a [gradient scaler](GradScaler).
+#[derive(Config)]
+pub struct GradScalerConfig {
+    /// The initial scale of the loss.
+    #[config(default = 65536.0)]
+    init_scale: f32,
+    /// The factor by which the scale is multiplied after `growth_interval` consecutive steps
+    /// with finite gradients.
+    #[config(default = 2.0)]
+    growth_factor: f32,
+    /// The factor by which the scale is multiplied when inf or NaN gradients are found.
+    #[config(default = 0.5)]
+    backoff_factor: f32,
+    /// The number of consecutive steps with finite gradients before the scale grows.
+    #[config(default = 2000)]
+    growth_interval: usize,
+}
+
+impl GradScalerConfig {
+    /// Initialize a new [gradient scaler](GradScaler).
+    pub fn init(&self) -> GradScaler {
+        GradScaler {
+            scale: self.init_scale,
+            growth_factor: self.growth_factor,
+            backoff_factor: self.backoff_factor,
+            growth_interval: self.growth_interval,
+            growth_tracker: 0,
+        }
+    }
+}
+
+/// [Gradient scaler](GradScaler) record item, i.e., the scale and the number of consecutive steps
+/// with finite gradients.
+pub type GradScalerRecord = (f32, usize);
+
+/// Dynamic loss scaling for mixed precision training.
+///
+/// Small gradients underflow to zero in half precision, so the loss is multiplied by a large
+/// scale before the backward pass and the gradients are divided by the same scale before the
+/// optimizer step. When the scale is too large, the gradients overflow: the step is skipped and
+/// the scale is reduced. The scale grows again after a number of steps without overflow.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let loss = model.forward(item);
+/// let grads = scaler.scale_loss(loss).backward();
+/// let grads = GradientsParams::from_grads(grads, &model);
+/// let model = scaler.step(&mut optim, lr, model, grads);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GradScaler {
+    scale: f32,
+    growth_factor: f32,
+    backoff_factor: f32,
+    growth_interval: usize,
+    growth_tracker: usize,
+}
+
+impl GradScaler {
+    /// The current scale of the loss.
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Convert the state of the scaler into a [record](GradScalerRecord).
+    pub fn to_record(&self) -> GradScalerRecord {
+        (self.scale, self.growth_tracker)
+    }
+
+    /// Load the state of the scaler from a [record](GradScalerRecord).
+    pub fn load_record(mut self, record: GradScalerRecord) -> Self {
+        (self.scale, self.growth_tracker) = record;
+        self
+    }
+
+    /// Multiply the loss by the current scale.
+    pub fn scale_loss<B: Backend, const D: usize>(&self, loss: Tensor<B, D>) -> Tensor<B, D> {
+        loss.mul_scalar(self.scale)
+    }
+
+    /// Divide the gradients computed from a [scaled loss](GradScaler::scale_loss) by the current
+    /// scale.
+    ///
+    /// The division is computed in full precision, but the unscaled gradients are cast back to the
+    /// precision of the backend, where the smallest ones can underflow. Prefer
+    /// [step](GradScaler::step), which unscales the gradients in the precision of the optimizer.
+    ///
+    /// # Returns
+    ///
+    /// The unscaled gradients, or `None` if any gradient contains inf or NaN values.
+    pub fn unscale<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        grads: GradientsParams,
+        module: &M,
+    ) -> Option<GradientsParams> {
+        if !grads.is_finite(module) {
+            return None;
+        }
+
+        Some(grads.scale(1.0 / self.scale, module))
+    }
+
+    /// Update the scale depending on whether inf or NaN gradients were found in the last step.
+    pub fn update(&mut self, found_inf: bool) {
+        if found_inf {
+            self.scale *= self.backoff_factor;
+            self.growth_tracker = 0;
+            return;
+        }
+
+        self.growth_tracker += 1;
+        if self.growth_tracker >= self.growth_interval {
+            self.scale *= self.growth_factor;
+            self.growth_tracker = 0;
+        }
+    }
+
+    /// Perform the optimizer step with the gradients computed from a
+    /// [scaled loss](GradScaler::scale_loss), then [update](GradScaler::update) the scale.
+    ///
+    /// The gradients are divided by the scale during the
+    /// [simple optimizer step](SimpleOptimizer::step_scaled), after they are cast to the precision
+    /// of the optimizer (e.g., the full precision of the [master weights](crate::optim::MixedPrecision)).
+    /// The step is skipped and the module is returned unchanged if any gradient contains inf or
+    /// NaN values.
+    pub fn step<B, M, O>(
+        &mut self,
+        optim: &mut OptimizerAdaptor<O, M, B>,
+        lr: LearningRate,
+        module: M,
+        grads: GradientsParams,
+    ) -> M
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B>,
+        O: SimpleOptimizer<B::InnerBackend>,
+    {
+        if !grads.is_finite(&module) {
+            self.update(true);
+            return module;
+        }
+
+        let grads = grads.with_loss_scale(self.scale);
+        self.update(false);
+        optim.step(lr, module, grads)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Module;
+    use crate::optim::SgdConfig;
+    use crate::tensor::{Distribution, TensorData};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+
+    #[test]
+    fn test_scaler_backoff_and_growth() {
+        let mut scaler = GradScalerConfig::new()
+            .with_init_scale(1024.0)
+            .with_growth_interval(2)
+            .init();
+
+        scaler.update(true);
+        assert_eq!(scaler.scale(), 512.0);
+        scaler.update(false);
+        assert_eq!(scaler.scale(), 512.0);
+        scaler.update(true);
+        assert_eq!(scaler.scale(), 256.0);
+        scaler.update(false);
+        scaler.update(false);
+        assert_eq!(scaler.scale(), 512.0);
+    }
+
+    #[test]
+    fn test_scaler_save_load_record() {
+        let config = GradScalerConfig::new()
+            .with_init_scale(1024.0)
+            .with_growth_interval(3);
+        let mut scaler = config.init();
+        scaler.update(true);
+        scaler.update(false);
+
+        let mut scaler = config.init().load_record(scaler.to_record());
+        assert_eq!(scaler.scale(), 512.0);
+        scaler.update(false);
+        scaler.update(false);
+        assert_eq!(scaler.scale(), 1024.0);
+    }
+
+    #[test]
+    fn test_scaler_unscale_gradients() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(4, 2).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([3, 4], Distribution::Default, &device);
+        let scaler = GradScalerConfig::new().with_init_scale(128.0).init();
+
+        let grads = linear.forward(x.clone()).sum().backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let grads_scaled = scaler.scale_loss(linear.forward(x).sum()).backward();
+        let grads_scaled = GradientsParams::from_grads(grads_scaled, &linear);
+        let grads_unscaled = scaler.unscale(grads_scaled, &linear).unwrap();
+
+        let id = linear.weight.id.clone();
+        let expected = grads.get::<TestBackend, 2>(&id).unwrap().into_data();
+        grads_unscaled
+            .get::<TestBackend, 2>(&id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_scaler_skips_step_with_nan_gradients() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(2, 2).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::from_data(
+            TensorData::from([[f32::NAN, 1.0], [2.0, 3.0]]),
+            &device,
+        );
+        let mut optim = SgdConfig::new().init();
+        let mut scaler = GradScalerConfig::new().init();
+        let weight = linear.weight.val().into_data();
+
+        let grads = scaler.scale_loss(linear.forward(x).sum()).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = scaler.step(&mut optim, 0.1, linear, grads);
+
+        linear.weight.val().into_data().assert_eq(&weight, true);
+        assert_eq!(scaler.scale(), 32768.0);
+    }
+}
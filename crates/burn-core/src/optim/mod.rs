@@ -4,23 +4,35 @@ pub mod decay;
 /// Momentum module for optimizers.
 pub mod momentum;
 
+mod adafactor;
 mod adagrad;
 mod adam;
 mod adamw;
+mod averaging;
 mod base;
 mod grad_accum;
 mod grads;
+mod lamb;
+mod lion;
+mod mixed_precision;
+mod param_group;
 mod rmsprop;
 mod sgd;
 mod simple;
 mod visitor;
 
+pub use adafactor::*;
 pub use adagrad::*;
 pub use adam::*;
 pub use adamw::*;
+pub use averaging::*;
 pub use base::*;
 pub use grad_accum::*;
 pub use grads::*;
+pub use lamb::*;
+pub use lion::*;
+pub use mixed_precision::*;
+pub use param_group::*;
 pub use rmsprop::*;
 pub use sgd::*;
 pub use simple::*;
@@ -0,0 +1,358 @@
+use crate::{
+    grad_clipping::GradientClipping,
+    module::{ModuleVisitor, ParamId},
+};
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use burn_tensor::{backend::Backend, Tensor};
+use hashbrown::{HashMap, HashSet};
+
+/// Information about a parameter used to select its [group](ParamGroup).
+#[derive(Clone, Debug)]
+pub struct ParamInfo {
+    /// The id of the parameter.
+    pub id: ParamId,
+    /// The path of the parameter in the module, made of the field names, enum variants and
+    /// collection indices separated by dots, e.g. `layers.0.linear.weight`.
+    pub path: String,
+    /// The shape of the parameter.
+    pub shape: Vec<usize>,
+}
+
+/// Select the parameters belonging to a [group](ParamGroup).
+#[derive(Clone)]
+pub enum ParamSelector {
+    /// Select the parameters with the given ids.
+    Ids(HashSet<ParamId>),
+    /// Select the parameters of the submodule with the given path, or the parameter itself when
+    /// the path is the one of a parameter.
+    Path(String),
+    /// Select the parameters for which the predicate returns true.
+    Predicate(Arc<dyn Fn(&ParamInfo) -> bool + Send + Sync>),
+}
+
+impl ParamSelector {
+    /// Whether the parameter is selected.
+    pub fn matches(&self, param: &ParamInfo) -> bool {
+        match self {
+            ParamSelector::Ids(ids) => ids.contains(&param.id),
+            ParamSelector::Path(path) => {
+                path.is_empty()
+                    || param.path == *path
+                    || param
+                        .path
+                        .strip_prefix(path.as_str())
+                        .is_some_and(|rest| rest.starts_with('.'))
+            }
+            ParamSelector::Predicate(predicate) => predicate(param),
+        }
+    }
+}
+
+/// A group of parameters optimized with their own settings.
+///
+/// The groups are registered on an [optimizer adaptor](crate::optim::adaptor::OptimizerAdaptor)
+/// with [with_param_group](crate::optim::Optimizer::with_param_group). Each
+/// parameter belongs to the first group selecting it, and the parameters not selected by any
+/// group use the settings of the optimizer.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let optim = AdamWConfig::new()
+///     .with_weight_decay(0.01)
+///     .init()
+///     .with_param_group(
+///         ParamGroup::predicate(|param| param.shape.len() == 1).with_weight_decay(0.0),
+///     )?
+///     .with_param_group(ParamGroup::path("head").with_lr_multiplier(10.0))?;
+/// ```
+#[derive(Clone)]
+pub struct ParamGroup {
+    pub(crate) selector: ParamSelector,
+    pub(crate) lr_multiplier: f64,
+    pub(crate) weight_decay: Option<f64>,
+    pub(crate) grad_clipping: Option<GradientClipping>,
+}
+
+impl ParamGroup {
+    /// Create a group of parameters selected by the given [selector](ParamSelector).
+    pub fn new(selector: ParamSelector) -> Self {
+        Self {
+            selector,
+            lr_multiplier: 1.0,
+            weight_decay: None,
+            grad_clipping: None,
+        }
+    }
+
+    /// Create a group of the parameters with the given ids.
+    pub fn ids<I: IntoIterator<Item = ParamId>>(ids: I) -> Self {
+        Self::new(ParamSelector::Ids(ids.into_iter().collect()))
+    }
+
+    /// Create a group of the parameters of the submodule with the given path, e.g. `head` or
+    /// `layers.0`.
+    pub fn path(path: &str) -> Self {
+        Self::new(ParamSelector::Path(path.to_string()))
+    }
+
+    /// Create a group of the parameters for which the predicate returns true.
+    pub fn predicate<F>(predicate: F) -> Self
+    where
+        F: Fn(&ParamInfo) -> bool + Send + Sync + 'static,
+    {
+        Self::new(ParamSelector::Predicate(Arc::new(predicate)))
+    }
+
+    /// Set the factor by which the learning rate is multiplied for the parameters of the group.
+    pub fn with_lr_multiplier(mut self, lr_multiplier: f64) -> Self {
+        self.lr_multiplier = lr_multiplier;
+        self
+    }
+
+    /// Set the weight decay of the parameters of the group, replacing the one of the optimizer.
+    pub fn with_weight_decay(mut self, weight_decay: f64) -> Self {
+        self.weight_decay = Some(weight_decay);
+        self
+    }
+
+    /// Set the gradient clipping of the parameters of the group, replacing the one of the
+    /// optimizer.
+    ///
+    /// The [global norm](GradientClipping::GlobalNorm) is computed over the gradients of the
+    /// group only.
+    pub fn with_grad_clipping(mut self, grad_clipping: GradientClipping) -> Self {
+        self.grad_clipping = Some(grad_clipping);
+        self
+    }
+
+    /// The selector of the parameters of the group.
+    pub fn selector(&self) -> &ParamSelector {
+        &self.selector
+    }
+}
+
+/// Error returned when a [parameter group](ParamGroup) can't be added to an optimizer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamGroupError {
+    /// The optimizer doesn't support parameter groups.
+    Unsupported,
+    /// The group sets a weight decay, but the optimizer doesn't support overriding it.
+    WeightDecayUnsupported,
+}
+
+impl core::fmt::Display for ParamGroupError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("The optimizer doesn't support parameter groups."),
+            Self::WeightDecayUnsupported => f.write_str(
+                "The optimizer doesn't support setting the weight decay of a parameter group.",
+            ),
+        }
+    }
+}
+
+// TODO: Move from std to core after Error is core (see https://github.com/rust-lang/rust/issues/103765)
+#[cfg(feature = "std")]
+impl std::error::Error for ParamGroupError {}
+
+/// Find the group of each parameter of a module, tracking the path of the visited parameters.
+pub(crate) struct ParamGroupResolver<'a> {
+    groups: &'a [ParamGroup],
+    path: Vec<String>,
+    pub(crate) assignment: HashMap<ParamId, usize>,
+}
+
+impl<'a> ParamGroupResolver<'a> {
+    pub(crate) fn new(groups: &'a [ParamGroup]) -> Self {
+        Self {
+            groups,
+            path: Vec::new(),
+            assignment: HashMap::new(),
+        }
+    }
+}
+
+impl<'a, B: Backend> ModuleVisitor<B> for ParamGroupResolver<'a> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let param = ParamInfo {
+            id: id.clone(),
+            path: self.path.join("."),
+            shape: tensor.dims().to_vec(),
+        };
+
+        if let Some(index) = self
+            .groups
+            .iter()
+            .position(|group| group.selector.matches(&param))
+        {
+            self.assignment.insert(id.clone(), index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Module;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::optim::{
+        adaptor::OptimizerAdaptor, decay::WeightDecayConfig, GradientsParams, Optimizer, SgdConfig,
+        SimpleOptimizer,
+    };
+    use crate::tensor::Distribution;
+    use crate::LearningRate;
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    const LEARNING_RATE: f64 = 0.1;
+
+    #[test]
+    fn should_select_params_by_path_and_predicate() {
+        let device = Default::default();
+        let layers: Vec<Linear<TestAutodiffBackend>> = vec![
+            LinearConfig::new(4, 4).init(&device),
+            LinearConfig::new(4, 4).init(&device),
+        ];
+        let groups = [
+            ParamGroup::path("1"),
+            ParamGroup::predicate(|param| param.shape.len() == 1),
+        ];
+
+        let mut resolver = ParamGroupResolver::new(&groups);
+        Module::<TestAutodiffBackend>::visit(&layers, &mut resolver);
+
+        // The parameters of the second layer belong to the first group.
+        assert_eq!(resolver.assignment.len(), 3);
+        assert_eq!(resolver.assignment[&layers[1].weight.id], 0);
+        assert_eq!(resolver.assignment[&layers[1].bias.as_ref().unwrap().id], 0);
+        assert_eq!(resolver.assignment[&layers[0].bias.as_ref().unwrap().id], 1);
+        assert!(!resolver.assignment.contains_key(&layers[0].weight.id));
+    }
+
+    #[test]
+    fn should_not_match_path_prefix_of_another_module() {
+        let param = ParamInfo {
+            id: ParamId::new(),
+            path: "head_norm.weight".to_string(),
+            shape: vec![4],
+        };
+
+        assert!(!ParamSelector::Path("head".to_string()).matches(&param));
+        assert!(ParamSelector::Path("head_norm".to_string()).matches(&param));
+        assert!(ParamSelector::Path("head_norm.weight".to_string()).matches(&param));
+    }
+
+    #[test]
+    fn should_apply_learning_rate_multiplier() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(4, 4).init(&device);
+        let bias = linear.bias.as_ref().unwrap().val().into_data();
+        let mut optim = SgdConfig::new()
+            .init()
+            .with_param_group(ParamGroup::path("bias").with_lr_multiplier(0.0))
+            .unwrap();
+
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 4], Distribution::Default, &device);
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let weight_grad = grads.get::<TestBackend, 2>(&linear.weight.id).unwrap();
+        let weight = linear.weight.val().inner();
+        let linear = optim.step(LEARNING_RATE, linear, grads);
+
+        linear
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&bias, 5);
+        linear.weight.val().into_data().assert_approx_eq(
+            &(weight - weight_grad.mul_scalar(LEARNING_RATE)).into_data(),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_override_weight_decay() {
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(4, 4).init(&device);
+        let bias_id = linear.bias.as_ref().unwrap().id.clone();
+        let mut optim = SgdConfig::new()
+            .with_weight_decay(Some(WeightDecayConfig::new(0.5)))
+            .init()
+            .with_param_group(ParamGroup::ids([bias_id]).with_weight_decay(0.0))
+            .unwrap();
+        let mut optim_no_decay = SgdConfig::new().init();
+
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 4], Distribution::Default, &device);
+        let grads = GradientsParams::from_grads(linear.forward(x.clone()).backward(), &linear);
+        let linear_no_decay = optim_no_decay.step(LEARNING_RATE, linear.clone(), grads);
+        let grads = GradientsParams::from_grads(linear.forward(x).backward(), &linear);
+        let linear = optim.step(LEARNING_RATE, linear, grads);
+
+        linear
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&linear_no_decay.bias.unwrap().val().into_data(), 5);
+        assert!(linear
+            .weight
+            .val()
+            .into_data()
+            .as_slice::<f32>()
+            .unwrap()
+            .iter()
+            .zip(
+                linear_no_decay
+                    .weight
+                    .val()
+                    .into_data()
+                    .as_slice::<f32>()
+                    .unwrap()
+            )
+            .any(|(a, b)| (a - b).abs() > 1e-6));
+    }
+
+    #[test]
+    fn should_reject_weight_decay_unsupported_by_the_optimizer() {
+        let optim: OptimizerAdaptor<_, Linear<TestAutodiffBackend>, TestAutodiffBackend> =
+            OptimizerAdaptor::from(GradientDescent);
+
+        let result = optim.with_param_group(ParamGroup::path("bias").with_weight_decay(0.0));
+
+        assert_eq!(result.err(), Some(ParamGroupError::WeightDecayUnsupported));
+    }
+
+    /// An optimizer without weight decay.
+    #[derive(Clone)]
+    struct GradientDescent;
+
+    impl<B: Backend> SimpleOptimizer<B> for GradientDescent {
+        type State<const D: usize> = ();
+
+        fn step<const D: usize>(
+            &self,
+            lr: LearningRate,
+            tensor: Tensor<B, D>,
+            grad: Tensor<B, D>,
+            _state: Option<Self::State<D>>,
+        ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+            (tensor - grad.mul_scalar(lr), None)
+        }
+
+        fn to_device<const D: usize>(state: Self::State<D>, _device: &B::Device) -> Self::State<D> {
+            state
+        }
+    }
+}
@@ -9,8 +9,9 @@ use super::{
 };
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::MixedPrecision;
 use crate::tensor::{backend::AutodiffBackend, Tensor};
-use burn_tensor::backend::Backend;
+use burn_tensor::{backend::Backend, ops::FullPrecisionBackend};
 
 /// Configuration to create the [RmsProp](RmsProp) optimizer.
 #[derive(Config)]
@@ -42,9 +43,34 @@ impl RmsPropConfig {
     pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
         &self,
     ) -> OptimizerAdaptor<RmsProp<B::InnerBackend>, M, B> {
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize RmsProp optimizer with full precision master weights, for
+    /// [mixed precision](MixedPrecision) training.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<MixedPrecision<RmsProp<FullPrecisionBackend<B::InnerBackend>>>, M, B>
+    {
+        let mut optim = OptimizerAdaptor::from(MixedPrecision::new(self.optimizer()));
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> RmsProp<B> {
         let weight_decay = self.weight_decay.as_ref().map(WeightDecay::new);
 
-        let mut optim = OptimizerAdaptor::from(RmsProp {
+        RmsProp {
             alpha: self.alpha,
             centered: self.centered,
             weight_decay,
@@ -52,13 +78,7 @@ impl RmsPropConfig {
                 momentum: self.momentum,
                 epsilon: self.epsilon,
             },
-        });
-
-        if let Some(config) = &self.grad_clipping {
-            optim = optim.with_grad_clipping(config.init());
         }
-
-        optim
     }
 }
 
@@ -134,6 +154,13 @@ impl<B: Backend> SimpleOptimizer<B> for RmsProp<B> {
         state.momentum = state.momentum.map(|momentum| momentum.to_device(device));
         state
     }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: WeightDecay::from_penalty(penalty),
+            ..self.clone()
+        })
+    }
 }
 
 /// State of [RmsProp](RmsProp)
@@ -7,9 +7,11 @@ use super::momentum::{Momentum, MomentumConfig, MomentumState};
 use super::SimpleOptimizer;
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
+use crate::optim::MixedPrecision;
 use crate::record::Record;
 use crate::tensor::Tensor;
 use burn_tensor::backend::{AutodiffBackend, Backend};
+use burn_tensor::ops::FullPrecisionBackend;
 
 /// Configuration to create the [Sgd](Sgd) optimizer.
 #[derive(Config)]
@@ -42,17 +44,37 @@ impl SgdConfig {
     pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
         &self,
     ) -> OptimizerAdaptor<Sgd<B::InnerBackend>, M, B> {
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+        if let Some(config) = &self.gradient_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize Sgd optimizer with full precision master weights, for
+    /// [mixed precision](MixedPrecision) training.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<MixedPrecision<Sgd<FullPrecisionBackend<B::InnerBackend>>>, M, B> {
+        let mut optim = OptimizerAdaptor::from(MixedPrecision::new(self.optimizer()));
+        if let Some(config) = &self.gradient_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> Sgd<B> {
         let momentum = self.momentum.as_ref().map(Momentum::new);
         let weight_decay = self.weight_decay.as_ref().map(WeightDecay::new);
 
-        let mut optim = OptimizerAdaptor::from(Sgd {
+        Sgd {
             momentum,
             weight_decay,
-        });
-        if let Some(config) = &self.gradient_clipping {
-            optim = optim.with_grad_clipping(config.init());
         }
-        optim
     }
 }
 
@@ -92,6 +114,13 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
         state.momentum = state.momentum.map(|state| state.to_device(device));
         state
     }
+
+    fn with_weight_decay(&self, penalty: f64) -> Option<Self> {
+        Some(Self {
+            weight_decay: WeightDecay::from_penalty(penalty),
+            ..self.clone()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -135,6 +164,22 @@ mod tests {
         assert!(optim.has_gradient_clipping());
     }
 
+    #[test]
+    fn should_report_global_grad_norm() {
+        let device = Default::default();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let mut optim = sgd_with_all().with_grad_clipping(GradientClipping::GlobalNorm(1e-3));
+        assert_eq!(optim.grad_norm(), None);
+
+        let loss = layer.forward(random_tensor(&device));
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let norm = grads.global_norm(&layer) as f64;
+        let _layer = optim.step(LEARNING_RATE, layer, grads);
+
+        let grad_norm = optim.grad_norm().unwrap();
+        assert!((grad_norm - norm).abs() < 1e-6);
+    }
+
     #[test]
     fn should_load_state() {
         let device = Default::default();
@@ -1,10 +1,13 @@
 use super::{record::AdaptorRecord, SimpleOptimizer};
 use crate::{
     grad_clipping::GradientClipping,
-    module::{AutodiffModule, ModuleMapper, ParamId},
-    optim::{GradientsParams, Optimizer},
+    module::{AutodiffModule, ModuleMapper, ModuleVisitor, ParamId},
+    optim::{
+        param_group::ParamGroupResolver, GradientsParams, Optimizer, ParamGroup, ParamGroupError,
+    },
     LearningRate,
 };
+use alloc::vec::Vec;
 use burn_tensor::{backend::AutodiffBackend, Tensor};
 use core::marker::PhantomData;
 use hashbrown::HashMap;
@@ -22,6 +25,9 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    grad_norm: Option<f64>,
+    groups: Vec<ParamGroup>,
+    group_optims: Vec<O>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -36,6 +42,9 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            grad_norm: None,
+            groups: Vec::new(),
+            group_optims: Vec::new(),
         }
     }
 }
@@ -60,6 +69,76 @@ where
         self
     }
 
+    /// Step with the settings of the parameter groups.
+    fn step_groups(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        let mut resolver = ParamGroupResolver::new(&self.groups);
+        module.visit(&mut resolver);
+        let assignment = resolver.assignment;
+        let loss_scale = grads.loss_scale();
+
+        // The gradients of the parameters without group are at the last index.
+        let clippings = self
+            .groups
+            .iter()
+            .map(|group| group.grad_clipping.as_ref().or(self.grad_clipping.as_ref()))
+            .chain([self.grad_clipping.as_ref()])
+            .map(|clipping| scale_clipping(clipping, loss_scale))
+            .collect::<Vec<_>>();
+
+        // The global norm of each group requires all its gradients, so the factors used to clip
+        // them are computed before the mapping.
+        let mut grad_scales = alloc::vec![None; clippings.len()];
+        if clippings
+            .iter()
+            .any(|clipping| matches!(clipping, Some(GradientClipping::GlobalNorm(_))))
+        {
+            let mut visitor = GroupsSquaredNorm::<M, B>::new(&grads, &assignment, clippings.len());
+            module.visit(&mut visitor);
+
+            let squared_norms = visitor.read();
+            let norm = squared_norms.iter().sum::<f64>().sqrt();
+            self.grad_norm = Some(norm / loss_scale.unwrap_or(1.0) as f64);
+
+            for (index, clipping) in clippings.iter().enumerate() {
+                if let Some(GradientClipping::GlobalNorm(max_norm)) = clipping {
+                    let norm = squared_norms[index].sqrt() as f32;
+                    let scale = max_norm / (norm + 1e-6);
+                    grad_scales[index] = Some(scale).filter(|scale| *scale < 1.0);
+                }
+            }
+        }
+
+        let default = ParamStep {
+            optimizer: &self.optim,
+            lr,
+            grad_clipping: clippings[self.groups.len()].as_ref(),
+            grad_scale: grad_scales[self.groups.len()],
+        };
+        let groups = self
+            .groups
+            .iter()
+            .zip(self.group_optims.iter())
+            .enumerate()
+            .map(|(index, (group, optimizer))| ParamStep {
+                optimizer,
+                lr: lr * group.lr_multiplier,
+                grad_clipping: clippings[index].as_ref(),
+                grad_scale: grad_scales[index],
+            })
+            .collect();
+
+        let mut mapper = SimpleOptimizerMapper::<M, B, O> {
+            default,
+            groups,
+            assignment,
+            records: &mut self.records,
+            grads: &mut grads,
+            loss_scale,
+            phantom: PhantomData,
+        };
+        module.map(&mut mapper)
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
@@ -75,16 +154,62 @@ where
     type Record = HashMap<ParamId, AdaptorRecord<O, B>>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
-        let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
-            &self.optim,
-            &mut self.records,
-            &mut grads,
-            lr,
-            self.grad_clipping.as_ref(),
-        );
+        if !self.groups.is_empty() {
+            return self.step_groups(lr, module, grads);
+        }
+
+        let loss_scale = grads.loss_scale();
+        let clipping = scale_clipping(self.grad_clipping.as_ref(), loss_scale);
+        let mut grad_clipping = clipping.as_ref();
+
+        // The global norm requires all the gradients, so they are clipped before the mapping.
+        if let Some(GradientClipping::GlobalNorm(max_norm)) = grad_clipping {
+            let (grads_clipped, norm) =
+                GradientClipping::clip_gradients_global_norm(*max_norm, &module, grads);
+            grads = grads_clipped;
+            grad_clipping = None;
+            self.grad_norm = Some((norm / loss_scale.unwrap_or(1.0)) as f64);
+        }
+
+        let mut mapper = SimpleOptimizerMapper::<M, B, O> {
+            default: ParamStep {
+                optimizer: &self.optim,
+                lr,
+                grad_clipping,
+                grad_scale: None,
+            },
+            groups: Vec::new(),
+            assignment: HashMap::new(),
+            records: &mut self.records,
+            grads: &mut grads,
+            loss_scale,
+            phantom: PhantomData,
+        };
         module.map(&mut mapper)
     }
 
+    fn grad_norm(&self) -> Option<f64> {
+        self.grad_norm
+    }
+
+    /// Each parameter belongs to the first registered group selecting it.
+    ///
+    /// Returns an error if the group sets a weight decay and the optimizer doesn't support
+    /// [overriding it](SimpleOptimizer::with_weight_decay).
+    fn with_param_group(mut self, group: ParamGroup) -> Result<Self, ParamGroupError> {
+        let optim = match group.weight_decay {
+            Some(penalty) => self
+                .optim
+                .with_weight_decay(penalty)
+                .ok_or(ParamGroupError::WeightDecayUnsupported)?,
+            None => self.optim.clone(),
+        };
+
+        self.groups.push(group);
+        self.group_optims.push(optim);
+        Ok(self)
+    }
+
     fn to_record(&self) -> Self::Record {
         self.records.clone()
     }
@@ -95,19 +220,27 @@ where
     }
 }
 
-#[derive(new)]
+/// The settings used to update a parameter.
+struct ParamStep<'a, O> {
+    optimizer: &'a O,
+    lr: LearningRate,
+    grad_clipping: Option<&'a GradientClipping>,
+    grad_scale: Option<f32>,
+}
+
 struct SimpleOptimizerMapper<'a, M, B, O>
 where
     M: AutodiffModule<B>,
     B: AutodiffBackend,
     O: SimpleOptimizer<B::InnerBackend>,
 {
-    optimizer: &'a O,
+    default: ParamStep<'a, O>,
+    groups: Vec<ParamStep<'a, O>>,
+    assignment: HashMap<ParamId, usize>,
     records: &'a mut HashMap<ParamId, AdaptorRecord<O, B>>,
     grads: &'a mut GradientsParams,
-    lr: LearningRate,
+    loss_scale: Option<f32>,
     phantom: PhantomData<M>,
-    grad_clipping: Option<&'a GradientClipping>,
 }
 
 impl<'a, M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'a, M, B, O>
@@ -120,22 +253,34 @@ where
         let grad = self.grads.remove(id);
 
         if let Some(grad) = grad {
+            let step = match self.assignment.get(id) {
+                Some(index) => &self.groups[*index],
+                None => &self.default,
+            };
             let device = grad.device();
             let is_require_grad = tensor.is_require_grad();
             let (key, record) = self.records.remove_entry(id).unzip();
 
-            let clipped_grad = if let Some(g_clipping) = self.grad_clipping {
-                g_clipping.clip_gradient(grad)
-            } else {
-                grad
+            let clipped_grad = match (step.grad_scale, step.grad_clipping) {
+                (Some(scale), _) => grad.mul_scalar(scale),
+                (None, Some(GradientClipping::GlobalNorm(_))) => grad,
+                (None, Some(g_clipping)) => g_clipping.clip_gradient(grad),
+                (None, None) => grad,
             };
 
-            let (tensor, state) = self.optimizer.step(
-                self.lr,
-                tensor.inner(),
-                clipped_grad,
-                record.map(|record| O::to_device(record.into_state(), &device)),
-            );
+            let state = record.map(|record| O::to_device(record.into_state(), &device));
+            let (tensor, state) = match self.loss_scale {
+                Some(loss_scale) => step.optimizer.step_scaled(
+                    step.lr,
+                    tensor.inner(),
+                    clipped_grad,
+                    loss_scale,
+                    state,
+                ),
+                None => step
+                    .optimizer
+                    .step(step.lr, tensor.inner(), clipped_grad, state),
+            };
 
             if let Some(state) = state {
                 self.records.insert(
@@ -154,3 +299,93 @@ where
         tensor
     }
 }
+
+/// The gradients of a scaled loss are clipped with thresholds scaled the same way.
+fn scale_clipping(
+    clipping: Option<&GradientClipping>,
+    loss_scale: Option<f32>,
+) -> Option<GradientClipping> {
+    match loss_scale {
+        Some(loss_scale) => clipping.map(|clipping| clipping.scale(loss_scale)),
+        None => clipping.cloned(),
+    }
+}
+
+/// Compute the squared global norm of the gradients of each parameter group, the parameters
+/// without group being at the last index.
+struct GroupsSquaredNorm<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    assignment: &'a HashMap<ParamId, usize>,
+    squared_norms: Vec<Option<Tensor<B::InnerBackend, 1>>>,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, M: AutodiffModule<B>, B: AutodiffBackend> GroupsSquaredNorm<'a, M, B> {
+    fn new(
+        grads: &'a GradientsParams,
+        assignment: &'a HashMap<ParamId, usize>,
+        num_groups: usize,
+    ) -> Self {
+        Self {
+            grads,
+            assignment,
+            squared_norms: alloc::vec![None; num_groups],
+            phantom: PhantomData,
+        }
+    }
+
+    /// Read the squared norms of all the groups at once.
+    fn read(self) -> Vec<f64> {
+        let mut values = alloc::vec![0.0; self.squared_norms.len()];
+        let (indices, squared_norms): (Vec<_>, Vec<_>) = self
+            .squared_norms
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, squared_norm)| squared_norm.map(|value| (index, value)))
+            .unzip();
+
+        if squared_norms.is_empty() {
+            return values;
+        }
+
+        let data = Tensor::cat(squared_norms, 0).into_data();
+        for (index, value) in indices.into_iter().zip(data.iter::<f64>()) {
+            values[index] = value;
+        }
+
+        values
+    }
+}
+
+impl<'a, M, B> ModuleVisitor<B> for GroupsSquaredNorm<'a, M, B>
+where
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            let index = match self.assignment.get(id) {
+                Some(index) => *index,
+                None => self.squared_norms.len() - 1,
+            };
+            let squared_norm = grad.powf_scalar(2.0).sum();
+
+            // The norms are accumulated on the device of the first gradient.
+            let device = self
+                .squared_norms
+                .iter()
+                .flatten()
+                .next()
+                .map(|total| total.device());
+            let squared_norm = match device {
+                Some(device) => squared_norm.to_device(&device),
+                None => squared_norm,
+            };
+
+            self.squared_norms[index] = Some(match self.squared_norms[index].take() {
+                Some(total) => total + squared_norm,
+                None => squared_norm,
+            });
+        }
+    }
+}
@@ -25,9 +25,34 @@ where
         state: Option<Self::State<D>>,
     ) -> (Tensor<B, D>, Option<Self::State<D>>);
 
+    /// Perform the [step](SimpleOptimizer::step) with the gradient of a loss multiplied by
+    /// `loss_scale`, e.g., by a [gradient scaler](crate::optim::GradScaler).
+    ///
+    /// The default implementation divides the gradient by the scale before the step.
+    /// Implementations computing the step in a higher precision should divide the gradient after
+    /// casting it, so that small gradients don't underflow.
+    fn step_scaled<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        loss_scale: f32,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        self.step(lr, tensor, grad.div_scalar(loss_scale), state)
+    }
+
     /// Change the device of the state.
     ///
     /// This function will be called accordindly to have the state on the same device as the
     /// gradient and the tensor when the [step](SimpleOptimizer::step) function is called.
     fn to_device<const D: usize>(state: Self::State<D>, device: &B::Device) -> Self::State<D>;
+
+    /// Create a copy of the optimizer using the given weight decay, which is used by the
+    /// [parameter groups](crate::optim::ParamGroup) overriding the weight decay.
+    ///
+    /// Returns `None` when the optimizer doesn't support weight decay, which is the default.
+    fn with_weight_decay(&self, _penalty: f64) -> Option<Self> {
+        None
+    }
 }
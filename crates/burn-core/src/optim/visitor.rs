@@ -10,6 +10,27 @@ pub struct GradientsParamsConverter<'a, M: AutodiffModule<B>, B: AutodiffBackend
     phatom: PhantomData<M>,
 }
 
+#[derive(new)]
+pub struct GradientsParamsSquaredNorm<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    pub squared_norm: Option<Tensor<B::InnerBackend, 1>>,
+    phatom: PhantomData<M>,
+}
+
+#[derive(new)]
+pub struct GradientsParamsIsFinite<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    pub differences: Option<Tensor<B::InnerBackend, 1>>,
+    phatom: PhantomData<M>,
+}
+
+#[derive(new)]
+pub struct GradientsParamsScale<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
+    factor: f32,
+    grads: &'a mut GradientsParams,
+    phatom: PhantomData<M>,
+}
+
 #[derive(new)]
 pub struct GradientsParamsChangeDevice<'a, M: AutodiffModule<B>, B: AutodiffBackend> {
     device: &'a B::Device,
@@ -42,3 +63,61 @@ where
         }
     }
 }
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsParamsSquaredNorm<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            let squared_norm = grad.powf_scalar(2.0).sum();
+
+            self.squared_norm = Some(match self.squared_norm.take() {
+                Some(total) => {
+                    let device = total.device();
+                    total + squared_norm.to_device(&device)
+                }
+                None => squared_norm,
+            });
+        }
+    }
+}
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsParamsIsFinite<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            // The difference of a value with itself is zero, except for inf and NaN values where
+            // it is NaN. Unlike the values, the sum of the differences can't overflow.
+            let difference = (grad.clone() - grad).sum();
+
+            self.differences = Some(match self.differences.take() {
+                Some(total) => {
+                    let device = total.device();
+                    total + difference.to_device(&device)
+                }
+                None => difference,
+            });
+        }
+    }
+}
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsParamsScale<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            // Scaled in full precision, so that half precision gradients don't overflow or
+            // underflow before being cast back.
+            let grad = grad.into_full_precision().mul_scalar(self.factor);
+            self.grads
+                .register::<B::InnerBackend, D>(id.clone(), Tensor::from_full_precision(grad));
+        }
+    }
+}
@@ -74,7 +74,14 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkBytesRecorder<S>
 mod tests {
     use super::*;
     use crate::{
-        module::Module, nn, record::FullPrecisionSettings, tensor::backend::Backend, TestBackend,
+        module::{Module, Quantizer},
+        nn,
+        record::FullPrecisionSettings,
+        tensor::{
+            backend::Backend,
+            quantization::{MinMaxCalibration, QuantizationScheme, QuantizationType},
+        },
+        TestBackend,
     };
 
     #[test]
@@ -105,6 +112,24 @@ mod tests {
         assert_eq!(bytes1, bytes2_after);
     }
 
+    #[test]
+    fn test_can_save_and_load_int4_quantized_record() {
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let device = Default::default();
+        let mut quantizer = Quantizer {
+            calibration: MinMaxCalibration {},
+            scheme: QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4),
+        };
+        let model = create_model::<TestBackend>(&device).quantize_weights(&mut quantizer);
+        let bytes = recorder.record(model.into_record(), ()).unwrap();
+
+        let model = create_model::<TestBackend>(&device)
+            .load_record(recorder.load(bytes.clone(), &device).unwrap());
+        let bytes_after = recorder.record(model.into_record(), ()).unwrap();
+
+        assert_eq!(bytes, bytes_after);
+    }
+
     pub fn create_model<B: Backend>(device: &B::Device) -> nn::Linear<B> {
         nn::LinearConfig::new(32, 32).with_bias(true).init(device)
     }
@@ -30,7 +30,10 @@ where
         let data = match TensorDataSerde::<E>::deserialize(deserializer)? {
             TensorDataSerde::V1(data) => data.into_tensor_data(),
             // NOTE: loading f32 weights with f16 precision will deserialize the f32 weights (bytes) first and then convert to f16
-            TensorDataSerde::V2(data) => data.convert::<E>(),
+            TensorDataSerde::V2(data) => match data.dtype {
+                DType::QFloat(_) => data, // do not convert quantized tensors
+                _ => data.convert::<E>(),
+            },
         };
         Ok(data)
     }
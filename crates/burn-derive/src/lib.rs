@@ -20,7 +20,9 @@ pub fn module_derive(input: TokenStream) -> TokenStream {
 }
 
 /// Derive macro for the record.
-#[proc_macro_derive(Record)]
+///
+/// The `serde` attributes of the fields are forwarded to the record item.
+#[proc_macro_derive(Record, attributes(serde))]
 pub fn record_derive(input: TokenStream) -> TokenStream {
     let input = syn::parse(input).unwrap();
     record::derive_impl(&input)
@@ -25,9 +25,14 @@ impl ModuleCodegen for EnumModuleCodegen {
     }
 
     fn gen_visit(&self) -> TokenStream {
-        let match_body = self.gen_variants_match_fn(|_| {
+        let match_body = self.gen_variants_match_fn(|variant| {
+            let name_str = variant.to_string();
             quote! {
-                burn::module::Module::visit(module, visitor)
+                {
+                    visitor.enter_module(#name_str);
+                    burn::module::Module::visit(module, visitor);
+                    visitor.exit_module(#name_str);
+                }
             }
         });
 
@@ -31,9 +31,17 @@ impl RecordItemCodegen for StructRecordItemCodegen {
         for field in self.fields.iter() {
             let ty = &field.field.ty;
             let name = &field.field.ident;
+            // The serde attributes are forwarded, e.g. `#[serde(default)]` for a field added
+            // after records were saved.
+            let attrs = field
+                .field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("serde"));
 
             fields.extend(quote! {
                 /// Field to be serialized.
+                #(#attrs)*
                 pub #name: <#ty as burn::record::Record<B>>::Item<S>,
             });
 
@@ -0,0 +1,50 @@
+use crate::{Fusion, FusionBackend};
+use burn_tensor::linalg::device;
+use burn_tensor::ops::{FloatTensor, LinalgOps};
+
+impl<B: FusionBackend> LinalgOps<Self> for Fusion<B> {
+    fn solve<const D: usize>(
+        a: FloatTensor<Self, D>,
+        b: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        device::solve::<Self, D>(a, b)
+    }
+
+    fn inverse<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        device::inverse::<Self, D>(tensor)
+    }
+
+    fn det<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        device::det::<Self, D>(tensor)
+    }
+
+    fn logdet<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        device::logdet::<Self, D>(tensor)
+    }
+
+    fn cholesky<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        device::cholesky::<Self, D>(tensor)
+    }
+
+    fn qr<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        device::qr::<Self, D>(tensor)
+    }
+
+    fn svd<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+        FloatTensor<Self, D>,
+    ) {
+        device::svd::<Self, D>(tensor)
+    }
+
+    fn eigh<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        device::eigh::<Self, D>(tensor)
+    }
+}
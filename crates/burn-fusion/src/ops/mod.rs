@@ -3,6 +3,7 @@ mod binary;
 mod boolean;
 mod float;
 mod int;
+mod linalg;
 mod module;
 mod qtensor;
 mod unary;
@@ -11,6 +11,7 @@ fn main() {
         .input("tests/argmax/argmax.onnx")
         .input("tests/avg_pool1d/avg_pool1d.onnx")
         .input("tests/avg_pool2d/avg_pool2d.onnx")
+        .input("tests/avg_pool3d/avg_pool3d.onnx")
         .input("tests/batch_norm/batch_norm.onnx")
         .input("tests/cast/cast.onnx")
         .input("tests/clip/clip_opset16.onnx")
@@ -31,6 +32,7 @@ fn main() {
         .input("tests/gather_elements/gather_elements.onnx")
         .input("tests/gelu/gelu.onnx")
         .input("tests/global_avr_pool/global_avr_pool.onnx")
+        .input("tests/grid_sample/grid_sample.onnx")
         .input("tests/layer_norm/layer_norm.onnx")
         .input("tests/linear/linear.onnx")
         .input("tests/log_softmax/log_softmax.onnx")
@@ -40,6 +42,7 @@ fn main() {
         .input("tests/max/max.onnx")
         .input("tests/maxpool1d/maxpool1d.onnx")
         .input("tests/maxpool2d/maxpool2d.onnx")
+        .input("tests/maxpool3d/maxpool3d.onnx")
         .input("tests/mul/mul.onnx")
         .input("tests/neg/neg.onnx")
         .input("tests/not/not.onnx")
@@ -0,0 +1,96 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/avg_pool3d/avg_pool3d.onnx
+
+import numpy as np
+import onnx
+import onnx.checker
+import onnx.helper
+from onnx.reference import ReferenceEvaluator
+
+# Create the input and output tensors
+inputs = [
+    onnx.helper.make_tensor_value_info(name, onnx.TensorProto.FLOAT, [1, 1, 3, 4, 4])
+    for name in ['input1', 'input2', 'input3']
+]
+outputs = [
+    onnx.helper.make_tensor_value_info('output1', onnx.TensorProto.FLOAT, [1, 1, 2, 2, 2]),
+    onnx.helper.make_tensor_value_info('output2', onnx.TensorProto.FLOAT, [1, 1, 4, 4, 3]),
+    onnx.helper.make_tensor_value_info('output3', onnx.TensorProto.FLOAT, [1, 1, 4, 4, 3]),
+]
+
+# Create the AveragePool nodes, with a 3-D kernel they are imported as AvgPool3d
+pool_node1 = onnx.helper.make_node(
+    'AveragePool',
+    inputs=['input1'],
+    outputs=['output1'],
+    name='avgpool3d1',
+    kernel_shape=[2, 2, 2],
+    strides=[1, 2, 2],
+)
+pool_node2 = onnx.helper.make_node(
+    'AveragePool',
+    inputs=['input2'],
+    outputs=['output2'],
+    name='avgpool3d2',
+    kernel_shape=[2, 3, 2],
+    strides=[1, 1, 2],
+    pads=[1, 1, 1, 1, 1, 1],
+    count_include_pad=1,
+)
+pool_node3 = onnx.helper.make_node(
+    'AveragePool',
+    inputs=['input3'],
+    outputs=['output3'],
+    name='avgpool3d3',
+    kernel_shape=[2, 3, 2],
+    strides=[1, 1, 2],
+    pads=[1, 1, 1, 1, 1, 1],
+    count_include_pad=0,
+)
+
+# Create the graph (GraphProto)
+graph_def = onnx.helper.make_graph(
+    nodes=[pool_node1, pool_node2, pool_node3],
+    name='AvgPool3dGraph',
+    inputs=inputs,
+    outputs=outputs,
+)
+
+# Create the model (ModelProto)
+model_def = onnx.helper.make_model(
+    graph_def,
+    producer_name='avg-pool3d-model',
+    ir_version=8,
+    opset_imports=[onnx.helper.make_opsetid('', 16)],
+)
+onnx.checker.check_model(model_def)
+
+# Save the ONNX model
+onnx.save(model_def, 'avg_pool3d.onnx')
+
+print("ONNX model 'avg_pool3d.onnx' generated successfully.")
+
+# Output some test data for use in the test
+test_input = np.array([[[
+    [[-0.144, -0.173, -0.111, 0.702],
+     [-0.128, -1.497, 0.332, -0.267],
+     [-0.217, 0.116, 0.232, 1.164],
+     [0.657, 0.111, -0.738, -1.015]],
+    [[0.246, 1.311, 0.042, -0.106],
+     [0.532, -1.454, -0.312, 0.490],
+     [0.873, -0.241, 0.377, 0.248],
+     [0.782, -1.113, 0.568, -1.515]],
+    [[-2.620, -0.607, -0.916, 0.876],
+     [0.664, -1.219, 0.847, -1.002],
+     [-0.086, -0.294, 0.114, 0.819],
+     [0.638, 0.350, 0.650, 0.478]],
+]]], dtype=np.float32)
+
+test_outputs = ReferenceEvaluator(model_def).run(
+    None, {'input1': test_input, 'input2': test_input, 'input3': test_input}
+)
+print("Test input data shape: {}".format(test_input.shape))
+for i, test_output in enumerate(test_outputs, start=1):
+    print("Test output{} data shape: {}".format(i, test_output.shape))
+    print("Test output{}: {}".format(i, np.round(test_output, 3)))
@@ -0,0 +1,75 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/grid_sample/grid_sample.onnx
+
+import numpy as np
+import onnx
+import onnx.checker
+import onnx.helper
+from onnx.reference import ReferenceEvaluator
+
+# Create the input and output tensors
+input = onnx.helper.make_tensor_value_info('input', onnx.TensorProto.FLOAT, [1, 1, 3, 4])
+grid = onnx.helper.make_tensor_value_info('grid', onnx.TensorProto.FLOAT, [1, 2, 3, 2])
+output1 = onnx.helper.make_tensor_value_info('output1', onnx.TensorProto.FLOAT, [1, 1, 2, 3])
+output2 = onnx.helper.make_tensor_value_info('output2', onnx.TensorProto.FLOAT, [1, 1, 2, 3])
+
+# Create the GridSample nodes
+bilinear_node = onnx.helper.make_node(
+    'GridSample',
+    inputs=['input', 'grid'],
+    outputs=['output1'],
+    name='gridsample1',
+    mode='bilinear',
+    padding_mode='zeros',
+    align_corners=0,
+)
+nearest_node = onnx.helper.make_node(
+    'GridSample',
+    inputs=['input', 'grid'],
+    outputs=['output2'],
+    name='gridsample2',
+    mode='nearest',
+    padding_mode='border',
+    align_corners=1,
+)
+
+# Create the graph (GraphProto)
+graph_def = onnx.helper.make_graph(
+    nodes=[bilinear_node, nearest_node],
+    name='GridSampleGraph',
+    inputs=[input, grid],
+    outputs=[output1, output2],
+)
+
+# Create the model (ModelProto)
+model_def = onnx.helper.make_model(
+    graph_def,
+    producer_name='grid-sample-model',
+    ir_version=8,
+    opset_imports=[onnx.helper.make_opsetid('', 16)],
+)
+onnx.checker.check_model(model_def)
+
+# Save the ONNX model
+onnx.save(model_def, 'grid_sample.onnx')
+
+print("ONNX model 'grid_sample.onnx' generated successfully.")
+
+# Output some test data for use in the test, the grid samples points outside of the input and
+# avoids coordinates halfway between two pixels in the nearest mode
+test_input = np.array([[[
+    [0.5, -1.2, 2.0, 0.3],
+    [1.1, 0.0, -0.7, 1.6],
+    [-0.4, 0.9, 1.3, -2.1],
+]]], dtype=np.float32)
+test_grid = np.array([[
+    [[-0.9, -0.8], [0.1, 0.3], [1.2, -0.2]],
+    [[-1.3, 0.7], [0.55, 0.95], [0.3, -1.1]],
+]], dtype=np.float32)
+
+test_output1, test_output2 = ReferenceEvaluator(model_def).run(
+    None, {'input': test_input, 'grid': test_grid}
+)
+print("Test output1: {}".format(np.round(test_output1, 3)))
+print("Test output2: {}".format(np.round(test_output2, 3)))
@@ -0,0 +1,68 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/maxpool3d/maxpool3d.onnx
+
+import numpy as np
+import onnx
+import onnx.checker
+import onnx.helper
+from onnx.reference import ReferenceEvaluator
+
+# Create the input and output tensors
+input = onnx.helper.make_tensor_value_info('input', onnx.TensorProto.FLOAT, [1, 1, 3, 4, 4])
+output = onnx.helper.make_tensor_value_info('output', onnx.TensorProto.FLOAT, [1, 1, 4, 2, 2])
+
+# Create the MaxPool node, with a 3-D kernel it is imported as a MaxPool3d
+maxpool_node = onnx.helper.make_node(
+    'MaxPool',
+    inputs=['input'],
+    outputs=['output'],
+    name='maxpool3d1',
+    kernel_shape=[2, 3, 2],
+    strides=[1, 2, 1],
+    pads=[1, 1, 0, 1, 1, 0],
+    dilations=[1, 1, 2],
+)
+
+# Create the graph (GraphProto)
+graph_def = onnx.helper.make_graph(
+    nodes=[maxpool_node],
+    name='MaxPool3dGraph',
+    inputs=[input],
+    outputs=[output],
+)
+
+# Create the model (ModelProto)
+model_def = onnx.helper.make_model(
+    graph_def,
+    producer_name='maxpool3d-model',
+    ir_version=8,
+    opset_imports=[onnx.helper.make_opsetid('', 16)],
+)
+onnx.checker.check_model(model_def)
+
+# Save the ONNX model
+onnx.save(model_def, 'maxpool3d.onnx')
+
+print("ONNX model 'maxpool3d.onnx' generated successfully.")
+
+# Output some test data for use in the test
+test_input = np.array([[[
+    [[-0.144, -0.173, -0.111, 0.702],
+     [-0.128, -1.497, 0.332, -0.267],
+     [-0.217, 0.116, 0.232, 1.164],
+     [0.657, 0.111, -0.738, -1.015]],
+    [[0.246, 1.311, 0.042, -0.106],
+     [0.532, -1.454, -0.312, 0.490],
+     [0.873, -0.241, 0.377, 0.248],
+     [0.782, -1.113, 0.568, -1.515]],
+    [[-2.620, -0.607, -0.916, 0.876],
+     [0.664, -1.219, 0.847, -1.002],
+     [-0.086, -0.294, 0.114, 0.819],
+     [0.638, 0.350, 0.650, 0.478]],
+]]], dtype=np.float32)
+
+(test_output,) = ReferenceEvaluator(model_def).run(None, {'input': test_input})
+print("Test input data shape: {}".format(test_input.shape))
+print("Test output data shape: {}".format(test_output.shape))
+print("Test output: {}".format(np.round(test_output, 3)))
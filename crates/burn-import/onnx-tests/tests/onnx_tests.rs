@@ -18,6 +18,7 @@ include_models!(
     add_int,
     add,
     argmax,
+    avg_pool3d,
     avg_pool2d,
     avg_pool1d,
     batch_norm,
@@ -41,6 +42,7 @@ include_models!(
     gather_elements,
     gelu,
     global_avr_pool,
+    grid_sample,
     layer_norm,
     leaky_relu,
     linear,
@@ -52,6 +54,7 @@ include_models!(
     max,
     maxpool1d,
     maxpool2d,
+    maxpool3d,
     mul,
     neg,
     not,
@@ -659,6 +662,47 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn maxpool3d() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: maxpool3d::Model<Backend> = maxpool3d::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 5>::from_floats(
+            [[[
+                [
+                    [-0.144, -0.173, -0.111, 0.702],
+                    [-0.128, -1.497, 0.332, -0.267],
+                    [-0.217, 0.116, 0.232, 1.164],
+                    [0.657, 0.111, -0.738, -1.015],
+                ],
+                [
+                    [0.246, 1.311, 0.042, -0.106],
+                    [0.532, -1.454, -0.312, 0.490],
+                    [0.873, -0.241, 0.377, 0.248],
+                    [0.782, -1.113, 0.568, -1.515],
+                ],
+                [
+                    [-2.620, -0.607, -0.916, 0.876],
+                    [0.664, -1.219, 0.847, -1.002],
+                    [-0.086, -0.294, 0.114, 0.819],
+                    [0.638, 0.350, 0.650, 0.478],
+                ],
+            ]]],
+            &device,
+        );
+        let output = model.forward(input);
+        let expected = TensorData::from([[[
+            [[0.332f32, 0.702], [0.657, 1.164]],
+            [[0.532, 1.311], [0.873, 1.164]],
+            [[0.847, 1.311], [0.873, 0.819]],
+            [[0.847, 0.876], [0.847, 0.819]],
+        ]]]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
     #[test]
     fn avg_pool1d() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -749,6 +793,107 @@ mod tests {
         output3.to_data().assert_approx_eq(&expected3, 3);
     }
 
+    #[test]
+    fn avg_pool3d() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: avg_pool3d::Model<Backend> = avg_pool3d::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 5>::from_floats(
+            [[[
+                [
+                    [-0.144, -0.173, -0.111, 0.702],
+                    [-0.128, -1.497, 0.332, -0.267],
+                    [-0.217, 0.116, 0.232, 1.164],
+                    [0.657, 0.111, -0.738, -1.015],
+                ],
+                [
+                    [0.246, 1.311, 0.042, -0.106],
+                    [0.532, -1.454, -0.312, 0.490],
+                    [0.873, -0.241, 0.377, 0.248],
+                    [0.782, -1.113, 0.568, -1.515],
+                ],
+                [
+                    [-2.620, -0.607, -0.916, 0.876],
+                    [0.664, -1.219, 0.847, -1.002],
+                    [-0.086, -0.294, 0.114, 0.819],
+                    [0.638, 0.350, 0.650, 0.478],
+                ],
+            ]]],
+            &device,
+        );
+        let (output1, output2, output3) = model.forward(input.clone(), input.clone(), input);
+        let expected1 = TensorData::from([[[
+            [[-0.163f32, 0.096], [0.121, -0.085]],
+            [[-0.393, -0.010], [0.114, 0.217]],
+        ]]]);
+        let expected2 = TensorData::from([[[
+            [
+                [-0.023f32, -0.121, 0.036],
+                [-0.041, -0.092, 0.133],
+                [0.026, -0.120, -0.010],
+                [0.037, -0.023, 0.012],
+            ],
+            [
+                [0.042, -0.155, 0.068],
+                [0.097, -0.115, 0.186],
+                [0.208, -0.302, -0.075],
+                [0.175, -0.057, -0.093],
+            ],
+            [
+                [-0.098, -0.192, 0.022],
+                [-0.033, -0.196, 0.110],
+                [0.284, -0.144, -0.040],
+                [0.184, 0.034, 0.003],
+            ],
+            [
+                [-0.163, -0.158, -0.011],
+                [-0.170, -0.173, 0.058],
+                [0.101, 0.037, 0.025],
+                [0.046, 0.068, 0.108],
+            ],
+        ]]]);
+        let expected3 = TensorData::from([[[
+            [
+                [-0.136f32, -0.362, 0.217],
+                [-0.163, -0.183, 0.533],
+                [0.104, -0.241, -0.039],
+                [0.220, -0.070, 0.075],
+            ],
+            [
+                [0.127, -0.233, 0.205],
+                [0.194, -0.115, 0.372],
+                [0.417, -0.302, -0.149],
+                [0.524, -0.086, -0.279],
+            ],
+            [
+                [-0.294, -0.289, 0.065],
+                [-0.065, -0.196, 0.221],
+                [0.567, -0.144, -0.080],
+                [0.552, 0.051, 0.008],
+            ],
+            [
+                [-0.978, -0.474, -0.063],
+                [-0.681, -0.346, 0.231],
+                [0.405, 0.075, 0.098],
+                [0.276, 0.205, 0.648],
+            ],
+        ]]]);
+
+        let expected_shape1 = Shape::from([1, 1, 2, 2, 2]);
+        let expected_shape2 = Shape::from([1, 1, 4, 4, 3]);
+        let expected_shape3 = Shape::from([1, 1, 4, 4, 3]);
+
+        assert_eq!(output1.shape(), expected_shape1);
+        assert_eq!(output2.shape(), expected_shape2);
+        assert_eq!(output3.shape(), expected_shape3);
+
+        output1.to_data().assert_approx_eq(&expected1, 3);
+        output2.to_data().assert_approx_eq(&expected2, 3);
+        output3.to_data().assert_approx_eq(&expected3, 3);
+    }
+
     #[test]
     fn reduce_max() {
         let device = Default::default();
@@ -888,6 +1033,38 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn grid_sample() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: grid_sample::Model<Backend> = grid_sample::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[[
+                [0.5, -1.2, 2.0, 0.3],
+                [1.1, 0.0, -0.7, 1.6],
+                [-0.4, 0.9, 1.3, -2.1],
+            ]]],
+            &device,
+        );
+        let grid = Tensor::<Backend, 4>::from_floats(
+            [[
+                [[-0.9, -0.8], [0.1, 0.3], [1.2, -0.2]],
+                [[-1.3, 0.7], [0.55, 0.95], [0.3, -1.1]],
+            ]],
+            &device,
+        );
+        let (output1, output2) = model.forward(input, grid);
+        // Bilinear sampling with zeros padding, without aligned corners
+        let expected1 = TensorData::from([[[[0.280f32, 0.262, 0.121], [0.000, -0.426, 0.640]]]]);
+        // Nearest sampling with border padding, with aligned corners
+        let expected2 = TensorData::from([[[[0.5f32, -0.7, 1.6], [-0.4, 1.3, 2.0]]]]);
+
+        output1.to_data().assert_approx_eq(&expected1, 3);
+        output2.to_data().assert_eq(&expected2, true);
+    }
+
     #[test]
     fn shape() {
         let device = Default::default();
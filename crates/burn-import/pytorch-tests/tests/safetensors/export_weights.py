@@ -0,0 +1,45 @@
+#!/usr/bin/env python3
+
+import torch
+import torch.nn as nn
+from safetensors.torch import save_file
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+        self.fc = nn.Linear(4, 3)
+        self.norm = nn.LayerNorm(3)
+
+    def forward(self, x):
+        x = self.fc(x)
+        x = self.norm(x)
+        return x
+
+
+def main():
+
+    torch.set_printoptions(precision=8)
+
+    model = Model().to(torch.device("cpu"))
+
+    # Use fixed values, so that the expected output does not depend on the random generator.
+    with torch.no_grad():
+        model.fc.weight.copy_(torch.arange(12, dtype=torch.float32).reshape(3, 4) / 10.0 - 0.5)
+        model.fc.bias.copy_(torch.tensor([0.1, -0.2, 0.3]))
+        model.norm.weight.copy_(torch.tensor([1.0, 2.0, 0.5]))
+        model.norm.bias.copy_(torch.tensor([0.0, 0.1, -0.1]))
+
+    save_file(model.state_dict(), "linear_layer_norm.safetensors")
+
+    input = torch.tensor([[1.0, 2.0, 3.0, 4.0], [-1.0, 0.5, 0.0, 2.0]])
+    print("Input shape: {}", input.shape)
+    print("Input: {}", input)
+
+    output = model(input)
+    print("Output: {}", output)
+    print("Output Shape: {}", output.shape)
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,79 @@
+use burn::{
+    module::Module,
+    nn::{LayerNorm, LayerNormConfig, Linear, LinearConfig},
+    tensor::{backend::Backend, Tensor},
+};
+
+#[derive(Module, Debug)]
+pub struct Net<B: Backend> {
+    fc: Linear<B>,
+    norm: LayerNorm<B>,
+}
+
+impl<B: Backend> Net<B> {
+    /// Create a new model.
+    pub fn init(device: &B::Device) -> Self {
+        let fc = LinearConfig::new(4, 3).init(device);
+        let norm = LayerNormConfig::new(3).init(device);
+
+        Self { fc, norm }
+    }
+
+    /// Forward pass of the model.
+    pub fn forward(&self, x: Tensor<B, 2>) -> Tensor<B, 2> {
+        let x = self.fc.forward(x);
+
+        self.norm.forward(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    use burn::record::{FullPrecisionSettings, Recorder};
+    use burn_import::safetensors::{AdapterType, LoadArgs, SafetensorsFileRecorder};
+
+    use super::*;
+
+    const FILE: &str = "tests/safetensors/linear_layer_norm.safetensors";
+
+    #[test]
+    fn linear_layer_norm_from_pytorch() {
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .load(
+                LoadArgs::new(FILE.into()).with_adapter_type(AdapterType::PyTorch),
+                &device,
+            )
+            .expect("Should decode state successfully");
+
+        let model = Net::<Backend>::init(&device).load_record(record);
+
+        let input =
+            Tensor::<Backend, 2>::from_data([[1.0, 2.0, 3.0, 4.0], [-1.0, 0.5, 0.0, 2.0]], &device);
+
+        let output = model.forward(input);
+
+        let expected = Tensor::<Backend, 2>::from_data(
+            [
+                [-1.18304000, -0.05906420, 0.53128605],
+                [-0.94152993, -0.78614580, 0.59230141],
+            ],
+            &device,
+        );
+
+        output.to_data().assert_approx_eq(&expected.to_data(), 5);
+    }
+
+    #[test]
+    fn linear_layer_norm_from_pytorch_requires_adapter() {
+        let device = Default::default();
+        // The PyTorch parameter names (e.g. "norm.weight") don't match the Burn module layout.
+        let result: Result<NetRecord<Backend>, _> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .load(LoadArgs::new(FILE.into()), &device);
+
+        assert!(result.is_err());
+    }
+}
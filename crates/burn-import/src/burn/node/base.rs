@@ -2,13 +2,15 @@ use std::marker::PhantomData;
 
 use super::{
     argmax::ArgMaxNode, avg_pool1d::AvgPool1dNode, avg_pool2d::AvgPool2dNode,
-    batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode, concat::ConcatNode,
-    constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, conv1d::Conv1dNode,
-    conv2d::Conv2dNode, conv3d::Conv3dNode, conv_transpose_2d::ConvTranspose2dNode,
-    conv_transpose_3d::ConvTranspose3dNode, dropout::DropoutNode, expand::ExpandNode,
-    gather::GatherNode, gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
-    layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
-    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, pad::PadNode, prelu::PReluNode,
+    avg_pool3d::AvgPool3dNode, batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode,
+    concat::ConcatNode, constant::ConstantNode, constant_of_shape::ConstantOfShapeNode,
+    conv1d::Conv1dNode, conv2d::Conv2dNode, conv3d::Conv3dNode,
+    conv_transpose_2d::ConvTranspose2dNode, conv_transpose_3d::ConvTranspose3dNode,
+    dropout::DropoutNode, expand::ExpandNode, gather::GatherNode,
+    gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
+    grid_sample::GridSampleNode, layer_norm::LayerNormNode, linear::LinearNode,
+    mask_where::WhereNode, matmul::MatmulNode, max_pool1d::MaxPool1dNode,
+    max_pool2d::MaxPool2dNode, max_pool3d::MaxPool3dNode, pad::PadNode, prelu::PReluNode,
     random_normal::RandomNormalNode, random_uniform::RandomUniformNode, range::RangeNode,
     reshape::ReshapeNode, resize::ResizeNode, slice::SliceNode, squeeze::SqueezeNode, sum::SumNode,
     unary::UnaryNode, unsqueeze::UnsqueezeNode,
@@ -84,6 +86,7 @@ pub enum Node<PS: PrecisionSettings> {
     ArgMax(ArgMaxNode),
     AvgPool1d(AvgPool1dNode),
     AvgPool2d(AvgPool2dNode),
+    AvgPool3d(AvgPool3dNode),
     BatchNorm(BatchNormNode),
     Binary(BinaryNode),
     Clip(ClipNode),
@@ -100,11 +103,13 @@ pub enum Node<PS: PrecisionSettings> {
     Gather(GatherNode),
     GatherElements(GatherElementsNode),
     GlobalAvgPool(GlobalAvgPoolNode),
+    GridSample(GridSampleNode),
     LayerNorm(LayerNormNode),
     Linear(LinearNode),
     Matmul(MatmulNode),
     MaxPool1d(MaxPool1dNode),
     MaxPool2d(MaxPool2dNode),
+    MaxPool3d(MaxPool3dNode),
     Pad(PadNode),
     Range(RangeNode),
     Reshape(ReshapeNode),
@@ -130,6 +135,7 @@ macro_rules! match_all {
             Node::ArgMax(node) => $func(node),
             Node::AvgPool1d(node) => $func(node),
             Node::AvgPool2d(node) => $func(node),
+            Node::AvgPool3d(node) => $func(node),
             Node::BatchNorm(node) => $func(node),
             Node::Binary(node) => $func(node),
             Node::Clip(node) => $func(node),
@@ -146,11 +152,13 @@ macro_rules! match_all {
             Node::Gather(node) => $func(node),
             Node::GatherElements(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
+            Node::GridSample(node) => $func(node),
             Node::LayerNorm(node) => $func(node),
             Node::Linear(node) => $func(node),
             Node::Matmul(node) => $func(node),
             Node::MaxPool1d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
+            Node::MaxPool3d(node) => $func(node),
             Node::Pad(node) => $func(node),
             Node::Range(node) => $func(node),
             Node::Reshape(node) => $func(node),
@@ -184,6 +192,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::ArgMax(_) => "argmax",
             Node::AvgPool1d(_) => "avg_pool1d",
             Node::AvgPool2d(_) => "avg_pool2d",
+            Node::AvgPool3d(_) => "avg_pool3d",
             Node::BatchNorm(_) => "batch_norm",
             Node::Binary(binary) => binary.binary_type.as_str(),
             Node::Concat(_) => "concat",
@@ -200,11 +209,13 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Gather(_) => "gather",
             Node::GatherElements(_) => "gather_elements",
             Node::GlobalAvgPool(_) => "global_avg_pool",
+            Node::GridSample(_) => "grid_sample",
             Node::LayerNorm(_) => "layer_norm",
             Node::Linear(_) => "linear",
             Node::Matmul(_) => "matmul",
             Node::MaxPool1d(_) => "max_pool1d",
             Node::MaxPool2d(_) => "max_pool2d",
+            Node::MaxPool3d(_) => "max_pool3d",
             Node::Pad(_) => "pad",
             Node::Range(_) => "range",
             Node::Reshape(_) => "reshape",
@@ -0,0 +1,181 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{OtherType, Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use burn::tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone)]
+pub struct GridSampleNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub grid: TensorType,
+    pub output: TensorType,
+    pub config: GridSampleOptions,
+}
+
+impl GridSampleNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        grid: TensorType,
+        output: TensorType,
+        config: GridSampleOptions,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    burn::module::Ignored<GridSampleOptions>
+                },
+            ),
+            input,
+            grid,
+            output,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GridSampleNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.grid.clone()),
+        ]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+
+        let mode = match self.config.mode {
+            GridSampleMode::Bilinear => quote! { GridSampleMode::Bilinear },
+            GridSampleMode::Nearest => quote! { GridSampleMode::Nearest },
+        };
+        let padding_mode = match self.config.padding_mode {
+            GridSamplePaddingMode::Zeros => quote! { GridSamplePaddingMode::Zeros },
+            GridSamplePaddingMode::Border => quote! { GridSamplePaddingMode::Border },
+            GridSamplePaddingMode::Reflection => quote! { GridSamplePaddingMode::Reflection },
+        };
+        let align_corners = self.config.align_corners;
+
+        let tokens = quote! {
+            let #name = GridSampleOptions::new(#mode, #padding_mode, #align_corners);
+            let #name = burn::module::Ignored(#name);
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        S::serialize_none(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let grid = scope.tensor_use_owned(&self.grid, node_position);
+        let output = &self.output.name;
+
+        let field = &self.field.name;
+
+        quote! {
+            let #output = grid_sample_2d(#input, #grid, self.#field.0.clone());
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::GridSample(self)
+    }
+
+    fn register_imports(&self, imports: &mut crate::burn::BurnImports) {
+        imports.register("burn::tensor::module::grid_sample_2d");
+        imports.register("burn::tensor::ops::GridSampleMode");
+        imports.register("burn::tensor::ops::GridSampleOptions");
+        imports.register("burn::tensor::ops::GridSamplePaddingMode");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{grid_sample::GridSampleNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GridSampleNode::new(
+            "grid_sample",
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("grid", 4),
+            TensorType::new_float("tensor2", 4),
+            GridSampleOptions::new(
+                GridSampleMode::Bilinear,
+                GridSamplePaddingMode::Border,
+                true,
+            ),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "grid".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::module::grid_sample_2d;
+            use burn::tensor::ops::GridSampleMode;
+            use burn::tensor::ops::GridSampleOptions;
+            use burn::tensor::ops::GridSamplePaddingMode;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                grid_sample: burn::module::Ignored<GridSampleOptions>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let grid_sample = GridSampleOptions::new(
+                        GridSampleMode::Bilinear,
+                        GridSamplePaddingMode::Border,
+                        true
+                    );
+                    let grid_sample = burn::module::Ignored(grid_sample);
+                    Self {
+                        grid_sample,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>, grid: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = grid_sample_2d(tensor1, grid, self.grid_sample.0.clone());
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -3,6 +3,7 @@ mod base;
 pub(crate) mod argmax;
 pub(crate) mod avg_pool1d;
 pub(crate) mod avg_pool2d;
+pub(crate) mod avg_pool3d;
 pub(crate) mod batch_norm;
 pub(crate) mod binary;
 pub(crate) mod clip;
@@ -19,12 +20,14 @@ pub(crate) mod expand;
 pub(crate) mod gather;
 pub(crate) mod gather_elements;
 pub(crate) mod global_avg_pool;
+pub(crate) mod grid_sample;
 pub(crate) mod layer_norm;
 pub(crate) mod linear;
 pub(crate) mod mask_where;
 pub(crate) mod matmul;
 pub(crate) mod max_pool1d;
 pub(crate) mod max_pool2d;
+pub(crate) mod max_pool3d;
 pub(crate) mod pad;
 pub(crate) mod prelu;
 pub(crate) mod random_normal;
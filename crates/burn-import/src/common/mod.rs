@@ -0,0 +1,2 @@
+/// The adapter used to convert PyTorch modules to Burn modules.
+pub(crate) mod adapter;
@@ -9,7 +9,7 @@
 //! aligns the imported model with Burn's model and converts tensor data into a format compatible with
 //! Burn.
 
-#[cfg(any(feature = "pytorch", feature = "onnx"))]
+#[cfg(any(feature = "pytorch", feature = "safetensors", feature = "onnx"))]
 #[macro_use]
 extern crate derive_new;
 
@@ -29,5 +29,13 @@ pub mod burn;
 #[cfg(feature = "pytorch")]
 pub mod pytorch;
 
+/// The Safetensors module for recorder.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
+// Shared by the `pytorch` and `safetensors` recorders.
+#[cfg(any(feature = "pytorch", feature = "safetensors"))]
+mod common;
+
 mod formatter;
 pub use formatter::*;
@@ -1,10 +1,16 @@
-use burn::nn::{
-    conv::{
-        Conv1dConfig, Conv2dConfig, Conv3dConfig, ConvTranspose2dConfig, ConvTranspose3dConfig,
+use burn::{
+    nn::{
+        conv::{
+            Conv1dConfig, Conv2dConfig, Conv3dConfig, ConvTranspose2dConfig, ConvTranspose3dConfig,
+        },
+        pool::{
+            AvgPool1dConfig, AvgPool2dConfig, AvgPool3dConfig, MaxPool1dConfig, MaxPool2dConfig,
+            MaxPool3dConfig,
+        },
+        BatchNormConfig, DropoutConfig, LayerNormConfig, LinearConfig, PaddingConfig1d,
+        PaddingConfig2d, PaddingConfig3d,
     },
-    pool::{AvgPool1dConfig, AvgPool2dConfig, MaxPool1dConfig, MaxPool2dConfig},
-    BatchNormConfig, DropoutConfig, LayerNormConfig, LinearConfig, PaddingConfig1d,
-    PaddingConfig2d, PaddingConfig3d,
+    tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode},
 };
 
 use crate::burn::node::{pad::PadConfig, resize::ResizeMode};
@@ -207,6 +213,43 @@ pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
         .with_padding(padding)
         .with_dilation([dilations[0] as usize, dilations[1] as usize])
 }
+
+/// Create a MaxPool3dConfig from the attributes of the node
+pub fn max_pool3d_config(curr: &Node) -> MaxPool3dConfig {
+    let mut kernel_shape = Vec::new();
+    let mut strides = vec![1, 1, 1];
+    let mut pads = vec![0, 0, 0, 0, 0, 0];
+    let mut dilations = vec![1, 1, 1];
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "kernel_shape" => kernel_shape = value.clone().into_i64s(),
+            "strides" => strides = value.clone().into_i64s(),
+            "pads" => pads = value.clone().into_i64s(),
+            "dilations" => dilations = value.clone().into_i64s(),
+            _ => {}
+        }
+    }
+
+    let padding = padding_config_3d(&pads);
+
+    MaxPool3dConfig::new([
+        kernel_shape[0] as usize,
+        kernel_shape[1] as usize,
+        kernel_shape[2] as usize,
+    ])
+    .with_strides([
+        strides[0] as usize,
+        strides[1] as usize,
+        strides[2] as usize,
+    ])
+    .with_padding(padding)
+    .with_dilation([
+        dilations[0] as usize,
+        dilations[1] as usize,
+        dilations[2] as usize,
+    ])
+}
 pub fn conv_transpose2d_config(curr: &Node) -> ConvTranspose2dConfig {
     let mut attrs = curr.attrs.clone();
     let kernel_shape = attrs
@@ -382,6 +425,45 @@ pub fn avg_pool2d_config(curr: &Node) -> AvgPool2dConfig {
         .with_count_include_pad(count_include_pad == 1)
 }
 
+/// Create a AvgPool3dConfig from the attributes of the node
+pub fn avg_pool3d_config(curr: &Node) -> AvgPool3dConfig {
+    let mut kernel_shape = Vec::new();
+    let mut strides = vec![1, 1, 1];
+    let mut pads = vec![0, 0, 0, 0, 0, 0];
+    let mut count_include_pad: i64 = 0;
+    let mut ceil_mode: i64 = 0;
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "kernel_shape" => kernel_shape = value.clone().into_i64s(),
+            "strides" => strides = value.clone().into_i64s(),
+            "pads" => pads = value.clone().into_i64s(),
+            "count_include_pad" => count_include_pad = value.clone().into_i64(),
+            "ceil_mode" => ceil_mode = value.clone().into_i64(),
+            _ => {}
+        }
+    }
+
+    if ceil_mode == 1 {
+        panic!("ceil_mode is not supported");
+    }
+
+    let padding = padding_config_3d(&pads);
+
+    AvgPool3dConfig::new([
+        kernel_shape[0] as usize,
+        kernel_shape[1] as usize,
+        kernel_shape[2] as usize,
+    ])
+    .with_strides([
+        strides[0] as usize,
+        strides[1] as usize,
+        strides[2] as usize,
+    ])
+    .with_padding(padding)
+    .with_count_include_pad(count_include_pad == 1)
+}
+
 pub fn expand_config(node: &Node) -> Vec<i64> {
     let input_value = &node.inputs[1].value;
     match &node.inputs[1].ty {
@@ -475,6 +557,41 @@ pub fn gather_config(curr: &Node) -> usize {
     dim as usize
 }
 
+/// Create a GridSampleOptions from the attributes of the node
+pub fn grid_sample_config(curr: &Node) -> GridSampleOptions {
+    // Defaults per ONNX spec
+    let mut mode = "linear".to_string();
+    let mut padding_mode = "zeros".to_string();
+    let mut align_corners = 0;
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "align_corners" => align_corners = value.clone().into_i64(),
+            "mode" => mode = value.clone().into_string(),
+            "padding_mode" => padding_mode = value.clone().into_string(),
+            _ => {}
+        }
+    }
+
+    let mode = match mode.as_str() {
+        // "bilinear" was renamed to "linear" in opset 20
+        "linear" | "bilinear" => GridSampleMode::Bilinear,
+        "nearest" => GridSampleMode::Nearest,
+        _ => panic!("GridSample: unsupported mode '{mode}', must be 'linear' or 'nearest'"),
+    };
+
+    let padding_mode = match padding_mode.as_str() {
+        "zeros" => GridSamplePaddingMode::Zeros,
+        "border" => GridSamplePaddingMode::Border,
+        "reflection" => GridSamplePaddingMode::Reflection,
+        _ => panic!(
+            "GridSample: invalid padding mode '{padding_mode}', must be 'zeros', 'border' or 'reflection'"
+        ),
+    };
+
+    GridSampleOptions::new(mode, padding_mode, align_corners == 1)
+}
+
 /// Create a LinearConfig from the attributes of the node
 pub fn linear_config(node: &Node) -> LinearConfig {
     if node.inputs.len() < 2 {
@@ -18,6 +18,7 @@ use crate::{
             argmax::ArgMaxNode,
             avg_pool1d::AvgPool1dNode,
             avg_pool2d::AvgPool2dNode,
+            avg_pool3d::AvgPool3dNode,
             batch_norm::BatchNormNode,
             binary::BinaryNode,
             clip::ClipNode,
@@ -34,12 +35,14 @@ use crate::{
             gather::GatherNode,
             gather_elements::GatherElementsNode,
             global_avg_pool::GlobalAvgPoolNode,
+            grid_sample::GridSampleNode,
             layer_norm::LayerNormNode,
             linear::LinearNode,
             mask_where::WhereNode,
             matmul::MatmulNode,
             max_pool1d::MaxPool1dNode,
             max_pool2d::MaxPool2dNode,
+            max_pool3d::MaxPool3dNode,
             pad::PadNode,
             prelu::PReluNode,
             random_normal::RandomNormalNode,
@@ -60,13 +63,14 @@ use crate::{
 };
 
 use super::op_configuration::{
-    argmax_config, avg_pool1d_config, avg_pool2d_config, batch_norm_config, clip_config,
-    concat_config, conv1d_config, conv2d_config, conv3d_config, conv_transpose2d_config,
-    conv_transpose3d_config, dropout_config, expand_config, flatten_config, gather_config,
-    layer_norm_config, leaky_relu_config, linear_config, log_softmax_config, max_pool1d_config,
-    max_pool2d_config, pad_config, reduce_max_config, reduce_mean_config, reduce_min_config,
-    reduce_prod_config, reduce_sum_config, reshape_config, resize_config, shape_config,
-    slice_config, softmax_config, squeeze_config, transpose_config, unsqueeze_config,
+    argmax_config, avg_pool1d_config, avg_pool2d_config, avg_pool3d_config, batch_norm_config,
+    clip_config, concat_config, conv1d_config, conv2d_config, conv3d_config,
+    conv_transpose2d_config, conv_transpose3d_config, dropout_config, expand_config,
+    flatten_config, gather_config, grid_sample_config, layer_norm_config, leaky_relu_config,
+    linear_config, log_softmax_config, max_pool1d_config, max_pool2d_config, max_pool3d_config,
+    pad_config, reduce_max_config, reduce_mean_config, reduce_min_config, reduce_prod_config,
+    reduce_sum_config, reshape_config, resize_config, shape_config, slice_config, softmax_config,
+    squeeze_config, transpose_config, unsqueeze_config,
 };
 use onnx_ir::{
     convert_constant_value,
@@ -268,9 +272,11 @@ impl ParsedOnnxGraph {
                 NodeType::Max => graph.register(Self::max_conversion(node)),
                 NodeType::MaxPool1d => graph.register(Self::max_pool1d_conversion(node)),
                 NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
+                NodeType::MaxPool3d => graph.register(Self::max_pool3d_conversion(node)),
                 NodeType::PRelu => graph.register(Self::prelu_conversion::<PS>(node)),
                 NodeType::AveragePool1d => graph.register(Self::avg_pool_1d_conversion(node)),
                 NodeType::AveragePool2d => graph.register(Self::avg_pool_2d_conversion(node)),
+                NodeType::AveragePool3d => graph.register(Self::avg_pool_3d_conversion(node)),
                 NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
                 NodeType::Neg => graph.register(Self::neg_conversion(node)),
                 NodeType::Not => graph.register(Self::not_conversion(node)),
@@ -290,6 +296,7 @@ impl ParsedOnnxGraph {
                 NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
                 NodeType::Gather => graph.register(Self::gather_conversion(node)),
                 NodeType::GatherElements => graph.register(Self::gather_elements_conversion(node)),
+                NodeType::GridSample => graph.register(Self::grid_sample_conversion(node)),
                 NodeType::Log => graph.register(Self::log_conversion(node)),
                 NodeType::LeakyRelu => graph.register(Self::leaky_relu_conversion(node)),
                 NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
@@ -655,6 +662,18 @@ impl ParsedOnnxGraph {
         ResizeNode::new(name, input, output, output_size, ResizeOptions { mode })
     }
 
+    fn grid_sample_conversion(node: Node) -> GridSampleNode {
+        let name = &node.name;
+
+        let input = TensorType::from(&node.inputs[0]);
+        let grid = TensorType::from(&node.inputs[1]);
+        let output = TensorType::from(node.outputs.first().unwrap());
+
+        let config = grid_sample_config(&node);
+
+        GridSampleNode::new(name, input, grid, output, config)
+    }
+
     fn min_conversion(node: Node) -> BinaryNode {
         let lhs = Type::from(node.inputs.first().unwrap());
         let rhs = Type::from(node.inputs.get(1).unwrap());
@@ -973,6 +992,15 @@ impl ParsedOnnxGraph {
         MaxPool2dNode::new(name, input, output, config)
     }
 
+    fn max_pool3d_conversion(node: Node) -> MaxPool3dNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = max_pool3d_config(&node);
+
+        let name = &node.name;
+        MaxPool3dNode::new(name, input, output, config)
+    }
+
     fn prelu_conversion<PS: PrecisionSettings>(node: Node) -> PReluNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -1039,6 +1067,15 @@ impl ParsedOnnxGraph {
         AvgPool2dNode::new(name, input, output, config)
     }
 
+    fn avg_pool_3d_conversion(node: Node) -> AvgPool3dNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = avg_pool3d_config(&node);
+
+        let name = &node.name;
+        AvgPool3dNode::new(name, input, output, config)
+    }
+
     fn global_avg_pool_conversion(node: Node) -> GlobalAvgPoolNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
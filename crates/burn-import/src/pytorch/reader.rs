@@ -2,7 +2,8 @@ use core::ops::Deref;
 use std::collections::HashMap;
 use std::path::Path;
 
-use super::{adapter::PyTorchAdapter, error::Error};
+use super::error::Error;
+use crate::common::adapter::PyTorchAdapter;
 
 use burn::{
     module::ParamId,
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{error::Error, recorder::AdapterType};
+use crate::common::adapter::PyTorchAdapter;
+
+use burn::{
+    module::ParamId,
+    record::{
+        serde::{
+            adapter::DefaultAdapter,
+            data::{remap, unflatten, NestedValue, Serializable},
+            de::Deserializer,
+            error,
+            ser::Serializer,
+        },
+        PrecisionSettings,
+    },
+    tensor::{backend::Backend, DType, TensorData},
+};
+
+use ::safetensors::{Dtype, SafeTensors};
+use half::{bf16, f16};
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Deserializes a Safetensors file.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `adapter_type` - The adapter used to convert the stored modules to Burn modules.
+/// * `debug` - Whether to print the keys and tensor shapes.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    adapter_type: AdapterType,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    let bytes = std::fs::read(path)?;
+
+    from_bytes::<PS, D, B>(&bytes, key_remap, adapter_type, debug)
+}
+
+/// Deserializes the content of a Safetensors file.
+///
+/// # Arguments
+///
+/// * `bytes` - The raw content of the Safetensors file.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `adapter_type` - The adapter used to convert the stored modules to Burn modules.
+/// * `debug` - Whether to print the keys and tensor shapes.
+pub fn from_bytes<PS, D, B>(
+    bytes: &[u8],
+    key_remap: Vec<(Regex, String)>,
+    adapter_type: AdapterType,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    // Read the tensors stored in the file
+    let tensors: HashMap<String, SafetensorsTensor> = SafeTensors::deserialize(bytes)?
+        .tensors()
+        .into_iter()
+        .map(|(key, view)| {
+            let data = tensor_data(view.dtype(), view.shape().to_vec(), view.data())?;
+            Ok((key, SafetensorsTensor(data)))
+        })
+        .collect::<Result<_, Error>>()?;
+
+    // Remap the keys (replace the keys in the map with the new keys)
+    let (tensors, remapped_keys) = remap(tensors, key_remap);
+
+    // Print the remapped keys if debug is enabled
+    if debug {
+        let mut remapped_keys = remapped_keys;
+        remapped_keys.sort();
+        println!("Debug information of keys and tensor shapes:\n---");
+        for (new_key, old_key) in remapped_keys {
+            if old_key != new_key {
+                println!("Original Key: {old_key}");
+                println!("Remapped Key: {new_key}");
+            } else {
+                println!("Key: {}", new_key);
+            }
+
+            let data = &tensors[&new_key].0;
+            println!("Shape: {:?}", data.shape);
+            println!("Dtype: {:?}", data.dtype);
+            println!("---");
+        }
+    }
+
+    // Convert the map of tensors to a nested value data structure
+    let nested_value = unflatten::<PS, _>(tensors)?;
+
+    // Deserialize the nested value into a record type
+    let value = match adapter_type {
+        AdapterType::PyTorch => D::deserialize(Deserializer::<PyTorchAdapter<PS, B>>::new(
+            nested_value,
+            true,
+        ))?,
+        AdapterType::NoAdapter => {
+            D::deserialize(Deserializer::<DefaultAdapter>::new(nested_value, true))?
+        }
+    };
+
+    Ok(value)
+}
+
+/// Decodes the little-endian values of a stored tensor.
+///
+/// The values are copied into a properly aligned buffer, since the tensors in a Safetensors
+/// file are only guaranteed to be aligned on 8 bytes relative to the start of the data section.
+fn tensor_data(dtype: Dtype, shape: Vec<usize>, bytes: &[u8]) -> Result<TensorData, Error> {
+    fn decode<E, const N: usize>(bytes: &[u8], from_le_bytes: fn([u8; N]) -> E) -> Vec<E> {
+        bytes
+            .chunks_exact(N)
+            .map(|chunk| from_le_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+
+    let data = match dtype {
+        Dtype::BOOL => TensorData::new(bytes.iter().map(|b| *b != 0).collect(), shape),
+        Dtype::U8 => TensorData::new(bytes.to_vec(), shape),
+        Dtype::I8 => TensorData::new(decode(bytes, i8::from_le_bytes), shape),
+        Dtype::I16 => TensorData::new(decode(bytes, i16::from_le_bytes), shape),
+        Dtype::I32 => TensorData::new(decode(bytes, i32::from_le_bytes), shape),
+        Dtype::I64 => TensorData::new(decode(bytes, i64::from_le_bytes), shape),
+        Dtype::U32 => TensorData::new(decode(bytes, u32::from_le_bytes), shape),
+        Dtype::U64 => TensorData::new(decode(bytes, u64::from_le_bytes), shape),
+        Dtype::F16 => TensorData::new(decode(bytes, f16::from_le_bytes), shape),
+        Dtype::BF16 => TensorData::new(decode(bytes, bf16::from_le_bytes), shape),
+        Dtype::F32 => TensorData::new(decode(bytes, f32::from_le_bytes), shape),
+        Dtype::F64 => TensorData::new(decode(bytes, f64::from_le_bytes), shape),
+        dtype => {
+            return Err(Error::Other(format!(
+                "Unsupported Safetensors dtype: {dtype:?}"
+            )))
+        }
+    };
+
+    Ok(data)
+}
+
+/// Serializes a stored tensor.
+///
+/// Tensors are wrapped in a `Param` struct (learnable parameters) and serialized as a `TensorData` struct.
+///
+/// Values are converted to `FloatElem` or `IntElem` depending on the precision settings.
+impl Serializable for SafetensorsTensor {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let data = self.0.clone();
+        let data = match data.dtype {
+            DType::F64 | DType::F32 | DType::F16 | DType::BF16 => data.convert::<PS::FloatElem>(),
+            DType::Bool => data,
+            _ => data.convert::<PS::IntElem>(),
+        };
+
+        let TensorData {
+            bytes,
+            shape,
+            dtype,
+        } = data;
+
+        // Manually serialize the tensor instead of using the `ParamSerde` struct to avoid copying
+        // individual elements of TensorData `bytes` into a new Vec<u8>.
+        let mut tensor_data: HashMap<String, NestedValue> = HashMap::new();
+        tensor_data.insert("bytes".into(), NestedValue::U8s(bytes));
+        tensor_data.insert("shape".into(), shape.serialize(serializer.clone())?);
+        tensor_data.insert("dtype".into(), dtype.serialize(serializer)?);
+
+        let mut param: HashMap<String, NestedValue> = HashMap::new();
+        param.insert(
+            "id".into(),
+            NestedValue::String(ParamId::new().into_string()),
+        );
+        param.insert("param".into(), NestedValue::Map(tensor_data));
+
+        Ok(NestedValue::Map(param))
+    }
+}
+
+/// New type struct for the tensors read from a Safetensors file, because we need to implement
+/// the `Serializable` trait for it.
+struct SafetensorsTensor(TensorData);
@@ -0,0 +1,432 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{BytesRecorder, PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{reader, writer};
+
+/// A recorder that loads and saves Safetensors files (`.safetensors`) into and from Burn modules.
+///
+/// LoadArgs can be used to remap keys, choose the module adapter or file path.
+/// See [LoadArgs](struct.LoadArgs.html) for more information.
+///
+/// Records are saved with the Burn module layout and parameter names, which is also the layout
+/// expected when loading by default. Files exported from PyTorch should be loaded with
+/// [AdapterType::PyTorch](AdapterType::PyTorch).
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder<PS: PrecisionSettings> {
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn record<R: Record<B>>(
+        &self,
+        record: R,
+        args: Self::RecordArgs,
+    ) -> Result<Self::RecordOutput, RecorderError> {
+        // The item is saved without the burn metadata, since Safetensors only stores tensors.
+        Recorder::<B>::save_item(self, record.into_item::<PS>(), args)
+    }
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        file.set_extension("safetensors");
+        let path = file.as_path();
+
+        // Add parent directories if they don't exist
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        writer::to_file(item, path).map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
+        if !args.file.exists() {
+            return Err(RecorderError::FileNotFound(
+                args.file.to_string_lossy().to_string(),
+            ));
+        }
+
+        let item = reader::from_file::<PS, I, B>(
+            &args.file,
+            args.key_remap,
+            args.adapter_type,
+            args.debug,
+        )?;
+        Ok(item)
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item = Recorder::<B>::load_item::<R::Item<Self::Settings>>(self, args)?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+/// In memory recorder using the Safetensors format.
+///
+/// The key remapping and the module adapter are configured on the recorder itself, since the
+/// load arguments are the raw bytes.
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::safetensors::{AdapterType, SafetensorsBytesRecorder};
+/// use burn::record::FullPrecisionSettings;
+/// use burn::record::Recorder;
+///
+/// let recorder = SafetensorsBytesRecorder::<FullPrecisionSettings>::default()
+///     .with_key_remap("conv\\.(.*)", "$1") // Remove "conv" prefix, e.g. "conv.conv1" -> "conv1"
+///     .with_adapter_type(AdapterType::PyTorch);
+///
+/// let record = recorder
+///     .load(bytes, &device)
+///     .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SafetensorsBytesRecorder<PS: PrecisionSettings> {
+    key_remap: Vec<(Regex, String)>,
+    adapter_type: AdapterType,
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings> SafetensorsBytesRecorder<PS> {
+    /// Sets key remapping applied when loading.
+    ///
+    /// See [LoadArgs::with_key_remap](LoadArgs::with_key_remap) for more information.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets the adapter used to convert the stored modules to Burn modules when loading.
+    pub fn with_adapter_type(mut self, adapter_type: AdapterType) -> Self {
+        self.adapter_type = adapter_type;
+        self
+    }
+}
+
+impl<PS: PrecisionSettings, B: Backend> BytesRecorder<B> for SafetensorsBytesRecorder<PS> {}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsBytesRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = ();
+    type RecordOutput = Vec<u8>;
+    type LoadArgs = Vec<u8>;
+
+    fn record<R: Record<B>>(
+        &self,
+        record: R,
+        args: Self::RecordArgs,
+    ) -> Result<Self::RecordOutput, RecorderError> {
+        // The item is saved without the burn metadata, since Safetensors only stores tensors.
+        Recorder::<B>::save_item(self, record.into_item::<PS>(), args)
+    }
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        _args: Self::RecordArgs,
+    ) -> Result<Self::RecordOutput, RecorderError> {
+        writer::to_bytes(item).map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
+        let item = reader::from_bytes::<PS, I, B>(
+            &args,
+            self.key_remap.clone(),
+            self.adapter_type,
+            false,
+        )?;
+        Ok(item)
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item = Recorder::<B>::load_item::<R::Item<Self::Settings>>(self, args)?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+/// The adapter used to convert the modules stored in a Safetensors file to Burn modules.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AdapterType {
+    /// The file was exported from PyTorch (e.g. most checkpoints from model hubs).
+    ///
+    /// Linear weights are transposed and normalization weights/biases are renamed to
+    /// gamma/beta.
+    PyTorch,
+
+    /// The file uses the Burn module layout (e.g. files saved by the Safetensors recorders).
+    #[default]
+    NoAdapter,
+}
+
+/// Arguments for loading a Safetensors file.
+///
+/// # Fields
+///
+/// * `file` - The path to the file to load.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+///                See [regex::Regex::replace](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace)
+///                for more information.
+/// * `adapter_type` - The adapter used to convert the stored modules to Burn modules. Defaults to
+///                    [AdapterType::NoAdapter](AdapterType::NoAdapter).
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::safetensors::{AdapterType, LoadArgs, SafetensorsFileRecorder};
+/// use burn::record::FullPrecisionSettings;
+/// use burn::record::Recorder;
+///
+/// let args = LoadArgs::new("tests/key_remap/key_remap.safetensors".into())
+///    .with_key_remap("conv\\.(.*)", "$1") // Remove "conv" prefix, e.g. "conv.conv1" -> "conv1"
+///    .with_adapter_type(AdapterType::PyTorch);
+///
+/// let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+///   .load(args, &device)
+///   .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LoadArgs {
+    /// The path to the file to load.
+    pub file: PathBuf,
+
+    /// A list of key remappings.
+    pub key_remap: Vec<(Regex, String)>,
+
+    /// The adapter used to convert the stored modules to Burn modules.
+    pub adapter_type: AdapterType,
+
+    /// Whether to print debug information.
+    pub debug: bool,
+}
+
+impl LoadArgs {
+    /// Creates a new `LoadArgs` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path to the file to load.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            key_remap: Vec::new(),
+            adapter_type: AdapterType::default(),
+            debug: false,
+        }
+    }
+
+    /// Sets key remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern to be replaced.
+    /// * `replacement` - The pattern to replace with.
+    ///
+    /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+    /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace) for the
+    /// replacement syntax.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets the adapter used to convert the stored modules to Burn modules.
+    ///
+    /// # Arguments
+    ///
+    /// * `adapter_type` - The adapter type.
+    pub fn with_adapter_type(mut self, adapter_type: AdapterType) -> Self {
+        self.adapter_type = adapter_type;
+        self
+    }
+
+    /// Sets printing debug information on.
+    pub fn with_debug_print(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+}
+
+impl From<PathBuf> for LoadArgs {
+    fn from(val: PathBuf) -> Self {
+        LoadArgs::new(val)
+    }
+}
+
+impl From<String> for LoadArgs {
+    fn from(val: String) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+impl From<&str> for LoadArgs {
+    fn from(val: &str) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::{
+        module::Module,
+        nn::{
+            conv::Conv2dConfig, BatchNormConfig, LayerNorm, LayerNormConfig, Linear, LinearConfig,
+        },
+        record::FullPrecisionSettings,
+    };
+
+    type TestBackend = burn::backend::NdArray<f32>;
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        conv: burn::nn::conv::Conv2d<B>,
+        norm: burn::nn::BatchNorm<B, 2>,
+        blocks: Vec<burn::nn::Linear<B>>,
+    }
+
+    impl<B: Backend> Net<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                conv: Conv2dConfig::new([2, 4], [3, 3]).init(device),
+                norm: BatchNormConfig::new(4).init(device),
+                blocks: vec![
+                    LinearConfig::new(4, 3).init(device),
+                    LinearConfig::new(3, 2).init(device),
+                ],
+            }
+        }
+    }
+
+    #[derive(Module, Debug)]
+    struct Head<B: Backend> {
+        fc: Linear<B>,
+        norm: LayerNorm<B>,
+    }
+
+    impl<B: Backend> Head<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                fc: LinearConfig::new(4, 3).init(device),
+                norm: LayerNormConfig::new(3).init(device),
+            }
+        }
+    }
+
+    #[test]
+    fn should_save_and_load_file_with_default_args() {
+        let device = Default::default();
+        let mut head = Head::<TestBackend>::new(&device);
+        // Non-default values, so that a parameter loaded under the wrong name is noticed.
+        head.norm.gamma = head.norm.gamma.map(|gamma| gamma.mul_scalar(2.0));
+        head.norm.beta = head.norm.beta.map(|beta| beta.add_scalar(0.5));
+        let file_path = std::env::temp_dir().join("burn_safetensors_round_trip");
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+
+        Recorder::<TestBackend>::record(&recorder, head.clone().into_record(), file_path.clone())
+            .expect("Should encode state successfully");
+        let record = Recorder::<TestBackend>::load(
+            &recorder,
+            LoadArgs::new(file_path.with_extension("safetensors")),
+            &device,
+        )
+        .expect("Should decode state successfully");
+        let loaded = Head::<TestBackend>::new(&device).load_record(record);
+
+        loaded
+            .fc
+            .weight
+            .to_data()
+            .assert_eq(&head.fc.weight.to_data(), true);
+        loaded
+            .norm
+            .gamma
+            .to_data()
+            .assert_eq(&head.norm.gamma.to_data(), true);
+        loaded
+            .norm
+            .beta
+            .to_data()
+            .assert_eq(&head.norm.beta.to_data(), true);
+    }
+
+    #[test]
+    fn should_save_and_load_bytes() {
+        let device = Default::default();
+        let net = Net::<TestBackend>::new(&device);
+        let recorder = SafetensorsBytesRecorder::<FullPrecisionSettings>::default()
+            .with_adapter_type(AdapterType::NoAdapter);
+
+        let bytes = Recorder::<TestBackend>::record(&recorder, net.clone().into_record(), ())
+            .expect("Should encode state successfully");
+        let record = Recorder::<TestBackend>::load(&recorder, bytes, &device)
+            .expect("Should decode state successfully");
+        let loaded = Net::<TestBackend>::new(&device).load_record(record);
+
+        loaded
+            .conv
+            .weight
+            .to_data()
+            .assert_eq(&net.conv.weight.to_data(), true);
+        loaded
+            .norm
+            .running_var
+            .value()
+            .to_data()
+            .assert_eq(&net.norm.running_var.value().to_data(), true);
+        loaded.blocks[1]
+            .weight
+            .to_data()
+            .assert_eq(&net.blocks[1].weight.to_data(), true);
+    }
+
+    #[test]
+    fn should_remap_keys_when_loading_bytes() {
+        let device = Default::default();
+        let net = Net::<TestBackend>::new(&device);
+        let bytes = writer::to_bytes(net.clone().into_record()).expect("Should encode state");
+
+        // Load the first block only, e.g. "blocks.0.weight" -> "weight"
+        let recorder = SafetensorsBytesRecorder::<FullPrecisionSettings>::default()
+            .with_key_remap("blocks\\.0\\.(.*)", "$1")
+            .with_adapter_type(AdapterType::NoAdapter);
+        let record = Recorder::<TestBackend>::load(&recorder, bytes, &device)
+            .expect("Should decode state successfully");
+        let loaded = LinearConfig::new(4, 3)
+            .init::<TestBackend>(&device)
+            .load_record(record);
+
+        loaded
+            .weight
+            .to_data()
+            .assert_eq(&net.blocks[0].weight.to_data(), true);
+    }
+}
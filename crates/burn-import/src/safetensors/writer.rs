@@ -0,0 +1,143 @@
+use std::borrow::Cow;
+use std::path::Path;
+
+use super::error::Error;
+
+use burn::{
+    record::serde::{
+        adapter::DefaultAdapter, data::NestedValue, de::Deserializer, ser::Serializer,
+    },
+    tensor::{DType, TensorData},
+};
+
+use ::safetensors::{Dtype, View};
+use serde::{Deserialize, Serialize};
+
+/// Serializes an item into the content of a Safetensors file.
+///
+/// Every tensor of the item is stored under its dotted path in the record (e.g. `conv1.weight`),
+/// while the other values (such as configuration constants) are skipped.
+pub fn to_bytes<I: Serialize>(item: I) -> Result<Vec<u8>, Error> {
+    let tensors = flatten(item)?;
+
+    Ok(::safetensors::serialize(tensors, &None)?)
+}
+
+/// Serializes an item into a Safetensors file.
+///
+/// See [to_bytes](to_bytes) for the layout of the stored tensors.
+pub fn to_file<I: Serialize>(item: I, path: &Path) -> Result<(), Error> {
+    let tensors = flatten(item)?;
+
+    Ok(::safetensors::serialize_to_file(tensors, &None, path)?)
+}
+
+/// Collects the tensors of an item along with their dotted path.
+fn flatten<I: Serialize>(item: I) -> Result<Vec<(String, SafetensorsView)>, Error> {
+    let nested_value = item.serialize(Serializer::new())?;
+
+    let mut tensors = Vec::new();
+    collect_tensors(nested_value, String::new(), &mut tensors)?;
+
+    Ok(tensors)
+}
+
+/// Walks the nested value recursively and collects every tensor it contains.
+fn collect_tensors(
+    value: NestedValue,
+    path: String,
+    tensors: &mut Vec<(String, SafetensorsView)>,
+) -> Result<(), Error> {
+    match value {
+        NestedValue::Map(mut map) => {
+            if map.contains_key("bytes") && map.contains_key("shape") && map.contains_key("dtype") {
+                let data = TensorData::deserialize(Deserializer::<DefaultAdapter>::new(
+                    NestedValue::Map(map),
+                    false,
+                ))?;
+                tensors.push((path, SafetensorsView::new(data)?));
+            } else if map.len() == 2 && map.contains_key("id") && map.contains_key("param") {
+                // Parameters are stored without their wrapper, like any other tensor.
+                collect_tensors(map.remove("param").unwrap(), path, tensors)?;
+            } else {
+                for (key, value) in map {
+                    collect_tensors(value, join(&path, &key), tensors)?;
+                }
+            }
+        }
+        NestedValue::Vec(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                collect_tensors(value, join(&path, &index.to_string()), tensors)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Appends a key to a dotted path.
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// A tensor ready to be written in a Safetensors file.
+struct SafetensorsView {
+    dtype: Dtype,
+    shape: Vec<usize>,
+    bytes: Vec<u8>,
+}
+
+impl SafetensorsView {
+    fn new(data: TensorData) -> Result<Self, Error> {
+        let dtype = match data.dtype {
+            DType::F64 => Dtype::F64,
+            DType::F32 => Dtype::F32,
+            DType::F16 => Dtype::F16,
+            DType::BF16 => Dtype::BF16,
+            DType::I64 => Dtype::I64,
+            DType::I32 => Dtype::I32,
+            DType::I16 => Dtype::I16,
+            DType::I8 => Dtype::I8,
+            DType::U64 => Dtype::U64,
+            DType::U32 => Dtype::U32,
+            DType::U8 => Dtype::U8,
+            DType::Bool => Dtype::BOOL,
+            DType::QFloat(_) => {
+                return Err(Error::Other(
+                    "Quantized tensors can't be stored in a Safetensors file".into(),
+                ))
+            }
+        };
+
+        // Safetensors stores the values in little-endian order, which is the in-memory
+        // representation of `TensorData` on every supported target.
+        Ok(Self {
+            dtype,
+            shape: data.shape,
+            bytes: data.bytes,
+        })
+    }
+}
+
+impl View for SafetensorsView {
+    fn dtype(&self) -> Dtype {
+        self.dtype
+    }
+
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    fn data(&self) -> Cow<[u8]> {
+        Cow::Borrowed(&self.bytes)
+    }
+
+    fn data_len(&self) -> usize {
+        self.bytes.len()
+    }
+}
@@ -15,8 +15,8 @@ pub(crate) trait ComparisonOp<C: Numeric>: 'static + Send + Sync {
 struct EqualOp;
 struct GreaterEqualOp;
 struct LowerEqualOp;
-struct GreaterOp;
-struct LowerOp;
+pub(crate) struct GreaterOp;
+pub(crate) struct LowerOp;
 
 #[cube]
 impl<N: Numeric> ComparisonOp<N> for EqualOp {
@@ -12,7 +12,7 @@ use crate::{
         reshape,
     },
     tensor::JitTensor,
-    FloatElement, JitRuntime,
+    JitElement, JitRuntime,
 };
 
 #[derive(CubeLaunch)]
@@ -27,11 +27,11 @@ struct Conv2dArgs {
 }
 
 #[cube(launch)]
-fn conv2d_kernel<F: Float>(
-    input: &Tensor<F>,
-    weight: &Tensor<F>,
-    bias: &Tensor<F>,
-    output: &mut Tensor<F>,
+fn conv2d_kernel<N: Numeric>(
+    input: &Tensor<N>,
+    weight: &Tensor<N>,
+    bias: &Tensor<N>,
+    output: &mut Tensor<N>,
     args: &Conv2dArgs,
     kernel_size_0_unroll: Comptime<Option<UInt>>,
     kernel_size_1_unroll: Comptime<Option<UInt>>,
@@ -115,7 +115,7 @@ fn conv2d_kernel<F: Float>(
     output[ABSOLUTE_POS] = sum;
 }
 
-pub(crate) fn conv2d<R: JitRuntime, E: FloatElement>(
+pub(crate) fn conv2d<R: JitRuntime, E: JitElement>(
     input: JitTensor<R, E, 4>,
     weight: JitTensor<R, E, 4>,
     bias: Option<JitTensor<R, E, 1>>,
@@ -163,7 +163,7 @@ pub(crate) fn conv2d<R: JitRuntime, E: FloatElement>(
     let num_elems_output = output.shape.num_elements();
     let cube_dim = calculate_cube_count_elemwise(num_elems_output, SUBCUBE_DIM_APPROX);
 
-    conv2d_kernel::launch::<E::FloatPrimitive, R>(
+    conv2d_kernel::launch::<E::Primitive, R>(
         &input.client,
         cube_dim,
         CubeDim::default(),
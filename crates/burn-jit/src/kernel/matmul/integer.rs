@@ -0,0 +1,89 @@
+use crate::{
+    kernel::{into_contiguous, SUBCUBE_DIM_APPROX},
+    ops::swap_dims,
+    tensor::JitTensor,
+    IntElement, JitRuntime,
+};
+use cubecl::{frontend::TensorArg, prelude::*};
+
+use super::{init_matmul_output, simple_cube_count};
+
+#[cube(launch)]
+fn int_matmul_kernel<I: Int>(
+    lhs: &Tensor<I>,
+    rhs: &Tensor<I>,
+    out: &mut Tensor<I>,
+    num_batches: Comptime<Option<UInt>>,
+) {
+    let rank = out.rank();
+    let end = Comptime::unwrap_or_else(num_batches, || rank - UInt::new(2));
+    let unroll = Comptime::is_some(num_batches);
+
+    let n_rows = lhs.shape(rank - UInt::new(2));
+    let n_cols = rhs.shape(rank - UInt::new(1));
+    let k = rhs.shape(rank - UInt::new(2));
+
+    let batch_pos = ABSOLUTE_POS_Z;
+    let row = CUBE_DIM_X * CUBE_POS_X + UNIT_POS_X;
+    let col = CUBE_DIM_Y * CUBE_POS_Y + UNIT_POS_Y;
+
+    if row >= n_rows || col >= n_cols {
+        return;
+    }
+
+    let mut offset_lhs = UInt::new(0);
+    let mut offset_rhs = UInt::new(0);
+    let offset_out = n_rows * n_cols * batch_pos;
+
+    for i in range(0u32, end, unroll) {
+        let ogwl = offset_out / out.stride(i);
+
+        offset_lhs += ogwl % lhs.shape(i) * lhs.stride(i);
+        offset_rhs += ogwl % rhs.shape(i) * rhs.stride(i);
+    }
+
+    let mut sum = I::new(0);
+
+    for i in range(0u32, k, Comptime::new(false)) {
+        let lhs_index = row * k + i + offset_lhs;
+        let rhs_index = col * k + i + offset_rhs;
+
+        sum += lhs[lhs_index] * rhs[rhs_index];
+    }
+
+    out[row * n_cols + col + offset_out] = sum;
+}
+
+/// Integer matrix multiplication, where the products are accumulated with the integer element
+/// type (e.g., `i32` accumulators for quantized values).
+pub fn int_matmul<R: JitRuntime, I: IntElement, const D: usize>(
+    lhs: JitTensor<R, I, D>,
+    rhs: JitTensor<R, I, D>,
+) -> JitTensor<R, I, D> {
+    lhs.assert_is_on_same_device(&rhs);
+    let out = init_matmul_output(&lhs, &rhs);
+    let lhs = into_contiguous(lhs);
+
+    let rhs_original_shape = rhs.shape.clone();
+    let rhs = into_contiguous(swap_dims(rhs, D - 1, D - 2));
+
+    let cube_count = simple_cube_count::<R, D>(
+        &lhs.shape,
+        &rhs_original_shape,
+        &out.shape,
+        SUBCUBE_DIM_APPROX,
+        SUBCUBE_DIM_APPROX,
+    );
+
+    int_matmul_kernel::launch::<I::IntPrimitive, R>(
+        &lhs.client,
+        cube_count,
+        CubeDim::new(SUBCUBE_DIM_APPROX as u32, SUBCUBE_DIM_APPROX as u32, 1),
+        TensorArg::new(&lhs.handle, &lhs.strides, &lhs.shape.dims),
+        TensorArg::new(&rhs.handle, &rhs.strides, &rhs_original_shape.dims),
+        TensorArg::new(&out.handle, &out.strides, &out.shape.dims),
+        Some(UInt::new(D as u32 - 2)),
+    );
+
+    out
+}
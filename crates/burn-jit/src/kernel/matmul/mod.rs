@@ -1,4 +1,5 @@
 mod base;
+mod integer;
 mod simple;
 mod tune;
 
@@ -6,6 +7,7 @@ mod tune;
 pub mod utils;
 
 pub use base::*;
+pub use integer::*;
 pub use simple::*;
 pub use tune::*;
 pub use utils::*;
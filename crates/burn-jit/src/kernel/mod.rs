@@ -27,6 +27,8 @@ pub mod pool;
 pub mod prng;
 /// Reduction algorithms
 pub mod reduce;
+/// Scan (cumulative) algorithms
+pub mod scan;
 
 pub(crate) use clamp::*;
 pub(crate) use comparison::*;
@@ -0,0 +1,232 @@
+use crate::{
+    element::JitElement,
+    kernel::{into_contiguous, ComparisonOp, GreaterOp, LowerOp},
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    JitRuntime,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*, SUBCUBE_DIM_APPROX};
+
+#[cube]
+pub(crate) trait ScanOp<N: Numeric>: 'static + Send + Sync {
+    /// Combine the accumulated value of the previous elements with the current value.
+    fn execute(previous: N, current: N) -> N;
+}
+
+pub(crate) struct SumOp;
+pub(crate) struct ProdOp;
+
+#[cube]
+impl<N: Numeric> ScanOp<N> for SumOp {
+    fn execute(previous: N, current: N) -> N {
+        previous + current
+    }
+}
+
+#[cube]
+impl<N: Numeric> ScanOp<N> for ProdOp {
+    fn execute(previous: N, current: N) -> N {
+        previous * current
+    }
+}
+
+/// One step of the Hillis-Steele scan, where each value is combined with the value `offset`
+/// positions before along `dim`.
+#[cube(launch)]
+fn scan_step_kernel<N: Numeric, O: ScanOp<N>>(
+    input: &Tensor<N>,
+    output: &mut Tensor<N>,
+    dim: UInt,
+    offset: UInt,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let stride = output.stride(dim);
+    let position = ABSOLUTE_POS / stride % output.shape(dim);
+    let mut value = input[ABSOLUTE_POS];
+
+    if position >= offset {
+        value = O::execute(input[ABSOLUTE_POS - offset * stride], value);
+    }
+
+    output[ABSOLUTE_POS] = value;
+}
+
+#[cube(launch)]
+fn scan_indices_init_kernel<I: Numeric>(output: &mut Tensor<I>, dim: UInt) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    output[ABSOLUTE_POS] = I::cast_from(ABSOLUTE_POS / output.stride(dim) % output.shape(dim));
+}
+
+/// One step of the Hillis-Steele scan with indices, where the previous value is selected when the
+/// comparison with the current value is true.
+#[cube(launch)]
+fn scan_indices_step_kernel<N: Numeric, I: Numeric, O: ComparisonOp<N>>(
+    input: &Tensor<N>,
+    indices: &Tensor<I>,
+    output: &mut Tensor<N>,
+    output_indices: &mut Tensor<I>,
+    dim: UInt,
+    offset: UInt,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let stride = output.stride(dim);
+    let position = ABSOLUTE_POS / stride % output.shape(dim);
+    let mut value = input[ABSOLUTE_POS];
+    let mut index = indices[ABSOLUTE_POS];
+
+    if position >= offset {
+        let previous = ABSOLUTE_POS - offset * stride;
+
+        if O::execute(input[previous], value) {
+            value = input[previous];
+            index = indices[previous];
+        }
+    }
+
+    output[ABSOLUTE_POS] = value;
+    output_indices[ABSOLUTE_POS] = index;
+}
+
+/// Inclusive scan along `dim`, computed in `log2(n)` parallel steps.
+fn scan<R: JitRuntime, E: JitElement, O: ScanOp<E::Primitive>, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    let size = tensor.shape.dims[dim];
+    let num_elems = tensor.shape.num_elements();
+    let mut output = into_contiguous(tensor);
+    let mut offset = 1;
+
+    while offset < size {
+        let input = output;
+        output = empty_device(
+            input.client.clone(),
+            input.device.clone(),
+            input.shape.clone(),
+        );
+
+        scan_step_kernel::launch::<E::Primitive, O, R>(
+            &input.client,
+            calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX),
+            CubeDim::default(),
+            TensorArg::new(&input.handle, &input.strides, &input.shape.dims),
+            TensorArg::new(&output.handle, &output.strides, &output.shape.dims),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(offset as u32),
+        );
+
+        offset *= 2;
+    }
+
+    output
+}
+
+/// Inclusive scan along `dim` that selects one of the values and keeps track of its index.
+fn scan_with_indices<
+    R: JitRuntime,
+    E: JitElement,
+    I: JitElement,
+    O: ComparisonOp<E::Primitive>,
+    const D: usize,
+>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> (JitTensor<R, E, D>, JitTensor<R, I, D>) {
+    let size = tensor.shape.dims[dim];
+    let num_elems = tensor.shape.num_elements();
+    let mut output = into_contiguous(tensor);
+    let mut indices = empty_device(
+        output.client.clone(),
+        output.device.clone(),
+        output.shape.clone(),
+    );
+
+    scan_indices_init_kernel::launch::<I::Primitive, R>(
+        &indices.client,
+        calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX),
+        CubeDim::default(),
+        TensorArg::new(&indices.handle, &indices.strides, &indices.shape.dims),
+        ScalarArg::new(dim as u32),
+    );
+
+    let mut offset = 1;
+
+    while offset < size {
+        let (input, input_indices) = (output, indices);
+        output = empty_device(
+            input.client.clone(),
+            input.device.clone(),
+            input.shape.clone(),
+        );
+        indices = empty_device(
+            input.client.clone(),
+            input.device.clone(),
+            input.shape.clone(),
+        );
+
+        scan_indices_step_kernel::launch::<E::Primitive, I::Primitive, O, R>(
+            &input.client,
+            calculate_cube_count_elemwise(num_elems, SUBCUBE_DIM_APPROX),
+            CubeDim::default(),
+            TensorArg::new(&input.handle, &input.strides, &input.shape.dims),
+            TensorArg::new(
+                &input_indices.handle,
+                &input_indices.strides,
+                &input_indices.shape.dims,
+            ),
+            TensorArg::new(&output.handle, &output.strides, &output.shape.dims),
+            TensorArg::new(&indices.handle, &indices.strides, &indices.shape.dims),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(offset as u32),
+        );
+
+        offset *= 2;
+    }
+
+    (output, indices)
+}
+
+/// Cumulative sum of the elements along `dim`.
+pub fn cumsum<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    scan::<R, E, SumOp, D>(tensor, dim)
+}
+
+/// Cumulative product of the elements along `dim`.
+pub fn cumprod<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    scan::<R, E, ProdOp, D>(tensor, dim)
+}
+
+/// Cumulative maximum of the elements along `dim` with the index of the maximum.
+///
+/// The index of the last occurrence is returned for equal values.
+pub fn cummax<R: JitRuntime, E: JitElement, I: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> (JitTensor<R, E, D>, JitTensor<R, I, D>) {
+    scan_with_indices::<R, E, I, GreaterOp, D>(tensor, dim)
+}
+
+/// Cumulative minimum of the elements along `dim` with the index of the minimum.
+///
+/// The index of the last occurrence is returned for equal values.
+pub fn cummin<R: JitRuntime, E: JitElement, I: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> (JitTensor<R, E, D>, JitTensor<R, I, D>) {
+    scan_with_indices::<R, E, I, LowerOp, D>(tensor, dim)
+}
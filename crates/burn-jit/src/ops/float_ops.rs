@@ -431,6 +431,34 @@ where
         })
     }
 
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::scan::cumsum(tensor, dim)
+    }
+
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::scan::cumprod(tensor, dim)
+    }
+
+    fn float_cummax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::scan::cummax(tensor, dim)
+    }
+
+    fn float_cummin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::scan::cummin(tensor, dim)
+    }
+
     fn float_argmax<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -277,6 +277,28 @@ where
         kernel::reduce::mean_dim(tensor, dim, Default::default())
     }
 
+    fn int_cumsum<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        kernel::scan::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        kernel::scan::cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::scan::cummax(tensor, dim)
+    }
+
+    fn int_cummin<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::scan::cummin(tensor, dim)
+    }
+
     fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
         kernel::reduce::argmax(tensor, dim, Default::default())
     }
@@ -1,10 +1,166 @@
 use burn_tensor::{
-    ops::{FloatTensor, QTensorOps, QuantizedTensor},
-    quantization::{QuantizationParametersPrimitive, QuantizationScheme},
-    Device, Shape, TensorData,
+    ops::{
+        BoolTensorOps, ConvOptions, FloatTensor, FloatTensorOps, ModuleOps, QTensorOps,
+        QuantizedTensor,
+    },
+    quantization::{
+        GroupQuantization, QuantizationGroups, QuantizationParametersPrimitive, QuantizationScheme,
+        QuantizationStrategy, QuantizationType, NF4_VALUES,
+    },
+    DType, Device, ElementConversion, Shape, TensorData,
 };
 
-use crate::{tensor::QJitTensor, FloatElement, IntElement, JitBackend, JitRuntime};
+use crate::{
+    kernel,
+    tensor::{JitQuantizationParameters, JitTensor, QJitTensor},
+    FloatElement, IntElement, JitBackend, JitElement, JitRuntime,
+};
+
+/// Reshape the quantization parameters so that they can be broadcast to the quantized values.
+fn broadcast_params<R: JitRuntime, E: JitElement, const D: usize>(
+    params: JitTensor<R, E, 1>,
+    groups: Option<QuantizationGroups>,
+    shape: &Shape<D>,
+) -> JitTensor<R, E, D> {
+    match groups {
+        None => super::reshape(params, Shape::new([1; D])),
+        Some(QuantizationGroups::Channel { axis }) => {
+            let mut dims = [1; D];
+            dims[axis] = shape.dims[axis];
+            super::reshape(params, Shape::new(dims))
+        }
+        Some(QuantizationGroups::Block { size }) => {
+            let num_blocks = shape.num_elements() / size;
+            let params = super::reshape(params, Shape::new([num_blocks, 1]));
+            let params = super::expand(params, Shape::new([num_blocks, size]));
+            super::reshape(params, shape.clone())
+        }
+    }
+}
+
+/// Returns the indices of the nearest values in the NormalFloat lookup table, for values
+/// normalized to `[-1, 1]`.
+fn nf4_indices<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize>(
+    values: JitTensor<R, F, D>,
+) -> JitTensor<R, i32, D> {
+    // The table is sorted, so the index is the number of midpoints below the value
+    let indices = NF4_VALUES
+        .windows(2)
+        .map(|w| (w[0] + w[1]) / 2.)
+        .map(|midpoint| {
+            let above = JitBackend::<R, F, I>::float_greater_elem(values.clone(), midpoint.elem());
+            JitBackend::<R, F, I>::bool_into_float(above)
+        })
+        .reduce(JitBackend::<R, F, I>::float_add)
+        .unwrap();
+
+    kernel::cast::<R, F, i32, D>(indices)
+}
+
+/// Returns the normalized values of the NormalFloat lookup table at the given indices.
+fn nf4_values<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize>(
+    indices: JitTensor<R, i32, D>,
+) -> JitTensor<R, F, D> {
+    let shape = indices.shape.clone();
+    let table = super::from_data::<R, F, 1>(
+        TensorData::new(NF4_VALUES.to_vec(), [NF4_VALUES.len()]),
+        &indices.device,
+    );
+    let indices = super::reshape(indices, Shape::new([shape.num_elements()]));
+    let values =
+        JitBackend::<R, F, I>::float_gather(0, table, kernel::cast::<R, i32, I, 1>(indices));
+
+    super::reshape(values, shape)
+}
+
+/// Returns true if the quantization parameters of the tensor can be factored out of the products
+/// of an integer-domain operation.
+///
+/// Per-channel parameters are only supported along `channel_axis`, and NF4 values are not
+/// integers.
+fn supports_integer_domain<R: JitRuntime, const D: usize>(
+    tensor: &QJitTensor<R, D>,
+    channel_axis: Option<usize>,
+) -> bool {
+    if let QuantizationType::NF4 = tensor.scheme.dtype() {
+        return false;
+    }
+
+    match tensor.scheme.groups() {
+        None => true,
+        Some(QuantizationGroups::Channel { axis }) => Some(axis) == channel_axis,
+        Some(QuantizationGroups::Block { .. }) => false,
+    }
+}
+
+/// Quantize the values already divided by the scale: add the zero-point offset, then round and
+/// clamp the values to the quantized range.
+fn quantize_normalized<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize>(
+    values: JitTensor<R, F, D>,
+    scheme: &QuantizationScheme,
+    qparams: QuantizationParametersPrimitive<JitBackend<R, F, I>>,
+) -> QJitTensor<R, D> {
+    let groups = scheme.groups();
+    let shape = values.shape.clone();
+
+    if let QuantizationType::NF4 = scheme.dtype() {
+        return QJitTensor {
+            qtensor: nf4_indices::<R, F, I, D>(values),
+            scheme: scheme.clone(),
+            qparams: JitQuantizationParameters {
+                scale: kernel::cast::<R, F, f32, 1>(qparams.scale),
+                offset: None,
+            },
+        };
+    }
+
+    // Quantized range `[a, b]`
+    let (a, b) = match scheme.is_affine() {
+        true => scheme.dtype().affine_range(),
+        false => scheme.dtype().symmetric_range(),
+    };
+
+    let mut values = values;
+    if let Some(offset) = qparams.offset.clone() {
+        let offset = kernel::cast::<R, I, F, 1>(offset);
+        values = JitBackend::<R, F, I>::float_add(values, broadcast_params(offset, groups, &shape));
+    }
+
+    // Round half away from zero, since casting to an integer truncates the values
+    let half = JitBackend::<R, F, I>::float_mul_scalar(
+        JitBackend::<R, F, I>::float_sign(values.clone()),
+        0.5f32.elem(),
+    );
+    let values = JitBackend::<R, F, I>::float_clamp(
+        JitBackend::<R, F, I>::float_add(values, half),
+        a.elem(),
+        b.elem(),
+    );
+
+    QJitTensor {
+        qtensor: kernel::cast::<R, F, i32, D>(values),
+        scheme: scheme.clone(),
+        qparams: JitQuantizationParameters {
+            scale: kernel::cast::<R, F, f32, 1>(qparams.scale),
+            offset: qparams.offset.map(kernel::cast::<R, I, i32, 1>),
+        },
+    }
+}
+
+/// Subtract the zero-point offset from the quantized values.
+fn centered_values<R: JitRuntime, const D: usize>(
+    tensor: QJitTensor<R, D>,
+) -> JitTensor<R, i32, D> {
+    let groups = tensor.scheme.groups();
+    let shape = tensor.qtensor.shape.clone();
+
+    match tensor.qparams.offset {
+        Some(offset) => {
+            super::numeric::sub(tensor.qtensor, broadcast_params(offset, groups, &shape))
+        }
+        None => tensor.qtensor,
+    }
+}
 
 impl<R, F, I> QTensorOps<Self> for JitBackend<R, F, I>
 where
@@ -13,22 +169,83 @@ where
     I: IntElement,
 {
     fn q_from_data<const D: usize>(
-        _data: TensorData,
-        _device: &Device<Self>,
+        data: TensorData,
+        device: &Device<Self>,
     ) -> QuantizedTensor<Self, D> {
-        todo!()
+        match data.dtype {
+            DType::QFloat(strategy) => {
+                let (scales, offsets) = match strategy {
+                    QuantizationStrategy::PerTensorAffineInt8(q) => {
+                        (vec![q.scale], Some(vec![q.offset as i32]))
+                    }
+                    QuantizationStrategy::PerTensorAffineUInt8(q) => {
+                        (vec![q.scale], Some(vec![q.offset as i32]))
+                    }
+                    QuantizationStrategy::PerTensorAffineInt4(q) => {
+                        (vec![q.scale], Some(vec![q.offset as i32]))
+                    }
+                    QuantizationStrategy::PerTensorSymmetricInt8(q) => (vec![q.scale], None),
+                    QuantizationStrategy::PerTensorSymmetricInt4(q) => (vec![q.scale], None),
+                    QuantizationStrategy::PerTensorNF4(q) => (vec![q.scale], None),
+                    QuantizationStrategy::GroupedAffineInt8(_)
+                    | QuantizationStrategy::GroupedSymmetricInt8(_)
+                    | QuantizationStrategy::GroupedAffineUInt8(_)
+                    | QuantizationStrategy::GroupedAffineInt4(_)
+                    | QuantizationStrategy::GroupedSymmetricInt4(_)
+                    | QuantizationStrategy::GroupedNF4(_) => {
+                        let params = data
+                            .group_quantization()
+                            .expect("Per-group quantized data should have parameters");
+                        (params.scales, params.offsets)
+                    }
+                };
+                let values = data.iter::<i32>().collect::<Vec<_>>();
+                let num_params = scales.len();
+
+                QJitTensor {
+                    qtensor: super::from_data(TensorData::new(values, data.shape), device),
+                    scheme: strategy.scheme(),
+                    qparams: JitQuantizationParameters {
+                        scale: super::from_data(TensorData::new(scales, [num_params]), device),
+                        offset: offsets.map(|offsets: Vec<i32>| {
+                            super::from_data(TensorData::new(offsets, [num_params]), device)
+                        }),
+                    },
+                }
+            }
+            _ => panic!(
+                "Invalid dtype (expected DType::QFloat, got {:?})",
+                data.dtype
+            ),
+        }
     }
 
     fn quantize<const D: usize>(
-        _tensor: FloatTensor<Self, D>,
-        _scheme: &QuantizationScheme,
-        _qparams: QuantizationParametersPrimitive<Self>,
+        tensor: FloatTensor<Self, D>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
     ) -> QuantizedTensor<Self, D> {
-        unimplemented!()
+        let shape = tensor.shape.clone();
+        let scale = broadcast_params(qparams.scale.clone(), scheme.groups(), &shape);
+
+        quantize_normalized::<R, F, I, D>(Self::float_div(tensor, scale), scheme, qparams)
     }
 
-    fn dequantize<const D: usize>(_tensor: QuantizedTensor<Self, D>) -> FloatTensor<Self, D> {
-        unimplemented!()
+    fn dequantize<const D: usize>(tensor: QuantizedTensor<Self, D>) -> FloatTensor<Self, D> {
+        let groups = tensor.scheme.groups();
+        let shape = tensor.qtensor.shape.clone();
+
+        let mut values = match tensor.scheme.dtype() {
+            QuantizationType::NF4 => nf4_values::<R, F, I, D>(tensor.qtensor),
+            _ => kernel::cast::<R, i32, F, D>(tensor.qtensor),
+        };
+        if let Some(offset) = tensor.qparams.offset {
+            let offset = kernel::cast::<R, i32, F, 1>(offset);
+            values = Self::float_sub(values, broadcast_params(offset, groups, &shape));
+        }
+
+        let scale = kernel::cast::<R, f32, F, 1>(tensor.qparams.scale);
+        Self::float_mul(values, broadcast_params(scale, groups, &shape))
     }
 
     fn q_shape<const D: usize>(tensor: &QuantizedTensor<Self, D>) -> Shape<D> {
@@ -43,13 +260,226 @@ where
         tensor: QuantizedTensor<Self, D1>,
         shape: Shape<D2>,
     ) -> QuantizedTensor<Self, D2> {
+        let scheme = tensor
+            .scheme
+            .reshape(&tensor.qtensor.shape.dims, &shape.dims)
+            .expect("Reshape should preserve the quantization channel axis");
+
         QJitTensor {
             qtensor: super::reshape(tensor.qtensor, shape),
-            scheme: tensor.scheme,
+            scheme,
+            qparams: tensor.qparams,
+        }
+    }
+
+    fn q_matmul<const D: usize>(
+        lhs: QuantizedTensor<Self, D>,
+        rhs: QuantizedTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        if !supports_integer_domain(&lhs, Some(D - 2))
+            || !supports_integer_domain(&rhs, Some(D - 1))
+        {
+            return Self::float_matmul(Self::dequantize(lhs), Self::dequantize(rhs));
+        }
+
+        let lhs_groups = lhs.scheme.groups();
+        let rhs_groups = rhs.scheme.groups();
+        let lhs_scale = kernel::cast::<R, f32, F, 1>(lhs.qparams.scale.clone());
+        let rhs_scale = kernel::cast::<R, f32, F, 1>(rhs.qparams.scale.clone());
+
+        // Accumulate the products of the quantized values in the integer domain
+        let output = kernel::matmul::int_matmul(centered_values(lhs), centered_values(rhs));
+        let shape = output.shape.clone();
+
+        // Rescale the accumulators with the scales of each row and column
+        let output = kernel::cast::<R, i32, F, D>(output);
+        let output = Self::float_mul(output, broadcast_params(lhs_scale, lhs_groups, &shape));
+        Self::float_mul(output, broadcast_params(rhs_scale, rhs_groups, &shape))
+    }
+
+    fn q_conv2d(
+        x: QuantizedTensor<Self, 4>,
+        weight: QuantizedTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        if !supports_integer_domain(&x, None) || !supports_integer_domain(&weight, Some(0)) {
+            return Self::conv2d(Self::dequantize(x), Self::dequantize(weight), bias, options);
+        }
+
+        // The scales of the weights are applied to the output channels
+        let channels = Some(QuantizationGroups::Channel { axis: 1 });
+        let weight_groups = weight.scheme.groups().and(channels);
+        let x_scale = kernel::cast::<R, f32, F, 1>(x.qparams.scale.clone());
+        let weight_scale = kernel::cast::<R, f32, F, 1>(weight.qparams.scale.clone());
+
+        // Accumulate the products of the quantized values in the integer domain
+        let output = kernel::conv::conv2d::<R, i32>(
+            centered_values(x),
+            centered_values(weight),
+            None,
+            options,
+        );
+        let shape = output.shape.clone();
+
+        // Rescale the accumulators and add the bias
+        let output = kernel::cast::<R, i32, F, 4>(output);
+        let output = Self::float_mul(output, broadcast_params(x_scale, None, &shape));
+        let output = Self::float_mul(
+            output,
+            broadcast_params(weight_scale, weight_groups, &shape),
+        );
+        match bias {
+            Some(bias) => Self::float_add(output, broadcast_params(bias, channels, &shape)),
+            None => output,
+        }
+    }
+
+    fn q_matmul_requantize<const D: usize>(
+        lhs: QuantizedTensor<Self, D>,
+        rhs: QuantizedTensor<Self, D>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self, D> {
+        if !supports_integer_domain(&lhs, Some(D - 2))
+            || !supports_integer_domain(&rhs, Some(D - 1))
+        {
+            return Self::quantize(Self::q_matmul(lhs, rhs), scheme, qparams);
+        }
+
+        let lhs_groups = lhs.scheme.groups();
+        let rhs_groups = rhs.scheme.groups();
+        let lhs_scale = kernel::cast::<R, f32, F, 1>(lhs.qparams.scale.clone());
+        let rhs_scale = kernel::cast::<R, f32, F, 1>(rhs.qparams.scale.clone());
+
+        // Accumulate the products of the quantized values in the integer domain
+        let output = kernel::matmul::int_matmul(centered_values(lhs), centered_values(rhs));
+        let shape = output.shape.clone();
+
+        // A per-tensor output scale is folded into the scales of the rows
+        let (lhs_scale, output_scale) = match scheme.groups() {
+            None => (Self::float_div(lhs_scale, qparams.scale.clone()), None),
+            groups => (
+                lhs_scale,
+                Some(broadcast_params(qparams.scale.clone(), groups, &shape)),
+            ),
+        };
+
+        // Rescale the accumulators directly to the output quantization
+        let output = kernel::cast::<R, i32, F, D>(output);
+        let output = Self::float_mul(output, broadcast_params(lhs_scale, lhs_groups, &shape));
+        let output = Self::float_mul(output, broadcast_params(rhs_scale, rhs_groups, &shape));
+        let output = match output_scale {
+            Some(scale) => Self::float_div(output, scale),
+            None => output,
+        };
+
+        quantize_normalized::<R, F, I, D>(output, scheme, qparams)
+    }
+
+    fn q_conv2d_requantize(
+        x: QuantizedTensor<Self, 4>,
+        weight: QuantizedTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self, 4> {
+        if !supports_integer_domain(&x, None) || !supports_integer_domain(&weight, Some(0)) {
+            return Self::quantize(Self::q_conv2d(x, weight, bias, options), scheme, qparams);
         }
+
+        let channels = Some(QuantizationGroups::Channel { axis: 1 });
+        let weight_groups = weight.scheme.groups().and(channels);
+        let x_scale = kernel::cast::<R, f32, F, 1>(x.qparams.scale.clone());
+        let weight_scale = kernel::cast::<R, f32, F, 1>(weight.qparams.scale.clone());
+
+        // Accumulate the products of the quantized values in the integer domain
+        let output = kernel::conv::conv2d::<R, i32>(
+            centered_values(x),
+            centered_values(weight),
+            None,
+            options,
+        );
+        let shape = output.shape.clone();
+
+        // A per-tensor output scale is folded into the input scale and the bias
+        let (x_scale, bias, output_scale) = match scheme.groups() {
+            None => (
+                Self::float_div(x_scale, qparams.scale.clone()),
+                bias.map(|bias| Self::float_div(bias, qparams.scale.clone())),
+                None,
+            ),
+            groups => (
+                x_scale,
+                bias,
+                Some(broadcast_params(qparams.scale.clone(), groups, &shape)),
+            ),
+        };
+
+        // Rescale the accumulators directly to the output quantization
+        let output = kernel::cast::<R, i32, F, 4>(output);
+        let output = Self::float_mul(output, broadcast_params(x_scale, None, &shape));
+        let output = Self::float_mul(
+            output,
+            broadcast_params(weight_scale, weight_groups, &shape),
+        );
+        let output = match bias {
+            Some(bias) => Self::float_add(output, broadcast_params(bias, channels, &shape)),
+            None => output,
+        };
+        let output = match output_scale {
+            Some(scale) => Self::float_div(output, scale),
+            None => output,
+        };
+
+        quantize_normalized::<R, F, I, 4>(output, scheme, qparams)
     }
 
-    async fn q_into_data<const D: usize>(_tensor: QuantizedTensor<Self, D>) -> TensorData {
-        unimplemented!()
+    async fn q_into_data<const D: usize>(tensor: QuantizedTensor<Self, D>) -> TensorData {
+        let data = super::into_data(tensor.qtensor).await;
+        let values = data.iter::<i32>().collect::<Vec<_>>();
+
+        let scales = super::into_data(tensor.qparams.scale)
+            .await
+            .iter::<f32>()
+            .collect::<Vec<_>>();
+        let offsets = match tensor.qparams.offset {
+            Some(offset) => Some(
+                super::into_data(offset)
+                    .await
+                    .iter::<i32>()
+                    .collect::<Vec<_>>(),
+            ),
+            None => None,
+        };
+
+        match tensor.scheme.grouped_strategy() {
+            Some(strategy) => TensorData::quantized_groups(
+                values.into_iter().map(|v| v as i8).collect(),
+                data.shape,
+                strategy,
+                &GroupQuantization { scales, offsets },
+            ),
+            None => {
+                let offset = offsets.as_ref().map(|offsets| offsets[0]);
+                let strategy = tensor
+                    .scheme
+                    .per_tensor_strategy(scales[0], offset)
+                    .unwrap();
+                match strategy.dtype() {
+                    QuantizationType::QUInt8 => TensorData::quantized(
+                        values.into_iter().map(|v| v as u8).collect(),
+                        data.shape,
+                        strategy,
+                    ),
+                    _ => TensorData::quantized(
+                        values.into_iter().map(|v| v as i8).collect(),
+                        data.shape,
+                        strategy,
+                    ),
+                }
+            }
+        }
     }
 }
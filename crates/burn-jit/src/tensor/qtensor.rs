@@ -1,6 +1,8 @@
 use burn_tensor::quantization::{QTensorPrimitive, QuantizationScheme, QuantizationStrategy};
 
-use crate::JitRuntime;
+use cubecl::CubeElement;
+
+use crate::{JitElement, JitRuntime};
 
 use super::JitTensor;
 
@@ -9,9 +11,42 @@ use super::JitTensor;
 pub struct QJitTensor<R: JitRuntime, const D: usize> {
     /// The quantized tensor.
     // TODO: implement `JitElement` / `CubeElement` for quantized type
-    pub qtensor: JitTensor<R, u32, D>,
+    pub qtensor: JitTensor<R, i32, D>,
     /// The quantization scheme.
     pub scheme: QuantizationScheme,
+    /// The quantization parameters.
+    pub qparams: JitQuantizationParameters<R>,
+}
+
+/// The quantization parameters of a quantized tensor.
+///
+/// Per-tensor schemes have a single scale (and offset), while per-channel and block-wise schemes
+/// have one value per group.
+#[derive(Debug)]
+pub struct JitQuantizationParameters<R: JitRuntime> {
+    /// The scaling factor.
+    pub scale: JitTensor<R, f32, 1>,
+    /// The zero-point offset.
+    pub offset: Option<JitTensor<R, i32, 1>>,
+}
+
+impl<R: JitRuntime> Clone for JitQuantizationParameters<R> {
+    fn clone(&self) -> Self {
+        Self {
+            scale: self.scale.clone(),
+            offset: self.offset.clone(),
+        }
+    }
+}
+
+/// Read the first value of a tensor synchronously.
+fn read_scalar<R: JitRuntime, E: JitElement>(tensor: &JitTensor<R, E, 1>) -> E {
+    let bytes = burn_common::reader::try_read_sync(
+        tensor.client.read_async(tensor.handle.clone().binding()),
+    )
+    .expect("Can only read the quantization parameters synchronously");
+
+    E::from_bytes(&bytes)[0]
 }
 
 impl<R: JitRuntime, const D: usize> QTensorPrimitive for QJitTensor<R, D> {
@@ -20,7 +55,13 @@ impl<R: JitRuntime, const D: usize> QTensorPrimitive for QJitTensor<R, D> {
     }
 
     fn strategy(&self) -> QuantizationStrategy {
-        todo!()
+        // The per-tensor parameters are only read for per-tensor schemes, since the parameters of
+        // each group are not part of the strategy.
+        self.scheme.grouped_strategy().unwrap_or_else(|| {
+            let scale = read_scalar(&self.qparams.scale);
+            let offset = self.qparams.offset.as_ref().map(read_scalar);
+            self.scheme.per_tensor_strategy(scale, offset).unwrap()
+        })
     }
 }
 
@@ -29,6 +70,7 @@ impl<R: JitRuntime, const D: usize> Clone for QJitTensor<R, D> {
         Self {
             qtensor: self.qtensor.clone(),
             scheme: self.scheme.clone(),
+            qparams: self.qparams.clone(),
         }
     }
 }
@@ -0,0 +1,84 @@
+#[burn_tensor_testgen::testgen(quantization)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        quantization::{MinMaxCalibration, QuantizationScheme, QuantizationType},
+        Tensor, TensorData,
+    };
+
+    fn quantize_should_match_reference(scheme: QuantizationScheme) {
+        let data = TensorData::from([[-1.8, -1.0, 0.0, 0.25], [0.5, 0.0, 1.0, -0.127]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data.clone(), &Default::default());
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(data.clone(), &Default::default());
+        let calibration = MinMaxCalibration {};
+
+        let range = scheme.compute_range(&calibration, &tensor);
+        let qtensor = tensor.quantize(&scheme, scheme.compute_q_params(range));
+        let range_ref = scheme.compute_range(&calibration, &tensor_ref);
+        let qtensor_ref = tensor_ref.quantize(&scheme, scheme.compute_q_params(range_ref));
+
+        qtensor
+            .clone()
+            .into_data()
+            .assert_eq(&qtensor_ref.clone().into_data(), false);
+        qtensor
+            .dequantize()
+            .into_data()
+            .assert_approx_eq(&qtensor_ref.dequantize().into_data(), 3);
+    }
+
+    #[test]
+    fn per_tensor_affine_quantize_should_match_reference() {
+        quantize_should_match_reference(QuantizationScheme::PerTensorAffine(
+            QuantizationType::QInt8,
+        ));
+    }
+
+    #[test]
+    fn per_channel_affine_quantize_should_match_reference() {
+        quantize_should_match_reference(QuantizationScheme::PerChannelAffine {
+            dtype: QuantizationType::QInt8,
+            axis: 0,
+        });
+    }
+
+    #[test]
+    fn per_channel_symmetric_quantize_should_match_reference() {
+        quantize_should_match_reference(QuantizationScheme::PerChannelSymmetric {
+            dtype: QuantizationType::QInt8,
+            axis: 1,
+        });
+    }
+
+    #[test]
+    fn per_channel_affine_uint8_quantize_should_match_reference() {
+        quantize_should_match_reference(QuantizationScheme::PerChannelAffine {
+            dtype: QuantizationType::QUInt8,
+            axis: 0,
+        });
+    }
+
+    #[test]
+    fn per_tensor_nf4_quantize_should_match_reference() {
+        quantize_should_match_reference(QuantizationScheme::PerTensorSymmetric(
+            QuantizationType::NF4,
+        ));
+    }
+
+    #[test]
+    fn block_nf4_quantize_should_match_reference() {
+        quantize_should_match_reference(QuantizationScheme::BlockSymmetric {
+            dtype: QuantizationType::NF4,
+            block_size: 2,
+        });
+    }
+
+    #[test]
+    fn block_symmetric_quantize_should_match_reference() {
+        quantize_should_match_reference(QuantizationScheme::BlockSymmetric {
+            dtype: QuantizationType::QInt8,
+            block_size: 2,
+        });
+    }
+}
@@ -2,7 +2,7 @@ use crate::{element::FloatNdArrayElement, sharing::UnsafeSharedRef, tensor::NdAr
 use burn_common::{iter_range_par, run_par};
 
 use burn_tensor::ElementConversion;
-use ndarray::Array4;
+use ndarray::{Array4, Array5};
 
 pub(crate) fn avg_pool2d<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 4>,
@@ -131,3 +131,155 @@ pub(crate) fn avg_pool2d_backward<E: FloatNdArrayElement>(
 
     NdArrayTensor::new(output_grad.into_dyn().into_shared())
 }
+
+pub(crate) fn avg_pool3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> NdArrayTensor<E, 5> {
+    let [kernel_depth, kernel_height, kernel_width] = kernel_size;
+    let [padding_depth, padding_height, padding_width] = padding;
+    let [stride_depth, stride_height, stride_width] = stride;
+    let [batch_size, channels, x_depth, x_height, x_width] = x.shape().dims;
+
+    let out_depth = ((x_depth + 2 * padding_depth - kernel_depth) / stride_depth) + 1;
+    let out_height = ((x_height + 2 * padding_height - kernel_height) / stride_height) + 1;
+    let out_width = ((x_width + 2 * padding_width - kernel_width) / stride_width) + 1;
+
+    let x = x.array;
+
+    let mut output = Array5::from_elem(
+        (batch_size, channels, out_depth, out_height, out_width),
+        0.elem(),
+    );
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output = unsafe_shared_out.get();
+
+            for od in 0..out_depth {
+                for oh in 0..out_height {
+                    for ow in 0..out_width {
+                        let mut sum_val: E = 0.elem();
+                        let mut count: E = 0.elem();
+
+                        for kd in 0..kernel_depth {
+                            for kh in 0..kernel_height {
+                                for kw in 0..kernel_width {
+                                    let id = od * stride_depth + kd;
+                                    let ih = oh * stride_height + kh;
+                                    let iw = ow * stride_width + kw;
+
+                                    if id >= x_depth + padding_depth
+                                        || ih >= x_height + padding_height
+                                        || iw >= x_width + padding_width
+                                        || id < padding_depth
+                                        || ih < padding_height
+                                        || iw < padding_width
+                                    {
+                                        continue;
+                                    }
+
+                                    let id = id - padding_depth;
+                                    let ih = ih - padding_height;
+                                    let iw = iw - padding_width;
+
+                                    count += 1.elem();
+                                    sum_val += x[[b, c, id, ih, iw]];
+                                }
+                            }
+                        }
+
+                        if count_include_pad {
+                            count = ((kernel_depth * kernel_height * kernel_width) as i32).elem();
+                        }
+
+                        output[[b, c, od, oh, ow]] = sum_val / count;
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
+
+pub(crate) fn avg_pool3d_backward<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    grad: NdArrayTensor<E, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> NdArrayTensor<E, 5> {
+    let [kernel_depth, kernel_height, kernel_width] = kernel_size;
+    let [stride_depth, stride_height, stride_width] = stride;
+    let [padding_depth, padding_height, padding_width] = padding;
+    let [batch_size, channels, x_depth, x_height, x_width] = x.shape().dims;
+    let [_batch_size, _channels, out_depth, out_height, out_width] = grad.shape().dims;
+
+    let grad = grad.array;
+
+    let mut output_grad =
+        Array5::from_elem((batch_size, channels, x_depth, x_height, x_width), 0.elem());
+    let unsafe_shared_grad = UnsafeSharedRef::new(&mut output_grad);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * channels).for_each(|k| unsafe {
+            let b = k / channels;
+            let c = k % channels;
+
+            let output_grad = unsafe_shared_grad.get();
+
+            for od in 0..out_depth {
+                for oh in 0..out_height {
+                    for ow in 0..out_width {
+                        let id_start = od * stride_depth;
+                        let ih_start = oh * stride_height;
+                        let iw_start = ow * stride_width;
+
+                        let id_end = id_start + kernel_depth;
+                        let ih_end = ih_start + kernel_height;
+                        let iw_end = iw_start + kernel_width;
+
+                        let id_start = usize::max(id_start, padding_depth);
+                        let ih_start = usize::max(ih_start, padding_height);
+                        let iw_start = usize::max(iw_start, padding_width);
+
+                        let id_end = usize::min(id_end, x_depth + padding_depth);
+                        let ih_end = usize::min(ih_end, x_height + padding_height);
+                        let iw_end = usize::min(iw_end, x_width + padding_width);
+
+                        let count = match count_include_pad {
+                            true => kernel_depth * kernel_height * kernel_width,
+                            false => {
+                                (id_end - id_start) * (ih_end - ih_start) * (iw_end - iw_start)
+                            }
+                        };
+
+                        for id in id_start..id_end {
+                            for ih in ih_start..ih_end {
+                                for iw in iw_start..iw_end {
+                                    let id = id - padding_depth;
+                                    let ih = ih - padding_height;
+                                    let iw = iw - padding_width;
+
+                                    output_grad[[b, c, id, ih, iw]] +=
+                                        grad[[b, c, od, oh, ow]] / (count as i32).elem();
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    });
+
+    NdArrayTensor::new(output_grad.into_dyn().into_shared())
+}
@@ -499,6 +499,38 @@ where
         arg(tensor, dim, CmpType::Min)
     }
 
+    pub fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&previous, current| {
+            *current = previous + *current
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cumprod<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&previous, current| {
+            *current = previous * *current
+        });
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cummax<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        cumulative_arg(tensor, dim, CmpType::Max)
+    }
+
+    pub fn cummin<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        cumulative_arg(tensor, dim, CmpType::Min)
+    }
+
     pub fn clamp_min<const D: usize>(
         mut tensor: NdArrayTensor<E, D>,
         min: E,
@@ -620,6 +652,45 @@ fn arg<E: NdArrayElement, const D: usize>(
     }
 }
 
+/// Computes the cumulative min/max along the dimension, with the index of the last occurrence of
+/// the min/max value.
+fn cumulative_arg<E: NdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    dim: usize,
+    cmp: CmpType,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+    let mut values = tensor.array.into_owned();
+    let mut indices = ndarray::ArrayD::<i64>::zeros(values.raw_dim());
+
+    for (mut lane, mut lane_indices) in values
+        .lanes_mut(Axis(dim))
+        .into_iter()
+        .zip(indices.lanes_mut(Axis(dim)))
+    {
+        let mut index = 0;
+
+        for i in 0..lane.len() {
+            let keep_previous = i > 0
+                && match cmp {
+                    CmpType::Min => lane[i - 1] < lane[i],
+                    CmpType::Max => lane[i - 1] > lane[i],
+                };
+
+            if keep_previous {
+                lane[i] = lane[i - 1];
+            } else {
+                index = i;
+            }
+            lane_indices[i] = index as i64;
+        }
+    }
+
+    (
+        NdArrayTensor::new(values.into_shared()),
+        NdArrayTensor::new(indices.into_shared()),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -11,14 +11,14 @@ use ndarray::{
 };
 
 use crate::{
-    element::{FloatNdArrayElement, QuantElement},
+    element::{FloatNdArrayElement, NdArrayElement},
     ops::padding::{apply_padding_4d, apply_padding_5d},
     sharing::UnsafeSharedRef,
     tensor::NdArrayTensor,
 };
 
 #[inline(always)]
-fn conv2d_mad_inner<E: FloatNdArrayElement>(
+fn conv2d_mad_inner<E: NdArrayElement>(
     mut output: ArrayViewMut2<E>,
     x: ArrayView2<E>,
     k: E,
@@ -98,7 +98,7 @@ fn conv3d_mad_inner<E: FloatNdArrayElement>(
     }
 }
 
-pub(crate) fn conv2d<E: FloatNdArrayElement, Q: QuantElement>(
+pub(crate) fn conv2d<E: NdArrayElement>(
     x: NdArrayTensor<E, 4>,
     weight: NdArrayTensor<E, 4>,
     bias: Option<NdArrayTensor<E, 1>>,
@@ -125,7 +125,7 @@ pub(crate) fn conv2d<E: FloatNdArrayElement, Q: QuantElement>(
         in_width,
     );
 
-    let x = apply_padding_4d::<E, Q>(x, options.padding, 0i32.elem()).array;
+    let x = apply_padding_4d(x, options.padding, 0i32.elem()).array;
 
     // Convert inputs from dynamic indexes to static to improve perf.
     let x = x.into_dimensionality::<ndarray::Ix4>().unwrap();
@@ -309,7 +309,7 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
     NdArrayTensor::new(output.into_dyn().into_shared())
 }
 
-pub(crate) fn conv3d<E: FloatNdArrayElement, Q: QuantElement>(
+pub(crate) fn conv3d<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 5>,
     weight: NdArrayTensor<E, 5>,
     bias: Option<NdArrayTensor<E, 1>>,
@@ -344,7 +344,7 @@ pub(crate) fn conv3d<E: FloatNdArrayElement, Q: QuantElement>(
         in_width,
     );
 
-    let x = apply_padding_5d::<E, Q>(x, options.padding, 0i32.elem()).array;
+    let x = apply_padding_5d(x, options.padding, 0i32.elem()).array;
 
     // Convert inputs from dynamic indexes to static to improve perf.
     let x = x.into_dimensionality::<ndarray::Ix5>().unwrap();
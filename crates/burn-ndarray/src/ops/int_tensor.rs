@@ -355,6 +355,34 @@ impl<E: FloatNdArrayElement, Q: QuantElement> IntTensorOps<Self> for NdArray<E,
         NdArrayMathOps::argmin(tensor, dim)
     }
 
+    fn int_cumsum<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn int_cummax<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::cummax(tensor, dim)
+    }
+
+    fn int_cummin<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::cummin(tensor, dim)
+    }
+
     fn int_clamp_min<const D: usize>(
         tensor: NdArrayTensor<i64, D>,
         min: i64,
@@ -0,0 +1,7 @@
+use crate::{
+    element::{FloatNdArrayElement, QuantElement},
+    NdArray,
+};
+use burn_tensor::ops::LinalgOps;
+
+impl<E: FloatNdArrayElement, Q: QuantElement> LinalgOps<Self> for NdArray<E, Q> {}
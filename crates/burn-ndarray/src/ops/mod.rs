@@ -2,6 +2,7 @@ mod activations;
 mod base;
 mod bool_tensor;
 mod int_tensor;
+mod linalg;
 mod module;
 mod qtensor;
 mod tensor;
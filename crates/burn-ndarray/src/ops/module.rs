@@ -1,9 +1,12 @@
 use super::{
     adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
-    avgpool::{avg_pool2d, avg_pool2d_backward},
+    avgpool::{avg_pool2d, avg_pool2d_backward, avg_pool3d, avg_pool3d_backward},
     conv::{conv2d, conv3d, conv_transpose2d, conv_transpose3d},
     interpolate::{bicubic_interpolate, bilinear_interpolate, nearest_interpolate},
-    maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
+    maxpool::{
+        max_pool2d, max_pool2d_backward, max_pool2d_with_indices, max_pool3d, max_pool3d_backward,
+        max_pool3d_with_indices,
+    },
 };
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
 use crate::{element::QuantElement, ops::interpolate::nearest_interpolate_backward};
@@ -16,7 +19,7 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
         bias: Option<NdArrayTensor<E, 1>>,
         options: ConvOptions<2>,
     ) -> NdArrayTensor<E, 4> {
-        conv2d::<E, Q>(x, weight, bias, options)
+        conv2d(x, weight, bias, options)
     }
 
     fn conv_transpose2d(
@@ -56,7 +59,7 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
         padding: [usize; 2],
         dilation: [usize; 2],
     ) -> NdArrayTensor<E, 4> {
-        max_pool2d::<E, Q>(x, kernel_size, stride, padding, dilation)
+        max_pool2d(x, kernel_size, stride, padding, dilation)
     }
 
     fn max_pool2d_with_indices(
@@ -66,8 +69,7 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
         padding: [usize; 2],
         dilation: [usize; 2],
     ) -> MaxPool2dWithIndices<NdArray<E, Q>> {
-        let (output, indices) =
-            max_pool2d_with_indices::<E, Q>(x, kernel_size, stride, padding, dilation);
+        let (output, indices) = max_pool2d_with_indices(x, kernel_size, stride, padding, dilation);
 
         MaxPool2dWithIndices::new(output, indices)
     }
@@ -138,7 +140,7 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
         bias: Option<NdArrayTensor<E, 1>>,
         options: ConvOptions<3>,
     ) -> NdArrayTensor<E, 5> {
-        conv3d::<E, Q>(x, weight, bias, options)
+        conv3d(x, weight, bias, options)
     }
 
     fn conv_transpose3d(
@@ -149,4 +151,59 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
     ) -> NdArrayTensor<E, 5> {
         conv_transpose3d(x, weight, bias, options)
     }
+
+    fn avg_pool3d(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> NdArrayTensor<E, 5> {
+        avg_pool3d(x, kernel_size, stride, padding, count_include_pad)
+    }
+
+    fn avg_pool3d_backward(
+        x: NdArrayTensor<E, 5>,
+        grad: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> NdArrayTensor<E, 5> {
+        avg_pool3d_backward(x, grad, kernel_size, stride, padding, count_include_pad)
+    }
+
+    fn max_pool3d(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> NdArrayTensor<E, 5> {
+        max_pool3d(x, kernel_size, stride, padding, dilation)
+    }
+
+    fn max_pool3d_with_indices(
+        x: NdArrayTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<NdArray<E, Q>> {
+        let (output, indices) = max_pool3d_with_indices(x, kernel_size, stride, padding, dilation);
+
+        MaxPool3dWithIndices::new(output, indices)
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: NdArrayTensor<E, 5>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        output_grad: NdArrayTensor<E, 5>,
+        indices: NdArrayTensor<i64, 5>,
+    ) -> MaxPool3dBackward<NdArray<E, Q>> {
+        MaxPool3dBackward::new(max_pool3d_backward(x, output_grad, indices))
+    }
 }
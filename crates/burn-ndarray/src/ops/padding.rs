@@ -1,12 +1,9 @@
-use crate::{
-    element::{FloatNdArrayElement, QuantElement},
-    tensor::NdArrayTensor,
-    NdArray,
-};
-use burn_tensor::ops::FloatTensorOps;
+use crate::{element::NdArrayElement, tensor::NdArrayTensor};
+
+use super::NdArrayOps;
 use ndarray::{Array4, Array5};
 
-pub(crate) fn apply_padding_4d<E: FloatNdArrayElement, Q: QuantElement>(
+pub(crate) fn apply_padding_4d<E: NdArrayElement>(
     x: NdArrayTensor<E, 4>,
     padding: [usize; 2],
     elem: E,
@@ -22,7 +19,7 @@ pub(crate) fn apply_padding_4d<E: FloatNdArrayElement, Q: QuantElement>(
     );
     let mut x_new = NdArrayTensor::new(x_new.into_shared().into_dyn());
 
-    x_new = NdArray::<E, Q>::float_slice_assign(
+    x_new = NdArrayOps::slice_assign(
         x_new,
         [
             0..batch_size,
@@ -36,7 +33,7 @@ pub(crate) fn apply_padding_4d<E: FloatNdArrayElement, Q: QuantElement>(
     x_new
 }
 
-pub(crate) fn apply_padding_5d<E: FloatNdArrayElement, Q: QuantElement>(
+pub(crate) fn apply_padding_5d<E: NdArrayElement>(
     x: NdArrayTensor<E, 5>,
     padding: [usize; 3],
     elem: E,
@@ -59,7 +56,7 @@ pub(crate) fn apply_padding_5d<E: FloatNdArrayElement, Q: QuantElement>(
     );
     let mut x_new = NdArrayTensor::new(x_new.into_shared().into_dyn());
 
-    x_new = NdArray::<E, Q>::float_slice_assign(
+    x_new = NdArrayOps::slice_assign(
         x_new,
         [
             0..batch_size,
@@ -1,18 +1,21 @@
+use alloc::{vec, vec::Vec};
+
 use burn_tensor::{
-    ops::{FloatTensor, QTensorOps, QuantizedTensor},
+    ops::{ConvOptions, FloatTensor, QTensorOps, QuantizedTensor},
     quantization::{
-        AffineQuantization, Quantization, QuantizationParametersPrimitive, QuantizationScheme,
-        QuantizationStrategy, QuantizationType, SymmetricQuantization,
+        GroupQuantization, Quantization, QuantizationGroups, QuantizationParametersPrimitive,
+        QuantizationScheme, QuantizationStrategy, QuantizationType,
     },
-    DType, Shape, TensorData,
+    DType, ElementConversion, Shape, TensorData,
 };
+use ndarray::{Axis, Zip};
 
 use crate::{
     element::{NdArrayElement, QuantElement},
     FloatNdArrayElement, NdArray, NdArrayDevice, NdArrayQTensor, NdArrayTensor,
 };
 
-use super::NdArrayOps;
+use super::{conv::conv2d, matmul::matmul};
 
 fn into_data<E: NdArrayElement, const D: usize>(tensor: NdArrayTensor<E, D>) -> TensorData {
     let shape = tensor.shape();
@@ -20,30 +23,147 @@ fn into_data<E: NdArrayElement, const D: usize>(tensor: NdArrayTensor<E, D>) ->
     TensorData::new(values, shape)
 }
 
+/// Reinterpret the stored signed values as unsigned values with the same bits.
+fn as_unsigned(values: &[i8]) -> Vec<u8> {
+    values.iter().map(|v| *v as u8).collect()
+}
+
+/// Quantized values with the zero-point offset subtracted, used to accumulate the products of
+/// quantized tensors in the integer domain.
+struct IntegerValues<const D: usize> {
+    values: NdArrayTensor<i32, D>,
+    /// The scale of the whole tensor, or of each channel.
+    scales: Vec<f32>,
+}
+
+impl<const D: usize> IntegerValues<D> {
+    fn scale(&self, channel: usize) -> f32 {
+        match self.scales.len() {
+            1 => self.scales[0],
+            _ => self.scales[channel],
+        }
+    }
+}
+
+/// Returns the integer values of a quantized tensor, if its quantization parameters can be
+/// factored out of the products.
+///
+/// Per-channel parameters are only supported along `channel_axis`, and NF4 or block-wise
+/// quantized tensors are not supported.
+fn integer_values<Q: QuantElement, const D: usize>(
+    tensor: &NdArrayQTensor<Q, D>,
+    channel_axis: Option<usize>,
+) -> Option<IntegerValues<D>> {
+    let (scales, offsets) = match tensor.strategy {
+        QuantizationStrategy::PerTensorAffineInt8(q) => (vec![q.scale], vec![q.offset as i32]),
+        QuantizationStrategy::PerTensorSymmetricInt8(q) => (vec![q.scale], vec![0]),
+        QuantizationStrategy::PerTensorAffineUInt8(q) => (vec![q.scale], vec![q.offset as i32]),
+        QuantizationStrategy::PerTensorAffineInt4(q) => (vec![q.scale], vec![q.offset as i32]),
+        QuantizationStrategy::PerTensorSymmetricInt4(q) => (vec![q.scale], vec![0]),
+        QuantizationStrategy::GroupedAffineInt8(QuantizationGroups::Channel { axis })
+        | QuantizationStrategy::GroupedSymmetricInt8(QuantizationGroups::Channel { axis })
+        | QuantizationStrategy::GroupedAffineUInt8(QuantizationGroups::Channel { axis })
+        | QuantizationStrategy::GroupedAffineInt4(QuantizationGroups::Channel { axis })
+        | QuantizationStrategy::GroupedSymmetricInt4(QuantizationGroups::Channel { axis })
+            if Some(axis) == channel_axis =>
+        {
+            let params = tensor.qparams.as_ref()?;
+            let offsets = match &params.offsets {
+                Some(offsets) => offsets.clone(),
+                None => vec![0; params.scales.len()],
+            };
+            (params.scales.clone(), offsets)
+        }
+        _ => return None,
+    };
+
+    let unsigned = tensor.strategy.dtype() == QuantizationType::QUInt8;
+    let values = tensor
+        .values()
+        .into_iter()
+        .map(|v| match unsigned {
+            true => v as u8 as i32,
+            false => v as i32,
+        })
+        .collect();
+    let mut values =
+        NdArrayTensor::<i32, D>::from_data(TensorData::new(values, tensor.shape.clone())).array;
+
+    match (offsets.as_slice(), channel_axis) {
+        ([offset], _) => values.mapv_inplace(|v| v - offset),
+        (offsets, Some(axis)) => values
+            .axis_iter_mut(Axis(axis))
+            .zip(offsets)
+            .for_each(|(mut channel, offset)| channel.mapv_inplace(|v| v - offset)),
+        (_, None) => unreachable!("Per-channel offsets should have a channel axis"),
+    }
+
+    Some(IntegerValues {
+        values: NdArrayTensor::new(values),
+        scales,
+    })
+}
+
+/// The per-tensor quantization of the output of a requantized operation.
+struct OutputQuantization {
+    strategy: QuantizationStrategy,
+    scale: f32,
+    offset: f32,
+    /// Quantized range `[a, b]`
+    range: (f32, f32),
+}
+
+impl OutputQuantization {
+    /// Returns the quantization of the output, if the scheme is a per-tensor integer scheme.
+    fn new<E: FloatNdArrayElement, Q: QuantElement>(
+        scheme: &QuantizationScheme,
+        qparams: &QuantizationParametersPrimitive<NdArray<E, Q>>,
+    ) -> Option<Self> {
+        if let QuantizationType::NF4 = scheme.dtype() {
+            return None;
+        }
+
+        let scale = (*qparams.scale.array.first()?).elem::<f32>();
+        let offset = match &qparams.offset {
+            Some(offset) => Some((*offset.array.first()?).elem::<i32>()),
+            None => None,
+        };
+        let strategy = scheme.per_tensor_strategy(scale, offset)?;
+        let (a, b) = match scheme.is_affine() {
+            true => scheme.dtype().affine_range(),
+            false => scheme.dtype().symmetric_range(),
+        };
+
+        Some(Self {
+            strategy,
+            scale,
+            offset: offset.unwrap_or(0).clamp(a, b) as f32,
+            range: (a as f32, b as f32),
+        })
+    }
+
+    /// Convert a value of the output to the quantized type.
+    ///
+    /// Unsigned values are returned as signed 8-bit values with the same bits.
+    fn quantize(&self, value: f32) -> i8 {
+        // x_q = clamp(round(x / scale + offset), a, b)
+        let (a, b) = self.range;
+        (value / self.scale + self.offset).round().clamp(a, b) as i32 as i8
+    }
+}
+
 impl<E: FloatNdArrayElement, Q: QuantElement> QTensorOps<Self> for NdArray<E, Q> {
     fn q_from_data<const D: usize>(
         data: TensorData,
         _device: &NdArrayDevice,
     ) -> QuantizedTensor<Self, D> {
         match data.dtype {
-            DType::QFloat(strategy) => match strategy {
-                QuantizationStrategy::PerTensorAffineInt8(_) => {
-                    let data = data.convert::<i8>();
-                    NdArrayQTensor {
-                        qtensor: NdArrayTensor::<Q, D>::from_data(data),
-                        scheme: strategy.scheme(),
-                        strategy,
-                    }
-                }
-                QuantizationStrategy::PerTensorSymmetricInt8(_) => {
-                    let data = data.convert::<i8>();
-                    NdArrayQTensor {
-                        qtensor: NdArrayTensor::<Q, D>::from_data(data),
-                        scheme: strategy.scheme(),
-                        strategy,
-                    }
-                }
-            },
+            DType::QFloat(strategy) => {
+                let qparams = data.group_quantization();
+                // Unsigned values are stored as signed 8-bit values with the same bits
+                let values = data.iter::<i32>().map(|v| v as i8).collect::<Vec<_>>();
+                NdArrayQTensor::new(values, data.shape.into(), strategy, qparams)
+            }
             _ => panic!(
                 "Invalid dtype (expected DType::QFloat, got {:?})",
                 data.dtype
@@ -56,43 +176,57 @@ impl<E: FloatNdArrayElement, Q: QuantElement> QTensorOps<Self> for NdArray<E, Q>
         scheme: &QuantizationScheme,
         qparams: QuantizationParametersPrimitive<Self>,
     ) -> QuantizedTensor<Self, D> {
-        let strategy = match scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
-                        into_data(qparams.scale).iter().next().unwrap(),
-                        into_data(qparams.offset.unwrap()).iter().next().unwrap(),
-                    ))
-                }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => QuantizationStrategy::PerTensorSymmetricInt8(
-                    SymmetricQuantization::init(into_data(qparams.scale).iter().next().unwrap()),
-                ),
-            },
+        let (strategy, qparams) = match scheme.grouped_strategy() {
+            Some(strategy) => {
+                let params = GroupQuantization {
+                    scales: into_data(qparams.scale).iter().collect(),
+                    offsets: qparams
+                        .offset
+                        .map(|offset| into_data(offset).iter().collect()),
+                };
+                (strategy, Some(params))
+            }
+            None => {
+                let scale = into_data(qparams.scale).iter().next().unwrap();
+                let offset = qparams
+                    .offset
+                    .map(|offset| into_data(offset).iter().next().unwrap());
+                (scheme.per_tensor_strategy(scale, offset).unwrap(), None)
+            }
         };
 
-        let data = into_data(tensor).with_quantization(strategy);
-        NdArrayQTensor {
-            qtensor: NdArrayTensor::<Q, D>::from_data(data),
-            strategy,
-            scheme: scheme.clone(),
-        }
+        let data = match &qparams {
+            Some(params) => into_data(tensor).with_group_quantization(strategy, params),
+            None => into_data(tensor).with_quantization(strategy),
+        };
+        Self::q_from_data(data, &NdArrayDevice::Cpu)
     }
 
     fn dequantize<const D: usize>(tensor: QuantizedTensor<Self, D>) -> FloatTensor<Self, D> {
-        let data = into_data(tensor.qtensor);
+        let shape = tensor.shape.clone();
+        let values = tensor.values();
         let values = match tensor.strategy {
-            QuantizationStrategy::PerTensorAffineInt8(s) => s.dequantize(data.as_slice().unwrap()),
-            QuantizationStrategy::PerTensorSymmetricInt8(s) => {
-                s.dequantize(data.as_slice().unwrap())
-            }
+            QuantizationStrategy::PerTensorAffineInt8(s) => s.dequantize(&values),
+            QuantizationStrategy::PerTensorSymmetricInt8(s) => s.dequantize(&values),
+            QuantizationStrategy::PerTensorAffineUInt8(s) => s.dequantize(&as_unsigned(&values)),
+            QuantizationStrategy::PerTensorAffineInt4(s) => s.dequantize(&values),
+            QuantizationStrategy::PerTensorSymmetricInt4(s) => s.dequantize(&values),
+            QuantizationStrategy::PerTensorNF4(s) => s.dequantize(&as_unsigned(&values)),
+            QuantizationStrategy::GroupedAffineInt8(_)
+            | QuantizationStrategy::GroupedSymmetricInt8(_)
+            | QuantizationStrategy::GroupedAffineUInt8(_)
+            | QuantizationStrategy::GroupedAffineInt4(_)
+            | QuantizationStrategy::GroupedSymmetricInt4(_)
+            | QuantizationStrategy::GroupedNF4(_) => tensor
+                .qparams
+                .expect("Per-group quantized tensor should have parameters")
+                .dequantize(&tensor.strategy, &values, &shape.dims),
         };
-        NdArrayTensor::<E, D>::from_data(TensorData::new(values, data.shape))
+        NdArrayTensor::<E, D>::from_data(TensorData::new(values, shape))
     }
 
     fn q_shape<const D: usize>(tensor: &QuantizedTensor<Self, D>) -> Shape<D> {
-        tensor.qtensor.shape()
+        tensor.shape.clone()
     }
 
     fn q_device<const D: usize>(_tensor: &QuantizedTensor<Self, D>) -> NdArrayDevice {
@@ -103,16 +237,143 @@ impl<E: FloatNdArrayElement, Q: QuantElement> QTensorOps<Self> for NdArray<E, Q>
         tensor: QuantizedTensor<Self, D1>,
         shape: Shape<D2>,
     ) -> QuantizedTensor<Self, D2> {
+        let scheme = tensor
+            .scheme
+            .reshape(&tensor.shape.dims, &shape.dims)
+            .expect("Reshape should preserve the quantization channel axis");
+        let strategy = scheme.grouped_strategy().unwrap_or(tensor.strategy);
+
+        // The values are stored in row-major order, so only the shape changes
         NdArrayQTensor {
-            qtensor: NdArrayOps::reshape(tensor.qtensor, shape),
-            scheme: tensor.scheme,
-            strategy: tensor.strategy,
+            qtensor: tensor.qtensor,
+            shape,
+            scheme,
+            strategy,
+            qparams: tensor.qparams,
         }
     }
 
+    fn q_matmul<const D: usize>(
+        lhs: QuantizedTensor<Self, D>,
+        rhs: QuantizedTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        let integer = integer_values(&lhs, Some(D - 2)).zip(integer_values(&rhs, Some(D - 1)));
+        let (lhs_int, rhs_int) = match integer {
+            Some(integer) => integer,
+            None => return matmul(Self::dequantize(lhs), Self::dequantize(rhs)),
+        };
+
+        // Accumulate the products of the quantized values in the integer domain
+        let output = matmul(lhs_int.values, rhs_int.values);
+
+        // Rescale the accumulators with the scales of each row and column
+        let output = Zip::indexed(&output.array).map_collect(|index, acc| {
+            let scale = lhs_int.scale(index[D - 2]) * rhs_int.scale(index[D - 1]);
+            (*acc as f32 * scale).elem::<E>()
+        });
+
+        NdArrayTensor::new(output.into_shared())
+    }
+
+    fn q_matmul_requantize<const D: usize>(
+        lhs: QuantizedTensor<Self, D>,
+        rhs: QuantizedTensor<Self, D>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self, D> {
+        let integer = integer_values(&lhs, Some(D - 2)).zip(integer_values(&rhs, Some(D - 1)));
+        let (lhs_int, rhs_int, output) = match (integer, OutputQuantization::new(scheme, &qparams))
+        {
+            (Some((lhs_int, rhs_int)), Some(output)) => (lhs_int, rhs_int, output),
+            _ => return Self::quantize(Self::q_matmul(lhs, rhs), scheme, qparams),
+        };
+
+        // Accumulate the products of the quantized values in the integer domain
+        let acc = matmul(lhs_int.values, rhs_int.values);
+        let shape = acc.shape();
+
+        // Rescale the accumulators with the scales of each row and column, directly to the
+        // quantized output
+        let values = Zip::indexed(&acc.array).map_collect(|index, acc| {
+            let scale = lhs_int.scale(index[D - 2]) * rhs_int.scale(index[D - 1]);
+            output.quantize(*acc as f32 * scale)
+        });
+
+        NdArrayQTensor::new(values.into_iter().collect(), shape, output.strategy, None)
+    }
+
+    fn q_conv2d(
+        x: QuantizedTensor<Self, 4>,
+        weight: QuantizedTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        let integer = integer_values(&x, None).zip(integer_values(&weight, Some(0)));
+        let (x_int, weight_int) = match integer {
+            Some(integer) => integer,
+            None => return conv2d(Self::dequantize(x), Self::dequantize(weight), bias, options),
+        };
+
+        // Accumulate the products of the quantized values in the integer domain
+        let output = conv2d(x_int.values, weight_int.values, None, options);
+
+        // Rescale the accumulators with the scale of each output channel and add the bias
+        let output = Zip::indexed(&output.array).map_collect(|index, acc| {
+            let channel = index[1];
+            let value = (*acc as f32 * x_int.scale(0) * weight_int.scale(channel)).elem::<E>();
+            match &bias {
+                Some(bias) => value + bias.array[channel],
+                None => value,
+            }
+        });
+
+        NdArrayTensor::new(output.into_shared())
+    }
+
+    fn q_conv2d_requantize(
+        x: QuantizedTensor<Self, 4>,
+        weight: QuantizedTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self, 4> {
+        let integer = integer_values(&x, None).zip(integer_values(&weight, Some(0)));
+        let (x_int, weight_int, output) = match (integer, OutputQuantization::new(scheme, &qparams))
+        {
+            (Some((x_int, weight_int)), Some(output)) => (x_int, weight_int, output),
+            _ => return Self::quantize(Self::q_conv2d(x, weight, bias, options), scheme, qparams),
+        };
+
+        // Accumulate the products of the quantized values in the integer domain
+        let acc = conv2d(x_int.values, weight_int.values, None, options);
+        let shape = acc.shape();
+
+        // Rescale the accumulators with the scale of each output channel and add the bias,
+        // directly to the quantized output
+        let values = Zip::indexed(&acc.array).map_collect(|index, acc| {
+            let channel = index[1];
+            let value = *acc as f32 * x_int.scale(0) * weight_int.scale(channel);
+            match &bias {
+                Some(bias) => output.quantize(value + bias.array[channel].elem::<f32>()),
+                None => output.quantize(value),
+            }
+        });
+
+        NdArrayQTensor::new(values.into_iter().collect(), shape, output.strategy, None)
+    }
+
     async fn q_into_data<const D: usize>(tensor: QuantizedTensor<Self, D>) -> TensorData {
-        let shape = tensor.qtensor.shape();
-        let values = tensor.qtensor.array.into_iter().collect();
-        TensorData::quantized(values, shape, tensor.strategy)
+        let shape = tensor.shape.clone();
+        let values = tensor.values();
+        match tensor.qparams {
+            Some(params) => TensorData::quantized_groups(values, shape, tensor.strategy, &params),
+            None => match tensor.strategy.dtype() {
+                QuantizationType::QUInt8 => {
+                    TensorData::quantized(as_unsigned(&values), shape, tensor.strategy)
+                }
+                _ => TensorData::quantized(values, shape, tensor.strategy),
+            },
+        }
     }
 }
@@ -1,6 +1,10 @@
+use alloc::vec::Vec;
 use burn_tensor::{
-    quantization::{QTensorPrimitive, QuantizationScheme, QuantizationStrategy},
-    Element, Shape, TensorData,
+    quantization::{
+        pack_nibbles, unpack_nibbles, GroupQuantization, QTensorPrimitive, QuantizationScheme,
+        QuantizationStrategy, QuantizationType,
+    },
+    Element, ElementConversion, Shape, TensorData,
 };
 
 use ndarray::{ArcArray, Array, Dim, IxDyn};
@@ -119,12 +123,64 @@ where
 /// A quantized tensor for the ndarray backend.
 #[derive(Clone, Debug)]
 pub struct NdArrayQTensor<Q: QuantElement, const D: usize> {
-    /// The quantized tensor.
-    pub qtensor: NdArrayTensor<Q, D>,
+    /// The quantized values in row-major order.
+    ///
+    /// Unsigned values are stored as signed 8-bit values with the same bits, and 4-bit values are
+    /// packed two per byte (with the first value in the low nibble).
+    pub qtensor: NdArrayTensor<Q, 1>,
+    /// The shape of the tensor.
+    pub shape: Shape<D>,
     /// The quantization scheme.
     pub scheme: QuantizationScheme,
     /// The quantization strategy.
     pub strategy: QuantizationStrategy,
+    /// The quantization parameters of each group (per-group strategies only).
+    pub qparams: Option<GroupQuantization>,
+}
+
+impl<Q: QuantElement, const D: usize> NdArrayQTensor<Q, D> {
+    /// Create a new quantized tensor from the quantized values in row-major order (one value per
+    /// element), packing 4-bit values.
+    pub fn new(
+        values: Vec<i8>,
+        shape: Shape<D>,
+        strategy: QuantizationStrategy,
+        qparams: Option<GroupQuantization>,
+    ) -> Self {
+        let values: Vec<i8> = match strategy.dtype().bits() {
+            4 => pack_nibbles(&values.iter().map(|v| *v as u8).collect::<Vec<_>>())
+                .into_iter()
+                .map(|v| v as i8)
+                .collect(),
+            _ => values,
+        };
+        let len = values.len();
+
+        Self {
+            qtensor: NdArrayTensor::from_data(TensorData::new(values, [len])),
+            shape,
+            scheme: strategy.scheme(),
+            strategy,
+            qparams,
+        }
+    }
+
+    /// Returns the quantized values in row-major order (one value per element), unpacking 4-bit
+    /// values.
+    pub fn values(&self) -> Vec<i8> {
+        let values = self.qtensor.array.iter().map(|v| v.elem::<i8>());
+
+        match self.strategy.dtype().bits() {
+            4 => {
+                let bytes = values.map(|v| v as u8).collect::<Vec<_>>();
+                let signed = self.strategy.dtype() == QuantizationType::QInt4;
+                unpack_nibbles(&bytes, signed)
+                    .take(self.shape.num_elements())
+                    .collect()
+            }
+            _ => values.collect(),
+        }
+    }
 }
 
 impl<Q: QuantElement, const D: usize> QTensorPrimitive for NdArrayQTensor<Q, D> {
@@ -142,10 +198,14 @@ mod tests {
     use crate::NdArray;
 
     use super::*;
+    use alloc::vec;
     use burn_common::rand::get_seeded_rng;
     use burn_tensor::{
         ops::QTensorOps,
-        quantization::{AffineQuantization, QuantizationParametersPrimitive, QuantizationType},
+        quantization::{
+            AffineQuantization, QuantizationGroups, QuantizationParametersPrimitive,
+            QuantizationType, SymmetricInt4Quantization,
+        },
         Distribution,
     };
 
@@ -221,4 +281,63 @@ mod tests {
             QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(0.009_019_608, 72))
         );
     }
+
+    #[test]
+    fn should_support_int4_qtensor_strategy() {
+        let tensor = NdArrayTensor::<f32, 1>::from_data(TensorData::from([-1.8, -1.0, 0.0, 0.5]));
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt4);
+        let qparams = QuantizationParametersPrimitive {
+            scale: NdArrayTensor::from_data(TensorData::from([0.257_142_87])),
+            offset: None,
+        };
+        let qtensor: NdArrayQTensor<i8, 1> = NdArray::quantize(tensor, &scheme, qparams);
+
+        assert_eq!(qtensor.scheme(), &scheme);
+        assert_eq!(
+            qtensor.strategy(),
+            QuantizationStrategy::PerTensorSymmetricInt4(SymmetricInt4Quantization::init(
+                0.257_142_87
+            ))
+        );
+
+        // The 4-bit values are packed two per byte
+        assert_eq!(qtensor.qtensor.array.len(), 2);
+        assert_eq!(qtensor.values(), vec![-7, -4, 0, 2]);
+
+        let tensor = NdArray::<f32>::dequantize(qtensor);
+        tensor.into_data().assert_approx_eq(
+            &TensorData::from([-1.8f32, -1.028_571_4, 0.0, 0.514_285_7]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_support_per_channel_qtensor_strategy() {
+        let tensor = NdArrayTensor::<f32, 2>::from_data(TensorData::from([
+            [-1.8, -1.0, 0.0],
+            [0.25, 0.0, 1.0],
+        ]));
+        let scheme = QuantizationScheme::PerChannelAffine {
+            dtype: QuantizationType::QInt8,
+            axis: 0,
+        };
+        let qparams = QuantizationParametersPrimitive {
+            scale: NdArrayTensor::from_data(TensorData::from([0.009_019_608, 0.003_921_569])),
+            offset: Some(NdArrayTensor::from_data(TensorData::from([72, -128]))),
+        };
+        let qtensor: NdArrayQTensor<i8, 2> = NdArray::quantize(tensor, &scheme, qparams);
+
+        assert_eq!(qtensor.scheme(), &scheme);
+        assert_eq!(
+            qtensor.strategy(),
+            QuantizationStrategy::GroupedAffineInt8(QuantizationGroups::Channel { axis: 0 })
+        );
+        assert_eq!(
+            qtensor.qparams,
+            Some(GroupQuantization {
+                scales: vec![0.009_019_608, 0.003_921_569],
+                offsets: Some(vec![72, -128]),
+            })
+        );
+    }
 }
@@ -341,6 +341,40 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         )
     }
 
+    pub fn cumsum<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::from_existing(tensor.tensor.cumsum(dim as i64, E::KIND), tensor.storage)
+    }
+
+    pub fn cumprod<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
+        TchTensor::from_existing(tensor.tensor.cumprod(dim as i64, E::KIND), tensor.storage)
+    }
+
+    pub fn cummax<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let storage = tensor.storage.clone();
+        let (tensor, indices) = tensor.tensor.cummax(dim as i64);
+
+        let tensor = TchTensor::from_existing(tensor, storage);
+        let indices = TchTensor::new(indices);
+
+        (tensor, indices)
+    }
+
+    pub fn cummin<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let storage = tensor.storage.clone();
+        let (tensor, indices) = tensor.tensor.cummin(dim as i64);
+
+        let tensor = TchTensor::from_existing(tensor, storage);
+        let indices = TchTensor::new(indices);
+
+        (tensor, indices)
+    }
+
     pub fn argmax<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<i64, D> {
         let storage = tensor.storage.clone();
         let tensor = tensor.tensor.argmax(dim as i64, true);
@@ -536,6 +570,26 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
                     .tensor
                     .quantize_per_tensor(q.scale.into(), 0, tch::Kind::QInt8),
             ),
+            QuantizationStrategy::PerTensorAffineUInt8(ref q) => {
+                TchTensor::new(tensor.tensor.quantize_per_tensor(
+                    q.scale.into(),
+                    q.offset.into(),
+                    tch::Kind::QUInt8,
+                ))
+            }
+            QuantizationStrategy::PerTensorAffineInt4(_)
+            | QuantizationStrategy::PerTensorSymmetricInt4(_)
+            | QuantizationStrategy::PerTensorNF4(_) => {
+                panic!("4-bit quantization is not supported by the tch backend")
+            }
+            QuantizationStrategy::GroupedAffineInt8(_)
+            | QuantizationStrategy::GroupedSymmetricInt8(_)
+            | QuantizationStrategy::GroupedAffineUInt8(_)
+            | QuantizationStrategy::GroupedAffineInt4(_)
+            | QuantizationStrategy::GroupedSymmetricInt4(_)
+            | QuantizationStrategy::GroupedNF4(_) => {
+                panic!("Per-group quantization requires the quantization parameters")
+            }
         }
     }
 }
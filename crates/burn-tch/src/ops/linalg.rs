@@ -0,0 +1,46 @@
+use crate::{element::TchElement, LibTorch, QuantElement, TchTensor};
+use burn_tensor::ops::LinalgOps;
+
+impl<E: TchElement, Q: QuantElement> LinalgOps<Self> for LibTorch<E, Q> {
+    fn solve<const D: usize>(a: TchTensor<E, D>, b: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tch::Tensor::linalg_solve(&a.tensor, &b.tensor, true))
+    }
+
+    fn inverse<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.inverse())
+    }
+
+    fn det<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.det().unsqueeze(-1).unsqueeze(-1))
+    }
+
+    fn logdet<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        let (_sign, logabsdet) = tensor.tensor.slogdet();
+        TchTensor::new(logabsdet.unsqueeze(-1).unsqueeze(-1))
+    }
+
+    fn cholesky<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::new(tensor.tensor.linalg_cholesky(false))
+    }
+
+    fn qr<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (q, r) = tensor.tensor.qr(true);
+        (TchTensor::new(q), TchTensor::new(r))
+    }
+
+    fn svd<const D: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>, TchTensor<E, D>) {
+        let (u, s, v) = tensor.tensor.svd(true, true);
+        (
+            TchTensor::new(u),
+            TchTensor::new(s.unsqueeze(-2)),
+            TchTensor::new(v.transpose(-2, -1)),
+        )
+    }
+
+    fn eigh<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let (w, v) = tensor.tensor.linalg_eigh("L");
+        (TchTensor::new(w.unsqueeze(-2)), TchTensor::new(v))
+    }
+}
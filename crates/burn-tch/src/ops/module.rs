@@ -1,7 +1,8 @@
 use crate::{element::TchElement, LibTorch, QuantElement, TchTensor};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions, MaxPool1dWithIndices,
-    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
+    ConvOptions, ConvTransposeOptions, GridSample2dBackward, GridSampleMode, GridSampleOptions,
+    GridSamplePaddingMode, InterpolateMode, InterpolateOptions, MaxPool1dWithIndices,
+    MaxPool2dBackward, MaxPool2dWithIndices, MaxPool3dBackward, MaxPool3dWithIndices, ModuleOps,
 };
 
 impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
@@ -323,6 +324,154 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
         TchTensor::new(tensor)
     }
 
+    fn avg_pool3d(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::avg_pool3d(
+            &x.tensor,
+            kernel_size.map(|e| e as i64),
+            stride.map(|e| e as i64),
+            padding.map(|e| e as i64),
+            false,
+            count_include_pad,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn avg_pool3d_backward(
+        x: TchTensor<E, 5>,
+        grad: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::avg_pool3d_backward(
+            &x.tensor,
+            &grad.tensor,
+            kernel_size.map(|e| e as i64),
+            stride.map(|e| e as i64),
+            padding.map(|e| e as i64),
+            false,
+            count_include_pad,
+            None,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn adaptive_avg_pool3d(x: TchTensor<E, 5>, output_size: [usize; 3]) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::adaptive_avg_pool3d(&x.tensor, output_size.map(|e| e as i64));
+
+        TchTensor::new(tensor)
+    }
+
+    fn adaptive_avg_pool3d_backward(x: TchTensor<E, 5>, grad: TchTensor<E, 5>) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::internal_adaptive_avg_pool3d_backward(&x.tensor, &grad.tensor);
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool3d(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::max_pool3d(
+            &x.tensor,
+            kernel_size.map(|e| e as i64),
+            stride.map(|e| e as i64),
+            padding.map(|e| e as i64),
+            dilation.map(|e| e as i64),
+            false,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn max_pool3d_with_indices(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<LibTorch<E, Q>> {
+        let (tensor, indices) = tch::Tensor::max_pool3d_with_indices(
+            &x.tensor,
+            kernel_size.map(|e| e as i64),
+            stride.map(|e| e as i64),
+            padding.map(|e| e as i64),
+            dilation.map(|e| e as i64),
+            false,
+        );
+
+        MaxPool3dWithIndices::new(TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
+    fn max_pool3d_with_indices_backward(
+        x: TchTensor<E, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+        output_grad: TchTensor<E, 5>,
+        indices: TchTensor<i64, 5>,
+    ) -> MaxPool3dBackward<LibTorch<E, Q>> {
+        let grad = tch::Tensor::max_pool3d_with_indices_backward(
+            &x.tensor,
+            &output_grad.tensor,
+            kernel_size.map(|e| e as i64),
+            stride.map(|e| e as i64),
+            padding.map(|e| e as i64),
+            dilation.map(|e| e as i64),
+            false,
+            &indices.tensor,
+        );
+
+        MaxPool3dBackward::new(TchTensor::new(grad))
+    }
+
+    fn adaptive_max_pool1d_with_indices(
+        x: TchTensor<E, 3>,
+        output_size: usize,
+    ) -> MaxPool1dWithIndices<LibTorch<E, Q>> {
+        let (tensor, indices) = tch::Tensor::adaptive_max_pool1d(&x.tensor, output_size as i64);
+
+        MaxPool1dWithIndices::new(TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
+    fn adaptive_max_pool2d_with_indices(
+        x: TchTensor<E, 4>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<LibTorch<E, Q>> {
+        let (tensor, indices) =
+            tch::Tensor::adaptive_max_pool2d(&x.tensor, output_size.map(|e| e as i64));
+
+        MaxPool2dWithIndices::new(TchTensor::new(tensor), TchTensor::new(indices))
+    }
+
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: TchTensor<E, 4>,
+        output_grad: TchTensor<E, 4>,
+        indices: TchTensor<i64, 4>,
+    ) -> MaxPool2dBackward<LibTorch<E, Q>> {
+        let grad = tch::Tensor::adaptive_max_pool2d_backward(
+            &x.tensor,
+            &output_grad.tensor,
+            &indices.tensor,
+        );
+
+        MaxPool2dBackward::new(TchTensor::new(grad))
+    }
+
     fn interpolate(
         x: TchTensor<E, 4>,
         output_size: [usize; 2],
@@ -383,4 +532,67 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
 
         TchTensor::new(tensor)
     }
+
+    fn grid_sample_2d(
+        x: TchTensor<E, 4>,
+        grid: TchTensor<E, 4>,
+        options: GridSampleOptions,
+    ) -> TchTensor<E, 4> {
+        let (mode, padding_mode) = grid_sampler_modes(&options);
+        let tensor = tch::Tensor::grid_sampler_2d(
+            &x.tensor,
+            &grid.tensor,
+            mode,
+            padding_mode,
+            options.align_corners,
+        );
+
+        TchTensor::new(tensor)
+    }
+
+    fn grid_sample_2d_backward(
+        x: TchTensor<E, 4>,
+        grid: TchTensor<E, 4>,
+        grad: TchTensor<E, 4>,
+        options: GridSampleOptions,
+    ) -> GridSample2dBackward<Self> {
+        let (mode, padding_mode) = grid_sampler_modes(&options);
+        let (x_grad, grid_grad) = tch::Tensor::grid_sampler_2d_backward(
+            &grad.tensor,
+            &x.tensor,
+            &grid.tensor,
+            mode,
+            padding_mode,
+            options.align_corners,
+            [true, true],
+        );
+
+        GridSample2dBackward::new(TchTensor::new(x_grad), TchTensor::new(grid_grad))
+    }
+
+    fn affine_grid_2d(
+        theta: TchTensor<E, 3>,
+        size: [usize; 4],
+        align_corners: bool,
+    ) -> TchTensor<E, 4> {
+        let size = size.map(|e| e as i64);
+        let tensor = tch::Tensor::affine_grid_generator(&theta.tensor, size, align_corners);
+
+        TchTensor::new(tensor)
+    }
+}
+
+/// Interpolation and padding modes as expected by libtorch's grid sampler.
+fn grid_sampler_modes(options: &GridSampleOptions) -> (i64, i64) {
+    let mode = match options.mode {
+        GridSampleMode::Bilinear => 0,
+        GridSampleMode::Nearest => 1,
+    };
+    let padding_mode = match options.padding_mode {
+        GridSamplePaddingMode::Zeros => 0,
+        GridSamplePaddingMode::Border => 1,
+        GridSamplePaddingMode::Reflection => 2,
+    };
+
+    (mode, padding_mode)
 }
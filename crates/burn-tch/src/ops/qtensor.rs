@@ -1,16 +1,47 @@
 use burn_tensor::{
     ops::{FloatTensor, QTensorOps, QuantizedTensor},
     quantization::{
-        QTensorPrimitive, Quantization, QuantizationParametersPrimitive, QuantizationScheme,
-        QuantizationStrategy, QuantizationType,
+        GroupQuantization, QTensorPrimitive, Quantization, QuantizationGroups,
+        QuantizationParametersPrimitive, QuantizationScheme, QuantizationStrategy,
+        QuantizationType,
     },
     DType, Shape, TensorData,
 };
 
-use crate::{LibTorch, LibTorchDevice, QuantElement, TchElement, TchQTensor, TchShape, TchTensor};
+use crate::{
+    LibTorch, LibTorchDevice, QuantElement, TchElement, TchQTensor, TchQuantization, TchShape,
+    TchTensor,
+};
 
 use super::TchOps;
 
+/// Reinterpret the stored signed values as unsigned values with the same bits.
+fn as_unsigned(values: &[i8]) -> Vec<u8> {
+    values.iter().map(|v| *v as u8).collect()
+}
+
+/// Convert the quantized values that are not stored in a libtorch quantized tensor back to `f32`.
+fn dequantize_values(quantization: &TchQuantization, values: &[i8], shape: &[usize]) -> Vec<f32> {
+    match quantization.strategy {
+        QuantizationStrategy::PerTensorAffineInt8(s) => s.dequantize(values),
+        QuantizationStrategy::PerTensorSymmetricInt8(s) => s.dequantize(values),
+        QuantizationStrategy::PerTensorAffineUInt8(s) => s.dequantize(&as_unsigned(values)),
+        QuantizationStrategy::PerTensorAffineInt4(s) => s.dequantize(values),
+        QuantizationStrategy::PerTensorSymmetricInt4(s) => s.dequantize(values),
+        QuantizationStrategy::PerTensorNF4(s) => s.dequantize(&as_unsigned(values)),
+        QuantizationStrategy::GroupedAffineInt8(_)
+        | QuantizationStrategy::GroupedSymmetricInt8(_)
+        | QuantizationStrategy::GroupedAffineUInt8(_)
+        | QuantizationStrategy::GroupedAffineInt4(_)
+        | QuantizationStrategy::GroupedSymmetricInt4(_)
+        | QuantizationStrategy::GroupedNF4(_) => quantization
+            .qparams
+            .as_ref()
+            .expect("Per-group quantized tensor should have parameters")
+            .dequantize(&quantization.strategy, values, shape),
+    }
+}
+
 impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
     fn q_from_data<const D: usize>(
         data: TensorData,
@@ -19,6 +50,25 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         let shape_tch = TchShape::<D>::from(data.shape.as_slice());
         let device = (*device).into();
 
+        if let DType::QFloat(strategy) = data.dtype {
+            if !TchQuantization::is_native(&strategy.scheme()) {
+                // Unsigned values are stored as signed 8-bit values with the same bits
+                let values = data.iter::<i32>().map(|v| v as i8).collect::<Vec<_>>();
+                let tensor = tch::Tensor::from_slice(&values)
+                    .to(device)
+                    .reshape(shape_tch.dims);
+
+                return TchQTensor {
+                    qtensor: TchTensor::new(tensor),
+                    scheme: strategy.scheme(),
+                    quantization: Some(TchQuantization {
+                        strategy,
+                        qparams: data.group_quantization(),
+                    }),
+                };
+            }
+        }
+
         // NOTE: tch-rs doesn't have `from_blob_quantized_*` APIs
         // https://github.com/pytorch/pytorch/blob/main/aten/src/ATen/quantized/Quantizer.cpp#L322
         // So for now we have to load the dequantized values to quantize them back since the dequantization
@@ -45,6 +95,59 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
                     .tensor;
                     (tensor, strategy.scheme())
                 }
+                QuantizationStrategy::PerTensorAffineUInt8(q) => {
+                    let values = q.dequantize(&data.iter::<u8>().collect::<Vec<_>>());
+                    let tensor = tch::Tensor::from_slice(&values).to(device);
+                    let tensor = TchOps::<E>::quantize::<D, u8>(
+                        TchTensor::new(tensor.reshape(shape_tch.dims)),
+                        &strategy,
+                    )
+                    .tensor;
+                    (tensor, strategy.scheme())
+                }
+                QuantizationStrategy::GroupedAffineInt8(groups)
+                | QuantizationStrategy::GroupedSymmetricInt8(groups)
+                | QuantizationStrategy::GroupedAffineUInt8(groups) => {
+                    let axis = match groups {
+                        QuantizationGroups::Channel { axis } => axis,
+                        QuantizationGroups::Block { .. } => {
+                            unreachable!("Block-wise quantized values are stored as integers")
+                        }
+                    };
+                    let kind = match strategy.dtype() {
+                        QuantizationType::QUInt8 => tch::Kind::QUInt8,
+                        _ => tch::Kind::QInt8,
+                    };
+                    let params = data
+                        .group_quantization()
+                        .expect("Per-group quantized data should have parameters");
+                    let values = params.dequantize(
+                        &strategy,
+                        &data.iter::<i32>().map(|v| v as i8).collect::<Vec<_>>(),
+                        &data.shape,
+                    );
+                    let tensor = tch::Tensor::from_slice(&values)
+                        .to(device)
+                        .reshape(shape_tch.dims);
+                    let scales = tch::Tensor::from_slice(&params.scales).to(device);
+                    let offsets = match params.offsets {
+                        Some(offsets) => tch::Tensor::from_slice(
+                            &offsets.iter().map(|o| *o as i64).collect::<Vec<_>>(),
+                        )
+                        .to(device),
+                        None => tch::Tensor::zeros_like(&scales).to_kind(tch::Kind::Int64),
+                    };
+                    let tensor = tensor.quantize_per_channel(&scales, &offsets, axis as i64, kind);
+                    (tensor, strategy.scheme())
+                }
+                QuantizationStrategy::PerTensorAffineInt4(_)
+                | QuantizationStrategy::PerTensorSymmetricInt4(_)
+                | QuantizationStrategy::PerTensorNF4(_)
+                | QuantizationStrategy::GroupedAffineInt4(_)
+                | QuantizationStrategy::GroupedSymmetricInt4(_)
+                | QuantizationStrategy::GroupedNF4(_) => {
+                    unreachable!("4-bit quantized values are stored as integers")
+                }
             },
             _ => panic!(
                 "Invalid dtype (expected DType::QFloat, got {:?})",
@@ -54,6 +157,7 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         TchQTensor {
             qtensor: TchTensor::new(tensor),
             scheme,
+            quantization: None,
         }
     }
 
@@ -68,30 +172,107 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
             tensor.tensor = tensor.tensor.to_kind(tch::Kind::Float);
         }
 
+        if !TchQuantization::is_native(scheme) {
+            // Quantize the values on the CPU and store them as integers
+            let device = tensor.tensor.device();
+            let shape = tensor.shape();
+            let values: Vec<f32> = tensor
+                .tensor
+                .to_kind(tch::Kind::Float)
+                .reshape([-1])
+                .try_into()
+                .unwrap();
+            let scales: Vec<f32> = qparams
+                .scale
+                .tensor
+                .to_kind(tch::Kind::Float)
+                .reshape([-1])
+                .try_into()
+                .unwrap();
+            let offsets: Option<Vec<i32>> = qparams.offset.map(|offset| {
+                offset
+                    .tensor
+                    .to_kind(tch::Kind::Int)
+                    .reshape([-1])
+                    .try_into()
+                    .unwrap()
+            });
+
+            let data = TensorData::new(values, shape.dims);
+            let data = match scheme.grouped_strategy() {
+                Some(strategy) => {
+                    data.with_group_quantization(strategy, &GroupQuantization { scales, offsets })
+                }
+                None => {
+                    let offset = offsets.map(|offsets| offsets[0]);
+                    data.with_quantization(scheme.per_tensor_strategy(scales[0], offset).unwrap())
+                }
+            };
+
+            return Self::q_from_data(data, &device.into());
+        }
+
+        let kind = match scheme.dtype() {
+            QuantizationType::QInt8 => tch::Kind::QInt8,
+            QuantizationType::QUInt8 => tch::Kind::QUInt8,
+            QuantizationType::QInt4 | QuantizationType::NF4 => {
+                unreachable!("4-bit quantized values are stored as integers")
+            }
+        };
+
         let qtensor = match scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => tensor.tensor.quantize_per_tensor_tensor_qparams(
+            QuantizationScheme::PerTensorAffine(_) => {
+                tensor.tensor.quantize_per_tensor_tensor_qparams(
                     &qparams.scale.tensor,
                     &qparams.offset.unwrap().tensor,
-                    tch::Kind::QInt8,
-                ),
-            },
+                    kind,
+                )
+            }
             QuantizationScheme::PerTensorSymmetric(_) => {
                 tensor.tensor.quantize_per_tensor_tensor_qparams(
                     &qparams.scale.tensor,
                     &tch::Tensor::zeros_like(&qparams.scale.tensor),
-                    tch::Kind::QInt8,
+                    kind,
+                )
+            }
+            QuantizationScheme::PerChannelAffine { axis, .. } => {
+                tensor.tensor.quantize_per_channel(
+                    &qparams.scale.tensor,
+                    &qparams.offset.unwrap().tensor,
+                    *axis as i64,
+                    kind,
                 )
             }
+            QuantizationScheme::PerChannelSymmetric { axis, .. } => {
+                tensor.tensor.quantize_per_channel(
+                    &qparams.scale.tensor,
+                    &tch::Tensor::zeros_like(&qparams.scale.tensor).to_kind(tch::Kind::Int64),
+                    *axis as i64,
+                    kind,
+                )
+            }
+            QuantizationScheme::BlockAffine { .. } | QuantizationScheme::BlockSymmetric { .. } => {
+                unreachable!("Block-wise quantized values are stored as integers")
+            }
         };
 
         TchQTensor {
             qtensor: TchTensor::new(qtensor),
             scheme: scheme.clone(),
+            quantization: None,
         }
     }
 
     fn dequantize<const D: usize>(tensor: QuantizedTensor<Self, D>) -> FloatTensor<Self, D> {
+        if let Some(quantization) = &tensor.quantization {
+            let shape = tensor.qtensor.shape();
+            let device = tensor.qtensor.tensor.device();
+            let values: Vec<i8> = tensor.qtensor.tensor.reshape([-1]).try_into().unwrap();
+            let values = dequantize_values(quantization, &values, &shape.dims);
+
+            return TchTensor::from_data(TensorData::new(values, shape.dims), device);
+        }
+
         TchTensor::new(tensor.qtensor.tensor.dequantize().to_kind(E::KIND))
     }
 
@@ -107,20 +288,76 @@ impl<E: TchElement, Q: QuantElement> QTensorOps<Self> for LibTorch<E, Q> {
         tensor: QuantizedTensor<Self, D1>,
         shape: Shape<D2>,
     ) -> QuantizedTensor<Self, D2> {
+        let scheme = tensor
+            .scheme
+            .reshape(&tensor.qtensor.shape().dims, &shape.dims)
+            .expect("Reshape should preserve the quantization channel axis");
+        let quantization = tensor.quantization.map(|quantization| TchQuantization {
+            strategy: scheme.grouped_strategy().unwrap_or(quantization.strategy),
+            qparams: quantization.qparams,
+        });
+
         TchQTensor {
             qtensor: TchOps::reshape(tensor.qtensor, shape),
-            scheme: tensor.scheme,
+            scheme,
+            quantization,
         }
     }
 
     async fn q_into_data<const D: usize>(tensor: QuantizedTensor<Self, D>) -> TensorData {
         let shape = Self::q_shape(&tensor);
-        let tensor = Self::q_reshape(tensor.clone(), Shape::new([shape.num_elements()]));
         let strategy = tensor.strategy();
 
-        // To get the integer values we have to call `int_repr()`
-        let values: Result<Vec<i8>, tch::TchError> = tensor.qtensor.tensor.int_repr().try_into();
+        if let Some(quantization) = &tensor.quantization {
+            let values: Result<Vec<i8>, tch::TchError> =
+                tensor.qtensor.tensor.reshape([-1]).try_into();
+
+            return match &quantization.qparams {
+                Some(params) => {
+                    TensorData::quantized_groups(values.unwrap(), shape, strategy, params)
+                }
+                None => TensorData::quantized(values.unwrap(), shape, strategy),
+            };
+        }
 
-        TensorData::quantized(values.unwrap(), shape, strategy)
+        if strategy.groups().is_some() {
+            let qtensor = &tensor.qtensor.tensor;
+            // Unsigned values are returned as signed 8-bit values with the same bits
+            let values: Result<Vec<i32>, tch::TchError> = qtensor
+                .int_repr()
+                .to_kind(tch::Kind::Int)
+                .reshape([-1])
+                .try_into();
+            let scales: Result<Vec<f32>, tch::TchError> = qtensor
+                .q_per_channel_scales()
+                .to_kind(tch::Kind::Float)
+                .try_into();
+            let offsets: Result<Vec<i32>, tch::TchError> = qtensor
+                .q_per_channel_zero_points()
+                .to_kind(tch::Kind::Int)
+                .try_into();
+            let params = GroupQuantization {
+                scales: scales.unwrap(),
+                offsets: strategy.scheme().is_affine().then(|| offsets.unwrap()),
+            };
+            let values = values.unwrap().into_iter().map(|v| v as i8).collect();
+
+            return TensorData::quantized_groups(values, shape, strategy, &params);
+        }
+
+        let tensor = Self::q_reshape(tensor.clone(), Shape::new([shape.num_elements()]));
+
+        // To get the integer values we have to call `int_repr()`
+        let values = tensor.qtensor.tensor.int_repr();
+        match strategy.dtype() {
+            QuantizationType::QUInt8 => {
+                let values: Result<Vec<u8>, tch::TchError> = values.try_into();
+                TensorData::quantized(values.unwrap(), shape, strategy)
+            }
+            _ => {
+                let values: Result<Vec<i8>, tch::TchError> = values.try_into();
+                TensorData::quantized(values.unwrap(), shape, strategy)
+            }
+        }
     }
 }
@@ -1,8 +1,8 @@
 use crate::{LibTorchDevice, QuantElement};
 use burn_tensor::{
     quantization::{
-        AffineQuantization, QTensorPrimitive, QuantizationScheme, QuantizationStrategy,
-        QuantizationType, SymmetricQuantization,
+        GroupQuantization, QTensorPrimitive, QuantizationGroups, QuantizationScheme,
+        QuantizationStrategy,
     },
     Element, Shape, TensorData,
 };
@@ -316,9 +316,35 @@ impl<E: tch::kind::Element + Default + Copy + std::fmt::Debug, const D: usize> T
 #[derive(Clone, Debug)]
 pub struct TchQTensor<Q: QuantElement, const D: usize> {
     /// The quantized tensor.
+    ///
+    /// For the schemes that are not supported by libtorch quantized tensors (see
+    /// [TchQuantization]), this is a plain `int8` tensor of the quantized values.
     pub qtensor: TchTensor<Q, D>,
     /// The quantization scheme.
     pub scheme: QuantizationScheme,
+    /// The quantization of the values that are not stored in a libtorch quantized tensor.
+    pub quantization: Option<TchQuantization>,
+}
+
+/// The quantization parameters of the schemes that libtorch quantized tensors don't support,
+/// i.e. 4-bit and block-wise quantization.
+///
+/// The quantized values are stored one per element (unsigned values with the same bits) and are
+/// dequantized on the CPU.
+#[derive(Clone, Debug)]
+pub struct TchQuantization {
+    /// The quantization strategy.
+    pub strategy: QuantizationStrategy,
+    /// The quantization parameters of each group (per-group strategies only).
+    pub qparams: Option<GroupQuantization>,
+}
+
+impl TchQuantization {
+    /// Returns true if the scheme is supported by libtorch quantized tensors.
+    pub fn is_native(scheme: &QuantizationScheme) -> bool {
+        scheme.dtype().bits() == 8
+            && !matches!(scheme.groups(), Some(QuantizationGroups::Block { .. }))
+    }
 }
 
 impl<Q: QuantElement, const D: usize> QTensorPrimitive for TchQTensor<Q, D> {
@@ -327,26 +353,18 @@ impl<Q: QuantElement, const D: usize> QTensorPrimitive for TchQTensor<Q, D> {
     }
 
     fn strategy(&self) -> QuantizationStrategy {
-        match &self.scheme {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    let scale = self.qtensor.tensor.q_scale();
-                    let offset = self.qtensor.tensor.q_zero_point();
-                    QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(
-                        scale as f32,
-                        offset as i8,
-                    ))
-                }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    let scale = self.qtensor.tensor.q_scale();
-                    QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(
-                        scale as f32,
-                    ))
-                }
-            },
+        if let Some(quantization) = &self.quantization {
+            return quantization.strategy;
         }
+
+        self.scheme.grouped_strategy().unwrap_or_else(|| {
+            let scale = self.qtensor.tensor.q_scale() as f32;
+            let offset = self
+                .scheme
+                .is_affine()
+                .then(|| self.qtensor.tensor.q_zero_point() as i32);
+            self.scheme.per_tensor_strategy(scale, offset).unwrap()
+        })
     }
 }
 
@@ -356,7 +374,9 @@ mod tests {
 
     use super::*;
     use burn_tensor::ops::QTensorOps;
-    use burn_tensor::quantization::QuantizationParametersPrimitive;
+    use burn_tensor::quantization::{
+        AffineQuantization, QuantizationParametersPrimitive, QuantizationType,
+    };
     use burn_tensor::{Distribution, Tensor, TensorPrimitive};
     use rand::prelude::StdRng;
     use rand::SeedableRng;
@@ -439,4 +459,35 @@ mod tests {
             QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(0.009_019_608, 72))
         );
     }
+
+    #[test]
+    fn should_support_block_int4_qtensor_strategy() {
+        let tensor = TchTensor::<f32, 1>::from_data(
+            TensorData::from([-1.8, -1.0, 0.0, 0.5]),
+            tch::Device::Cpu,
+        );
+        let scheme = QuantizationScheme::BlockSymmetric {
+            dtype: QuantizationType::QInt4,
+            block_size: 2,
+        };
+        let qparams = QuantizationParametersPrimitive {
+            scale: TchTensor::from_data(
+                TensorData::from([0.257_142_87, 0.071_428_575]),
+                tch::Device::Cpu,
+            ),
+            offset: None,
+        };
+        let qtensor: TchQTensor<i8, 1> = LibTorch::quantize(tensor, &scheme, qparams);
+
+        assert_eq!(qtensor.scheme(), &scheme);
+        assert_eq!(
+            qtensor.strategy(),
+            QuantizationStrategy::GroupedSymmetricInt4(QuantizationGroups::Block { size: 2 })
+        );
+
+        let tensor = LibTorch::<f32>::dequantize(qtensor);
+        Tensor::<LibTorch<f32>, 1>::from_primitive(TensorPrimitive::Float(tensor))
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.8f32, -1.028_571_4, 0.0, 0.5]), 3);
+    }
 }
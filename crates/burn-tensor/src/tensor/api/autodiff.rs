@@ -1,5 +1,8 @@
+use alloc::sync::Arc;
+
 use crate::{
-    backend::AutodiffBackend, BasicOps, Bool, Float, Int, Tensor, TensorKind, TensorPrimitive,
+    backend::{AutodiffBackend, GradientHook},
+    BasicOps, Bool, Float, Int, Tensor, TensorKind, TensorPrimitive,
 };
 
 impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
@@ -52,6 +55,37 @@ impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
             ),
         }
     }
+
+    /// Register a hook called with the gradient of the tensor during the backward pass.
+    ///
+    /// The hook receives the gradient of the tensor once it is fully accumulated, and returns the
+    /// gradient that is stored in the [grads](AutodiffBackend::Gradients) struct and propagated
+    /// to the tensors it depends on. This can be used to inspect gradients, e.g. logging their
+    /// norm, or to modify them, e.g. clipping or reversing them.
+    ///
+    /// The hook is never called if the tensor isn't tracked by the autodiff graph. Each call
+    /// registers a new hook, even with the same function, and the hooks of a tensor are called in
+    /// the order they were registered.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Gradient reversal.
+    /// let x = x.require_grad();
+    /// x.register_hook(|grad| grad.neg());
+    /// ```
+    pub fn register_hook<F>(&self, hook: F)
+    where
+        F: Fn(Tensor<B::InnerBackend, D>) -> Tensor<B::InnerBackend, D> + Send + Sync + 'static,
+    {
+        let hook: GradientHook<B::InnerBackend, D> = Arc::new(move |grad| {
+            hook(Tensor::new(TensorPrimitive::Float(grad)))
+                .primitive
+                .tensor()
+        });
+
+        B::register_hook(&self.primitive.clone().tensor(), hook);
+    }
 }
 
 impl<const D: usize, B: AutodiffBackend, K: BasicAutodiffOps<B>> Tensor<B, D, K> {
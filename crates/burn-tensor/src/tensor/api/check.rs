@@ -954,6 +954,206 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn cumulative_dim<const D: usize>(ops: &str, dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't compute a cumulative operation on a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the tensor is a matrix or a batch of matrices.
+    pub(crate) fn linalg_matrix<const D: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The input tensor must have at least 2 dimensions, got {D}"
+                )),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the tensor is a square matrix or a batch of square matrices.
+    pub(crate) fn linalg_square<const D: usize>(ops: &str, shape: &Shape<D>) -> Self {
+        let check = Self::linalg_matrix::<D>(ops);
+
+        if D >= 2 && shape.dims[D - 2] != shape.dims[D - 1] {
+            return check.register(
+                ops,
+                TensorError::new("The input matrices must be square")
+                    .details(format!("Got shape {:?}", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    /// Checks the shapes of the linear system `a x = b`.
+    pub(crate) fn linalg_solve<const D: usize>(shape_a: &Shape<D>, shape_b: &Shape<D>) -> Self {
+        let check = Self::linalg_square::<D>("Solve", shape_a);
+
+        if D < 2 {
+            return check;
+        }
+
+        if shape_a.dims[..D - 2] != shape_b.dims[..D - 2] {
+            return check.register(
+                "Solve",
+                TensorError::new("The batch dimensions of the coefficients and the right-hand side must be equal")
+                    .details(format!(
+                        "Got coefficients shape {:?} and right-hand side shape {:?}",
+                        shape_a.dims, shape_b.dims
+                    )),
+            );
+        }
+
+        if shape_a.dims[D - 1] != shape_b.dims[D - 2] {
+            return check.register(
+                "Solve",
+                TensorError::new("The number of rows of the right-hand side must match the size of the coefficient matrices")
+                    .details(format!(
+                        "Got coefficients shape {:?} and right-hand side shape {:?}",
+                        shape_a.dims, shape_b.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    /// Checks the result of the validation of an einsum equation against its operands.
+    pub(crate) fn einsum(equation: &str, error: Option<&str>) -> Self {
+        let mut check = Self::Ok;
+
+        if let Some(error) = error {
+            check = check.register(
+                "Einsum",
+                TensorError::new(error).details(format!("Equation: '{equation}'")),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the real and imaginary parts of complex tensors have the same shape, and that
+    /// they have enough dimensions to be transformed.
+    pub(crate) fn fft_complex<const D: usize>(
+        ops: &str,
+        num_dims: usize,
+        shape_re: &Shape<D>,
+        shape_im: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D < num_dims {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The input tensor must have at least {num_dims} dimensions, got {D}"
+                )),
+            );
+        }
+
+        if shape_re != shape_im {
+            check = check.register(
+                ops,
+                TensorError::new("The real and imaginary parts must have the same shape").details(
+                    format!(
+                        "Real part shape {:?}, imaginary part shape {:?}.",
+                        shape_re.dims, shape_im.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the number of frequency bins matches the length of the output of an inverse
+    /// real FFT.
+    pub(crate) fn irfft(ops: &str, num_bins: usize, n: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if n == 0 || num_bins != n / 2 + 1 {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "An output of length {n} requires {} frequency bins, got {num_bins}",
+                    n / 2 + 1
+                )),
+            );
+        }
+
+        check
+    }
+
+    /// Checks the arguments of the short-time Fourier transform and its inverse.
+    pub(crate) fn stft(
+        ops: &str,
+        n_fft: usize,
+        hop_length: usize,
+        window_size: Option<usize>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if n_fft == 0 || hop_length == 0 {
+            check = check.register(
+                ops,
+                TensorError::new("The FFT size and the hop length must be positive")
+                    .details(format!("Got n_fft {n_fft} and hop_length {hop_length}.")),
+            );
+        }
+
+        if let Some(window_size) = window_size {
+            if window_size != n_fft {
+                check = check.register(
+                    ops,
+                    TensorError::new(format!(
+                        "The window size must be equal to the FFT size ({n_fft}), got {window_size}"
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    /// Checks that a signal is long enough to be split into frames.
+    pub(crate) fn stft_signal(length: usize, n_fft: usize, center: bool) -> Self {
+        let mut check = Self::Ok;
+
+        if center && n_fft / 2 >= length {
+            check = check.register(
+                "STFT",
+                TensorError::new(format!(
+                    "The reflect padding ({}) must be smaller than the signal length ({length})",
+                    n_fft / 2
+                )),
+            );
+        } else if !center && n_fft > length {
+            check = check.register(
+                "STFT",
+                TensorError::new(format!(
+                    "The FFT size ({n_fft}) must not exceed the signal length ({length})"
+                )),
+            );
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occurred, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
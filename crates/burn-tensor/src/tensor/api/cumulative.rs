@@ -0,0 +1,188 @@
+use crate::{backend::Backend, Bool, Element, Int, Numeric, Tensor};
+use alloc::vec;
+
+/// Compute an inclusive scan of the `tensor` along a given dimension.
+///
+/// The scan is computed in `log2(n)` steps (Hillis-Steele), where each value is combined with the
+/// accumulated value `offset` positions before.
+fn scan<B, const D: usize, K, F>(tensor: Tensor<B, D, K>, dim: usize, combine: F) -> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+    F: Fn(Tensor<B, D, K>, Tensor<B, D, K>) -> Tensor<B, D, K>,
+{
+    let size = tensor.dims()[dim];
+    let mut output = tensor;
+    let mut offset = 1;
+
+    while offset < size {
+        let head = output.clone().narrow(dim, 0, offset);
+        let previous = output.clone().narrow(dim, 0, size - offset);
+        let current = output.narrow(dim, offset, size - offset);
+
+        output = Tensor::cat(vec![head, combine(previous, current)], dim);
+        offset *= 2;
+    }
+
+    output
+}
+
+/// Compute an inclusive scan of the `tensor` along a given dimension, where the previous value is
+/// selected over the current value when `select` is true. Also returns the indices of the selected
+/// values.
+fn scan_with_indices<B, const D: usize, K, F>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+    select: F,
+) -> (Tensor<B, D, K>, Tensor<B, D, Int>)
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+    F: Fn(Tensor<B, D, K>, Tensor<B, D, K>) -> Tensor<B, D, Bool>,
+{
+    let size = tensor.dims()[dim];
+    let mut shape = [1; D];
+    shape[dim] = size;
+
+    let mut indices = Tensor::<B, 1, Int>::arange(0..size as i64, &tensor.device())
+        .reshape(shape)
+        .expand(tensor.shape());
+    let mut output = tensor;
+    let mut offset = 1;
+
+    while offset < size {
+        let head = output.clone().narrow(dim, 0, offset);
+        let previous = output.clone().narrow(dim, 0, size - offset);
+        let current = output.narrow(dim, offset, size - offset);
+
+        let head_indices = indices.clone().narrow(dim, 0, offset);
+        let previous_indices = indices.clone().narrow(dim, 0, size - offset);
+        let current_indices = indices.narrow(dim, offset, size - offset);
+
+        let mask = select(previous.clone(), current.clone());
+        let values = current.mask_where(mask.clone(), previous);
+        let values_indices = current_indices.mask_where(mask, previous_indices);
+
+        output = Tensor::cat(vec![head, values], dim);
+        indices = Tensor::cat(vec![head_indices, values_indices], dim);
+        offset *= 2;
+    }
+
+    (output, indices)
+}
+
+/// Computes the cumulative sum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the cumulative sum.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the sum of all the
+/// previous elements (inclusive) along the dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cumsum<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+) -> Tensor<B, D, K>
+where
+    K::Elem: Element,
+{
+    scan(tensor, dim, |previous, current| previous.add(current))
+}
+
+/// Computes the cumulative product of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the cumulative product.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the product of all the
+/// previous elements (inclusive) along the dimension.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cumprod<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+) -> Tensor<B, D, K>
+where
+    K::Elem: Element,
+{
+    scan(tensor, dim, |previous, current| previous.mul(current))
+}
+
+/// Computes the cumulative maximum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the cumulative maximum.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the maximum of all the
+/// previous elements (inclusive) along the dimension, and the indices of the maximum values. The
+/// index of the last occurrence is returned for equal values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cummax<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+) -> (Tensor<B, D, K>, Tensor<B, D, Int>)
+where
+    K::Elem: Element,
+{
+    scan_with_indices(tensor, dim, |previous, current| previous.greater(current))
+}
+
+/// Computes the cumulative minimum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to compute the cumulative minimum.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the minimum of all the
+/// previous elements (inclusive) along the dimension, and the indices of the minimum values. The
+/// index of the last occurrence is returned for equal values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cummin<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+) -> (Tensor<B, D, K>, Tensor<B, D, Int>)
+where
+    K::Elem: Element,
+{
+    scan_with_indices(tensor, dim, |previous, current| previous.lower(current))
+}
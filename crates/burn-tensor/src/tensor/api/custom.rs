@@ -0,0 +1,149 @@
+use alloc::sync::Arc;
+
+use crate::{
+    backend::{AutodiffBackend, CustomBackward, CustomForward},
+    Tensor, TensorPrimitive,
+};
+
+/// A differentiable operation defined by its forward and backward functions.
+///
+/// The forward function returns the output of the operation and a state of type `S` saved for
+/// the backward pass, e.g. intermediate tensors, or `()` when nothing needs to be saved. The
+/// backward function receives the inputs of the operation, the saved state and the gradient of
+/// its output, and returns the gradients of the inputs, which is useful when the analytical
+/// gradients of an operation are cheaper to compute than the ones derived by the autodiff
+/// backend. Both functions operate on tensors of the inner backend, so their operations aren't
+/// tracked.
+///
+/// The inputs are saved for the backward pass according to the checkpointing strategy of the
+/// backend. The saved state is kept until the backward pass, unless the operation is
+/// [memory bound](CustomOp::memory_bound), in which case it is recomputed with the forward
+/// function.
+///
+/// All the inputs have the same rank `D_IN`. Inputs of a lower rank, e.g. the bias of a linear
+/// layer, can be unsqueezed before being passed to the operation, with gradients of the
+/// unsqueezed shape.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // The sigmoid, whose output is saved to compute the gradient.
+/// let sigmoid = CustomOp::new(
+///     |[x]| {
+///         let y = activation::sigmoid(x);
+///         (y.clone(), y)
+///     },
+///     |[_x], y, grad| [Some(grad * y.clone() * (y.neg() + 1.0))],
+/// );
+///
+/// let y = sigmoid.apply([x]);
+///
+/// // y = x @ w + b, with the bias unsqueezed to the rank of the other inputs.
+/// let linear = CustomOp::new(
+///     |[x, w, b]| (x.matmul(w) + b, ()),
+///     |[x, w, _b], (), grad| {
+///         [
+///             Some(grad.clone().matmul(w.transpose())),
+///             Some(x.transpose().matmul(grad.clone())),
+///             Some(grad.sum_dim(0)),
+///         ]
+///     },
+/// );
+///
+/// let y = linear.apply([x, w, b.unsqueeze()]);
+/// ```
+pub struct CustomOp<
+    B: AutodiffBackend,
+    const N: usize,
+    const D_IN: usize,
+    const D_OUT: usize,
+    S = (),
+> {
+    forward: CustomForward<B::InnerBackend, N, D_IN, D_OUT, S>,
+    backward: CustomBackward<B::InnerBackend, N, D_IN, D_OUT, S>,
+    memory_bound: bool,
+}
+
+impl<B: AutodiffBackend, const N: usize, const D_IN: usize, const D_OUT: usize, S>
+    CustomOp<B, N, D_IN, D_OUT, S>
+where
+    S: Clone + Send + 'static,
+{
+    /// Create a new custom operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - The function computing the output and the saved state from the inputs.
+    /// * `backward` - The function computing the gradients of the inputs from the inputs, the
+    ///   saved state and the gradient of the output. The gradients of inputs that don't require
+    ///   gradients can be `None`.
+    pub fn new<F, G>(forward: F, backward: G) -> Self
+    where
+        F: Fn([Tensor<B::InnerBackend, D_IN>; N]) -> (Tensor<B::InnerBackend, D_OUT>, S)
+            + Send
+            + Sync
+            + 'static,
+        G: Fn(
+                [Tensor<B::InnerBackend, D_IN>; N],
+                S,
+                Tensor<B::InnerBackend, D_OUT>,
+            ) -> [Option<Tensor<B::InnerBackend, D_IN>>; N]
+            + Send
+            + Sync
+            + 'static,
+    {
+        let forward: CustomForward<B::InnerBackend, N, D_IN, D_OUT, S> = Arc::new(move |inputs| {
+            let (output, saved) =
+                forward(inputs.map(|input| Tensor::new(TensorPrimitive::Float(input))));
+
+            (output.primitive.tensor(), saved)
+        });
+        let backward: CustomBackward<B::InnerBackend, N, D_IN, D_OUT, S> =
+            Arc::new(move |inputs, saved, grad| {
+                backward(
+                    inputs.map(|input| Tensor::new(TensorPrimitive::Float(input))),
+                    saved,
+                    Tensor::new(TensorPrimitive::Float(grad)),
+                )
+                .map(|grad| grad.map(|grad| grad.primitive.tensor()))
+            });
+
+        Self {
+            forward,
+            backward,
+            memory_bound: false,
+        }
+    }
+
+    /// Mark the operation as memory bound, meaning it is cheap to compute.
+    ///
+    /// The saved state of a memory bound operation isn't kept in memory, it is recomputed with
+    /// the forward function during the backward pass. When the backend uses a checkpointing
+    /// strategy, the output can also be recomputed instead of being kept in memory.
+    pub fn memory_bound(mut self) -> Self {
+        self.memory_bound = true;
+        self
+    }
+
+    /// Apply the operation on the given inputs.
+    pub fn apply(&self, inputs: [Tensor<B, D_IN>; N]) -> Tensor<B, D_OUT> {
+        Tensor::new(TensorPrimitive::Float(B::custom_op(
+            inputs.map(|input| input.primitive.tensor()),
+            self.forward.clone(),
+            self.backward.clone(),
+            self.memory_bound,
+        )))
+    }
+}
+
+impl<B: AutodiffBackend, const N: usize, const D_IN: usize, const D_OUT: usize, S> Clone
+    for CustomOp<B, N, D_IN, D_OUT, S>
+{
+    fn clone(&self) -> Self {
+        Self {
+            forward: self.forward.clone(),
+            backward: self.backward.clone(),
+            memory_bound: self.memory_bound,
+        }
+    }
+}
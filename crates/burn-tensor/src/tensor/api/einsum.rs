@@ -0,0 +1,629 @@
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Tensor};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The maximum number of dimensions of the permutations used to lower an einsum equation, after
+/// merging the dimensions that stay contiguous.
+const MAX_PERMUTE_RANK: usize = 8;
+
+/// A tensor that can be used as an operand of [einsum](Tensor::einsum).
+pub trait EinsumOperand<B: Backend> {
+    /// Flattens the operand, returning it with its original dimensions.
+    fn into_flat(self) -> (Tensor<B, 1>, Vec<usize>);
+}
+
+/// A collection of operands of [einsum](Tensor::einsum).
+///
+/// This is implemented for arrays and vectors of tensors with the same rank, and for tuples of
+/// up to 4 tensors with different ranks.
+pub trait EinsumOperands<B: Backend> {
+    /// Flattens the operands, returning them with their original dimensions.
+    fn into_flat(self) -> Vec<(Tensor<B, 1>, Vec<usize>)>;
+}
+
+impl<B: Backend, const D: usize> EinsumOperand<B> for Tensor<B, D> {
+    fn into_flat(self) -> (Tensor<B, 1>, Vec<usize>) {
+        let shape = self.shape();
+        (self.reshape([shape.num_elements()]), shape.dims.to_vec())
+    }
+}
+
+impl<B: Backend, T: EinsumOperand<B>, const N: usize> EinsumOperands<B> for [T; N] {
+    fn into_flat(self) -> Vec<(Tensor<B, 1>, Vec<usize>)> {
+        self.into_iter().map(EinsumOperand::into_flat).collect()
+    }
+}
+
+impl<B: Backend, T: EinsumOperand<B>> EinsumOperands<B> for Vec<T> {
+    fn into_flat(self) -> Vec<(Tensor<B, 1>, Vec<usize>)> {
+        self.into_iter().map(EinsumOperand::into_flat).collect()
+    }
+}
+
+macro_rules! einsum_operands_tuple {
+    ($($name:ident),+) => {
+        impl<B: Backend, $($name: EinsumOperand<B>),+> EinsumOperands<B> for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn into_flat(self) -> Vec<(Tensor<B, 1>, Vec<usize>)> {
+                let ($($name,)+) = self;
+                vec![$($name.into_flat()),+]
+            }
+        }
+    };
+}
+
+einsum_operands_tuple!(T1);
+einsum_operands_tuple!(T1, T2);
+einsum_operands_tuple!(T1, T2, T3);
+einsum_operands_tuple!(T1, T2, T3, T4);
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Evaluates the Einstein summation convention on the operands.
+    ///
+    /// The equation lists the subscripts of each operand separated by commas, optionally followed
+    /// by `->` and the subscripts of the output. Subscripts are ASCII letters, and `...` stands
+    /// for the dimensions that aren't labeled, which are aligned from the end across operands.
+    /// Labels that are repeated in an operand take its diagonal, and labels that are missing from
+    /// the output are summed. Without `->`, the output is made of the unlabeled dimensions
+    /// followed by the labels that appear once, in alphabetical order.
+    ///
+    /// The contraction order is planned greedily, contracting first the pair of operands that
+    /// produces the smallest intermediate tensor. The equation is lowered to `permute`, `reshape`,
+    /// `matmul` and `sum_dim` operations, so it's supported by every backend and by autodiff.
+    ///
+    /// # Arguments
+    ///
+    /// * `equation` - The subscripts of the operands and of the output, e.g. `"bhqd,bhkd->bhqk"`.
+    /// * `operands` - An array or a vector of tensors with the same rank, or a tuple of tensors.
+    ///
+    /// # Panics
+    ///
+    /// If the equation is invalid, if it doesn't match the rank of the operands or of the output,
+    /// or if the dimensions with the same label don't have the same size. Also panics if an
+    /// operand must be permuted over more than 8 dimensions (after merging the dimensions that
+    /// stay contiguous), which is the highest rank supported by the permutations. An output
+    /// without any label is returned with the shape `[1]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let q = Tensor::<B, 4>::ones([2, 4, 8, 16], &device);
+    ///     let k = Tensor::<B, 4>::ones([2, 4, 10, 16], &device);
+    ///     let scores = Tensor::<B, 4>::einsum("bhqd,bhkd->bhqk", [q, k]);
+    ///     println!("{:?}", scores.dims()); // [2, 4, 8, 10]
+    ///
+    ///     let matrix = Tensor::<B, 2>::ones([3, 4], &device);
+    ///     let vector = Tensor::<B, 1>::ones([4], &device);
+    ///     let output = Tensor::<B, 1>::einsum("ij,j->i", (matrix, vector));
+    ///     println!("{:?}", output.dims()); // [3]
+    /// }
+    /// ```
+    pub fn einsum<O: EinsumOperands<B>>(equation: &str, operands: O) -> Self {
+        let operands = operands.into_flat();
+        let ranks = operands
+            .iter()
+            .map(|(_, dims)| dims.len())
+            .collect::<Vec<_>>();
+
+        let parsed = parse(equation, &ranks).and_then(|(inputs, output)| {
+            let sizes = label_sizes(&inputs, &operands)?;
+            let output_rank = usize::max(output.len(), 1);
+
+            if output_rank != D {
+                return Err(format!(
+                    "The output has {} dimensions, but the result tensor has {D} dimensions.",
+                    output.len()
+                ));
+            }
+
+            Ok((inputs, output, sizes))
+        });
+
+        check!(TensorCheck::einsum(
+            equation,
+            parsed.as_ref().err().map(String::as_str)
+        ));
+        let (inputs, output, sizes) = parsed.unwrap();
+
+        let operands = operands
+            .into_iter()
+            .zip(inputs)
+            .map(|((tensor, dims), labels)| Operand {
+                tensor,
+                labels,
+                dims,
+            })
+            .collect();
+
+        let result = contract(operands, &output, &sizes);
+        let mut shape = [1; D];
+        shape[..result.dims.len()].copy_from_slice(&result.dims);
+
+        result.tensor.reshape(shape)
+    }
+}
+
+/// A dimension label, where the unlabeled dimensions are mapped to private use characters.
+type Label = char;
+
+/// Returns the label of the unlabeled dimension at the given position from the end.
+fn ellipsis_label(position: usize) -> Label {
+    char::from_u32(0xE000 + position as u32).unwrap()
+}
+
+/// Parses the subscripts of a term, returning the labels and whether it has an ellipsis.
+fn parse_term(term: &str) -> Result<(Vec<Label>, Option<usize>), String> {
+    let mut labels = Vec::new();
+    let mut ellipsis = None;
+    let mut chars = term.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                if chars.next() != Some('.') || chars.next() != Some('.') {
+                    return Err(format!("Invalid ellipsis in the subscripts '{term}'."));
+                }
+                if ellipsis.is_some() {
+                    return Err(format!("Multiple ellipses in the subscripts '{term}'."));
+                }
+                ellipsis = Some(labels.len());
+            }
+            c if c.is_ascii_alphabetic() => labels.push(c),
+            c => {
+                return Err(format!(
+                    "Invalid character '{c}' in the subscripts '{term}', only ASCII letters are supported."
+                ))
+            }
+        }
+    }
+
+    Ok((labels, ellipsis))
+}
+
+/// Inserts the labels of the unlabeled dimensions at the position of the ellipsis.
+fn expand_ellipsis(labels: Vec<Label>, ellipsis: Option<usize>, num_ellipsis: usize) -> Vec<Label> {
+    match ellipsis {
+        Some(position) => {
+            let mut expanded = labels[..position].to_vec();
+            expanded.extend((0..num_ellipsis).rev().map(ellipsis_label));
+            expanded.extend_from_slice(&labels[position..]);
+            expanded
+        }
+        None => labels,
+    }
+}
+
+/// Parses the equation, returning the labels of the operands and of the output.
+fn parse(equation: &str, ranks: &[usize]) -> Result<(Vec<Vec<Label>>, Vec<Label>), String> {
+    let equation: String = equation.chars().filter(|c| !c.is_whitespace()).collect();
+    let (inputs, output) = match equation.split_once("->") {
+        Some((inputs, output)) => (inputs, Some(output)),
+        None => (equation.as_str(), None),
+    };
+
+    let terms = inputs.split(',').collect::<Vec<_>>();
+    if terms.len() != ranks.len() {
+        return Err(format!(
+            "The equation has {} operands, but {} tensors were given.",
+            terms.len(),
+            ranks.len()
+        ));
+    }
+
+    let mut num_ellipsis = 0;
+    let mut parsed = Vec::with_capacity(terms.len());
+
+    for (term, &rank) in terms.iter().zip(ranks) {
+        let (labels, ellipsis) = parse_term(term)?;
+        let valid = match ellipsis {
+            Some(_) => labels.len() <= rank,
+            None => labels.len() == rank,
+        };
+
+        if !valid {
+            return Err(format!(
+                "The subscripts '{term}' don't match the rank of the operand ({rank})."
+            ));
+        }
+
+        let num_unlabeled = rank - labels.len();
+        num_ellipsis = usize::max(num_ellipsis, num_unlabeled);
+        parsed.push(expand_ellipsis(labels, ellipsis, num_unlabeled));
+    }
+
+    let output = match output {
+        Some(term) => {
+            let (labels, ellipsis) = parse_term(term)?;
+            let labels = expand_ellipsis(labels, ellipsis, num_ellipsis);
+
+            for (i, label) in labels.iter().enumerate() {
+                if labels[..i].contains(label) {
+                    return Err(format!(
+                        "The label '{label}' appears more than once in the output."
+                    ));
+                }
+                if !parsed.iter().any(|labels| labels.contains(label)) {
+                    return Err(format!(
+                        "The output label '{label}' doesn't appear in the operands."
+                    ));
+                }
+            }
+
+            labels
+        }
+        None => {
+            let mut output = (0..num_ellipsis)
+                .rev()
+                .map(ellipsis_label)
+                .collect::<Vec<_>>();
+            let mut single = parsed
+                .iter()
+                .flatten()
+                .filter(|label| label.is_ascii_alphabetic())
+                .filter(|label| parsed.iter().flatten().filter(|l| l == label).count() == 1)
+                .copied()
+                .collect::<Vec<_>>();
+            single.sort();
+            output.extend(single);
+            output
+        }
+    };
+
+    Ok((parsed, output))
+}
+
+/// Collects the size of each label, checking that they are consistent across the operands.
+fn label_sizes<B: Backend>(
+    inputs: &[Vec<Label>],
+    operands: &[(Tensor<B, 1>, Vec<usize>)],
+) -> Result<Vec<(Label, usize)>, String> {
+    let mut sizes: Vec<(Label, usize)> = Vec::new();
+
+    for (labels, (_, dims)) in inputs.iter().zip(operands) {
+        for (&label, &size) in labels.iter().zip(dims) {
+            match sizes.iter().find(|(l, _)| *l == label) {
+                Some((_, expected)) if *expected != size => {
+                    let name = match label.is_ascii_alphabetic() {
+                        true => format!("label '{label}'"),
+                        false => String::from("ellipsis dimension"),
+                    };
+                    return Err(format!(
+                        "The {name} has inconsistent sizes: {expected} and {size}."
+                    ));
+                }
+                Some(_) => {}
+                None => sizes.push((label, size)),
+            }
+        }
+    }
+
+    Ok(sizes)
+}
+
+/// A flattened operand with the labels of its dimensions.
+struct Operand<B: Backend> {
+    tensor: Tensor<B, 1>,
+    labels: Vec<Label>,
+    dims: Vec<usize>,
+}
+
+impl<B: Backend> Operand<B> {
+    /// Sums the dimension at the given position.
+    fn sum_dim(self, dim: usize) -> Self {
+        let outer = self.dims[..dim].iter().product::<usize>();
+        let inner = self.dims[dim + 1..].iter().product::<usize>();
+        let tensor = self
+            .tensor
+            .reshape([outer, self.dims[dim], inner])
+            .sum_dim(1)
+            .reshape([outer * inner]);
+
+        let mut labels = self.labels;
+        let mut dims = self.dims;
+        labels.remove(dim);
+        dims.remove(dim);
+
+        Self {
+            tensor,
+            labels,
+            dims,
+        }
+    }
+
+    /// Takes the diagonal of the dimensions at the given positions, removing the second one.
+    fn diagonal(self, dim1: usize, dim2: usize) -> Self {
+        let size = self.dims[dim1];
+        let outer = self.dims[..dim1].iter().product::<usize>();
+        let middle = self.dims[dim1 + 1..dim2].iter().product::<usize>();
+        let inner = self.dims[dim2 + 1..].iter().product::<usize>();
+
+        let mask = Tensor::<B, 2>::eye(size, &self.tensor.device()).reshape([1, size, 1, size, 1]);
+        let tensor = self
+            .tensor
+            .reshape([outer, size, middle, size, inner])
+            .mul(mask)
+            .sum_dim(3)
+            .reshape([outer * size * middle * inner]);
+
+        let mut labels = self.labels;
+        let mut dims = self.dims;
+        labels.remove(dim2);
+        dims.remove(dim2);
+
+        Self {
+            tensor,
+            labels,
+            dims,
+        }
+    }
+
+    /// Takes the diagonals of the repeated labels and sums the labels that aren't kept.
+    fn reduce<F: Fn(Label) -> bool>(mut self, keep: F) -> Self {
+        while let Some((dim1, dim2)) = (0..self.labels.len()).find_map(|dim2| {
+            (0..dim2)
+                .find(|&dim1| self.labels[dim1] == self.labels[dim2])
+                .map(|dim1| (dim1, dim2))
+        }) {
+            self = self.diagonal(dim1, dim2);
+        }
+
+        while let Some(dim) = (0..self.labels.len())
+            .rev()
+            .find(|&i| !keep(self.labels[i]))
+        {
+            self = self.sum_dim(dim);
+        }
+
+        self
+    }
+
+    /// Reorders the dimensions to follow the given labels.
+    fn permute(self, labels: &[Label]) -> Self {
+        let axes = labels
+            .iter()
+            .map(|label| self.labels.iter().position(|l| l == label).unwrap())
+            .collect::<Vec<_>>();
+        let dims = axes.iter().map(|&axis| self.dims[axis]).collect();
+        let tensor = permute(self.tensor, &self.dims, &axes);
+
+        Self {
+            tensor,
+            labels: labels.to_vec(),
+            dims,
+        }
+    }
+}
+
+/// Contracts all the operands into a tensor with the output labels.
+fn contract<B: Backend>(
+    operands: Vec<Operand<B>>,
+    output: &[Label],
+    sizes: &[(Label, usize)],
+) -> Operand<B> {
+    let size_of = |labels: &[Label]| -> usize {
+        labels
+            .iter()
+            .map(|label| sizes.iter().find(|(l, _)| l == label).unwrap().1)
+            .product()
+    };
+
+    // The labels that only appear in a single operand are summed before planning the contractions.
+    let labels = operands
+        .iter()
+        .map(|operand| operand.labels.clone())
+        .collect::<Vec<_>>();
+    let mut operands = operands
+        .into_iter()
+        .enumerate()
+        .map(|(i, operand)| {
+            operand.reduce(|label| {
+                output.contains(&label)
+                    || labels
+                        .iter()
+                        .enumerate()
+                        .any(|(j, labels)| j != i && labels.contains(&label))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    while operands.len() > 1 {
+        let (i, j) = plan(&operands, output, size_of);
+        let rhs = operands.remove(j);
+        let lhs = operands.remove(i);
+
+        let result = contract_pair(lhs, rhs, |label| {
+            output.contains(&label)
+                || operands
+                    .iter()
+                    .any(|operand| operand.labels.contains(&label))
+        });
+        operands.push(result);
+    }
+
+    operands
+        .pop()
+        .unwrap()
+        .reduce(|label| output.contains(&label))
+        .permute(output)
+}
+
+/// Selects the pair of operands to contract, which is the one producing the smallest
+/// intermediate tensor, with the number of multiplications as a tie-breaker.
+fn plan<B: Backend, F: Fn(&[Label]) -> usize>(
+    operands: &[Operand<B>],
+    output: &[Label],
+    size_of: F,
+) -> (usize, usize) {
+    let mut best = None;
+
+    for i in 0..operands.len() {
+        for j in i + 1..operands.len() {
+            let mut union = operands[i].labels.clone();
+            union.extend(
+                operands[j]
+                    .labels
+                    .iter()
+                    .filter(|label| !operands[i].labels.contains(label)),
+            );
+
+            let kept = union
+                .iter()
+                .filter(|label| {
+                    output.contains(label)
+                        || operands
+                            .iter()
+                            .enumerate()
+                            .any(|(k, operand)| k != i && k != j && operand.labels.contains(label))
+                })
+                .copied()
+                .collect::<Vec<_>>();
+
+            let cost = (size_of(&kept), size_of(&union));
+            let better = match best {
+                Some((best_cost, _)) => cost < best_cost,
+                None => true,
+            };
+
+            if better {
+                best = Some((cost, (i, j)));
+            }
+        }
+    }
+
+    best.unwrap().1
+}
+
+/// Contracts two operands with a batched matrix multiplication, keeping the given labels.
+fn contract_pair<B: Backend, F: Fn(Label) -> bool>(
+    lhs: Operand<B>,
+    rhs: Operand<B>,
+    keep: F,
+) -> Operand<B> {
+    let lhs = lhs.reduce(|label| keep(label) || rhs.labels.contains(&label));
+    let rhs = rhs.reduce(|label| keep(label) || lhs.labels.contains(&label));
+
+    let shared = |label: &&Label| rhs.labels.contains(label);
+    let batch = lhs
+        .labels
+        .iter()
+        .filter(shared)
+        .filter(|label| keep(**label))
+        .copied()
+        .collect::<Vec<_>>();
+    let contracted = lhs
+        .labels
+        .iter()
+        .filter(shared)
+        .filter(|label| !keep(**label))
+        .copied()
+        .collect::<Vec<_>>();
+    let left = lhs
+        .labels
+        .iter()
+        .filter(|label| !rhs.labels.contains(label))
+        .copied()
+        .collect::<Vec<_>>();
+    let right = rhs
+        .labels
+        .iter()
+        .filter(|label| !lhs.labels.contains(label))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let lhs = lhs.permute(&[batch.as_slice(), left.as_slice(), contracted.as_slice()].concat());
+    let rhs = rhs.permute(&[batch.as_slice(), contracted.as_slice(), right.as_slice()].concat());
+
+    let (b, m) = (batch.len(), left.len());
+    let size_batch = lhs.dims[..b].iter().product::<usize>();
+    let size_left = lhs.dims[b..b + m].iter().product::<usize>();
+    let size_contracted = lhs.dims[b + m..].iter().product::<usize>();
+    let size_right = rhs.dims[b + contracted.len()..].iter().product::<usize>();
+
+    let lhs_matrix = lhs.tensor.reshape([size_batch, size_left, size_contracted]);
+    let rhs_matrix = rhs
+        .tensor
+        .reshape([size_batch, size_contracted, size_right]);
+    let tensor = lhs_matrix
+        .matmul(rhs_matrix)
+        .reshape([size_batch * size_left * size_right]);
+
+    let mut dims = lhs.dims[..b + m].to_vec();
+    dims.extend_from_slice(&rhs.dims[b + contracted.len()..]);
+
+    Operand {
+        tensor,
+        labels: [batch, left, right].concat(),
+        dims,
+    }
+}
+
+/// Permutes the dimensions of a flattened tensor.
+///
+/// The dimensions that stay contiguous are merged, which lowers the rank of the permutation.
+fn permute<B: Backend>(tensor: Tensor<B, 1>, dims: &[usize], axes: &[usize]) -> Tensor<B, 1> {
+    // Groups of contiguous source axes, in the output order.
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for &axis in axes {
+        match groups.last_mut() {
+            Some(group) if group[group.len() - 1] + 1 == axis => group.push(axis),
+            _ => groups.push(vec![axis]),
+        }
+    }
+
+    if groups.len() <= 1 {
+        return tensor;
+    }
+
+    let mut source_order = (0..groups.len()).collect::<Vec<_>>();
+    source_order.sort_by_key(|&group| groups[group][0]);
+
+    let merged_dims = source_order
+        .iter()
+        .map(|&group| groups[group].iter().map(|&axis| dims[axis]).product())
+        .collect::<Vec<usize>>();
+    let mut merged_axes = vec![0; groups.len()];
+    for (position, &group) in source_order.iter().enumerate() {
+        merged_axes[group] = position;
+    }
+
+    macro_rules! permute_rank {
+        ($($rank:literal),*) => {
+            match merged_dims.len() {
+                $($rank => permute_with_rank::<B, $rank>(tensor, &merged_dims, &merged_axes),)*
+                rank => panic!(
+                    "Einsum doesn't support permutations of more than {MAX_PERMUTE_RANK} dimensions, got {rank}."
+                ),
+            }
+        };
+    }
+
+    permute_rank!(2, 3, 4, 5, 6, 7, 8)
+}
+
+fn permute_with_rank<B: Backend, const D: usize>(
+    tensor: Tensor<B, 1>,
+    dims: &[usize],
+    axes: &[usize],
+) -> Tensor<B, 1> {
+    let mut shape = [0; D];
+    let mut permutation = [0; D];
+    shape.copy_from_slice(dims);
+    for (permuted, &axis) in permutation.iter_mut().zip(axes) {
+        *permuted = axis as isize;
+    }
+
+    let num_elements = tensor.dims()[0];
+    tensor
+        .reshape(shape)
+        .permute(permutation)
+        .reshape([num_elements])
+}
@@ -203,12 +203,51 @@ where
     /// # Panics
     ///
     /// If the two tensors dont' have a compatible shape.
+    ///
+    /// When both tensors are quantized, the products are accumulated in the integer domain
+    /// if supported by the backend.
     pub fn matmul(self, other: Self) -> Self {
         check!(TensorCheck::matmul(&self, &other));
-        Self::new(TensorPrimitive::Float(B::float_matmul(
-            self.primitive.tensor(),
-            other.primitive.tensor(),
-        )))
+        let tensor = match (self.primitive, other.primitive) {
+            (TensorPrimitive::QFloat(lhs), TensorPrimitive::QFloat(rhs)) => B::q_matmul(lhs, rhs),
+            (lhs, rhs) => B::float_matmul(lhs.tensor(), rhs.tensor()),
+        };
+        Self::new(TensorPrimitive::Float(tensor))
+    }
+
+    /// Applies the matrix multiplication operation and quantizes the output.
+    ///
+    /// When both tensors are quantized, the integer accumulators are rescaled directly to the
+    /// quantized output if supported by the backend. Otherwise, the floating point output is
+    /// quantized.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The right hand side tensor.
+    /// * `scheme` - The quantization scheme of the output.
+    /// * `qparams` - The pre-computed quantization parameters of the output.
+    ///
+    /// # Returns
+    ///
+    /// The quantized result of the matrix multiplication.
+    pub fn matmul_requantize(
+        self,
+        other: Self,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParameters<B>,
+    ) -> Self {
+        check!(TensorCheck::matmul(&self, &other));
+        let tensor = match (self.primitive, other.primitive) {
+            (TensorPrimitive::QFloat(lhs), TensorPrimitive::QFloat(rhs)) => {
+                B::q_matmul_requantize(lhs, rhs, scheme, qparams.into())
+            }
+            (lhs, rhs) => B::quantize(
+                B::float_matmul(lhs.tensor(), rhs.tensor()),
+                scheme,
+                qparams.into(),
+            ),
+        };
+        Self::new(TensorPrimitive::QFloat(tensor))
     }
 
     /// Calculate the variance along the given dimension.
@@ -334,6 +373,32 @@ where
         )))
     }
 
+    /// Quantize and dequantize the tensor, which simulates the quantization error in floating point
+    /// precision (fake quantization).
+    ///
+    /// The gradients are propagated with a straight-through estimator: the rounding is ignored and
+    /// the gradient of the values clipped by the quantization range is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `scheme` - The quantization scheme.
+    /// * `qparams` - The pre-computed quantization parameters.
+    ///
+    /// # Returns
+    ///
+    /// The fake-quantized tensor.
+    pub fn fake_quantize(
+        self,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParameters<B>,
+    ) -> Tensor<B, D> {
+        Tensor::new(TensorPrimitive::Float(B::fake_quantize(
+            self.primitive.tensor(),
+            scheme,
+            qparams.into(),
+        )))
+    }
+
     /// Convert the tensor back to a higher precision data type.
     ///
     /// If the tensor is not quantized, its value is simply returned.
@@ -6,6 +6,9 @@ mod base;
 mod bool;
 mod cartesian_grid;
 mod chunk;
+mod cumulative;
+mod custom;
+mod einsum;
 mod float;
 mod int;
 mod kind;
@@ -18,6 +21,9 @@ pub use autodiff::*;
 pub use base::*;
 pub use cartesian_grid::cartesian_grid;
 pub use chunk::chunk;
+pub use cumulative::{cummax, cummin, cumprod, cumsum};
+pub use custom::CustomOp;
+pub use einsum::{EinsumOperand, EinsumOperands};
 pub use kind::*;
 pub use narrow::narrow;
 pub use numeric::*;
@@ -719,6 +719,65 @@ where
         Tensor::new(K::argsort(self.primitive, dim, /*descending*/ true))
     }
 
+    /// Computes the cumulative sum of the elements along the given dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///    let tensor = tensor.cumsum(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::cumulative_dim::<D>("Cumsum", dim));
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative product of the elements along the given dimension.
+    pub fn cumprod(self, dim: usize) -> Self {
+        check!(TensorCheck::cumulative_dim::<D>("Cumprod", dim));
+        Self::new(K::cumprod(self.primitive, dim))
+    }
+
+    /// Computes the cumulative maximum of the elements along the given dimension.
+    pub fn cummax(self, dim: usize) -> Self {
+        check!(TensorCheck::cumulative_dim::<D>("Cummax", dim));
+        Self::new(K::cummax(self.primitive, dim).0)
+    }
+
+    /// Computes the cumulative maximum of the elements along the given dimension.
+    ///
+    /// Also returns the indices of the maximum values, where the index of the last occurrence is
+    /// returned for equal values.
+    pub fn cummax_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::cumulative_dim::<D>("Cummax", dim));
+        let (values, indices) = K::cummax(self.primitive, dim);
+        (Self::new(values), Tensor::new(indices))
+    }
+
+    /// Computes the cumulative minimum of the elements along the given dimension.
+    pub fn cummin(self, dim: usize) -> Self {
+        check!(TensorCheck::cumulative_dim::<D>("Cummin", dim));
+        Self::new(K::cummin(self.primitive, dim).0)
+    }
+
+    /// Computes the cumulative minimum of the elements along the given dimension.
+    ///
+    /// Also returns the indices of the minimum values, where the index of the last occurrence is
+    /// returned for equal values.
+    pub fn cummin_with_indices(self, dim: usize) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::cumulative_dim::<D>("Cummin", dim));
+        let (values, indices) = K::cummin(self.primitive, dim);
+        (Self::new(values), Tensor::new(indices))
+    }
+
     /// Returns the `k` largest elements of the given input tensor along a given dimension.
     pub fn topk(self, k: usize, dim: usize) -> Tensor<B, D, K> {
         let k_indices = Tensor::arange(0..k as i64, &self.device());
@@ -2080,6 +2139,58 @@ where
         dim: usize,
         descending: bool,
     ) -> <Int as TensorKind<B>>::Primitive<D>;
+
+    /// Computes the cumulative sum of the elements along a given dimension.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::cumsum](Tensor::cumsum) function,
+    /// which is more high-level and designed for public use.
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative product of the elements along a given dimension.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::cumprod](Tensor::cumprod) function,
+    /// which is more high-level and designed for public use.
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
+    /// Computes the cumulative maximum of the elements along a given dimension, with the indices
+    /// of the maximum values.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::cummax_with_indices](Tensor::cummax_with_indices) function,
+    /// which is more high-level and designed for public use.
+    fn cummax<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>);
+
+    /// Computes the cumulative minimum of the elements along a given dimension, with the indices
+    /// of the minimum values.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::cummin_with_indices](Tensor::cummin_with_indices) function,
+    /// which is more high-level and designed for public use.
+    fn cummin<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>);
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -2421,6 +2532,28 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> <Int as TensorKind<B>>::Primitive<D> {
         B::int_argsort(tensor, dim, descending)
     }
+
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumprod(tensor, dim)
+    }
+
+    fn cummax<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        B::int_cummax(tensor, dim)
+    }
+
+    fn cummin<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        B::int_cummin(tensor, dim)
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -2782,6 +2915,30 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> <Int as TensorKind<B>>::Primitive<D> {
         B::float_argsort(tensor.tensor(), dim, descending)
     }
+
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        TensorPrimitive::Float(B::float_cumsum(tensor.tensor(), dim))
+    }
+
+    fn cumprod<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        TensorPrimitive::Float(B::float_cumprod(tensor.tensor(), dim))
+    }
+
+    fn cummax<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        let (tensor, indices) = B::float_cummax(tensor.tensor(), dim);
+        (TensorPrimitive::Float(tensor), indices)
+    }
+
+    fn cummin<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        let (tensor, indices) = B::float_cummin(tensor.tensor(), dim);
+        (TensorPrimitive::Float(tensor), indices)
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
@@ -1,4 +1,5 @@
 use alloc::string::String;
+use alloc::sync::Arc;
 pub use burn_common::sync_type::SyncType;
 
 use crate::tensor::Element;
@@ -58,6 +59,7 @@ pub trait Backend:
     + IntTensorOps<Self>
     + ModuleOps<Self>
     + ActivationOps<Self>
+    + LinalgOps<Self>
     + QTensorOps<Self>
     + Clone
     + Sized
@@ -175,6 +177,53 @@ pub trait AutodiffBackend: Backend {
         grad: FloatTensor<Self::InnerBackend, D>,
     );
 
+    /// Registers a hook called with the gradient of a tensor during the backward pass.
+    ///
+    /// The hook is called once the gradient of the tensor is fully accumulated, and the gradient
+    /// it returns replaces the original one, both in the [gradients](AutodiffBackend::Gradients)
+    /// and when propagated to the tensors it depends on. Hooks registered on untracked tensors are
+    /// never called.
+    ///
+    /// Registering the same hook, i.e. a clone of the same [GradientHook], multiple times on a
+    /// tensor has no effect. Different hooks registered on a tensor are called in the order they
+    /// were registered, each one receiving the gradient returned by the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to register the hook on.
+    /// * `hook` - The function called with the gradient of the tensor.
+    fn register_hook<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+        hook: GradientHook<Self::InnerBackend, D>,
+    );
+
+    /// Applies an operation with a custom backward function.
+    ///
+    /// The inputs are saved for the backward pass according to the checkpointing strategy of the
+    /// backend, and the state returned by the forward function is kept for the backward function.
+    /// When the operation is memory bound, the state isn't kept but recomputed with the forward
+    /// function during the backward pass, as well as the output if the checkpointing strategy
+    /// allows it.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The inputs of the operation.
+    /// * `forward` - The function computing the output and the saved state of the operation.
+    /// * `backward` - The function computing the gradients of the inputs.
+    /// * `memory_bound` - Whether the operation is cheap enough to be recomputed.
+    ///
+    /// # Returns
+    ///
+    /// The output of the operation.
+    fn custom_op<const N: usize, const D_IN: usize, const D_OUT: usize, S>(
+        inputs: [FloatTensor<Self, D_IN>; N],
+        forward: CustomForward<Self::InnerBackend, N, D_IN, D_OUT, S>,
+        backward: CustomBackward<Self::InnerBackend, N, D_IN, D_OUT, S>,
+        memory_bound: bool,
+    ) -> FloatTensor<Self, D_OUT>
+    where
+        S: Clone + Send + 'static;
+
     /// Returns the tensor with inner backend type.
     ///
     /// # Arguments
@@ -278,3 +327,28 @@ pub trait AutodiffBackend: Backend {
         tensor: QuantizedTensor<Self::InnerBackend, D>,
     ) -> QuantizedTensor<Self, D>;
 }
+
+/// A function called with the gradient of a tensor during the backward pass, returning the
+/// gradient to use instead.
+///
+/// See [register_hook](AutodiffBackend::register_hook).
+pub type GradientHook<B, const D: usize> =
+    Arc<dyn Fn(FloatTensor<B, D>) -> FloatTensor<B, D> + Send + Sync>;
+
+/// The forward function of a custom operation, computing the output and the state saved for the
+/// backward pass from the inputs.
+///
+/// See [custom_op](AutodiffBackend::custom_op).
+pub type CustomForward<B, const N: usize, const D_IN: usize, const D_OUT: usize, S> =
+    Arc<dyn Fn([FloatTensor<B, D_IN>; N]) -> (FloatTensor<B, D_OUT>, S) + Send + Sync>;
+
+/// The backward function of a custom operation, computing the gradients of the inputs from the
+/// inputs, the saved state and the gradient of the output. Gradients of inputs that aren't needed
+/// can be `None`.
+///
+/// See [custom_op](AutodiffBackend::custom_op).
+pub type CustomBackward<B, const N: usize, const D_IN: usize, const D_OUT: usize, S> = Arc<
+    dyn Fn([FloatTensor<B, D_IN>; N], S, FloatTensor<B, D_OUT>) -> [Option<FloatTensor<B, D_IN>>; N]
+        + Send
+        + Sync,
+>;
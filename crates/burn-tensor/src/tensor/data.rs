@@ -7,7 +7,10 @@ use alloc::vec::Vec;
 use half::{bf16, f16};
 
 use crate::{
-    quantization::{Quantization, QuantizationStrategy},
+    quantization::{
+        pack_nibbles, unpack_nibbles, GroupQuantization, Quantization, QuantizationStrategy,
+        QuantizationType,
+    },
     tensor::Shape,
     DType, Distribution, Element, ElementConversion,
 };
@@ -50,12 +53,131 @@ impl TensorData {
     }
 
     /// Creates a new quantized tensor data structure.
+    ///
+    /// For 4-bit quantization types, the values are packed two per byte.
     pub fn quantized<E: Element, S: Into<Vec<usize>>>(
         value: Vec<E>,
         shape: S,
         strategy: QuantizationStrategy,
     ) -> Self {
-        Self::init(value, shape, DType::QFloat(strategy))
+        let mut data = Self::init(value, shape, DType::QFloat(strategy));
+
+        if strategy.dtype().bits() == 4 {
+            assert_eq!(
+                core::mem::size_of::<E>(),
+                1,
+                "4-bit quantized values should be provided as 8-bit integers"
+            );
+            data.bytes = pack_nibbles(&data.bytes);
+        }
+
+        data
+    }
+
+    /// Creates a new quantized tensor data structure with per-group quantization parameters.
+    ///
+    /// The parameters are stored after the (packed) quantized values: the scale of each group
+    /// (as `f32`), followed by the offset of each group (as a byte of the quantized type) for
+    /// affine quantization.
+    ///
+    /// Unsigned quantized values are provided as `i8` with the same bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strategy is not a per-group quantization strategy.
+    pub fn quantized_groups<S: Into<Vec<usize>>>(
+        value: Vec<i8>,
+        shape: S,
+        strategy: QuantizationStrategy,
+        params: &GroupQuantization,
+    ) -> Self {
+        let groups = strategy
+            .groups()
+            .expect("Per-group quantization strategy should be provided");
+        let mut data = Self::quantized(value, shape, strategy);
+        assert_eq!(
+            params.scales.len(),
+            groups.num_groups(&data.shape),
+            "Expected one scale per group"
+        );
+
+        for scale in params.scales.iter() {
+            data.bytes.extend_from_slice(&scale.to_le_bytes());
+        }
+        if let Some(offsets) = &params.offsets {
+            data.bytes
+                .extend(offsets.iter().map(|offset| *offset as u8));
+        }
+
+        data
+    }
+
+    /// Returns the per-group quantization parameters, or `None` if the data is not quantized
+    /// with a per-group quantization strategy.
+    pub fn group_quantization(&self) -> Option<GroupQuantization> {
+        let (strategy, groups) = match self.dtype {
+            DType::QFloat(strategy) => (strategy, strategy.groups()?),
+            _ => return None,
+        };
+
+        let start = self.quantized_len(strategy);
+        let end = start + groups.num_groups(&self.shape) * core::mem::size_of::<f32>();
+        let scales = self.bytes[start..end]
+            .chunks_exact(core::mem::size_of::<f32>())
+            .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+            .collect();
+        let unsigned = strategy.dtype() == QuantizationType::QUInt8;
+        let offsets = match strategy.scheme().is_affine() {
+            true => Some(
+                self.bytes[end..]
+                    .iter()
+                    .map(|offset| match unsigned {
+                        true => *offset as i32,
+                        false => *offset as i8 as i32,
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        };
+
+        Some(GroupQuantization { scales, offsets })
+    }
+
+    /// Returns the number of bytes used by the (packed) quantized values.
+    fn quantized_len(&self, strategy: QuantizationStrategy) -> usize {
+        match strategy.dtype().bits() {
+            4 => (self.num_elements() + 1) / 2,
+            _ => self.num_elements(),
+        }
+    }
+
+    /// Returns an iterator over the quantized values, without the per-group quantization
+    /// parameters.
+    fn quantized_iter<E: Element>(
+        &self,
+        strategy: QuantizationStrategy,
+    ) -> Box<dyn Iterator<Item = E> + '_> {
+        let bytes = &self.bytes[..self.quantized_len(strategy)];
+        let num_elements = self.num_elements();
+
+        match strategy.dtype() {
+            QuantizationType::QInt8 => Box::new(
+                bytemuck::checked::cast_slice(bytes)
+                    .iter()
+                    .map(|e: &i8| e.elem::<E>()),
+            ),
+            QuantizationType::QUInt8 => Box::new(bytes.iter().map(|e| e.elem::<E>())),
+            QuantizationType::QInt4 => Box::new(
+                unpack_nibbles(bytes, true)
+                    .take(num_elements)
+                    .map(|e| e.elem::<E>()),
+            ),
+            QuantizationType::NF4 => Box::new(
+                unpack_nibbles(bytes, false)
+                    .take(num_elements)
+                    .map(|e| e.elem::<E>()),
+            ),
+        }
     }
 
     /// Initializes a new tensor data structure from the provided values.
@@ -176,20 +298,8 @@ impl TensorData {
                 ),
                 // bool is a byte value equal to either 0 or 1
                 DType::Bool => Box::new(self.bytes.iter().map(|e| e.elem::<E>())),
-                DType::QFloat(q) => match q {
-                    // NOTE: we do not dequantize the values to iterate over
-                    QuantizationStrategy::PerTensorAffineInt8(_strategy) => Box::new(
-                        bytemuck::checked::cast_slice(&self.bytes)
-                            .iter()
-                            .map(|e: &i8| e.elem::<E>()),
-                    ),
-
-                    QuantizationStrategy::PerTensorSymmetricInt8(_strategy) => Box::new(
-                        bytemuck::checked::cast_slice(&self.bytes)
-                            .iter()
-                            .map(|e: &i8| e.elem::<E>()),
-                    ),
-                },
+                // NOTE: we do not dequantize the values to iterate over
+                DType::QFloat(strategy) => self.quantized_iter(strategy),
             }
         }
     }
@@ -283,20 +393,58 @@ impl TensorData {
             DType::F32,
             "Only f32 data type can be quantized"
         );
+        let values = self.as_slice().unwrap();
         match &quantization {
-            QuantizationStrategy::PerTensorAffineInt8(strategy) => TensorData::quantized(
-                strategy.quantize(self.as_slice().unwrap()),
-                self.shape,
-                quantization,
-            ),
-            QuantizationStrategy::PerTensorSymmetricInt8(strategy) => TensorData::quantized(
-                strategy.quantize(self.as_slice().unwrap()),
-                self.shape,
-                quantization,
+            QuantizationStrategy::PerTensorAffineInt8(strategy) => {
+                TensorData::quantized(strategy.quantize(values), self.shape, quantization)
+            }
+            QuantizationStrategy::PerTensorSymmetricInt8(strategy) => {
+                TensorData::quantized(strategy.quantize(values), self.shape, quantization)
+            }
+            QuantizationStrategy::PerTensorAffineUInt8(strategy) => {
+                TensorData::quantized(strategy.quantize(values), self.shape, quantization)
+            }
+            QuantizationStrategy::PerTensorAffineInt4(strategy) => {
+                TensorData::quantized(strategy.quantize(values), self.shape, quantization)
+            }
+            QuantizationStrategy::PerTensorSymmetricInt4(strategy) => {
+                TensorData::quantized(strategy.quantize(values), self.shape, quantization)
+            }
+            QuantizationStrategy::PerTensorNF4(strategy) => {
+                TensorData::quantized(strategy.quantize(values), self.shape, quantization)
+            }
+            QuantizationStrategy::GroupedAffineInt8(_)
+            | QuantizationStrategy::GroupedSymmetricInt8(_)
+            | QuantizationStrategy::GroupedAffineUInt8(_)
+            | QuantizationStrategy::GroupedAffineInt4(_)
+            | QuantizationStrategy::GroupedSymmetricInt4(_)
+            | QuantizationStrategy::GroupedNF4(_) => panic!(
+                "Per-group quantization requires the parameters of each group, use `with_group_quantization` instead"
             ),
         }
     }
 
+    /// Applies the data per-group quantization strategy with the given parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data type is not supported for quantization, or if the strategy is not a
+    /// per-group quantization strategy.
+    pub fn with_group_quantization(
+        self,
+        quantization: QuantizationStrategy,
+        params: &GroupQuantization,
+    ) -> Self {
+        assert_eq!(
+            self.dtype,
+            DType::F32,
+            "Only f32 data type can be quantized"
+        );
+        let values = params.quantize(&quantization, self.as_slice().unwrap(), &self.shape);
+
+        TensorData::quantized_groups(values, self.shape, quantization, params)
+    }
+
     /// Asserts the data is approximately equal to another data.
     ///
     /// # Arguments
@@ -359,14 +507,13 @@ impl TensorData {
                 } else {
                     panic!("Quantized data differs from other not quantized data")
                 }
-                match q {
-                    QuantizationStrategy::PerTensorAffineInt8(_) => {
-                        self.assert_eq_elem::<i8>(other)
-                    }
-                    QuantizationStrategy::PerTensorSymmetricInt8(_) => {
-                        self.assert_eq_elem::<i8>(other)
-                    }
-                }
+                assert_eq!(
+                    self.group_quantization(),
+                    other.group_quantization(),
+                    "Quantization parameters differ"
+                );
+                // Compare the quantized values in a type that fits every quantization type
+                self.assert_eq_elem::<i32>(other)
             }
         }
     }
@@ -613,14 +760,13 @@ impl core::fmt::Display for TensorData {
             DType::U32 => format!("{:?}", self.as_slice::<u32>().unwrap()),
             DType::U8 => format!("{:?}", self.as_slice::<u8>().unwrap()),
             DType::Bool => format!("{:?}", self.as_slice::<bool>().unwrap()),
-            DType::QFloat(q) => match &q {
-                QuantizationStrategy::PerTensorAffineInt8(_) => {
-                    format!("{:?} {q:?}", self.try_as_slice::<i8>().unwrap())
-                }
-                QuantizationStrategy::PerTensorSymmetricInt8(_) => {
-                    format!("{:?} {q:?}", self.try_as_slice::<i8>().unwrap())
+            DType::QFloat(q) => {
+                let values = self.iter::<i32>().collect::<Vec<_>>();
+                match self.group_quantization() {
+                    Some(params) => format!("{values:?} {q:?} {params:?}"),
+                    None => format!("{values:?} {q:?}"),
                 }
-            },
+            }
         };
         f.write_str(fmt.as_str())
     }
@@ -1008,6 +1154,7 @@ impl<E: core::fmt::Debug, const D: usize> core::fmt::Display for Data<E, D> {
 #[allow(deprecated)]
 mod tests {
     use super::*;
+    use crate::quantization::{AffineInt4Quantization, QuantizationGroups};
     use alloc::vec;
     use rand::{rngs::StdRng, SeedableRng};
 
@@ -1074,4 +1221,50 @@ mod tests {
         assert_eq!(data1.bytes.len(), 2 * factor);
         assert_eq!(data1.bytes.capacity(), 5 * factor);
     }
+
+    #[test]
+    fn should_pack_int4_quantized_values() {
+        let strategy = QuantizationStrategy::PerTensorAffineInt4(AffineInt4Quantization::init(
+            0.153_333_34,
+            4,
+        ));
+        let data = TensorData::from([-1.8f32, -1.0, 0.0, 0.5, 0.25]).with_quantization(strategy);
+
+        assert_eq!(data.bytes.len(), 3);
+        assert_eq!(data.iter::<i8>().collect::<Vec<_>>(), vec![-8, -3, 4, 7, 6]);
+    }
+
+    #[test]
+    fn should_store_nf4_block_quantization_parameters() {
+        let strategy = QuantizationStrategy::GroupedNF4(QuantizationGroups::Block { size: 2 });
+        let params = GroupQuantization {
+            scales: vec![1.8, 0.2],
+            offsets: None,
+        };
+        let data =
+            TensorData::from([-1.8f32, 0.5, 0.1, -0.2]).with_group_quantization(strategy, &params);
+
+        // Two bytes of packed values followed by the scales
+        assert_eq!(data.bytes.len(), 2 + 2 * 4);
+        assert_eq!(data.iter::<u8>().collect::<Vec<_>>(), vec![0, 10, 12, 0]);
+        assert_eq!(data.group_quantization(), Some(params));
+    }
+
+    #[test]
+    fn should_store_uint8_channel_quantization_parameters() {
+        let strategy =
+            QuantizationStrategy::GroupedAffineUInt8(QuantizationGroups::Channel { axis: 0 });
+        let params = GroupQuantization {
+            scales: vec![0.01, 0.02],
+            offsets: Some(vec![200, 0]),
+        };
+        let data = TensorData::from([[-1.0f32, 0.5], [1.0, 2.0]])
+            .with_group_quantization(strategy, &params);
+
+        assert_eq!(
+            data.iter::<u8>().collect::<Vec<_>>(),
+            vec![100, 250, 50, 100]
+        );
+        assert_eq!(data.group_quantization(), Some(params));
+    }
 }
@@ -0,0 +1,163 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{check, Tensor, TensorPrimitive};
+
+/// Solves the linear system `a x = b` for `x`.
+///
+/// The operation is batched over the leading dimensions, which must be equal for `a` and `b`.
+///
+/// # Shapes
+///
+/// - a: `[..., n, n]`
+/// - b: `[..., n, k]`
+/// - output: `[..., n, k]`
+pub fn solve<B: Backend, const D: usize>(a: Tensor<B, D>, b: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_solve::<D>(&a.shape(), &b.shape()));
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::solve(
+        a.primitive.tensor(),
+        b.primitive.tensor(),
+    )))
+}
+
+/// Computes the inverse of square matrices.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `[..., n, n]`
+pub fn inverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_square::<D>("Inverse", &tensor.shape()));
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::inverse(
+        tensor.primitive.tensor(),
+    )))
+}
+
+/// Computes the determinant of square matrices.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `[..., 1, 1]`
+pub fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_square::<D>("Det", &tensor.shape()));
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::det(tensor.primitive.tensor())))
+}
+
+/// Computes the natural logarithm of the absolute value of the determinant of square matrices,
+/// which doesn't overflow for large matrices.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `[..., 1, 1]`
+pub fn logdet<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_square::<D>("Logdet", &tensor.shape()));
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::logdet(tensor.primitive.tensor())))
+}
+
+/// Computes the Cholesky decomposition `a = l l^T` of symmetric positive-definite matrices,
+/// where `l` is lower triangular.
+///
+/// Only the lower triangular part of the input is used.
+///
+/// # Panics
+///
+/// The reference implementation panics if a matrix isn't positive-definite.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - output: `[..., n, n]`
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_square::<D>("Cholesky", &tensor.shape()));
+
+    Tensor::from_primitive(TensorPrimitive::Float(B::cholesky(
+        tensor.primitive.tensor(),
+    )))
+}
+
+/// Computes the reduced QR decomposition `a = q r`, where `q` has orthonormal columns and `r` is
+/// upper triangular.
+///
+/// # Returns
+///
+/// The tuple `(q, r)`.
+///
+/// # Shapes
+///
+/// With `k = min(m, n)`:
+///
+/// - tensor: `[..., m, n]`
+/// - q: `[..., m, k]`
+/// - r: `[..., k, n]`
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>("QR"));
+
+    let (q, r) = B::qr(tensor.primitive.tensor());
+
+    (
+        Tensor::from_primitive(TensorPrimitive::Float(q)),
+        Tensor::from_primitive(TensorPrimitive::Float(r)),
+    )
+}
+
+/// Computes the reduced singular value decomposition `a = u diag(s) vh`.
+///
+/// The singular values are returned as row vectors in descending order, so that the input is
+/// reconstructed with `u.mul(s).matmul(vh)`.
+///
+/// # Returns
+///
+/// The tuple `(u, s, vh)`.
+///
+/// # Shapes
+///
+/// With `k = min(m, n)`:
+///
+/// - tensor: `[..., m, n]`
+/// - u: `[..., m, k]`
+/// - s: `[..., 1, k]`
+/// - vh: `[..., k, n]`
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>("SVD"));
+
+    let (u, s, vh) = B::svd(tensor.primitive.tensor());
+
+    (
+        Tensor::from_primitive(TensorPrimitive::Float(u)),
+        Tensor::from_primitive(TensorPrimitive::Float(s)),
+        Tensor::from_primitive(TensorPrimitive::Float(vh)),
+    )
+}
+
+/// Computes the eigenvalues and eigenvectors `a = v diag(w) v^T` of symmetric matrices.
+///
+/// Only the lower triangular part of the input is used. The eigenvalues are returned as row
+/// vectors in ascending order, so that the input is reconstructed with
+/// `v.clone().mul(w).matmul(v.transpose())`.
+///
+/// # Returns
+///
+/// The tuple `(w, v)`, where the eigenvectors are the columns of `v`.
+///
+/// # Shapes
+///
+/// - tensor: `[..., n, n]`
+/// - w: `[..., 1, n]`
+/// - v: `[..., n, n]`
+pub fn eigh<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_square::<D>("Eigh", &tensor.shape()));
+
+    let (w, v) = B::eigh(tensor.primitive.tensor());
+
+    (
+        Tensor::from_primitive(TensorPrimitive::Float(w)),
+        Tensor::from_primitive(TensorPrimitive::Float(v)),
+    )
+}
@@ -0,0 +1,488 @@
+//! Implementations of the linear algebra operations composed of tensor operations, so that the
+//! values stay on the device.
+//!
+//! They are used by the backends that can't read the tensor values synchronously (e.g., WebGPU),
+//! where the [reference](super::reference) implementations aren't available. Since the values
+//! aren't read, the iterative algorithms apply a fixed number of sweeps to all the matrices of the
+//! batch. They aren't designed for large matrices either.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, ops::FloatTensor, Shape, Tensor, TensorData, TensorPrimitive};
+
+/// Number of sweeps of the Jacobi algorithms, which converge quadratically.
+const JACOBI_SWEEPS: usize = 12;
+
+fn from_primitive<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> Tensor<B, D> {
+    Tensor::from_primitive(TensorPrimitive::Float(tensor))
+}
+
+fn into_primitive<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> FloatTensor<B, D> {
+    tensor.into_primitive().tensor()
+}
+
+/// Returns the shape with the given number of rows and columns.
+fn matrix_shape<const D: usize>(shape: &Shape<D>, rows: usize, cols: usize) -> Shape<D> {
+    let mut dims = shape.dims;
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+    Shape::new(dims)
+}
+
+/// Returns a constant `rows x cols` matrix, broadcast over the batch dimensions.
+fn constant<B: Backend, const D: usize>(
+    values: Vec<f32>,
+    rows: usize,
+    cols: usize,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let mut dims = [1; D];
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+    let data = TensorData::new(values, dims).convert::<B::FloatElem>();
+
+    Tensor::from_data(data, device)
+}
+
+fn identity<B: Backend, const D: usize>(n: usize, device: &B::Device) -> Tensor<B, D> {
+    let mut values = vec![0.0; n * n];
+    for i in 0..n {
+        values[i * n + i] = 1.0;
+    }
+
+    constant(values, n, n, device)
+}
+
+/// Returns the column of size `n` with ones at the rows `>= start` and zeros elsewhere.
+fn rows_from<B: Backend, const D: usize>(
+    start: usize,
+    n: usize,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let values = (0..n).map(|i| if i >= start { 1.0 } else { 0.0 }).collect();
+    constant(values, n, 1, device)
+}
+
+/// Returns the unit column `e_i` of size `n`.
+fn unit<B: Backend, const D: usize>(i: usize, n: usize, device: &B::Device) -> Tensor<B, D> {
+    let values = (0..n).map(|row| if row == i { 1.0 } else { 0.0 }).collect();
+    constant(values, n, 1, device)
+}
+
+/// Returns the sign of the values, where zero is positive.
+fn sign<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor
+        .greater_equal_elem(0.0)
+        .float()
+        .mul_scalar(2.0)
+        .sub_scalar(1.0)
+}
+
+/// Divides the values by the divisor, where a zero divisor leaves the values unchanged.
+fn safe_div<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    divisor: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let zero = divisor.clone().equal_elem(0.0);
+    tensor / divisor.mask_fill(zero, 1.0)
+}
+
+/// Returns the columns divided by their norm.
+fn normalize<B: Backend, const D: usize>(columns: Tensor<B, D>) -> Tensor<B, D> {
+    let norms = (columns.clone() * columns.clone()).sum_dim(D - 2).sqrt();
+    safe_div(columns, norms)
+}
+
+/// The result of the Gauss-Jordan elimination of `[a | b]`.
+struct Elimination<B: Backend, const D: usize> {
+    /// The solution `x` of `a x = b`.
+    solution: Tensor<B, D>,
+    /// The determinant of `a`.
+    det: Tensor<B, D>,
+    /// The logarithm of the absolute value of the determinant of `a`.
+    logabsdet: Tensor<B, D>,
+}
+
+/// Gauss-Jordan elimination with partial pivoting of the rows of `[a | b]`, where `a` has shape
+/// `[..., n, n]` and `b` has shape `[..., n, k]`.
+fn gauss_jordan<B: Backend, const D: usize>(
+    a: Tensor<B, D>,
+    b: Option<Tensor<B, D>>,
+) -> Elimination<B, D> {
+    let device = a.device();
+    let n = a.dims()[D - 1];
+    let mut m = match b {
+        Some(b) => Tensor::cat(vec![a, b], D - 1),
+        None => a,
+    };
+    let num_cols = m.dims()[D - 1];
+    let ones = m
+        .clone()
+        .narrow(D - 2, 0, 1)
+        .narrow(D - 1, 0, 1)
+        .ones_like();
+    let mut det = ones.clone();
+    let mut logabsdet = ones.zeros_like();
+
+    for k in 0..n {
+        // The pivot is the largest value of the column below the diagonal
+        let after = rows_from::<B, D>(k, n, &device);
+        let column = m.clone().narrow(D - 1, k, 1);
+        let candidates = column.clone().abs() * after.clone() + after.sub_scalar(1.0);
+        let pivot_row = candidates.argmax(D - 2);
+        let selected = column.zeros_like().scatter(D - 2, pivot_row, ones.clone());
+
+        // Swap the pivot row with the row k, which changes the sign of the determinant
+        let e_k = unit::<B, D>(k, n, &device);
+        let row_k = m.clone().narrow(D - 2, k, 1);
+        let row_pivot = selected.clone().transpose().matmul(m.clone());
+        m = m + (e_k.clone() - selected.clone()) * (row_pivot - row_k);
+        det = det * selected.narrow(D - 2, k, 1).mul_scalar(2.0).sub_scalar(1.0);
+
+        let pivot = m.clone().narrow(D - 2, k, 1).narrow(D - 1, k, 1);
+        det = det * pivot.clone();
+        logabsdet = logabsdet + pivot.clone().abs().log();
+
+        // Normalize the pivot row and eliminate the column from the other rows. A zero pivot
+        // means that the column is already eliminated below the diagonal.
+        let nonzero = pivot.clone().not_equal_elem(0.0).float();
+        let row = safe_div(m.clone().narrow(D - 2, k, 1), pivot);
+        let factors = m.clone().narrow(D - 1, k, 1) - e_k * nonzero;
+        m = m - factors * row;
+    }
+
+    Elimination {
+        solution: m.narrow(D - 1, n, num_cols - n),
+        det,
+        logabsdet,
+    }
+}
+
+/// Solves the linear system `a x = b` with the Gauss-Jordan elimination.
+pub fn solve<B: Backend, const D: usize>(
+    a: FloatTensor<B, D>,
+    b: FloatTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let elimination = gauss_jordan(from_primitive::<B, D>(a), Some(from_primitive(b)));
+    into_primitive(elimination.solution)
+}
+
+/// Computes the inverse of square matrices with the Gauss-Jordan elimination.
+pub fn inverse<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let a = from_primitive::<B, D>(tensor);
+    let n = a.dims()[D - 1];
+    let b = a.zeros_like() + identity::<B, D>(n, &a.device());
+
+    into_primitive(gauss_jordan(a, Some(b)).solution)
+}
+
+/// Computes the determinant of square matrices with the Gauss-Jordan elimination.
+pub fn det<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    into_primitive(gauss_jordan(from_primitive::<B, D>(tensor), None).det)
+}
+
+/// Computes the logarithm of the absolute value of the determinant of square matrices with the
+/// Gauss-Jordan elimination.
+pub fn logdet<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    into_primitive(gauss_jordan(from_primitive::<B, D>(tensor), None).logabsdet)
+}
+
+/// Computes the Cholesky decomposition column by column.
+///
+/// The values of the matrices that aren't positive-definite are NaN.
+pub fn cholesky<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let a = from_primitive::<B, D>(tensor);
+    let device = a.device();
+    let n = a.dims()[D - 1];
+    let mut l = a.zeros_like();
+
+    for j in 0..n {
+        let mut column = a.clone().narrow(D - 1, j, 1);
+        if j > 0 {
+            let previous = l.clone().narrow(D - 1, 0, j);
+            let row = previous.clone().narrow(D - 2, j, 1);
+            column = column - previous.matmul(row.transpose());
+        }
+
+        let diagonal = column.clone().narrow(D - 2, j, 1).sqrt();
+        let column = column / diagonal * rows_from::<B, D>(j, n, &device);
+        l = l + column * unit::<B, D>(j, n, &device).transpose();
+    }
+
+    into_primitive(l)
+}
+
+/// Computes the reduced QR decomposition with Householder reflections.
+pub fn qr<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let mut r = from_primitive::<B, D>(tensor);
+    let device = r.device();
+    let [m, n] = [r.dims()[D - 2], r.dims()[D - 1]];
+    let k = usize::min(m, n);
+    let mut q = identity::<B, D>(m, &device).expand(matrix_shape(&r.shape(), m, m));
+
+    for j in 0..k {
+        let x = r.clone().narrow(D - 1, j, 1) * rows_from::<B, D>(j, m, &device);
+        let norm = (x.clone() * x.clone()).sum_dim(D - 2).sqrt();
+        let alpha = sign(x.clone().narrow(D - 2, j, 1)) * norm.neg();
+        let v = x - unit::<B, D>(j, m, &device) * alpha;
+
+        // h = I - 2 v v^T / (v^T v), which is the identity for a zero column
+        let v_norm = (v.clone() * v.clone()).sum_dim(D - 2);
+        let factor = safe_div(v_norm.ones_like().mul_scalar(2.0), v_norm);
+        r = r.clone() - (v.clone() * factor.clone()).matmul(v.clone().transpose().matmul(r));
+        q = q.clone() - q.matmul(v.clone()).matmul(v.transpose() * factor);
+    }
+
+    (
+        into_primitive(q.narrow(D - 1, 0, k)),
+        into_primitive(r.narrow(D - 2, 0, k).triu(0)),
+    )
+}
+
+/// Returns the disjoint pairs of indices rotated at each step of a sweep of the Jacobi
+/// algorithms (round-robin ordering), so that each pair is rotated once per sweep.
+fn round_robin(n: usize) -> Vec<Vec<(usize, usize)>> {
+    let players = n + n % 2;
+    let mut order = (0..players).collect::<Vec<_>>();
+    let mut rounds = Vec::new();
+
+    for _ in 1..players {
+        let pairs = (0..players / 2)
+            .map(|i| (order[i], order[players - 1 - i]))
+            .filter(|&(p, q)| p < n && q < n)
+            .map(|(p, q)| (usize::min(p, q), usize::max(p, q)))
+            .collect::<Vec<_>>();
+
+        if !pairs.is_empty() {
+            rounds.push(pairs);
+        }
+
+        // All the indices but the first one move to the next position
+        order[1..].rotate_right(1);
+    }
+
+    rounds
+}
+
+/// Returns the `[n, h]` matrices selecting the first and second indices of the `h` pairs.
+fn selection<B: Backend, const D: usize>(
+    pairs: &[(usize, usize)],
+    n: usize,
+    device: &B::Device,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let h = pairs.len();
+    let mut first = vec![0.0; n * h];
+    let mut second = vec![0.0; n * h];
+
+    for (i, (p, q)) in pairs.iter().enumerate() {
+        first[p * h + i] = 1.0;
+        second[q * h + i] = 1.0;
+    }
+
+    (
+        constant(first, n, h, device),
+        constant(second, n, h, device),
+    )
+}
+
+/// Returns the rotations that zero the off-diagonal values `g[p, q]` of the symmetric matrices `g`
+/// for the disjoint pairs `(p, q)` selected by `first` and `second`.
+fn jacobi_rotations<B: Backend, const D: usize>(
+    g: Tensor<B, D>,
+    first: &Tensor<B, D>,
+    second: &Tensor<B, D>,
+    identity: &Tensor<B, D>,
+) -> Tensor<B, D> {
+    let g_first = g.clone().matmul(first.clone());
+    let g_second = g.matmul(second.clone());
+    let app = (first.clone() * g_first).sum_dim(D - 2);
+    let aqq = (second.clone() * g_second.clone()).sum_dim(D - 2);
+    let apq = (first.clone() * g_second).sum_dim(D - 2);
+
+    let theta = (aqq - app) / apq.clone().mul_scalar(2.0);
+    let t = sign(theta.clone())
+        / (theta.clone().abs() + (theta.clone() * theta).add_scalar(1.0).sqrt());
+    // The pairs that are already orthogonal aren't rotated
+    let t = t.mask_fill(apq.equal_elem(0.0), 0.0);
+    let c = (t.clone() * t.clone()).add_scalar(1.0).sqrt().recip();
+    let s = t * c.clone();
+    let c = c.sub_scalar(1.0);
+
+    identity.clone()
+        + (first.clone() * c.clone()).matmul(first.clone().transpose())
+        + (second.clone() * c).matmul(second.clone().transpose())
+        + (first.clone() * s.clone()).matmul(second.clone().transpose())
+        - (second.clone() * s).matmul(first.clone().transpose())
+}
+
+/// Returns the permutation matrices `p` of shape `[..., n, n]` that sort the rows of values of
+/// shape `[..., 1, n]`, i.e., the sorted values are `values p`.
+fn sort_permutation<B: Backend, const D: usize>(
+    values: Tensor<B, D>,
+    descending: bool,
+) -> Tensor<B, D> {
+    let device = values.device();
+    let n = values.dims()[D - 1];
+    let shape = matrix_shape(&values.shape(), n, n);
+    let rows = values.clone().transpose().expand(shape.clone());
+    let cols = values.expand(shape.clone());
+
+    // The rank of each value is the number of values sorted before it, where the ties are sorted
+    // by index
+    let before = match descending {
+        true => cols.clone().greater(rows.clone()),
+        false => cols.clone().lower(rows.clone()),
+    };
+    let lower = (0..n)
+        .flat_map(|i| (0..n).map(move |j| if j < i { 1.0 } else { 0.0 }))
+        .collect();
+    let ties = cols.equal(rows).float() * constant::<B, D>(lower, n, n, &device);
+    let ranks = (before.float() + ties).sum_dim(D - 1);
+
+    let positions = constant::<B, D>((0..n).map(|i| i as f32).collect(), 1, n, &device);
+    ranks
+        .expand(shape.clone())
+        .equal(positions.expand(shape))
+        .float()
+}
+
+/// Computes the eigendecomposition of symmetric matrices with the parallel Jacobi algorithm.
+pub fn eigh<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let a = from_primitive::<B, D>(tensor);
+    let device = a.device();
+    let n = a.dims()[D - 1];
+    let identity = identity::<B, D>(n, &device);
+    let rounds = round_robin(n)
+        .iter()
+        .map(|pairs| selection::<B, D>(pairs, n, &device))
+        .collect::<Vec<_>>();
+
+    // The symmetric matrices defined by the lower triangular part
+    let mut a = a.clone().tril(0) + a.tril(-1).transpose();
+    let mut v = identity.clone();
+
+    for _ in 0..JACOBI_SWEEPS {
+        for (first, second) in rounds.iter() {
+            let rotation = jacobi_rotations(a.clone(), first, second, &identity);
+            a = rotation
+                .clone()
+                .transpose()
+                .matmul(a)
+                .matmul(rotation.clone());
+            v = v.matmul(rotation);
+        }
+    }
+
+    let w = (a * identity).sum_dim(D - 2);
+    let order = sort_permutation(w.clone(), false);
+
+    (
+        into_primitive(w.matmul(order.clone())),
+        into_primitive(v.matmul(order)),
+    )
+}
+
+/// Normalizes the columns of `u` by the singular values `s`, and completes the columns of the zero
+/// singular values to an orthonormal basis.
+///
+/// The singular values are considered zero below `s_max * max(m, n) * eps`.
+fn orthonormal_columns<B: Backend, const D: usize>(
+    u: Tensor<B, D>,
+    s: Tensor<B, D>,
+) -> Tensor<B, D> {
+    let [m, n] = [u.dims()[D - 2], u.dims()[D - 1]];
+    let column_shape = matrix_shape(&u.shape(), m, 1);
+    let identity = identity::<B, D>(m, &u.device()).expand(matrix_shape(&u.shape(), m, m));
+    let tolerance = s
+        .clone()
+        .narrow(D - 1, 0, 1)
+        .mul_scalar(usize::max(m, n) as f32 * f32::EPSILON);
+    let mut columns: Vec<Tensor<B, D>> = Vec::with_capacity(n);
+
+    for j in 0..n {
+        let value = s.clone().narrow(D - 1, j, 1);
+        let projection = match j {
+            0 => identity.clone(),
+            _ => {
+                let basis = Tensor::cat(columns.clone(), D - 1);
+                identity.clone() - basis.clone().matmul(basis.transpose())
+            }
+        };
+
+        // The column is projected on the orthogonal complement of the previous columns
+        let column = safe_div(u.clone().narrow(D - 1, j, 1), value.clone());
+        let column = normalize(projection.clone().matmul(column));
+
+        // The largest column of the projection isn't in the span of the previous columns
+        let norms = (projection.clone() * projection.clone()).sum_dim(D - 2);
+        let index = norms.argmax(D - 1).expand(column_shape.clone());
+        let completion = normalize(projection.gather(D - 1, index));
+
+        let zero = value
+            .lower_equal(tolerance.clone())
+            .expand(column_shape.clone());
+        columns.push(column.mask_where(zero, completion));
+    }
+
+    Tensor::cat(columns, D - 1)
+}
+
+/// Singular value decomposition of `m x n` matrices with `m >= n` using the parallel one-sided
+/// Jacobi algorithm. Returns `u` (`m x n`), the singular values in descending order and `v`
+/// (`n x n`).
+fn jacobi_svd<B: Backend, const D: usize>(
+    a: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    let device = a.device();
+    let n = a.dims()[D - 1];
+    let identity = identity::<B, D>(n, &device);
+    let rounds = round_robin(n)
+        .iter()
+        .map(|pairs| selection::<B, D>(pairs, n, &device))
+        .collect::<Vec<_>>();
+
+    let mut u = a;
+    let mut v = identity.clone();
+
+    for _ in 0..JACOBI_SWEEPS {
+        for (first, second) in rounds.iter() {
+            let gram = u.clone().transpose().matmul(u.clone());
+            let rotation = jacobi_rotations(gram, first, second, &identity);
+            u = u.matmul(rotation.clone());
+            v = v.matmul(rotation);
+        }
+    }
+
+    let norms = (u.clone() * u.clone()).sum_dim(D - 2).sqrt();
+    let order = sort_permutation(norms.clone(), true);
+    let s = norms.matmul(order.clone());
+    let u = orthonormal_columns(u.matmul(order.clone()), s.clone());
+
+    (u, s, v.matmul(order))
+}
+
+/// Computes the reduced singular value decomposition with the one-sided Jacobi algorithm.
+#[allow(clippy::type_complexity)]
+pub fn svd<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+    let a = from_primitive::<B, D>(tensor);
+    let [m, n] = [a.dims()[D - 2], a.dims()[D - 1]];
+
+    let (u, s, vh) = if m >= n {
+        // a = u s v^T
+        let (u, s, v) = jacobi_svd(a);
+        (u, s, v.transpose())
+    } else {
+        // a^T = u' s v'^T, so a = v' s u'^T
+        let (u, s, v) = jacobi_svd(a.transpose());
+        (v, s, u.transpose())
+    };
+
+    (into_primitive(u), into_primitive(s), into_primitive(vh))
+}
@@ -0,0 +1,6 @@
+mod base;
+
+pub mod device;
+pub(crate) mod reference;
+
+pub use base::*;
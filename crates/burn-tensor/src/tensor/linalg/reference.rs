@@ -0,0 +1,577 @@
+//! Reference implementations of the linear algebra operations, computed on the host with `f64`
+//! precision.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_common::reader::try_read_sync;
+use num_traits::Float;
+
+use crate::{
+    backend::Backend,
+    ops::{FloatElem, FloatTensor},
+    Device, Shape, TensorData,
+};
+
+/// Maximum number of sweeps of the Jacobi algorithms.
+const MAX_SWEEPS: usize = 100;
+
+/// A batch of row-major matrices of the same size.
+struct Matrices {
+    values: Vec<f64>,
+    batch: usize,
+    rows: usize,
+    cols: usize,
+}
+
+impl Matrices {
+    fn new(batch: usize, rows: usize, cols: usize) -> Self {
+        Self {
+            values: vec![0.0; batch * rows * cols],
+            batch,
+            rows,
+            cols,
+        }
+    }
+
+    fn matrix(&self, index: usize) -> &[f64] {
+        let size = self.rows * self.cols;
+        &self.values[index * size..(index + 1) * size]
+    }
+
+    fn matrix_mut(&mut self, index: usize) -> &mut [f64] {
+        let size = self.rows * self.cols;
+        &mut self.values[index * size..(index + 1) * size]
+    }
+}
+
+fn read<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> (Matrices, Shape<D>, Device<B>) {
+    let shape = B::float_shape(&tensor);
+    let device = B::float_device(&tensor);
+    let data = try_read_sync(B::float_into_data(tensor)).expect(
+        "Failed to synchronously read tensor data. This operation is not supported until this \
+         backend has a linear algebra implementation.",
+    );
+
+    let rows = shape.dims[D - 2];
+    let cols = shape.dims[D - 1];
+    let batch = shape.num_elements() / usize::max(rows * cols, 1);
+    let matrices = Matrices {
+        values: data.iter::<f64>().collect(),
+        batch,
+        rows,
+        cols,
+    };
+
+    (matrices, shape, device)
+}
+
+fn write<B: Backend, const D: usize>(
+    matrices: Matrices,
+    shape: &Shape<D>,
+    device: &Device<B>,
+) -> FloatTensor<B, D> {
+    let mut dims = shape.dims;
+    dims[D - 2] = matrices.rows;
+    dims[D - 1] = matrices.cols;
+
+    let data = TensorData::new(matrices.values, dims).convert::<FloatElem<B>>();
+    B::float_from_data(data, device)
+}
+
+/// LU decomposition with partial pivoting `p a = l u`, stored in place with the unit diagonal of
+/// `l` omitted. Returns the row permutation and its sign.
+fn lu(a: &mut [f64], n: usize) -> (Vec<usize>, f64) {
+    let mut permutation = (0..n).collect::<Vec<_>>();
+    let mut sign = 1.0;
+
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&i, &j| a[i * n + k].abs().total_cmp(&a[j * n + k].abs()))
+            .unwrap();
+
+        if pivot != k {
+            for j in 0..n {
+                a.swap(k * n + j, pivot * n + j);
+            }
+            permutation.swap(k, pivot);
+            sign = -sign;
+        }
+
+        let value = a[k * n + k];
+        if value == 0.0 {
+            continue;
+        }
+
+        for i in k + 1..n {
+            a[i * n + k] /= value;
+            let factor = a[i * n + k];
+            for j in k + 1..n {
+                a[i * n + j] -= factor * a[k * n + j];
+            }
+        }
+    }
+
+    (permutation, sign)
+}
+
+/// Solves `a x = b` from the LU decomposition of `a`, where `b` has `k` columns.
+fn lu_solve(lu: &[f64], permutation: &[usize], b: &[f64], n: usize, k: usize) -> Vec<f64> {
+    let mut x = vec![0.0; n * k];
+
+    for c in 0..k {
+        // Forward substitution with the unit lower triangular matrix
+        for i in 0..n {
+            let mut value = b[permutation[i] * k + c];
+            for j in 0..i {
+                value -= lu[i * n + j] * x[j * k + c];
+            }
+            x[i * k + c] = value;
+        }
+
+        // Backward substitution with the upper triangular matrix
+        for i in (0..n).rev() {
+            let mut value = x[i * k + c];
+            for j in i + 1..n {
+                value -= lu[i * n + j] * x[j * k + c];
+            }
+            x[i * k + c] = value / lu[i * n + i];
+        }
+    }
+
+    x
+}
+
+/// Returns the symmetric matrix defined by the lower triangular part of `a`.
+fn symmetric_from_lower(a: &[f64], n: usize) -> Vec<f64> {
+    let mut output = a.to_vec();
+    for i in 0..n {
+        for j in i + 1..n {
+            output[i * n + j] = a[j * n + i];
+        }
+    }
+    output
+}
+
+/// Computes the rotation `(c, s)` that zeroes the off-diagonal element of the symmetric matrix
+/// `[[app, apq], [apq, aqq]]`.
+fn jacobi_rotation(app: f64, aqq: f64, apq: f64) -> (f64, f64) {
+    let theta = (aqq - app) / (2.0 * apq);
+    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+    let c = 1.0 / (t * t + 1.0).sqrt();
+
+    (c, t * c)
+}
+
+/// Rotates the columns `p` and `q` of the row-major matrix `a` with `cols` columns.
+fn rotate_columns(a: &mut [f64], cols: usize, p: usize, q: usize, c: f64, s: f64) {
+    for row in a.chunks_exact_mut(cols) {
+        let (ap, aq) = (row[p], row[q]);
+        row[p] = c * ap - s * aq;
+        row[q] = s * ap + c * aq;
+    }
+}
+
+/// Rotates the rows `p` and `q` of the row-major matrix `a` with `cols` columns.
+fn rotate_rows(a: &mut [f64], cols: usize, p: usize, q: usize, c: f64, s: f64) {
+    for k in 0..cols {
+        let (ap, aq) = (a[p * cols + k], a[q * cols + k]);
+        a[p * cols + k] = c * ap - s * aq;
+        a[q * cols + k] = s * ap + c * aq;
+    }
+}
+
+fn identity(n: usize) -> Vec<f64> {
+    let mut output = vec![0.0; n * n];
+    for i in 0..n {
+        output[i * n + i] = 1.0;
+    }
+    output
+}
+
+/// Eigendecomposition of a symmetric matrix with the cyclic Jacobi algorithm. Returns the
+/// eigenvalues in ascending order and the eigenvectors as columns.
+fn jacobi_eigh(a: &[f64], n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut a = symmetric_from_lower(a, n);
+    let mut v = identity(n);
+    let norm = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal = (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .map(|(i, j)| a[i * n + j] * a[i * n + j])
+            .sum::<f64>()
+            .sqrt();
+
+        if off_diagonal <= f64::EPSILON * norm {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[p * n + q];
+                if apq == 0.0 {
+                    continue;
+                }
+
+                let (c, s) = jacobi_rotation(a[p * n + p], a[q * n + q], apq);
+                rotate_columns(&mut a, n, p, q, c, s);
+                rotate_rows(&mut a, n, p, q, c, s);
+                rotate_columns(&mut v, n, p, q, c, s);
+            }
+        }
+    }
+
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| a[i * n + i].total_cmp(&a[j * n + j]));
+
+    let eigenvalues = order.iter().map(|&i| a[i * n + i]).collect();
+    let mut eigenvectors = vec![0.0; n * n];
+    for (col, &i) in order.iter().enumerate() {
+        for row in 0..n {
+            eigenvectors[row * n + col] = v[row * n + i];
+        }
+    }
+
+    (eigenvalues, eigenvectors)
+}
+
+/// Singular value decomposition of a `m x n` matrix with `m >= n` using the one-sided Jacobi
+/// algorithm. Returns `u` (`m x n`), the singular values in descending order and `v` (`n x n`).
+fn jacobi_svd(a: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let mut u = a.to_vec();
+    let mut v = identity(n);
+    let column = |u: &[f64], p: usize, q: usize| -> f64 {
+        (0..m).map(|i| u[i * n + p] * u[i * n + q]).sum()
+    };
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = column(&u, p, p);
+                let beta = column(&u, q, q);
+                let gamma = column(&u, p, q);
+
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() || gamma == 0.0 {
+                    continue;
+                }
+
+                rotated = true;
+                let (c, s) = jacobi_rotation(alpha, beta, gamma);
+                rotate_columns(&mut u, n, p, q, c, s);
+                rotate_columns(&mut v, n, p, q, c, s);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms = (0..n).map(|j| column(&u, j, j).sqrt()).collect::<Vec<_>>();
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+
+    let singular_values = order.iter().map(|&i| norms[i]).collect::<Vec<_>>();
+    let mut u_sorted = vec![0.0; m * n];
+    let mut v_sorted = vec![0.0; n * n];
+    for (col, &i) in order.iter().enumerate() {
+        for row in 0..m {
+            u_sorted[row * n + col] = u[row * n + i] / norms[i];
+        }
+        for row in 0..n {
+            v_sorted[row * n + col] = v[row * n + i];
+        }
+    }
+
+    // The left singular vectors of the zero singular values are completed to an orthonormal basis
+    for col in 0..n {
+        if singular_values[col] > f64::MIN_POSITIVE {
+            continue;
+        }
+
+        for basis in 0..m {
+            let mut w = vec![0.0; m];
+            w[basis] = 1.0;
+
+            for other in (0..n).filter(|&other| {
+                other != col && (singular_values[other] > f64::MIN_POSITIVE || other < col)
+            }) {
+                let dot = (0..m).map(|i| u_sorted[i * n + other] * w[i]).sum::<f64>();
+                for (i, value) in w.iter_mut().enumerate() {
+                    *value -= dot * u_sorted[i * n + other];
+                }
+            }
+
+            let norm = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm > 0.5 {
+                for (i, value) in w.iter().enumerate() {
+                    u_sorted[i * n + col] = value / norm;
+                }
+                break;
+            }
+        }
+    }
+
+    (u_sorted, singular_values, v_sorted)
+}
+
+/// Reduced QR decomposition of a `m x n` matrix with Householder reflections. Returns `q`
+/// (`m x k`) and `r` (`k x n`), where `k = min(m, n)`.
+fn householder_qr(a: &[f64], m: usize, n: usize) -> (Vec<f64>, Vec<f64>) {
+    let k = usize::min(m, n);
+    let mut r = a.to_vec();
+    let mut q = identity(m);
+
+    for j in 0..k {
+        let mut v = (j..m).map(|i| r[i * n + j]).collect::<Vec<_>>();
+        let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+
+        let alpha = if v[0] >= 0.0 { -norm } else { norm };
+        v[0] -= alpha;
+        let v_norm = v.iter().map(|x| x * x).sum::<f64>();
+        if v_norm == 0.0 {
+            continue;
+        }
+
+        // r = h r with h = I - 2 v v^T / (v^T v)
+        for c in j..n {
+            let dot = v
+                .iter()
+                .enumerate()
+                .map(|(i, vi)| vi * r[(j + i) * n + c])
+                .sum::<f64>();
+            let factor = 2.0 * dot / v_norm;
+            for (i, vi) in v.iter().enumerate() {
+                r[(j + i) * n + c] -= factor * vi;
+            }
+        }
+
+        // q = q h
+        for row in 0..m {
+            let dot = v
+                .iter()
+                .enumerate()
+                .map(|(i, vi)| q[row * m + j + i] * vi)
+                .sum::<f64>();
+            let factor = 2.0 * dot / v_norm;
+            for (i, vi) in v.iter().enumerate() {
+                q[row * m + j + i] -= factor * vi;
+            }
+        }
+    }
+
+    let q = (0..m)
+        .flat_map(|row| q[row * m..row * m + k].to_vec())
+        .collect();
+    let r = (0..k)
+        .flat_map(|row| (0..n).map(move |col| (row, col)))
+        .map(|(row, col)| if col < row { 0.0 } else { r[row * n + col] })
+        .collect();
+
+    (q, r)
+}
+
+pub(crate) fn solve<B: Backend, const D: usize>(
+    a: FloatTensor<B, D>,
+    b: FloatTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let (mut a, _, _) = read::<B, D>(a);
+    let (b, shape, device) = read::<B, D>(b);
+    let (n, k) = (b.rows, b.cols);
+    let mut output = Matrices::new(b.batch, n, k);
+
+    for index in 0..b.batch {
+        let (permutation, _) = lu(a.matrix_mut(index), n);
+        let x = lu_solve(a.matrix(index), &permutation, b.matrix(index), n, k);
+        output.matrix_mut(index).copy_from_slice(&x);
+    }
+
+    write::<B, D>(output, &shape, &device)
+}
+
+pub(crate) fn inverse<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let (mut a, shape, device) = read::<B, D>(tensor);
+    let n = a.rows;
+    let mut output = Matrices::new(a.batch, n, n);
+    let eye = identity(n);
+
+    for index in 0..a.batch {
+        let (permutation, _) = lu(a.matrix_mut(index), n);
+        let x = lu_solve(a.matrix(index), &permutation, &eye, n, n);
+        output.matrix_mut(index).copy_from_slice(&x);
+    }
+
+    write::<B, D>(output, &shape, &device)
+}
+
+/// Returns the sign and the logarithm of the absolute value of the determinant of each matrix.
+fn slogdet(a: &mut Matrices) -> Vec<(f64, f64)> {
+    let n = a.rows;
+
+    (0..a.batch)
+        .map(|index| {
+            let matrix = a.matrix_mut(index);
+            let (_, mut sign) = lu(matrix, n);
+            let mut logabsdet = 0.0;
+
+            for i in 0..n {
+                let value = matrix[i * n + i];
+                sign *= value.signum();
+                logabsdet += value.abs().ln();
+            }
+
+            match logabsdet == f64::NEG_INFINITY {
+                true => (0.0, logabsdet),
+                false => (sign, logabsdet),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn det<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let (mut a, shape, device) = read::<B, D>(tensor);
+    let mut output = Matrices::new(a.batch, 1, 1);
+
+    for (value, (sign, logabsdet)) in output.values.iter_mut().zip(slogdet(&mut a)) {
+        *value = sign * logabsdet.exp();
+    }
+
+    write::<B, D>(output, &shape, &device)
+}
+
+pub(crate) fn logdet<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let (mut a, shape, device) = read::<B, D>(tensor);
+    let mut output = Matrices::new(a.batch, 1, 1);
+
+    for (value, (_, logabsdet)) in output.values.iter_mut().zip(slogdet(&mut a)) {
+        *value = logabsdet;
+    }
+
+    write::<B, D>(output, &shape, &device)
+}
+
+pub(crate) fn cholesky<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let (a, shape, device) = read::<B, D>(tensor);
+    let n = a.rows;
+    let mut output = Matrices::new(a.batch, n, n);
+
+    for index in 0..a.batch {
+        let a = a.matrix(index);
+        let l = output.matrix_mut(index);
+
+        for j in 0..n {
+            let mut diagonal = a[j * n + j];
+            for k in 0..j {
+                diagonal -= l[j * n + k] * l[j * n + k];
+            }
+            assert!(
+                diagonal > 0.0,
+                "Cholesky decomposition failed, the matrix is not positive-definite"
+            );
+            let diagonal = diagonal.sqrt();
+            l[j * n + j] = diagonal;
+
+            for i in j + 1..n {
+                let mut value = a[i * n + j];
+                for k in 0..j {
+                    value -= l[i * n + k] * l[j * n + k];
+                }
+                l[i * n + j] = value / diagonal;
+            }
+        }
+    }
+
+    write::<B, D>(output, &shape, &device)
+}
+
+pub(crate) fn qr<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let (a, shape, device) = read::<B, D>(tensor);
+    let (m, n) = (a.rows, a.cols);
+    let k = usize::min(m, n);
+    let mut q = Matrices::new(a.batch, m, k);
+    let mut r = Matrices::new(a.batch, k, n);
+
+    for index in 0..a.batch {
+        let (q_matrix, r_matrix) = householder_qr(a.matrix(index), m, n);
+        q.matrix_mut(index).copy_from_slice(&q_matrix);
+        r.matrix_mut(index).copy_from_slice(&r_matrix);
+    }
+
+    (
+        write::<B, D>(q, &shape, &device),
+        write::<B, D>(r, &shape, &device),
+    )
+}
+
+pub(crate) fn svd<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+    let (a, shape, device) = read::<B, D>(tensor);
+    let (m, n) = (a.rows, a.cols);
+    let k = usize::min(m, n);
+    let mut u = Matrices::new(a.batch, m, k);
+    let mut s = Matrices::new(a.batch, 1, k);
+    let mut vh = Matrices::new(a.batch, k, n);
+
+    for index in 0..a.batch {
+        let matrix = a.matrix(index);
+
+        if m >= n {
+            // a = u s v^T
+            let (u_matrix, s_values, v_matrix) = jacobi_svd(matrix, m, n);
+            u.matrix_mut(index).copy_from_slice(&u_matrix);
+            s.matrix_mut(index).copy_from_slice(&s_values);
+            transpose_into(&v_matrix, n, n, vh.matrix_mut(index));
+        } else {
+            // a^T = u' s v'^T, so a = v' s u'^T
+            let mut transposed = vec![0.0; m * n];
+            transpose_into(matrix, m, n, &mut transposed);
+            let (u_matrix, s_values, v_matrix) = jacobi_svd(&transposed, n, m);
+            u.matrix_mut(index).copy_from_slice(&v_matrix);
+            s.matrix_mut(index).copy_from_slice(&s_values);
+            transpose_into(&u_matrix, n, m, vh.matrix_mut(index));
+        }
+    }
+
+    (
+        write::<B, D>(u, &shape, &device),
+        write::<B, D>(s, &shape, &device),
+        write::<B, D>(vh, &shape, &device),
+    )
+}
+
+pub(crate) fn eigh<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let (a, shape, device) = read::<B, D>(tensor);
+    let n = a.rows;
+    let mut w = Matrices::new(a.batch, 1, n);
+    let mut v = Matrices::new(a.batch, n, n);
+
+    for index in 0..a.batch {
+        let (eigenvalues, eigenvectors) = jacobi_eigh(a.matrix(index), n);
+        w.matrix_mut(index).copy_from_slice(&eigenvalues);
+        v.matrix_mut(index).copy_from_slice(&eigenvectors);
+    }
+
+    (
+        write::<B, D>(w, &shape, &device),
+        write::<B, D>(v, &shape, &device),
+    )
+}
+
+/// Transposes the `rows x cols` matrix `a` into `output`.
+fn transpose_into(a: &[f64], rows: usize, cols: usize, output: &mut [f64]) {
+    for i in 0..rows {
+        for j in 0..cols {
+            output[j * rows + i] = a[i * cols + j];
+        }
+    }
+}
@@ -21,6 +21,9 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// The linear algebra module.
+pub mod linalg;
+
 /// The loss module.
 pub mod loss;
 
@@ -33,6 +36,9 @@ pub mod ops;
 /// Tensor quantization module.
 pub mod quantization;
 
+/// The signal processing module.
+pub mod signal;
+
 #[cfg(feature = "experimental-named-tensor")]
 mod named;
 #[cfg(feature = "experimental-named-tensor")]
@@ -1,6 +1,9 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
+    ops::{
+        ConvOptions, ConvTransposeOptions, GridSampleOptions, InterpolateOptions, UnfoldOptions,
+    },
+    quantization::{QuantizationParameters, QuantizationScheme},
     Int, Tensor, TensorPrimitive,
 };
 
@@ -34,6 +37,9 @@ where
 }
 
 /// Applies a [2D convolution](crate::ops::ModuleOps::conv2d).
+///
+/// When both the input and the weights are quantized, the
+/// [quantized convolution](crate::ops::QTensorOps::q_conv2d) is used.
 pub fn conv2d<B>(
     x: Tensor<B, 4>,
     weight: Tensor<B, 4>,
@@ -43,12 +49,44 @@ pub fn conv2d<B>(
 where
     B: Backend,
 {
-    Tensor::new(TensorPrimitive::Float(B::conv2d(
-        x.primitive.tensor(),
-        weight.primitive.tensor(),
-        bias.map(|b| b.primitive.tensor()),
-        options,
-    )))
+    let bias = bias.map(|b| b.primitive.tensor());
+    let tensor = match (x.primitive, weight.primitive) {
+        (TensorPrimitive::QFloat(x), TensorPrimitive::QFloat(weight)) => {
+            B::q_conv2d(x, weight, bias, options)
+        }
+        (x, weight) => B::conv2d(x.tensor(), weight.tensor(), bias, options),
+    };
+    Tensor::new(TensorPrimitive::Float(tensor))
+}
+
+/// Applies a [2D convolution](crate::ops::ModuleOps::conv2d) and quantizes the output.
+///
+/// When both the input and the weights are quantized, the
+/// [requantized convolution](crate::ops::QTensorOps::q_conv2d_requantize) is used. Otherwise,
+/// the floating point output is quantized.
+pub fn conv2d_requantize<B>(
+    x: Tensor<B, 4>,
+    weight: Tensor<B, 4>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvOptions<2>,
+    scheme: &QuantizationScheme,
+    qparams: QuantizationParameters<B>,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let bias = bias.map(|b| b.primitive.tensor());
+    let tensor = match (x.primitive, weight.primitive) {
+        (TensorPrimitive::QFloat(x), TensorPrimitive::QFloat(weight)) => {
+            B::q_conv2d_requantize(x, weight, bias, options, scheme, qparams.into())
+        }
+        (x, weight) => B::quantize(
+            B::conv2d(x.tensor(), weight.tensor(), bias, options),
+            scheme,
+            qparams.into(),
+        ),
+    };
+    Tensor::new(TensorPrimitive::QFloat(tensor))
 }
 
 /// Applies a [3D convolution](crate::ops::ModuleOps::conv3d).
@@ -277,6 +315,131 @@ where
     )))
 }
 
+/// Applies a [3D max pooling](crate::ops::ModuleOps::max_pool3d).
+pub fn max_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::max_pool3d(
+        x.primitive.tensor(),
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+    )))
+}
+
+/// Applies a [3D max pooling with indices](crate::ops::ModuleOps::max_pool3d_with_indices).
+pub fn max_pool3d_with_indices<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> (Tensor<B, 5>, Tensor<B, 5, Int>)
+where
+    B: Backend,
+{
+    let output =
+        B::max_pool3d_with_indices(x.primitive.tensor(), kernel_size, stride, padding, dilation);
+
+    (
+        Tensor::new(TensorPrimitive::Float(output.output)),
+        Tensor::new(output.indices),
+    )
+}
+
+/// Applies a [3D avg pooling](crate::ops::ModuleOps::avg_pool3d).
+pub fn avg_pool3d<B>(
+    x: Tensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::avg_pool3d(
+        x.primitive.tensor(),
+        kernel_size,
+        stride,
+        padding,
+        count_include_pad,
+    )))
+}
+
+/// Applies a [3D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool3d).
+pub fn adaptive_avg_pool3d<B>(x: Tensor<B, 5>, output_size: [usize; 3]) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::adaptive_avg_pool3d(
+        x.primitive.tensor(),
+        output_size,
+    )))
+}
+
+/// Applies a [1D adaptive max pooling](crate::ops::ModuleOps::adaptive_max_pool1d).
+pub fn adaptive_max_pool1d<B>(x: Tensor<B, 3>, output_size: usize) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::adaptive_max_pool1d(
+        x.primitive.tensor(),
+        output_size,
+    )))
+}
+
+/// Applies a [1D adaptive max pooling with indices](crate::ops::ModuleOps::adaptive_max_pool1d_with_indices).
+pub fn adaptive_max_pool1d_with_indices<B>(
+    x: Tensor<B, 3>,
+    output_size: usize,
+) -> (Tensor<B, 3>, Tensor<B, 3, Int>)
+where
+    B: Backend,
+{
+    let output = B::adaptive_max_pool1d_with_indices(x.primitive.tensor(), output_size);
+
+    (
+        Tensor::new(TensorPrimitive::Float(output.output)),
+        Tensor::new(output.indices),
+    )
+}
+
+/// Applies a [2D adaptive max pooling](crate::ops::ModuleOps::adaptive_max_pool2d).
+pub fn adaptive_max_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::adaptive_max_pool2d(
+        x.primitive.tensor(),
+        output_size,
+    )))
+}
+
+/// Applies a [2D adaptive max pooling with indices](crate::ops::ModuleOps::adaptive_max_pool2d_with_indices).
+pub fn adaptive_max_pool2d_with_indices<B>(
+    x: Tensor<B, 4>,
+    output_size: [usize; 2],
+) -> (Tensor<B, 4>, Tensor<B, 4, Int>)
+where
+    B: Backend,
+{
+    let output = B::adaptive_max_pool2d_with_indices(x.primitive.tensor(), output_size);
+
+    (
+        Tensor::new(TensorPrimitive::Float(output.output)),
+        Tensor::new(output.indices),
+    )
+}
+
 /// Applies a [2D interpolation](crate::ops::ModuleOps::interpolate).
 pub fn interpolate<B>(
     x: Tensor<B, 4>,
@@ -292,3 +455,31 @@ where
         options,
     )))
 }
+
+/// Applies a [2D grid sampling](crate::ops::ModuleOps::grid_sample_2d).
+pub fn grid_sample_2d<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    options: GridSampleOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::grid_sample_2d(
+        x.primitive.tensor(),
+        grid.primitive.tensor(),
+        options,
+    )))
+}
+
+/// Generates a [2D affine grid](crate::ops::ModuleOps::affine_grid_2d).
+pub fn affine_grid_2d<B>(theta: Tensor<B, 3>, size: [usize; 4], align_corners: bool) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::affine_grid_2d(
+        theta.primitive.tensor(),
+        size,
+        align_corners,
+    )))
+}
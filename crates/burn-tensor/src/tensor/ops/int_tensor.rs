@@ -9,7 +9,7 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices};
+use crate::{argsort, cummax, cummin, cumprod, cumsum, sort, sort_with_indices};
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -1279,4 +1279,74 @@ pub trait IntTensorOps<B: Backend> {
     ) -> IntTensor<B, D> {
         argsort::<B, D, Int>(tensor, dim, descending)
     }
+
+    /// Computes the cumulative sum of the elements along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of all the
+    /// previous elements (inclusive) along the dimension.
+    fn int_cumsum<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cumsum::<B, D, Int>(Tensor::from_primitive(tensor), dim).into_primitive()
+    }
+
+    /// Computes the cumulative product of the elements along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of all
+    /// the previous elements (inclusive) along the dimension.
+    fn int_cumprod<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cumprod::<B, D, Int>(Tensor::from_primitive(tensor), dim).into_primitive()
+    }
+
+    /// Computes the cumulative maximum of the elements along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the maximum of all
+    /// the previous elements (inclusive) along the dimension, and the indices of the maximum values.
+    /// The index of the last occurrence is returned for equal values.
+    fn int_cummax<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        let (values, indices) = cummax::<B, D, Int>(Tensor::from_primitive(tensor), dim);
+        (values.into_primitive(), indices.into_primitive())
+    }
+
+    /// Computes the cumulative minimum of the elements along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative minimum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the minimum of all
+    /// the previous elements (inclusive) along the dimension, and the indices of the minimum values.
+    /// The index of the last occurrence is returned for equal values.
+    fn int_cummin<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        let (values, indices) = cummin::<B, D, Int>(Tensor::from_primitive(tensor), dim);
+        (values.into_primitive(), indices.into_primitive())
+    }
 }
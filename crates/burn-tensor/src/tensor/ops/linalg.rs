@@ -0,0 +1,130 @@
+use super::FloatTensor;
+use crate::backend::Backend;
+use crate::tensor::linalg::reference;
+
+/// Linear algebra operations.
+///
+/// The operations are applied on the last two dimensions of the tensors, which are the rows and
+/// the columns of the matrices, and are batched over the other dimensions.
+///
+/// The default implementations are reference implementations computed on the host, which are
+/// synchronous and aren't designed for large matrices. This trait let backend implementations
+/// override them with optimized routines (e.g., LAPACK), or with the [device](crate::linalg::device)
+/// implementations when the values can't be read synchronously.
+pub trait LinalgOps<B: Backend> {
+    /// Solves the linear system `a x = b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The coefficient matrices of shape `[..., n, n]`.
+    /// * `b` - The right-hand side of shape `[..., n, k]`.
+    ///
+    /// # Returns
+    ///
+    /// The solution `x` of shape `[..., n, k]`.
+    fn solve<const D: usize>(a: FloatTensor<B, D>, b: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        reference::solve::<B, D>(a, b)
+    }
+
+    /// Computes the inverse of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The inverse matrices of shape `[..., n, n]`.
+    fn inverse<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        reference::inverse::<B, D>(tensor)
+    }
+
+    /// Computes the determinant of square matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The determinants of shape `[..., 1, 1]`.
+    fn det<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        reference::det::<B, D>(tensor)
+    }
+
+    /// Computes the natural logarithm of the absolute value of the determinant of square
+    /// matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The log-determinants of shape `[..., 1, 1]`.
+    fn logdet<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        reference::logdet::<B, D>(tensor)
+    }
+
+    /// Computes the Cholesky decomposition `a = l l^T` of symmetric positive-definite matrices.
+    ///
+    /// Only the lower triangular part of the matrices is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular matrices `l` of shape `[..., n, n]`.
+    fn cholesky<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        reference::cholesky::<B, D>(tensor)
+    }
+
+    /// Computes the reduced QR decomposition `a = q r`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The orthonormal matrices `q` of shape `[..., m, k]` and the upper triangular matrices `r`
+    /// of shape `[..., k, n]`, where `k = min(m, n)`.
+    fn qr<const D: usize>(tensor: FloatTensor<B, D>) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        reference::qr::<B, D>(tensor)
+    }
+
+    /// Computes the reduced singular value decomposition `a = u diag(s) vh`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The left singular vectors `u` of shape `[..., m, k]`, the singular values `s` in
+    /// descending order of shape `[..., 1, k]` and the transposed right singular vectors `vh` of
+    /// shape `[..., k, n]`, where `k = min(m, n)`.
+    fn svd<const D: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>, FloatTensor<B, D>) {
+        reference::svd::<B, D>(tensor)
+    }
+
+    /// Computes the eigenvalues and eigenvectors `a = v diag(w) v^T` of symmetric matrices.
+    ///
+    /// Only the lower triangular part of the matrices is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues `w` in ascending order of shape `[..., 1, n]` and the orthonormal
+    /// eigenvectors `v` (as columns) of shape `[..., n, n]`.
+    fn eigh<const D: usize>(tensor: FloatTensor<B, D>) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        reference::eigh::<B, D>(tensor)
+    }
+}
@@ -2,6 +2,7 @@ mod activation;
 mod alias;
 mod bool_tensor;
 mod int_tensor;
+mod linalg;
 mod modules;
 mod qtensor;
 mod tensor;
@@ -10,6 +11,7 @@ pub use activation::*;
 pub use alias::*;
 pub use bool_tensor::*;
 pub use int_tensor::*;
+pub use linalg::*;
 pub use modules::*;
 pub use qtensor::*;
 pub use tensor::*;
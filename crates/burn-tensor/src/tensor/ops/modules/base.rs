@@ -1,4 +1,4 @@
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{conv, grid_sample, pool, unfold::unfold4d_using_conv2d};
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
@@ -31,14 +31,16 @@ pub struct Conv3dBackward<B: Backend> {
     pub bias_grad: Option<FloatTensor<B, 1>>,
 }
 
-/// Gradient computed during the backward pass for each tensor used by [max_pool1d](ModuleOps::max_pool1d).
+/// Gradient computed during the backward pass for each tensor used by [max_pool1d](ModuleOps::max_pool1d)
+/// and [adaptive_max_pool1d](ModuleOps::adaptive_max_pool1d).
 #[derive(new)]
 pub struct MaxPool1dBackward<B: Backend> {
     /// Gradient.
     pub x_grad: FloatTensor<B, 3>,
 }
 
-/// Results from [max_pool1d](ModuleOps::max_pool1d_with_indices).
+/// Results from [max_pool1d](ModuleOps::max_pool1d_with_indices) and
+/// [adaptive_max_pool1d](ModuleOps::adaptive_max_pool1d_with_indices).
 #[derive(new)]
 pub struct MaxPool1dWithIndices<B: Backend> {
     /// The output tensor.
@@ -48,14 +50,16 @@ pub struct MaxPool1dWithIndices<B: Backend> {
     pub indices: IntTensor<B, 3>,
 }
 
-/// Gradient computed during the backward pass for each tensor used by [max_pool2d](ModuleOps::max_pool2d).
+/// Gradient computed during the backward pass for each tensor used by [max_pool2d](ModuleOps::max_pool2d)
+/// and [adaptive_max_pool2d](ModuleOps::adaptive_max_pool2d).
 #[derive(new)]
 pub struct MaxPool2dBackward<B: Backend> {
     /// Gradient.
     pub x_grad: FloatTensor<B, 4>,
 }
 
-/// Results from [max_pool2d](ModuleOps::max_pool2d_with_indices).
+/// Results from [max_pool2d](ModuleOps::max_pool2d_with_indices) and
+/// [adaptive_max_pool2d](ModuleOps::adaptive_max_pool2d_with_indices).
 #[derive(new)]
 pub struct MaxPool2dWithIndices<B: Backend> {
     /// The output tensor.
@@ -65,6 +69,23 @@ pub struct MaxPool2dWithIndices<B: Backend> {
     pub indices: IntTensor<B, 4>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [max_pool3d](ModuleOps::max_pool3d).
+#[derive(new)]
+pub struct MaxPool3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B, 5>,
+}
+
+/// Results from [max_pool3d](ModuleOps::max_pool3d_with_indices).
+#[derive(new)]
+pub struct MaxPool3dWithIndices<B: Backend> {
+    /// The output tensor.
+    pub output: FloatTensor<B, 5>,
+
+    /// The indices tensor.
+    pub indices: IntTensor<B, 5>,
+}
+
 /// Gradient computed during the backward pass for each tensor used by [conv1d](ModuleOps::conv1d).
 #[derive(new)]
 pub struct Conv1dBackward<B: Backend> {
@@ -157,6 +178,64 @@ pub struct InterpolateBackward<B: Backend> {
     pub x_grad: FloatTensor<B, 4>,
 }
 
+/// Algorithm used to compute the sampled values in [grid sampling](ModuleOps::grid_sample_2d).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSampleMode {
+    /// Bilinear interpolation of the four nearest input values.
+    Bilinear,
+
+    /// Value of the nearest input pixel, with coordinates halfway between two pixels rounded to
+    /// the even one.
+    Nearest,
+}
+
+/// How [grid sampling](ModuleOps::grid_sample_2d) handles grid locations outside of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSamplePaddingMode {
+    /// Out of bound locations are sampled as zeros.
+    Zeros,
+
+    /// Out of bound locations are clamped to the border of the input.
+    Border,
+
+    /// Out of bound locations are reflected by the border of the input.
+    Reflection,
+}
+
+/// Grid sampling options.
+#[derive(new, Debug, Clone)]
+pub struct GridSampleOptions {
+    /// Algorithm used to compute the sampled values.
+    pub mode: GridSampleMode,
+
+    /// How out of bound grid locations are handled.
+    pub padding_mode: GridSamplePaddingMode,
+
+    /// If true, the extrema `-1` and `1` of the grid refer to the centers of the corner pixels of
+    /// the input. Otherwise, they refer to the outer edges of the corner pixels.
+    pub align_corners: bool,
+}
+
+impl Default for GridSampleOptions {
+    fn default() -> Self {
+        Self::new(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            false,
+        )
+    }
+}
+
+/// Gradient computed during the backward pass for each tensor used by [grid_sample_2d](ModuleOps::grid_sample_2d).
+#[derive(new)]
+pub struct GridSample2dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: FloatTensor<B, 4>,
+
+    /// Grid gradient.
+    pub grid_grad: FloatTensor<B, 4>,
+}
+
 /// Module operations trait.
 pub trait ModuleOps<B: Backend> {
     /// Embedding operation.
@@ -530,6 +609,167 @@ pub trait ModuleOps<B: Backend> {
         indices: IntTensor<B, 4>,
     ) -> MaxPool2dBackward<B>;
 
+    /// Three dimensional avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn avg_pool3d(
+        x: FloatTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<B, 5> {
+        pool::avg_pool3d_from_2d::<B>(x, kernel_size, stride, padding, count_include_pad)
+    }
+    /// Backward pass for the [avg pooling 3d](ModuleOps::avg_pool3d) operation.
+    fn avg_pool3d_backward(
+        x: FloatTensor<B, 5>,
+        grad: FloatTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        count_include_pad: bool,
+    ) -> FloatTensor<B, 5> {
+        pool::avg_pool3d_backward_from_2d::<B>(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        )
+    }
+    /// Three dimensional adaptive avg pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn adaptive_avg_pool3d(x: FloatTensor<B, 5>, output_size: [usize; 3]) -> FloatTensor<B, 5> {
+        pool::adaptive_avg_pool3d_from_2d::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive avg pooling 3d](ModuleOps::adaptive_avg_pool3d) operation.
+    fn adaptive_avg_pool3d_backward(
+        x: FloatTensor<B, 5>,
+        grad: FloatTensor<B, 5>,
+    ) -> FloatTensor<B, 5> {
+        pool::adaptive_avg_pool3d_backward_from_2d::<B>(x, grad)
+    }
+    /// Three dimensional max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d(
+        x: FloatTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> FloatTensor<B, 5> {
+        pool::max_pool3d_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+    }
+
+    /// Three dimensional max pooling with indices.
+    ///
+    /// The indices are flattened over the depth, height and width dimensions of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, depth, height, width],
+    fn max_pool3d_with_indices(
+        x: FloatTensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        padding: [usize; 3],
+        dilation: [usize; 3],
+    ) -> MaxPool3dWithIndices<B> {
+        pool::max_pool3d_with_indices_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+    }
+    /// Backward pass for the [max pooling 3d](ModuleOps::max_pool3d_with_indices) operation.
+    fn max_pool3d_with_indices_backward(
+        x: FloatTensor<B, 5>,
+        _kernel_size: [usize; 3],
+        _stride: [usize; 3],
+        _padding: [usize; 3],
+        _dilation: [usize; 3],
+        output_grad: FloatTensor<B, 5>,
+        indices: IntTensor<B, 5>,
+    ) -> MaxPool3dBackward<B> {
+        MaxPool3dBackward::new(pool::max_pool_backward_with_indices::<B, 5>(
+            x,
+            output_grad,
+            indices,
+        ))
+    }
+
+    /// One dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn adaptive_max_pool1d(x: FloatTensor<B, 3>, output_size: usize) -> FloatTensor<B, 3> {
+        B::adaptive_max_pool1d_with_indices(x, output_size).output
+    }
+    /// One dimensional adaptive max pooling with indices.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, length],
+    fn adaptive_max_pool1d_with_indices(
+        x: FloatTensor<B, 3>,
+        output_size: usize,
+    ) -> MaxPool1dWithIndices<B> {
+        pool::adaptive_max_pool1d_with_indices_from_2d::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive max pooling 1d](ModuleOps::adaptive_max_pool1d_with_indices) operation.
+    fn adaptive_max_pool1d_with_indices_backward(
+        x: FloatTensor<B, 3>,
+        output_grad: FloatTensor<B, 3>,
+        indices: IntTensor<B, 3>,
+    ) -> MaxPool1dBackward<B> {
+        MaxPool1dBackward::new(pool::max_pool_backward_with_indices::<B, 3>(
+            x,
+            output_grad,
+            indices,
+        ))
+    }
+
+    /// Two dimensional adaptive max pooling.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d(x: FloatTensor<B, 4>, output_size: [usize; 2]) -> FloatTensor<B, 4> {
+        B::adaptive_max_pool2d_with_indices(x, output_size).output
+    }
+    /// Two dimensional adaptive max pooling with indices.
+    ///
+    /// The indices are flattened over the height and width dimensions of the input.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d_with_indices(
+        x: FloatTensor<B, 4>,
+        output_size: [usize; 2],
+    ) -> MaxPool2dWithIndices<B> {
+        pool::adaptive_max_pool2d_with_indices_default::<B>(x, output_size)
+    }
+    /// Backward pass for the [adaptive max pooling 2d](ModuleOps::adaptive_max_pool2d_with_indices) operation.
+    fn adaptive_max_pool2d_with_indices_backward(
+        x: FloatTensor<B, 4>,
+        output_grad: FloatTensor<B, 4>,
+        indices: IntTensor<B, 4>,
+    ) -> MaxPool2dBackward<B> {
+        MaxPool2dBackward::new(pool::max_pool_backward_with_indices::<B, 4>(
+            x,
+            output_grad,
+            indices,
+        ))
+    }
+
     /// Down/up samples the input.
     ///
     /// # Shapes
@@ -548,4 +788,48 @@ pub trait ModuleOps<B: Backend> {
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> FloatTensor<B, 4>;
+
+    /// Samples the input at the locations given by the grid.
+    ///
+    /// The grid holds `(x, y)` locations normalized to `[-1, 1]`, where `(-1, -1)` is the top-left
+    /// corner of the input and `(1, 1)` is the bottom-right corner.
+    ///
+    /// # Shapes
+    ///
+    /// x: `[batch_size, channels, height, width]`,
+    /// grid: `[batch_size, height_out, width_out, 2]`,
+    /// output: `[batch_size, channels, height_out, width_out]`.
+    fn grid_sample_2d(
+        x: FloatTensor<B, 4>,
+        grid: FloatTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> FloatTensor<B, 4> {
+        grid_sample::grid_sample_2d_using_gather::<B>(x, grid, options)
+    }
+
+    /// Backward pass for the [grid sample 2d](ModuleOps::grid_sample_2d) operation.
+    fn grid_sample_2d_backward(
+        x: FloatTensor<B, 4>,
+        grid: FloatTensor<B, 4>,
+        grad: FloatTensor<B, 4>,
+        options: GridSampleOptions,
+    ) -> GridSample2dBackward<B> {
+        grid_sample::grid_sample_2d_backward_using_scatter::<B>(x, grid, grad, options)
+    }
+
+    /// Generates a sampling grid from a batch of affine matrices, to be used with
+    /// [grid sample 2d](ModuleOps::grid_sample_2d).
+    ///
+    /// # Shapes
+    ///
+    /// theta: `[batch_size, 2, 3]`,
+    /// size: `[batch_size, channels, height, width]`,
+    /// output: `[batch_size, height, width, 2]`.
+    fn affine_grid_2d(
+        theta: FloatTensor<B, 3>,
+        size: [usize; 4],
+        align_corners: bool,
+    ) -> FloatTensor<B, 4> {
+        grid_sample::affine_grid_2d_using_matmul::<B>(theta, size, align_corners)
+    }
 }
@@ -0,0 +1,365 @@
+use crate::{backend::Backend, ops::FloatTensor, Int, Tensor, TensorPrimitive};
+use alloc::{vec, vec::Vec};
+
+use super::{GridSample2dBackward, GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+
+/// Pixel coordinates sampled along one spatial dimension, with the derivative of those
+/// coordinates with respect to the normalized grid values.
+struct SourceCoordinates<B: Backend> {
+    coords: Tensor<B, 2>,
+    grad_scale: Tensor<B, 2>,
+}
+
+/// A neighbor of the sampled point in the flattened spatial dimensions of the input.
+struct Corner<B: Backend> {
+    /// Indices in the flattened input with shape `[batch_size, channels, points]`.
+    indices: Tensor<B, 3, Int>,
+    /// Interpolation weight with shape `[batch_size, points]`.
+    weight: Tensor<B, 2>,
+    /// Whether the neighbor lies inside the input, with shape `[batch_size, points]`.
+    valid: Tensor<B, 2>,
+}
+
+impl<B: Backend> Corner<B> {
+    /// Weight of the neighbor, which is zero when it lies in the padding.
+    fn masked_weight(&self) -> Tensor<B, 3> {
+        (self.weight.clone() * self.valid.clone()).unsqueeze_dim(1)
+    }
+}
+
+fn floor<B: Backend>(x: Tensor<B, 2>) -> Tensor<B, 2> {
+    // Casting truncates towards zero, so negative non-integer values are shifted down by one.
+    let truncated = x.clone().int().float();
+    truncated.clone() - x.lower(truncated).float()
+}
+
+/// Rounds to the nearest integer, with ties rounded to the nearest even integer like PyTorch and
+/// the ONNX reference implementation.
+fn round_half_to_even<B: Backend>(x: Tensor<B, 2>) -> Tensor<B, 2> {
+    let rounded = floor(x.clone().add_scalar(0.5));
+    let tie = (rounded.clone() - x).equal_elem(0.5).float();
+    let odd = (rounded.clone() - floor(rounded.clone().div_scalar(2.0)).mul_scalar(2.0))
+        .equal_elem(1.0)
+        .float();
+
+    // Ties are rounded up, so the odd ones are moved down to the even neighbor.
+    rounded - tie * odd
+}
+
+fn clip_coordinates<B: Backend>(coords: SourceCoordinates<B>, size: usize) -> SourceCoordinates<B> {
+    let max = (size - 1) as f64;
+    let inside = coords.coords.clone().greater_equal_elem(0.0).float()
+        * coords.coords.clone().lower_equal_elem(max).float();
+
+    SourceCoordinates {
+        coords: coords.coords.clamp(0.0, max),
+        grad_scale: coords.grad_scale * inside,
+    }
+}
+
+fn reflect_coordinates<B: Backend>(
+    coords: SourceCoordinates<B>,
+    twice_low: i64,
+    twice_high: i64,
+) -> SourceCoordinates<B> {
+    if twice_low == twice_high {
+        return SourceCoordinates {
+            coords: coords.coords.zeros_like(),
+            grad_scale: coords.grad_scale.zeros_like(),
+        };
+    }
+
+    let min = twice_low as f64 / 2.0;
+    let span = (twice_high - twice_low) as f64 / 2.0;
+
+    let shifted = coords.coords.sub_scalar(min);
+    let direction = shifted
+        .clone()
+        .lower_elem(0.0)
+        .float()
+        .mul_scalar(-2.0)
+        .add_scalar(1.0);
+    let shifted = shifted.abs();
+
+    let flips = floor(shifted.clone().div_scalar(span));
+    let extra = shifted - flips.clone().mul_scalar(span);
+    let even = flips.remainder_scalar(2.0).lower_elem(0.5);
+
+    let reflected = extra.clone().mul_scalar(-1.0).add_scalar(span + min);
+    let coords_out = reflected.mask_where(even.clone(), extra.add_scalar(min));
+    let parity = even.float().mul_scalar(2.0).sub_scalar(1.0);
+
+    SourceCoordinates {
+        coords: coords_out,
+        grad_scale: coords.grad_scale * direction * parity,
+    }
+}
+
+/// Converts the normalized grid values in `[-1, 1]` to pixel coordinates of a dimension of
+/// the given `size`, applying the padding mode.
+fn source_coordinates<B: Backend>(
+    grid: Tensor<B, 2>,
+    size: usize,
+    options: &GridSampleOptions,
+) -> SourceCoordinates<B> {
+    let size_f = size as f64;
+    let (coords, scale) = match options.align_corners {
+        true => (
+            grid.clone()
+                .add_scalar(1.0)
+                .mul_scalar((size_f - 1.0) / 2.0),
+            (size_f - 1.0) / 2.0,
+        ),
+        false => (
+            grid.clone()
+                .add_scalar(1.0)
+                .mul_scalar(size_f)
+                .sub_scalar(1.0)
+                .div_scalar(2.0),
+            size_f / 2.0,
+        ),
+    };
+    let coords = SourceCoordinates {
+        coords,
+        grad_scale: grid.ones_like().mul_scalar(scale),
+    };
+
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => coords,
+        GridSamplePaddingMode::Border => clip_coordinates(coords, size),
+        GridSamplePaddingMode::Reflection => {
+            let size = size as i64;
+            let coords = match options.align_corners {
+                true => reflect_coordinates(coords, 0, 2 * (size - 1)),
+                false => reflect_coordinates(coords, -1, 2 * size - 1),
+            };
+            clip_coordinates(coords, size as usize)
+        }
+    }
+}
+
+fn in_bounds<B: Backend>(coords: Tensor<B, 2>, size: usize) -> Tensor<B, 2> {
+    coords.clone().greater_equal_elem(0.0).float()
+        * coords.lower_equal_elem((size - 1) as f64).float()
+}
+
+fn corner<B: Backend>(
+    x_coords: Tensor<B, 2>,
+    y_coords: Tensor<B, 2>,
+    weight: Tensor<B, 2>,
+    [channels, height, width]: [usize; 3],
+) -> Corner<B> {
+    let valid = in_bounds(x_coords.clone(), width) * in_bounds(y_coords.clone(), height);
+
+    let x_coords = x_coords.clamp(0.0, (width - 1) as f64).int();
+    let y_coords = y_coords.clamp(0.0, (height - 1) as f64).int();
+    let indices = y_coords.mul_scalar(width as i64) + x_coords;
+    let [batch_size, points] = indices.dims();
+    let indices = indices
+        .unsqueeze_dim::<3>(1)
+        .expand([batch_size, channels, points]);
+
+    Corner {
+        indices,
+        weight,
+        valid,
+    }
+}
+
+/// Splits the grid into its `x` and `y` components, each with shape `[batch_size, points]`.
+fn split_grid<B: Backend>(grid: Tensor<B, 4>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let [batch_size, height_out, width_out, _] = grid.dims();
+    let points = height_out * width_out;
+
+    let grid_x = grid.clone().narrow(3, 0, 1).reshape([batch_size, points]);
+    let grid_y = grid.narrow(3, 1, 1).reshape([batch_size, points]);
+
+    (grid_x, grid_y)
+}
+
+/// Computes the corners used to sample each point of the grid, along with the source
+/// coordinates of the points.
+fn corners<B: Backend>(
+    x_dims: [usize; 4],
+    grid: Tensor<B, 4>,
+    options: &GridSampleOptions,
+) -> (Vec<Corner<B>>, SourceCoordinates<B>, SourceCoordinates<B>) {
+    let [_, channels, height, width] = x_dims;
+    let dims = [channels, height, width];
+    let (grid_x, grid_y) = split_grid(grid);
+
+    let source_x = source_coordinates(grid_x, width, options);
+    let source_y = source_coordinates(grid_y, height, options);
+
+    let corners = match options.mode {
+        GridSampleMode::Nearest => {
+            let x = round_half_to_even(source_x.coords.clone());
+            let y = round_half_to_even(source_y.coords.clone());
+            let weight = x.ones_like();
+
+            vec![corner(x, y, weight, dims)]
+        }
+        GridSampleMode::Bilinear => {
+            let x0 = floor(source_x.coords.clone());
+            let y0 = floor(source_y.coords.clone());
+            let x1 = x0.clone().add_scalar(1.0);
+            let y1 = y0.clone().add_scalar(1.0);
+
+            let wx1 = source_x.coords.clone() - x0.clone();
+            let wy1 = source_y.coords.clone() - y0.clone();
+            let wx0 = wx1.clone().mul_scalar(-1.0).add_scalar(1.0);
+            let wy0 = wy1.clone().mul_scalar(-1.0).add_scalar(1.0);
+
+            vec![
+                corner(x0.clone(), y0.clone(), wx0.clone() * wy0.clone(), dims),
+                corner(x1.clone(), y0, wx1.clone() * wy0, dims),
+                corner(x0, y1.clone(), wx0 * wy1.clone(), dims),
+                corner(x1, y1, wx1 * wy1, dims),
+            ]
+        }
+    };
+
+    (corners, source_x, source_y)
+}
+
+pub(crate) fn grid_sample_2d_using_gather<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grid: FloatTensor<B, 4>,
+    options: GridSampleOptions,
+) -> FloatTensor<B, 4> {
+    let x = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(x));
+    let grid = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(grid));
+
+    let [batch_size, channels, height, width] = x.dims();
+    let [_, height_out, width_out, _] = grid.dims();
+
+    let (corners, _, _) = corners(x.dims(), grid, &options);
+    let x = x.reshape([batch_size, channels, height * width]);
+
+    let output = corners
+        .into_iter()
+        .map(|corner| x.clone().gather(2, corner.indices.clone()) * corner.masked_weight())
+        .reduce(|acc, value| acc + value)
+        .unwrap();
+
+    output
+        .reshape([batch_size, channels, height_out, width_out])
+        .into_primitive()
+        .tensor()
+}
+
+pub(crate) fn grid_sample_2d_backward_using_scatter<B: Backend>(
+    x: FloatTensor<B, 4>,
+    grid: FloatTensor<B, 4>,
+    grad: FloatTensor<B, 4>,
+    options: GridSampleOptions,
+) -> GridSample2dBackward<B> {
+    let x = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(x));
+    let grid = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(grid));
+    let grad = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(grad));
+
+    let [batch_size, channels, height, width] = x.dims();
+    let [_, height_out, width_out, _] = grid.dims();
+    let points = height_out * width_out;
+
+    let (corners, source_x, source_y) = corners(x.dims(), grid.clone(), &options);
+    let x = x.reshape([batch_size, channels, height * width]);
+    let grad = grad.reshape([batch_size, channels, points]);
+
+    let mut x_grad = x.zeros_like();
+    for corner in corners.iter() {
+        x_grad = x_grad.scatter(
+            2,
+            corner.indices.clone(),
+            grad.clone() * corner.masked_weight(),
+        );
+    }
+    let x_grad = x_grad.reshape([batch_size, channels, height, width]);
+
+    let grid_grad = match options.mode {
+        // The output is piecewise constant with respect to the grid.
+        GridSampleMode::Nearest => grid.zeros_like(),
+        GridSampleMode::Bilinear => {
+            // Values of the in-bounds corners, without the interpolation weights.
+            let mut values = corners.iter().map(|corner| {
+                x.clone().gather(2, corner.indices.clone()) * corner.valid.clone().unsqueeze_dim(1)
+            });
+            let mut next_value = || values.next().unwrap();
+            let [v00, v10, v01, v11] = [next_value(), next_value(), next_value(), next_value()];
+
+            let wx1 = (source_x.coords.clone() - floor(source_x.coords)).unsqueeze_dim::<3>(1);
+            let wy1 = (source_y.coords.clone() - floor(source_y.coords)).unsqueeze_dim::<3>(1);
+            let wx0 = wx1.clone().mul_scalar(-1.0).add_scalar(1.0);
+            let wy0 = wy1.clone().mul_scalar(-1.0).add_scalar(1.0);
+
+            let grad_x = (v10.clone() - v00.clone()) * wy0 + (v11.clone() - v01.clone()) * wy1;
+            let grad_y = (v01 - v00) * wx0 + (v11 - v10) * wx1;
+
+            let grad_x = (grad.clone() * grad_x)
+                .sum_dim(1)
+                .reshape([batch_size, points])
+                * source_x.grad_scale;
+            let grad_y =
+                (grad * grad_y).sum_dim(1).reshape([batch_size, points]) * source_y.grad_scale;
+
+            Tensor::cat(
+                vec![
+                    grad_x.reshape([batch_size, points, 1]),
+                    grad_y.reshape([batch_size, points, 1]),
+                ],
+                2,
+            )
+            .reshape([batch_size, height_out, width_out, 2])
+        }
+    };
+
+    GridSample2dBackward::new(
+        x_grad.into_primitive().tensor(),
+        grid_grad.into_primitive().tensor(),
+    )
+}
+
+/// Normalized coordinates of the pixel centers (or corners) of a dimension of the given `size`.
+fn linspace<B: Backend>(size: usize, align_corners: bool, device: &B::Device) -> Tensor<B, 1> {
+    let steps = Tensor::<B, 1, Int>::arange(0..size as i64, device).float();
+
+    match (align_corners, size) {
+        (_, 1) => steps.zeros_like(),
+        (true, _) => steps.mul_scalar(2.0 / (size - 1) as f64).sub_scalar(1.0),
+        (false, _) => steps
+            .mul_scalar(2.0)
+            .add_scalar(1.0)
+            .div_scalar(size as f64)
+            .sub_scalar(1.0),
+    }
+}
+
+pub(crate) fn affine_grid_2d_using_matmul<B: Backend>(
+    theta: FloatTensor<B, 3>,
+    size: [usize; 4],
+    align_corners: bool,
+) -> FloatTensor<B, 4> {
+    let theta = Tensor::<B, 3>::from_primitive(TensorPrimitive::Float(theta));
+    let device = theta.device();
+    let [batch_size, _, height, width] = size;
+    let points = height * width;
+
+    let xs = linspace::<B>(width, align_corners, &device)
+        .reshape([1, width])
+        .expand([height, width])
+        .reshape([points, 1]);
+    let ys = linspace::<B>(height, align_corners, &device)
+        .reshape([height, 1])
+        .expand([height, width])
+        .reshape([points, 1]);
+    let ones = Tensor::<B, 2>::ones([points, 1], &device);
+
+    let base = Tensor::cat(vec![xs, ys, ones], 1)
+        .unsqueeze::<3>()
+        .expand([batch_size, points, 3]);
+
+    base.matmul(theta.swap_dims(1, 2))
+        .reshape([batch_size, height, width, 2])
+        .into_primitive()
+        .tensor()
+}
@@ -3,6 +3,8 @@ pub mod conv;
 
 /// Module with cat operation
 pub(crate) mod cat;
+/// Module with grid sampling operations.
+pub(crate) mod grid_sample;
 /// Module with repeat operation
 pub(crate) mod repeat;
 /// Module with unfold operations.
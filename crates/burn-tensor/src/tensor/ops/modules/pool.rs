@@ -1,10 +1,11 @@
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
-    Shape,
+    ElementConversion, Shape,
 };
+use alloc::vec::Vec;
 
-use super::{MaxPool1dBackward, MaxPool1dWithIndices};
+use super::{MaxPool1dBackward, MaxPool1dWithIndices, MaxPool2dWithIndices, MaxPool3dWithIndices};
 
 pub(crate) fn avg_pool1d_from_2d<B: Backend>(
     x: FloatTensor<B, 3>,
@@ -165,3 +166,347 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         Shape::from([batch_size, channels, length_in]),
     ))
 }
+
+pub(crate) fn avg_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+
+    // The pooling window is separable: pool over the height and width first, then over the depth.
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+    let [_, _, height_out, width_out] = B::float_shape(&x).dims;
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::avg_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+    let [_, _, depth_out, _] = B::float_shape(&x).dims;
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn avg_pool3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    grad: FloatTensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    count_include_pad: bool,
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::float_shape(&grad).dims;
+    let device = B::float_device(&x);
+
+    // Only the shape of the intermediate input is used by the backward pass.
+    let x_depth = B::float_zeros(
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+        &device,
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x_depth,
+        grad,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        count_include_pad,
+    );
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels * depth, height_out, width_out]),
+    );
+    let grad = B::avg_pool2d_backward(
+        x,
+        grad,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        count_include_pad,
+    );
+
+    B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth, height, width]),
+    )
+}
+
+pub(crate) fn adaptive_avg_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    output_size: [usize; 3],
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+    let [depth_out, height_out, width_out] = output_size;
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::adaptive_avg_pool2d(x, [height_out, width_out]);
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::adaptive_avg_pool2d(x, [depth_out, height_out * width_out]);
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn adaptive_avg_pool3d_backward_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    grad: FloatTensor<B, 5>,
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+    let [_, _, depth_out, height_out, width_out] = B::float_shape(&grad).dims;
+    let device = B::float_device(&x);
+
+    // Only the shape of the intermediate input is used by the backward pass.
+    let x_depth = B::float_zeros(
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+        &device,
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth_out, height_out * width_out]),
+    );
+    let grad = B::adaptive_avg_pool2d_backward(x_depth, grad);
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let grad = B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels * depth, height_out, width_out]),
+    );
+    let grad = B::adaptive_avg_pool2d_backward(x, grad);
+
+    B::float_reshape(
+        grad,
+        Shape::from([batch_size, channels, depth, height, width]),
+    )
+}
+
+pub(crate) fn max_pool3d_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> FloatTensor<B, 5> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+
+    // The pooling window is separable: pool over the height and width first, then over the depth.
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+    let [_, _, height_out, width_out] = B::float_shape(&x).dims;
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth, height_out * width_out]),
+    );
+    let x = B::max_pool2d(
+        x,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+    let [_, _, depth_out, _] = B::float_shape(&x).dims;
+
+    B::float_reshape(
+        x,
+        Shape::from([batch_size, channels, depth_out, height_out, width_out]),
+    )
+}
+
+pub(crate) fn max_pool3d_with_indices_from_2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    kernel_size: [usize; 3],
+    stride: [usize; 3],
+    padding: [usize; 3],
+    dilation: [usize; 3],
+) -> MaxPool3dWithIndices<B> {
+    let [batch_size, channels, depth, height, width] = B::float_shape(&x).dims;
+
+    let x = B::float_reshape(
+        x,
+        Shape::from([batch_size, channels * depth, height, width]),
+    );
+    let x = B::max_pool2d_with_indices(
+        x,
+        [kernel_size[1], kernel_size[2]],
+        [stride[1], stride[2]],
+        [padding[1], padding[2]],
+        [dilation[1], dilation[2]],
+    );
+    let [_, _, height_out, width_out] = B::float_shape(&x.output).dims;
+    let plane_out = height_out * width_out;
+
+    let output = B::float_reshape(
+        x.output,
+        Shape::from([batch_size, channels, depth, plane_out]),
+    );
+    let output = B::max_pool2d_with_indices(
+        output,
+        [kernel_size[0], 1],
+        [stride[0], 1],
+        [padding[0], 0],
+        [dilation[0], 1],
+    );
+    let [_, _, depth_out, _] = B::float_shape(&output.output).dims;
+
+    // The depth pooling indices point into the flattened [depth, plane_out] intermediate tensor, which is used to
+    // retrieve the matching height and width indices of the first pooling.
+    let indices_depth = B::int_reshape(
+        output.indices,
+        Shape::from([batch_size, channels, depth_out * plane_out]),
+    );
+    let indices_plane = B::int_reshape(
+        x.indices,
+        Shape::from([batch_size, channels, depth * plane_out]),
+    );
+    let indices_plane = B::int_gather(2, indices_plane, indices_depth.clone());
+    let indices_depth = B::int_div_scalar(indices_depth, (plane_out as i64).elem());
+    let indices = B::int_add(
+        B::int_mul_scalar(indices_depth, ((height * width) as i64).elem()),
+        indices_plane,
+    );
+
+    let shape = Shape::from([batch_size, channels, depth_out, height_out, width_out]);
+    MaxPool3dWithIndices::new(
+        B::float_reshape(output.output, shape.clone()),
+        B::int_reshape(indices, shape),
+    )
+}
+
+pub(crate) fn adaptive_max_pool1d_with_indices_from_2d<B: Backend>(
+    x: FloatTensor<B, 3>,
+    output_size: usize,
+) -> MaxPool1dWithIndices<B> {
+    let [batch_size, channels, length] = B::float_shape(&x).dims;
+
+    let x = B::float_reshape(x, Shape::from([batch_size, channels, length, 1]));
+    let x = B::adaptive_max_pool2d_with_indices(x, [output_size, 1]);
+
+    let shape = Shape::from([batch_size, channels, output_size]);
+    MaxPool1dWithIndices::new(
+        B::float_reshape(x.output, shape.clone()),
+        B::int_reshape(x.indices, shape),
+    )
+}
+
+pub(crate) fn adaptive_max_pool2d_with_indices_default<B: Backend>(
+    x: FloatTensor<B, 4>,
+    output_size: [usize; 2],
+) -> MaxPool2dWithIndices<B> {
+    let [_, _, _, width] = B::float_shape(&x).dims;
+    let [height_out, width_out] = output_size;
+
+    // The adaptive windows are separable: pool over the width first, then over the height.
+    let (output, indices_width) = adaptive_max_dim::<B>(x, 3, width_out);
+    let (output, indices_height) = adaptive_max_dim::<B>(output, 2, height_out);
+
+    let indices_width = B::int_gather(2, indices_width, indices_height.clone());
+    let indices = B::int_add(
+        B::int_mul_scalar(indices_height, (width as i64).elem()),
+        indices_width,
+    );
+
+    MaxPool2dWithIndices::new(output, indices)
+}
+
+/// Max pooling of each adaptive window along a single dimension, returning the indices along that dimension.
+fn adaptive_max_dim<B: Backend>(
+    x: FloatTensor<B, 4>,
+    dim: usize,
+    output_size: usize,
+) -> (FloatTensor<B, 4>, IntTensor<B, 4>) {
+    let dims = B::float_shape(&x).dims;
+    let size = dims[dim];
+
+    let mut outputs = Vec::with_capacity(output_size);
+    let mut indices = Vec::with_capacity(output_size);
+
+    for i in 0..output_size {
+        let start = (i * size) / output_size;
+        let end = ((i + 1) * size).div_ceil(output_size);
+
+        let mut ranges = dims.map(|dim_size| 0..dim_size);
+        ranges[dim] = start..end;
+
+        let window = B::float_slice(x.clone(), ranges);
+        let (output, index) = B::float_max_dim_with_indices(window, dim);
+
+        outputs.push(output);
+        indices.push(B::int_add_scalar(index, (start as i64).elem()));
+    }
+
+    (B::float_cat(outputs, dim), B::int_cat(indices, dim))
+}
+
+/// Backward pass of any max pooling operation with indices flattened over the spatial dimensions.
+pub(crate) fn max_pool_backward_with_indices<B: Backend, const D: usize>(
+    x: FloatTensor<B, D>,
+    output_grad: FloatTensor<B, D>,
+    indices: IntTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let shape = B::float_shape(&x);
+    let shape_out = B::float_shape(&output_grad);
+    let [batch_size, channels] = [shape.dims[0], shape.dims[1]];
+    let size = shape.num_elements() / (batch_size * channels);
+    let size_out = shape_out.num_elements() / (batch_size * channels);
+
+    let x_grad = B::float_zeros(
+        Shape::from([batch_size, channels, size]),
+        &B::float_device(&x),
+    );
+    let output_grad = B::float_reshape(output_grad, Shape::from([batch_size, channels, size_out]));
+    let indices = B::int_reshape(indices, Shape::from([batch_size, channels, size_out]));
+
+    B::float_reshape(B::float_scatter(2, x_grad, indices, output_grad), shape)
+}
@@ -6,7 +6,7 @@ use crate::{
     Device, Shape, TensorData,
 };
 
-use super::{FloatTensor, QuantizedTensor};
+use super::{ConvOptions, FloatTensor, QuantizedTensor};
 
 /// Quantized Tensor API for basic operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -33,6 +33,19 @@ pub trait QTensorOps<B: Backend> {
     /// Convert the tensor back to a higher precision data type.
     fn dequantize<const D: usize>(tensor: QuantizedTensor<B, D>) -> FloatTensor<B, D>;
 
+    /// Quantize and dequantize the tensor, which simulates the quantization error in floating
+    /// point precision.
+    ///
+    /// Used in quantization-aware training, where the autodiff backend propagates the gradients
+    /// with a straight-through estimator.
+    fn fake_quantize<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<B>,
+    ) -> FloatTensor<B, D> {
+        B::dequantize(B::quantize(tensor, scheme, qparams))
+    }
+
     /// Gets the shape of the tensor.
     ///
     /// # Arguments
@@ -83,6 +96,101 @@ pub trait QTensorOps<B: Backend> {
         tensor: QuantizedTensor<B, D>,
     ) -> impl Future<Output = TensorData> + Send;
 
+    /// Applies the matrix multiplication operation to two quantized tensors.
+    ///
+    /// Backends should accumulate the products of the quantized values in the integer domain
+    /// (e.g., `i8 x i8 -> i32`) and rescale the accumulators to floating point. The default
+    /// implementation dequantizes both tensors and performs the floating point operation.
+    ///
+    /// See [q_matmul_requantize](QTensorOps::q_matmul_requantize) to requantize the output for
+    /// another quantized operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of multiplying the two tensors together using matrix multiplication.
+    fn q_matmul<const D: usize>(
+        lhs: QuantizedTensor<B, D>,
+        rhs: QuantizedTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        B::float_matmul(B::dequantize(lhs), B::dequantize(rhs))
+    }
+
+    /// Applies the matrix multiplication operation to two quantized tensors and requantizes the
+    /// output with the given scheme and parameters.
+    ///
+    /// Backends should rescale the integer accumulators directly to the quantized output type,
+    /// i.e. `clamp(round(acc * lhs_scale * rhs_scale / scale + offset), a, b)`, without
+    /// materializing the floating point output. The default implementation quantizes the output
+    /// of [q_matmul](QTensorOps::q_matmul).
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    /// * `scheme` - The quantization scheme of the output.
+    /// * `qparams` - The quantization parameters of the output.
+    ///
+    /// # Returns
+    ///
+    /// The quantized result of the matrix multiplication.
+    fn q_matmul_requantize<const D: usize>(
+        lhs: QuantizedTensor<B, D>,
+        rhs: QuantizedTensor<B, D>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<B>,
+    ) -> QuantizedTensor<B, D> {
+        B::quantize(B::q_matmul(lhs, rhs), scheme, qparams)
+    }
+
+    /// Two dimensional convolution of a quantized input with quantized weights.
+    ///
+    /// Backends should accumulate the products of the quantized values in the integer domain
+    /// and rescale the accumulators to floating point before adding the bias. The default
+    /// implementation dequantizes the input and weights and performs the floating point
+    /// operation.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, height, width]`,
+    /// weight: `[channels_out, channels_in, kernel_size_1, kernel_size_2]`,
+    /// bias:   `[channels_out]`,
+    fn q_conv2d(
+        x: QuantizedTensor<B, 4>,
+        weight: QuantizedTensor<B, 4>,
+        bias: Option<FloatTensor<B, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<B, 4> {
+        B::conv2d(B::dequantize(x), B::dequantize(weight), bias, options)
+    }
+
+    /// Two dimensional convolution of a quantized input with quantized weights, where the output
+    /// is requantized with the given scheme and parameters.
+    ///
+    /// Backends should rescale the integer accumulators (and the bias) directly to the quantized
+    /// output type. The default implementation quantizes the output of
+    /// [q_conv2d](QTensorOps::q_conv2d).
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, height, width]`,
+    /// weight: `[channels_out, channels_in, kernel_size_1, kernel_size_2]`,
+    /// bias:   `[channels_out]`,
+    fn q_conv2d_requantize(
+        x: QuantizedTensor<B, 4>,
+        weight: QuantizedTensor<B, 4>,
+        bias: Option<FloatTensor<B, 1>>,
+        options: ConvOptions<2>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<B>,
+    ) -> QuantizedTensor<B, 4> {
+        B::quantize(B::q_conv2d(x, weight, bias, options), scheme, qparams)
+    }
+
     /// Sets the `require_grad` flag of a tensor.
     fn q_set_require_grad<const D: usize>(
         tensor: QuantizedTensor<B, D>,
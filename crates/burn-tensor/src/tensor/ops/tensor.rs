@@ -10,7 +10,7 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices};
+use crate::{argsort, cummax, cummin, cumprod, cumsum, sort, sort_with_indices};
 
 /// Operations on float tensors.
 pub trait FloatTensorOps<B: Backend> {
@@ -1439,4 +1439,80 @@ pub trait FloatTensorOps<B: Backend> {
     ) -> IntTensor<B, D> {
         argsort::<B, D, Float>(TensorPrimitive::Float(tensor), dim, descending)
     }
+
+    /// Computes the cumulative sum of the elements along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of all the
+    /// previous elements (inclusive) along the dimension.
+    fn float_cumsum<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumsum::<B, D, Float>(Tensor::from_primitive(TensorPrimitive::Float(tensor)), dim)
+            .into_primitive()
+            .tensor()
+    }
+
+    /// Computes the cumulative product of the elements along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of all
+    /// the previous elements (inclusive) along the dimension.
+    fn float_cumprod<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumprod::<B, D, Float>(Tensor::from_primitive(TensorPrimitive::Float(tensor)), dim)
+            .into_primitive()
+            .tensor()
+    }
+
+    /// Computes the cumulative maximum of the elements along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative maximum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the maximum of all
+    /// the previous elements (inclusive) along the dimension, and the indices of the maximum values.
+    /// The index of the last occurrence is returned for equal values.
+    fn float_cummax<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        let (values, indices) =
+            cummax::<B, D, Float>(Tensor::from_primitive(TensorPrimitive::Float(tensor)), dim);
+        (values.into_primitive().tensor(), indices.into_primitive())
+    }
+
+    /// Computes the cumulative minimum of the elements along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to compute the cumulative minimum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the minimum of all
+    /// the previous elements (inclusive) along the dimension, and the indices of the minimum values.
+    /// The index of the last occurrence is returned for equal values.
+    fn float_cummin<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        let (values, indices) =
+            cummin::<B, D, Float>(Tensor::from_primitive(TensorPrimitive::Float(tensor)), dim);
+        (values.into_primitive().tensor(), indices.into_primitive())
+    }
 }
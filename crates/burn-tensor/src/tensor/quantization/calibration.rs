@@ -1,4 +1,8 @@
-use crate::{backend::Backend, Tensor};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::{backend::Backend, Tensor, TensorData};
 
 /// The observed input calibration range.
 #[derive(Clone, Debug)]
@@ -16,9 +20,37 @@ pub trait Calibration {
         &self,
         tensor: &Tensor<B, D>,
     ) -> CalibrationRange<B>;
+
+    /// Compute the input range of each group of values.
+    ///
+    /// The tensor has shape `[num_groups, group_size]` and the returned range holds `num_groups`
+    /// values.
+    fn compute_group_range<B: Backend>(&self, tensor: &Tensor<B, 2>) -> CalibrationRange<B>;
+
+    /// Update a running range with the range of a new tensor (e.g., a batch of activations).
+    ///
+    /// The range of the first tensor is used when no range has been observed yet. Otherwise, the
+    /// running range is extended to include the range of the tensor by default. Calibration
+    /// methods that clip the outliers of each tensor should average the ranges instead, since the
+    /// union of the clipped ranges would grow back to the min and max values over many batches.
+    fn update_range<B: Backend, const D: usize>(
+        &self,
+        range: Option<CalibrationRange<B>>,
+        tensor: &Tensor<B, D>,
+    ) -> CalibrationRange<B> {
+        let observed = self.compute_range(tensor);
+
+        match range {
+            Some(range) => CalibrationRange {
+                min: range.min.min_pair(observed.min),
+                max: range.max.max_pair(observed.max),
+            },
+            None => observed,
+        }
+    }
 }
 
-/// Computes the per-tensor quantization range mapping based on the min and max values.
+/// Computes the quantization range mapping based on the min and max values.
 pub struct MinMaxCalibration {}
 
 impl Calibration for MinMaxCalibration {
@@ -31,4 +63,265 @@ impl Calibration for MinMaxCalibration {
 
         CalibrationRange { min, max }
     }
+
+    fn compute_group_range<B: Backend>(&self, tensor: &Tensor<B, 2>) -> CalibrationRange<B> {
+        let [num_groups, _] = tensor.dims();
+        let min = tensor.clone().min_dim(1).reshape([num_groups]);
+        let max = tensor.clone().max_dim(1).reshape([num_groups]);
+
+        CalibrationRange { min, max }
+    }
+}
+
+/// Computes the quantization range mapping based on the moving average of the min and max values
+/// observed for each tensor.
+///
+/// Compared to [MinMaxCalibration], a single batch with outliers has a limited impact on the range
+/// accumulated over a calibration dataset.
+pub struct MovingAverageCalibration {
+    /// Momentum used to update the running range, i.e., the weight of the new observed range.
+    pub momentum: f64,
+}
+
+impl Calibration for MovingAverageCalibration {
+    fn compute_range<B: Backend, const D: usize>(
+        &self,
+        tensor: &Tensor<B, D>,
+    ) -> CalibrationRange<B> {
+        MinMaxCalibration {}.compute_range(tensor)
+    }
+
+    fn compute_group_range<B: Backend>(&self, tensor: &Tensor<B, 2>) -> CalibrationRange<B> {
+        MinMaxCalibration {}.compute_group_range(tensor)
+    }
+
+    fn update_range<B: Backend, const D: usize>(
+        &self,
+        range: Option<CalibrationRange<B>>,
+        tensor: &Tensor<B, D>,
+    ) -> CalibrationRange<B> {
+        moving_average(range, self.compute_range(tensor), self.momentum)
+    }
+}
+
+/// Update the running range with the moving average of the observed range.
+fn moving_average<B: Backend>(
+    range: Option<CalibrationRange<B>>,
+    observed: CalibrationRange<B>,
+    momentum: f64,
+) -> CalibrationRange<B> {
+    match range {
+        Some(range) => CalibrationRange {
+            min: range.min.mul_scalar(1.0 - momentum) + observed.min.mul_scalar(momentum),
+            max: range.max.mul_scalar(1.0 - momentum) + observed.max.mul_scalar(momentum),
+        },
+        None => observed,
+    }
+}
+
+/// Computes the quantization range mapping based on the percentiles of the values, which clips
+/// the outliers in the tails of the distribution.
+///
+/// The range is `[p_low, p_high]` where `p_high` is the value at the given `percentile` and `p_low`
+/// the value at the symmetric percentile `100 - percentile`. When calibrating over multiple
+/// batches, the running range is the moving average of the percentiles of each batch.
+pub struct PercentileCalibration {
+    /// The percentile of the upper bound of the range, in `[50, 100]` (e.g., `99.99`).
+    pub percentile: f64,
+    /// Momentum used to update the running range, i.e., the weight of the new observed range.
+    pub momentum: f64,
+}
+
+impl PercentileCalibration {
+    /// Returns the indices of the lower and upper bounds in the sorted values, or `None` if there
+    /// are no values.
+    fn bounds(&self, num_values: usize) -> Option<(usize, usize)> {
+        let last = num_values.checked_sub(1)?;
+        let upper = (last as f64 * self.percentile / 100.0).round() as usize;
+        let upper = upper.clamp(last / 2, last);
+
+        Some((last - upper, upper))
+    }
+}
+
+impl Calibration for PercentileCalibration {
+    fn compute_range<B: Backend, const D: usize>(
+        &self,
+        tensor: &Tensor<B, D>,
+    ) -> CalibrationRange<B> {
+        match self.bounds(tensor.shape().num_elements()) {
+            Some((lower, upper)) => {
+                let values = tensor.clone().reshape([-1]).sort(0);
+
+                CalibrationRange {
+                    min: values.clone().narrow(0, lower, 1),
+                    max: values.narrow(0, upper, 1),
+                }
+            }
+            // An empty tensor has an empty range
+            None => CalibrationRange {
+                min: Tensor::zeros([1], &tensor.device()),
+                max: Tensor::zeros([1], &tensor.device()),
+            },
+        }
+    }
+
+    fn compute_group_range<B: Backend>(&self, tensor: &Tensor<B, 2>) -> CalibrationRange<B> {
+        let [num_groups, group_size] = tensor.dims();
+        match self.bounds(group_size) {
+            Some((lower, upper)) if num_groups > 0 => {
+                let values = tensor.clone().sort(1);
+
+                CalibrationRange {
+                    min: values.clone().narrow(1, lower, 1).reshape([num_groups]),
+                    max: values.narrow(1, upper, 1).reshape([num_groups]),
+                }
+            }
+            // Empty groups have an empty range
+            _ => CalibrationRange {
+                min: Tensor::zeros([num_groups], &tensor.device()),
+                max: Tensor::zeros([num_groups], &tensor.device()),
+            },
+        }
+    }
+
+    fn update_range<B: Backend, const D: usize>(
+        &self,
+        range: Option<CalibrationRange<B>>,
+        tensor: &Tensor<B, D>,
+    ) -> CalibrationRange<B> {
+        moving_average(range, self.compute_range(tensor), self.momentum)
+    }
+}
+
+/// Computes the quantization range mapping with the threshold that minimizes the information loss
+/// (Kullback-Leibler divergence) between the histogram of the values and its quantized version.
+///
+/// The threshold is searched on the histogram of the absolute values, so the range is symmetric
+/// (clamped to the min and max values). Values beyond the threshold are clipped, which trades a
+/// large error on a few outliers for a better resolution of the other values.
+///
+/// The histogram is computed on the host, so the tensor values are read synchronously. When
+/// calibrating over multiple batches, the running range is the moving average of the thresholds of
+/// each batch.
+pub struct EntropyCalibration {
+    /// The number of bins of the histogram (e.g., `2048`).
+    pub num_bins: usize,
+    /// The number of quantized bins, i.e., the number of positive quantized values (e.g., `128` for
+    /// 8-bit integers).
+    pub num_quantized_bins: usize,
+    /// Momentum used to update the running range, i.e., the weight of the new observed range.
+    pub momentum: f64,
+}
+
+impl EntropyCalibration {
+    /// Returns the range of the values clipped to the threshold that minimizes the divergence.
+    fn range(&self, values: &[f32]) -> (f32, f32) {
+        let min = values.iter().fold(f32::INFINITY, |acc, v| acc.min(*v));
+        let max = values.iter().fold(f32::NEG_INFINITY, |acc, v| acc.max(*v));
+        let max_abs = min.abs().max(max.abs());
+
+        if max_abs == 0.0 || self.num_bins <= self.num_quantized_bins {
+            return (min, max);
+        }
+
+        let bin_width = max_abs / self.num_bins as f32;
+        let mut histogram = vec![0f64; self.num_bins];
+        for value in values {
+            let bin = ((value.abs() / bin_width) as usize).min(self.num_bins - 1);
+            histogram[bin] += 1.0;
+        }
+
+        let mut best = (f64::INFINITY, self.num_bins);
+        for num_bins in self.num_quantized_bins..=self.num_bins {
+            let divergence = kl_divergence(&histogram, num_bins, self.num_quantized_bins);
+            if divergence < best.0 {
+                best = (divergence, num_bins);
+            }
+        }
+
+        let threshold = best.1 as f32 * bin_width;
+        (min.max(-threshold), max.min(threshold))
+    }
+}
+
+/// Compute the KL divergence between the histogram clipped to its first `num_bins` bins and its
+/// quantized version with `num_quantized_bins` bins.
+fn kl_divergence(histogram: &[f64], num_bins: usize, num_quantized_bins: usize) -> f64 {
+    // Reference distribution, where the values beyond the threshold are clipped to the last bin
+    let mut reference = histogram[..num_bins].to_vec();
+    reference[num_bins - 1] += histogram[num_bins..].iter().sum::<f64>();
+
+    // Candidate distribution, where the bins are merged into the quantized bins and expanded back
+    // over the non-empty bins
+    let mut candidate = vec![0f64; num_bins];
+    for i in 0..num_quantized_bins {
+        let start = i * num_bins / num_quantized_bins;
+        let end = (i + 1) * num_bins / num_quantized_bins;
+        let bins = &histogram[start..end];
+        let count = bins.iter().sum::<f64>();
+        let num_nonzero = bins.iter().filter(|c| **c > 0.0).count();
+
+        for (bin, c) in candidate[start..end].iter_mut().zip(bins) {
+            if *c > 0.0 {
+                *bin = count / num_nonzero as f64;
+            }
+        }
+    }
+
+    let reference_count = reference.iter().sum::<f64>();
+    let candidate_count = candidate.iter().sum::<f64>();
+    if candidate_count == 0.0 {
+        return f64::INFINITY;
+    }
+
+    reference
+        .iter()
+        .zip(candidate.iter())
+        .filter(|(p, _)| **p > 0.0)
+        .map(|(p, q)| {
+            let p = p / reference_count;
+            let q = (q / candidate_count).max(f64::EPSILON);
+            p * (p / q).ln()
+        })
+        .sum()
+}
+
+impl Calibration for EntropyCalibration {
+    fn compute_range<B: Backend, const D: usize>(
+        &self,
+        tensor: &Tensor<B, D>,
+    ) -> CalibrationRange<B> {
+        let values = tensor.to_data().iter::<f32>().collect::<Vec<_>>();
+        let (min, max) = self.range(&values);
+        let device = tensor.device();
+
+        CalibrationRange {
+            min: Tensor::from_floats([min], &device),
+            max: Tensor::from_floats([max], &device),
+        }
+    }
+
+    fn compute_group_range<B: Backend>(&self, tensor: &Tensor<B, 2>) -> CalibrationRange<B> {
+        let [num_groups, group_size] = tensor.dims();
+        let values = tensor.to_data().iter::<f32>().collect::<Vec<_>>();
+        let (min, max): (Vec<_>, Vec<_>) = values
+            .chunks(group_size)
+            .map(|group| self.range(group))
+            .unzip();
+        let device = tensor.device();
+
+        CalibrationRange {
+            min: Tensor::from_floats(TensorData::new(min, [num_groups]), &device),
+            max: Tensor::from_floats(TensorData::new(max, [num_groups]), &device),
+        }
+    }
+
+    fn update_range<B: Backend, const D: usize>(
+        &self,
+        range: Option<CalibrationRange<B>>,
+        tensor: &Tensor<B, D>,
+    ) -> CalibrationRange<B> {
+        moving_average(range, self.compute_range(tensor), self.momentum)
+    }
 }
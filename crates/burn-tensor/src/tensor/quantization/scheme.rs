@@ -1,71 +1,332 @@
 use crate::{backend::Backend, Int, Tensor};
+use serde::{Deserialize, Serialize};
 
-use super::{CalibrationRange, QuantizationParameters};
+use super::{
+    AffineInt4Quantization, AffineQuantization, Calibration, CalibrationRange,
+    NormalFloat4Quantization, QuantizationGroups, QuantizationParameters, QuantizationStrategy,
+    SymmetricInt4Quantization, SymmetricQuantization,
+};
 
 /// Quantization data type.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum QuantizationType {
     /// 8-bit signed integer.
     QInt8,
+    /// 8-bit unsigned integer.
+    QUInt8,
+    /// 4-bit signed integer, packed two values per byte.
+    QInt4,
+    /// 4-bit NormalFloat, where each value is an index in a lookup table of the quantiles of the
+    /// normal distribution. Packed two values per byte.
+    NF4,
+}
+
+impl QuantizationType {
+    /// Returns the number of bits of a quantized value.
+    pub fn bits(&self) -> usize {
+        match self {
+            QuantizationType::QInt8 | QuantizationType::QUInt8 => 8,
+            QuantizationType::QInt4 | QuantizationType::NF4 => 4,
+        }
+    }
+
+    /// Returns the quantized range `[a, b]` of affine quantization.
+    ///
+    /// # Panics
+    ///
+    /// Panics for the NormalFloat type, which only supports symmetric quantization.
+    pub fn affine_range(&self) -> (i32, i32) {
+        match self {
+            QuantizationType::QInt8 => (i8::MIN as i32, i8::MAX as i32),
+            QuantizationType::QUInt8 => (u8::MIN as i32, u8::MAX as i32),
+            QuantizationType::QInt4 => (-8, 7),
+            QuantizationType::NF4 => panic!("NF4 only supports symmetric quantization"),
+        }
+    }
+
+    /// Returns the quantized range `[-b, b]` of symmetric quantization.
+    ///
+    /// For the NormalFloat type, this is the normalized range `[-1, 1]` of the lookup table.
+    ///
+    /// # Panics
+    ///
+    /// Panics for unsigned types.
+    pub fn symmetric_range(&self) -> (i32, i32) {
+        let b = match self {
+            QuantizationType::QInt8 => i8::MAX as i32,
+            QuantizationType::QInt4 => 7,
+            QuantizationType::NF4 => 1,
+            QuantizationType::QUInt8 => {
+                panic!("Symmetric quantization is only valid for signed types")
+            }
+        };
+        (-b, b)
+    }
 }
 
 /// Quantization scheme.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum QuantizationScheme {
     /// Per-tensor affine/asymmetric quantization.
     PerTensorAffine(QuantizationType),
     /// Per-tensor symmetric quantization.
     PerTensorSymmetric(QuantizationType),
-    // /// Per-channel affine/asymmetric quantization.
-    // PerChannelAffine,
-    // /// Per-channel symmetric quantization.
-    // PerChannelSymmetric,
+    /// Per-channel affine/asymmetric quantization.
+    PerChannelAffine {
+        /// The quantization data type.
+        dtype: QuantizationType,
+        /// The channel axis (e.g. the output channels of a weight).
+        axis: usize,
+    },
+    /// Per-channel symmetric quantization.
+    PerChannelSymmetric {
+        /// The quantization data type.
+        dtype: QuantizationType,
+        /// The channel axis (e.g. the output channels of a weight).
+        axis: usize,
+    },
+    /// Block-wise affine/asymmetric quantization, where each block of contiguous values has its
+    /// own quantization parameters.
+    BlockAffine {
+        /// The quantization data type.
+        dtype: QuantizationType,
+        /// The number of values in each block.
+        block_size: usize,
+    },
+    /// Block-wise symmetric quantization, where each block of contiguous values has its own
+    /// quantization parameters.
+    BlockSymmetric {
+        /// The quantization data type.
+        dtype: QuantizationType,
+        /// The number of values in each block.
+        block_size: usize,
+    },
 }
 
-/// Round the tensor to the nearest integer.
+/// Round the tensor to the nearest integer (half away from zero).
 fn round<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D, Int> {
-    tensor.add_scalar(0.5).int()
+    // Casting to an integer truncates the values
+    let half = tensor.clone().sign().mul_scalar(0.5);
+    tensor.add(half).int()
 }
 
 impl QuantizationScheme {
-    /// Compute the quantization parameters.
-    pub fn compute_q_params<B: Backend>(
-        &self,
-        range: CalibrationRange<B>,
-    ) -> QuantizationParameters<B> {
+    /// Returns the quantization data type.
+    pub fn dtype(&self) -> &QuantizationType {
         match self {
-            QuantizationScheme::PerTensorAffine(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    // Quantized range `[a, b]`
-                    let a = i8::MIN as i32;
-                    let b = i8::MAX as i32;
-
-                    // Input range `[alpha, beta]`
-                    let input_range = range.max.clone().sub(range.min.clone());
-
-                    QuantizationParameters {
-                        scale: input_range.clone().div_scalar(b - a),
-                        offset: Some(round(
-                            (range.max.mul_scalar(a) - range.min.mul_scalar(b)).div(input_range),
-                        )),
+            QuantizationScheme::PerTensorAffine(dtype)
+            | QuantizationScheme::PerTensorSymmetric(dtype)
+            | QuantizationScheme::PerChannelAffine { dtype, .. }
+            | QuantizationScheme::PerChannelSymmetric { dtype, .. }
+            | QuantizationScheme::BlockAffine { dtype, .. }
+            | QuantizationScheme::BlockSymmetric { dtype, .. } => dtype,
+        }
+    }
+
+    /// Returns true if the scheme uses a zero-point offset.
+    pub fn is_affine(&self) -> bool {
+        matches!(
+            self,
+            QuantizationScheme::PerTensorAffine(_)
+                | QuantizationScheme::PerChannelAffine { .. }
+                | QuantizationScheme::BlockAffine { .. }
+        )
+    }
+
+    /// Returns the groups of values sharing the same quantization parameters, or `None` for
+    /// per-tensor schemes.
+    pub fn groups(&self) -> Option<QuantizationGroups> {
+        match self {
+            QuantizationScheme::PerTensorAffine(_) | QuantizationScheme::PerTensorSymmetric(_) => {
+                None
+            }
+            QuantizationScheme::PerChannelAffine { axis, .. }
+            | QuantizationScheme::PerChannelSymmetric { axis, .. } => {
+                Some(QuantizationGroups::Channel { axis: *axis })
+            }
+            QuantizationScheme::BlockAffine { block_size, .. }
+            | QuantizationScheme::BlockSymmetric { block_size, .. } => {
+                Some(QuantizationGroups::Block { size: *block_size })
+            }
+        }
+    }
+
+    /// Returns the scheme of a quantized tensor after reshaping it from `from` to `to`.
+    ///
+    /// For per-channel schemes, the channel axis is updated to its new position. Returns `None`
+    /// if the reshape doesn't preserve the channels.
+    pub fn reshape(&self, from: &[usize], to: &[usize]) -> Option<Self> {
+        match self {
+            QuantizationScheme::PerChannelAffine { dtype, axis } => {
+                match (QuantizationGroups::Channel { axis: *axis }).reshape(from, to)? {
+                    QuantizationGroups::Channel { axis } => {
+                        Some(QuantizationScheme::PerChannelAffine {
+                            dtype: dtype.clone(),
+                            axis,
+                        })
                     }
+                    QuantizationGroups::Block { .. } => None,
                 }
-            },
-            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
-                QuantizationType::QInt8 => {
-                    // Quantized range `[a, b]`
-                    let b = i8::MAX as i32;
-                    let a = -b;
+            }
+            QuantizationScheme::PerChannelSymmetric { dtype, axis } => {
+                match (QuantizationGroups::Channel { axis: *axis }).reshape(from, to)? {
+                    QuantizationGroups::Channel { axis } => {
+                        Some(QuantizationScheme::PerChannelSymmetric {
+                            dtype: dtype.clone(),
+                            axis,
+                        })
+                    }
+                    QuantizationGroups::Block { .. } => None,
+                }
+            }
+            scheme => Some(scheme.clone()),
+        }
+    }
+
+    /// Compute the input range of the tensor with the scheme granularity.
+    ///
+    /// The returned range has one value per tensor, channel or block.
+    pub fn compute_range<B: Backend, C: Calibration, const D: usize>(
+        &self,
+        calibration: &C,
+        tensor: &Tensor<B, D>,
+    ) -> CalibrationRange<B> {
+        match self.groups() {
+            None => calibration.compute_range(tensor),
+            Some(QuantizationGroups::Channel { axis }) => {
+                let channels = tensor.dims()[axis];
+                let groups = tensor
+                    .clone()
+                    .swap_dims(0, axis)
+                    .reshape([channels as i32, -1]);
+                calibration.compute_group_range(&groups)
+            }
+            Some(QuantizationGroups::Block { size }) => {
+                let groups = tensor.clone().reshape([-1, size as i32]);
+                calibration.compute_group_range(&groups)
+            }
+        }
+    }
 
-                    // Compute scale to convert an input value in range `[-alpha, alpha]`
-                    let values_range = range.min.abs().max_pair(range.max.abs()).mul_scalar(2);
+    /// Returns the per-group quantization strategy of the scheme, or `None` for per-tensor schemes.
+    ///
+    /// # Panics
+    ///
+    /// Panics for invalid schemes, i.e. symmetric quantization of unsigned types or affine NF4
+    /// quantization.
+    pub fn grouped_strategy(&self) -> Option<QuantizationStrategy> {
+        let groups = self.groups()?;
+        let strategy = match (self.dtype(), self.is_affine()) {
+            (QuantizationType::QInt8, true) => QuantizationStrategy::GroupedAffineInt8(groups),
+            (QuantizationType::QInt8, false) => QuantizationStrategy::GroupedSymmetricInt8(groups),
+            (QuantizationType::QUInt8, true) => QuantizationStrategy::GroupedAffineUInt8(groups),
+            (QuantizationType::QInt4, true) => QuantizationStrategy::GroupedAffineInt4(groups),
+            (QuantizationType::QInt4, false) => QuantizationStrategy::GroupedSymmetricInt4(groups),
+            (QuantizationType::NF4, false) => QuantizationStrategy::GroupedNF4(groups),
+            (dtype, _) => {
+                panic!("Unsupported per-group quantization scheme {self:?} for {dtype:?}")
+            }
+        };
 
-                    QuantizationParameters {
-                        scale: values_range.div_scalar(b - a),
-                        offset: None,
+        Some(strategy)
+    }
+
+    /// Returns the per-tensor quantization strategy of the scheme with the given parameters, or
+    /// `None` for per-channel and block-wise schemes.
+    ///
+    /// The offset is clamped to the quantized range of the data type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset is missing for affine quantization, or if the data type is not
+    /// supported for the scheme.
+    pub fn per_tensor_strategy(
+        &self,
+        scale: f32,
+        offset: Option<i32>,
+    ) -> Option<QuantizationStrategy> {
+        let strategy = match self {
+            QuantizationScheme::PerTensorAffine(dtype) => {
+                let offset = offset.expect("Affine quantization should have an offset");
+                let (a, b) = dtype.affine_range();
+                let offset = offset.clamp(a, b);
+                match dtype {
+                    QuantizationType::QInt8 => QuantizationStrategy::PerTensorAffineInt8(
+                        AffineQuantization::init(scale, offset as i8),
+                    ),
+                    QuantizationType::QUInt8 => QuantizationStrategy::PerTensorAffineUInt8(
+                        AffineQuantization::init(scale, offset as u8),
+                    ),
+                    QuantizationType::QInt4 => QuantizationStrategy::PerTensorAffineInt4(
+                        AffineInt4Quantization::init(scale, offset as i8),
+                    ),
+                    QuantizationType::NF4 => {
+                        panic!("NF4 only supports symmetric quantization")
                     }
                 }
+            }
+            QuantizationScheme::PerTensorSymmetric(dtype) => match dtype {
+                QuantizationType::QInt8 => {
+                    QuantizationStrategy::PerTensorSymmetricInt8(SymmetricQuantization::init(scale))
+                }
+                QuantizationType::QInt4 => QuantizationStrategy::PerTensorSymmetricInt4(
+                    SymmetricInt4Quantization::init(scale),
+                ),
+                QuantizationType::NF4 => {
+                    QuantizationStrategy::PerTensorNF4(NormalFloat4Quantization::init(scale))
+                }
+                QuantizationType::QUInt8 => {
+                    panic!("Symmetric quantization is only valid for signed types")
+                }
             },
+            _ => return None,
+        };
+
+        Some(strategy)
+    }
+
+    /// Compute the quantization parameters.
+    ///
+    /// The parameters are computed for each value of the range, so per-channel and block-wise
+    /// schemes get one scale (and offset) per group. The range of each group is clamped to a
+    /// small positive value, so that a constant group (e.g. an all-zero channel) doesn't produce
+    /// a zero scale. Per-tensor schemes are left unchanged.
+    pub fn compute_q_params<B: Backend>(
+        &self,
+        range: CalibrationRange<B>,
+    ) -> QuantizationParameters<B> {
+        let clamp_range = |range: Tensor<B, 1>| match self.groups() {
+            Some(_) => range.clamp_min(f32::EPSILON),
+            None => range,
+        };
+
+        if self.is_affine() {
+            // Quantized range `[a, b]`
+            let (a, b) = self.dtype().affine_range();
+
+            // Input range `[alpha, beta]`, extended to include 0 so that the offset is a valid
+            // quantized value (i.e., zero is exactly representable)
+            let min = range.min.clamp_max(0);
+            let max = range.max.clamp_min(0);
+            let input_range = clamp_range(max.clone().sub(min.clone()));
+
+            QuantizationParameters {
+                scale: input_range.clone().div_scalar(b - a),
+                offset: Some(round(
+                    (max.mul_scalar(a) - min.mul_scalar(b)).div(input_range),
+                )),
+            }
+        } else {
+            // Quantized range `[a, b]`
+            let (a, b) = self.dtype().symmetric_range();
+
+            // Compute scale to convert an input value in range `[-alpha, alpha]`
+            let values_range = clamp_range(range.min.abs().max_pair(range.max.abs()).mul_scalar(2));
+
+            QuantizationParameters {
+                scale: values_range.div_scalar(b - a),
+                offset: None,
+            }
         }
     }
 }
@@ -19,20 +19,275 @@ pub enum QuantizationStrategy {
     PerTensorAffineInt8(AffineQuantization<f32, i8, i32>),
     /// Per-tensor `int8` symmetric quantization.
     PerTensorSymmetricInt8(SymmetricQuantization<f32, i8>),
+    /// Per-tensor `uint8` affine/asymmetric quantization.
+    PerTensorAffineUInt8(AffineQuantization<f32, u8, i32>),
+    /// Per-tensor `int4` affine/asymmetric quantization.
+    PerTensorAffineInt4(AffineInt4Quantization),
+    /// Per-tensor `int4` symmetric quantization.
+    PerTensorSymmetricInt4(SymmetricInt4Quantization),
+    /// Per-tensor 4-bit NormalFloat quantization.
+    PerTensorNF4(NormalFloat4Quantization),
+    /// Per-group `int8` affine/asymmetric quantization.
+    ///
+    /// The [parameters](GroupQuantization) of each group are stored along with the quantized values.
+    GroupedAffineInt8(QuantizationGroups),
+    /// Per-group `int8` symmetric quantization.
+    ///
+    /// The [parameters](GroupQuantization) of each group are stored along with the quantized values.
+    GroupedSymmetricInt8(QuantizationGroups),
+    /// Per-group `uint8` affine/asymmetric quantization.
+    ///
+    /// The [parameters](GroupQuantization) of each group are stored along with the quantized values.
+    GroupedAffineUInt8(QuantizationGroups),
+    /// Per-group `int4` affine/asymmetric quantization.
+    ///
+    /// The [parameters](GroupQuantization) of each group are stored along with the quantized values.
+    GroupedAffineInt4(QuantizationGroups),
+    /// Per-group `int4` symmetric quantization.
+    ///
+    /// The [parameters](GroupQuantization) of each group are stored along with the quantized values.
+    GroupedSymmetricInt4(QuantizationGroups),
+    /// Per-group 4-bit NormalFloat quantization.
+    ///
+    /// The [parameters](GroupQuantization) of each group are stored along with the quantized values.
+    GroupedNF4(QuantizationGroups),
 }
 
 impl QuantizationStrategy {
     /// Returns the corresponding quantization scheme.
     pub fn scheme(&self) -> QuantizationScheme {
+        let dtype = self.dtype();
         match self {
-            QuantizationStrategy::PerTensorAffineInt8(_) => {
-                QuantizationScheme::PerTensorAffine(QuantizationType::QInt8)
+            QuantizationStrategy::PerTensorAffineInt8(_)
+            | QuantizationStrategy::PerTensorAffineUInt8(_)
+            | QuantizationStrategy::PerTensorAffineInt4(_) => {
+                QuantizationScheme::PerTensorAffine(dtype)
             }
-            QuantizationStrategy::PerTensorSymmetricInt8(_) => {
-                QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8)
+            QuantizationStrategy::PerTensorSymmetricInt8(_)
+            | QuantizationStrategy::PerTensorSymmetricInt4(_)
+            | QuantizationStrategy::PerTensorNF4(_) => {
+                QuantizationScheme::PerTensorSymmetric(dtype)
             }
+            QuantizationStrategy::GroupedAffineInt8(groups)
+            | QuantizationStrategy::GroupedAffineUInt8(groups)
+            | QuantizationStrategy::GroupedAffineInt4(groups) => match groups {
+                QuantizationGroups::Channel { axis } => {
+                    QuantizationScheme::PerChannelAffine { dtype, axis: *axis }
+                }
+                QuantizationGroups::Block { size } => QuantizationScheme::BlockAffine {
+                    dtype,
+                    block_size: *size,
+                },
+            },
+            QuantizationStrategy::GroupedSymmetricInt8(groups)
+            | QuantizationStrategy::GroupedSymmetricInt4(groups)
+            | QuantizationStrategy::GroupedNF4(groups) => match groups {
+                QuantizationGroups::Channel { axis } => {
+                    QuantizationScheme::PerChannelSymmetric { dtype, axis: *axis }
+                }
+                QuantizationGroups::Block { size } => QuantizationScheme::BlockSymmetric {
+                    dtype,
+                    block_size: *size,
+                },
+            },
         }
     }
+
+    /// Returns the quantization data type.
+    pub fn dtype(&self) -> QuantizationType {
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(_)
+            | QuantizationStrategy::PerTensorSymmetricInt8(_)
+            | QuantizationStrategy::GroupedAffineInt8(_)
+            | QuantizationStrategy::GroupedSymmetricInt8(_) => QuantizationType::QInt8,
+            QuantizationStrategy::PerTensorAffineUInt8(_)
+            | QuantizationStrategy::GroupedAffineUInt8(_) => QuantizationType::QUInt8,
+            QuantizationStrategy::PerTensorAffineInt4(_)
+            | QuantizationStrategy::PerTensorSymmetricInt4(_)
+            | QuantizationStrategy::GroupedAffineInt4(_)
+            | QuantizationStrategy::GroupedSymmetricInt4(_) => QuantizationType::QInt4,
+            QuantizationStrategy::PerTensorNF4(_) | QuantizationStrategy::GroupedNF4(_) => {
+                QuantizationType::NF4
+            }
+        }
+    }
+
+    /// Returns the groups sharing the same quantization parameters, or `None` for per-tensor
+    /// strategies.
+    pub fn groups(&self) -> Option<QuantizationGroups> {
+        match self {
+            QuantizationStrategy::PerTensorAffineInt8(_)
+            | QuantizationStrategy::PerTensorSymmetricInt8(_)
+            | QuantizationStrategy::PerTensorAffineUInt8(_)
+            | QuantizationStrategy::PerTensorAffineInt4(_)
+            | QuantizationStrategy::PerTensorSymmetricInt4(_)
+            | QuantizationStrategy::PerTensorNF4(_) => None,
+            QuantizationStrategy::GroupedAffineInt8(groups)
+            | QuantizationStrategy::GroupedSymmetricInt8(groups)
+            | QuantizationStrategy::GroupedAffineUInt8(groups)
+            | QuantizationStrategy::GroupedAffineInt4(groups)
+            | QuantizationStrategy::GroupedSymmetricInt4(groups)
+            | QuantizationStrategy::GroupedNF4(groups) => Some(*groups),
+        }
+    }
+}
+
+/// Layout of the groups of values that share the same quantization parameters.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuantizationGroups {
+    /// One group per index along the channel axis.
+    Channel {
+        /// The channel axis.
+        axis: usize,
+    },
+    /// One group per block of contiguous values (in row-major order).
+    Block {
+        /// The number of values in each block.
+        size: usize,
+    },
+}
+
+impl QuantizationGroups {
+    /// Returns the number of groups for a tensor of the given shape.
+    pub fn num_groups(&self, shape: &[usize]) -> usize {
+        match self {
+            QuantizationGroups::Channel { axis } => shape[*axis],
+            QuantizationGroups::Block { size } => {
+                let num_elements = shape.iter().product::<usize>();
+                assert_eq!(
+                    num_elements % size,
+                    0,
+                    "The number of elements ({num_elements}) should be a multiple of the block size ({size})"
+                );
+                num_elements / size
+            }
+        }
+    }
+
+    /// Returns a function mapping the (row-major) index of a value to the index of its group
+    /// for a tensor of the given shape.
+    pub fn group_index(&self, shape: &[usize]) -> impl Fn(usize) -> usize {
+        // group = (index / stride) % num_groups
+        let (stride, num_groups) = match self {
+            QuantizationGroups::Channel { axis } => {
+                (shape[axis + 1..].iter().product::<usize>(), shape[*axis])
+            }
+            QuantizationGroups::Block { size } => (*size, self.num_groups(shape)),
+        };
+
+        move |index| (index / stride) % num_groups
+    }
+
+    /// Returns the groups of a tensor reshaped from `from` to `to`, or `None` when the values of
+    /// a group are no longer laid out the same way.
+    pub fn reshape(&self, from: &[usize], to: &[usize]) -> Option<Self> {
+        match self {
+            QuantizationGroups::Channel { axis } => {
+                // The channel axis must be preserved along with the number of values after it.
+                let channels = from[*axis];
+                let stride = from[axis + 1..].iter().product::<usize>();
+
+                (0..to.len())
+                    .find(|&i| to[i] == channels && to[i + 1..].iter().product::<usize>() == stride)
+                    .map(|axis| QuantizationGroups::Channel { axis })
+            }
+            QuantizationGroups::Block { .. } => Some(*self),
+        }
+    }
+}
+
+/// Quantization parameters of each group of a per-group quantization strategy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupQuantization {
+    /// The scaling factor of each group.
+    pub scales: Vec<f32>,
+    /// The zero-point offset of each group (affine quantization only).
+    pub offsets: Option<Vec<i32>>,
+}
+
+impl GroupQuantization {
+    /// Convert the values of a tensor with the given shape to the quantized type of the per-group
+    /// strategy.
+    ///
+    /// The quantized values are returned as `i8`: unsigned values are returned with the same bits,
+    /// and 4-bit NormalFloat values are the indices of the lookup table.
+    pub fn quantize(
+        &self,
+        strategy: &QuantizationStrategy,
+        values: &[f32],
+        shape: &[usize],
+    ) -> Vec<i8> {
+        let group_index = Self::group_index(strategy, shape);
+
+        if let QuantizationType::NF4 = strategy.dtype() {
+            return values
+                .iter()
+                .enumerate()
+                .map(|(i, x)| nf4_index(x / self.scales[group_index(i)]) as i8)
+                .collect();
+        }
+
+        // Quantized range `[a, b]`
+        let (a, b) = match self.offsets {
+            Some(_) => strategy.dtype().affine_range(),
+            None => strategy.dtype().symmetric_range(),
+        };
+
+        // x_q = clamp(round(x / scale + offset), a, b)
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let g = group_index(i);
+                let z = self.offsets.as_ref().map(|o| o[g] as f32).unwrap_or(0.);
+                (x / self.scales[g] + z).round().clamp(a as f32, b as f32) as i32 as i8
+            })
+            .collect()
+    }
+
+    /// Convert the quantized values of a tensor with the given shape back to `f32`.
+    ///
+    /// Unsigned values are expected as `i8` with the same bits.
+    pub fn dequantize(
+        &self,
+        strategy: &QuantizationStrategy,
+        values: &[i8],
+        shape: &[usize],
+    ) -> Vec<f32> {
+        let group_index = Self::group_index(strategy, shape);
+
+        if let QuantizationType::NF4 = strategy.dtype() {
+            return values
+                .iter()
+                .enumerate()
+                .map(|(i, x_q)| self.scales[group_index(i)] * NF4_VALUES[*x_q as usize])
+                .collect();
+        }
+
+        let unsigned = strategy.dtype() == QuantizationType::QUInt8;
+
+        // x = scale * (x_q - offset)
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, x_q)| {
+                let g = group_index(i);
+                let z = self.offsets.as_ref().map(|o| o[g]).unwrap_or(0);
+                let x_q = match unsigned {
+                    true => *x_q as u8 as i32,
+                    false => *x_q as i32,
+                };
+                self.scales[g] * (x_q - z) as f32
+            })
+            .collect()
+    }
+
+    fn group_index(strategy: &QuantizationStrategy, shape: &[usize]) -> impl Fn(usize) -> usize {
+        strategy
+            .groups()
+            .expect("Per-group quantization strategy should be provided")
+            .group_index(shape)
+    }
 }
 
 /// Quantization scheme to convert elements of a higher precision data type `E` to a lower precision
@@ -172,6 +427,183 @@ impl<E: Float, Q: PrimInt> Quantization<E, Q> for SymmetricQuantization<E, Q> {
     }
 }
 
+/// `int4` affine quantization scheme, with quantized values in range `[-8, 7]` (stored as `i8`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct AffineInt4Quantization {
+    /// The scaling factor.
+    pub scale: f32,
+    /// The zero-point offset.
+    pub offset: i8,
+}
+
+impl AffineInt4Quantization {
+    /// Initialize an `int4` affine quantization scheme with the given parameters.
+    pub fn init(scale: f32, offset: i8) -> Self {
+        Self { scale, offset }
+    }
+}
+
+impl Quantization<f32, i8> for AffineInt4Quantization {
+    fn new(alpha: f32, beta: f32) -> Self {
+        // Quantized range `[a, b]`
+        let (a, b) = QuantizationType::QInt4.affine_range();
+        let (a, b) = (a as f32, b as f32);
+
+        // Compute scale and offset to convert a floating point value in range `[alpha, beta]` to the quantized range
+        let range = beta - alpha;
+        Self::init(
+            range / (b - a),
+            ((beta * a - alpha * b) / range).round() as i8,
+        )
+    }
+
+    fn quantize(&self, values: &[f32]) -> Vec<i8> {
+        // Quantized range `[a, b]`
+        let (a, b) = QuantizationType::QInt4.affine_range();
+
+        // x_q = clamp(round(x / scale + offset), a, b)
+        let z = self.offset as f32;
+        values
+            .iter()
+            .map(|x| (x / self.scale + z).round().clamp(a as f32, b as f32) as i8)
+            .collect()
+    }
+
+    fn dequantize(&self, values: &[i8]) -> Vec<f32> {
+        // x = scale * (x_q - offset)
+        values
+            .iter()
+            .map(|x_q| self.scale * (*x_q as i32 - self.offset as i32) as f32)
+            .collect()
+    }
+}
+
+/// `int4` symmetric quantization scheme, with quantized values in range `[-7, 7]` (stored as `i8`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SymmetricInt4Quantization {
+    /// The scaling factor.
+    pub scale: f32,
+}
+
+impl SymmetricInt4Quantization {
+    /// Initialize an `int4` symmetric quantization scheme with the given parameters.
+    pub fn init(scale: f32) -> Self {
+        Self { scale }
+    }
+}
+
+impl Quantization<f32, i8> for SymmetricInt4Quantization {
+    fn new(alpha: f32, beta: f32) -> Self {
+        // Quantized range `[a, b]`
+        let (a, b) = QuantizationType::QInt4.symmetric_range();
+
+        // Compute scale to convert a floating point value in range `[-alpha, alpha]` to the quantized range
+        let alpha = alpha.abs().max(beta.abs());
+        Self::init((alpha + alpha) / (b - a) as f32)
+    }
+
+    fn quantize(&self, values: &[f32]) -> Vec<i8> {
+        // Quantized range `[a, b]`
+        let (a, b) = QuantizationType::QInt4.symmetric_range();
+
+        // x_q = clamp(round(x / scale), a, b)
+        values
+            .iter()
+            .map(|x| (x / self.scale).round().clamp(a as f32, b as f32) as i8)
+            .collect()
+    }
+
+    fn dequantize(&self, values: &[i8]) -> Vec<f32> {
+        // x = scale * x_q
+        values.iter().map(|x_q| self.scale * *x_q as f32).collect()
+    }
+}
+
+/// The 4-bit NormalFloat lookup table: the quantiles of the standard normal distribution,
+/// normalized to `[-1, 1]` (see [QLoRA](https://arxiv.org/abs/2305.14314)).
+pub const NF4_VALUES: [f32; 16] = [
+    -1.0,
+    -0.696_192_8,
+    -0.525_073_05,
+    -0.394_917_5,
+    -0.284_441_38,
+    -0.184_773_43,
+    -0.091_050_036,
+    0.0,
+    0.079_580_3,
+    0.160_930_2,
+    0.246_112_3,
+    0.337_915_24,
+    0.440_709_83,
+    0.562_617,
+    0.722_956_84,
+    1.0,
+];
+
+/// Returns the index of the nearest value in the NormalFloat lookup table.
+fn nf4_index(value: f32) -> u8 {
+    // The table is sorted, so the index is the number of midpoints below the value
+    NF4_VALUES
+        .windows(2)
+        .filter(|w| value > (w[0] + w[1]) / 2.)
+        .count() as u8
+}
+
+/// 4-bit NormalFloat quantization scheme.
+///
+/// The values are scaled to `[-1, 1]` by their absolute maximum and mapped to the nearest value of
+/// the [lookup table](NF4_VALUES), so each quantized value is an index in range `[0, 15]`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct NormalFloat4Quantization {
+    /// The scaling factor (i.e., the absolute maximum).
+    pub scale: f32,
+}
+
+impl NormalFloat4Quantization {
+    /// Initialize a 4-bit NormalFloat quantization scheme with the given parameters.
+    pub fn init(scale: f32) -> Self {
+        Self { scale }
+    }
+}
+
+impl Quantization<f32, u8> for NormalFloat4Quantization {
+    fn new(alpha: f32, beta: f32) -> Self {
+        Self::init(alpha.abs().max(beta.abs()))
+    }
+
+    fn quantize(&self, values: &[f32]) -> Vec<u8> {
+        values.iter().map(|x| nf4_index(x / self.scale)).collect()
+    }
+
+    fn dequantize(&self, values: &[u8]) -> Vec<f32> {
+        values
+            .iter()
+            .map(|x_q| self.scale * NF4_VALUES[*x_q as usize])
+            .collect()
+    }
+}
+
+/// Packs 4-bit values two per byte, with the first value in the low nibble.
+pub fn pack_nibbles(values: &[u8]) -> Vec<u8> {
+    values
+        .chunks(2)
+        .map(|v| (v[0] & 0x0F) | (v.get(1).copied().unwrap_or(0) << 4))
+        .collect()
+}
+
+/// Unpacks 4-bit values stored two per byte, sign-extending the values when `signed` is true.
+///
+/// When the number of values is odd, the last (padding) value should be skipped.
+pub fn unpack_nibbles(bytes: &[u8], signed: bool) -> impl Iterator<Item = i8> + '_ {
+    bytes
+        .iter()
+        .flat_map(|b| [b & 0x0F, b >> 4])
+        .map(move |v| match signed {
+            true => ((v << 4) as i8) >> 4,
+            false => v as i8,
+        })
+}
+
 // Masks for the parts of the IEEE 754 float
 const SIGN_MASK: u64 = 0x8000000000000000u64;
 const EXP_MASK: u64 = 0x7ff0000000000000u64;
@@ -228,6 +660,55 @@ impl<E: Float, Q: PrimInt> PartialEq for SymmetricQuantization<E, Q> {
 
 impl<E: Float, Q: PrimInt> Eq for SymmetricQuantization<E, Q> {}
 
+impl Hash for AffineInt4Quantization {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash raw bits.
+        let bits = raw_double_bits(&canonicalize_signed_zero(self.scale));
+        bits.hash(state);
+        self.offset.hash(state);
+    }
+}
+
+impl PartialEq for AffineInt4Quantization {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale && self.offset == other.offset
+    }
+}
+
+impl Eq for AffineInt4Quantization {}
+
+impl Hash for SymmetricInt4Quantization {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash raw bits.
+        let bits = raw_double_bits(&canonicalize_signed_zero(self.scale));
+        bits.hash(state);
+    }
+}
+
+impl PartialEq for SymmetricInt4Quantization {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale
+    }
+}
+
+impl Eq for SymmetricInt4Quantization {}
+
+impl Hash for NormalFloat4Quantization {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash raw bits.
+        let bits = raw_double_bits(&canonicalize_signed_zero(self.scale));
+        bits.hash(state);
+    }
+}
+
+impl PartialEq for NormalFloat4Quantization {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale
+    }
+}
+
+impl Eq for NormalFloat4Quantization {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,4 +745,117 @@ mod tests {
 
         assert_eq!(d, expected_d);
     }
+
+    #[test]
+    fn test_int8_per_channel_affine_quantization() {
+        let x: [f32; 6] = [-1.8, -1.0, 0.0, 0.25, 0.0, 1.0];
+        let shape = [2, 3];
+        let expected_q = vec![-128, -39, 72, -64, -128, 127];
+        let expected_d = vec![-1.8039216, -1.0011765, 0.0, 0.2509804, 0.0, 1.0];
+
+        let strategy =
+            QuantizationStrategy::GroupedAffineInt8(QuantizationGroups::Channel { axis: 0 });
+        let params = GroupQuantization {
+            scales: vec![0.009_019_608, 0.003_921_569],
+            offsets: Some(vec![72, -128]),
+        };
+
+        let q = params.quantize(&strategy, &x, &shape);
+        assert_eq!(q, expected_q);
+
+        let d = params.dequantize(&strategy, &expected_q, &shape);
+        for (d, e) in d.iter().zip(expected_d) {
+            assert!((d - e).abs() < 1e-6, "{d} != {e}");
+        }
+    }
+
+    #[test]
+    fn test_int8_block_symmetric_quantization() {
+        let x: [f32; 4] = [-1.8, 0.5, 0.05, -0.2];
+        let expected_q = vec![-127, 35, 32, -127];
+
+        let strategy =
+            QuantizationStrategy::GroupedSymmetricInt8(QuantizationGroups::Block { size: 2 });
+        let params = GroupQuantization {
+            scales: vec![0.014_173_228, 0.001_574_803],
+            offsets: None,
+        };
+
+        let q = params.quantize(&strategy, &x, &[4]);
+        assert_eq!(q, expected_q);
+    }
+
+    #[test]
+    fn test_channel_groups_reshape() {
+        let groups = QuantizationGroups::Channel { axis: 1 };
+
+        assert_eq!(
+            groups.reshape(&[2, 3, 4], &[1, 2, 3, 4]),
+            Some(QuantizationGroups::Channel { axis: 2 })
+        );
+        assert_eq!(groups.reshape(&[2, 3, 4], &[6, 4]), None);
+    }
+
+    #[test]
+    fn test_int4_affine_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![-8, -3, 4, 7];
+        let expected_d = vec![-1.84, -1.073_333_4, 0.0, 0.46];
+
+        let affine = AffineInt4Quantization::new(-1.8, 0.5);
+        assert_eq!(affine.offset, 4);
+
+        let q = affine.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d = affine.dequantize(&expected_q);
+        for (d, e) in d.iter().zip(expected_d) {
+            assert!((d - e).abs() < 1e-6, "{d} != {e}");
+        }
+    }
+
+    #[test]
+    fn test_int4_symmetric_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![-7, -4, 0, 2];
+        let expected_d = vec![-1.8, -1.028_571_4, 0.0, 0.514_285_7];
+
+        let symmetric = SymmetricInt4Quantization::new(-1.8, 0.5);
+
+        let q = symmetric.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d = symmetric.dequantize(&expected_q);
+        for (d, e) in d.iter().zip(expected_d) {
+            assert!((d - e).abs() < 1e-6, "{d} != {e}");
+        }
+    }
+
+    #[test]
+    fn test_nf4_quantization() {
+        let x: [f32; 4] = [-1.8, -1.0, 0.0, 0.5];
+        let expected_q = vec![0, 2, 7, 10];
+        let expected_d = vec![-1.8, -0.945_131_5, 0.0, 0.443_002_13];
+
+        let nf4 = NormalFloat4Quantization::new(-1.8, 0.5);
+
+        let q = nf4.quantize(&x);
+        assert_eq!(q, expected_q);
+
+        let d = nf4.dequantize(&expected_q);
+        for (d, e) in d.iter().zip(expected_d) {
+            assert!((d - e).abs() < 1e-6, "{d} != {e}");
+        }
+    }
+
+    #[test]
+    fn test_pack_nibbles() {
+        let values: [i8; 5] = [-8, -3, 4, 7, -1];
+
+        let packed = pack_nibbles(&values.map(|v| v as u8));
+        assert_eq!(packed, vec![0xD8, 0x74, 0x0F]);
+
+        let unpacked = unpack_nibbles(&packed, true).take(5).collect::<Vec<_>>();
+        assert_eq!(unpacked, values);
+    }
 }
@@ -0,0 +1,427 @@
+use crate::backend::Backend;
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{Tensor, TensorData};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// The largest size transformed with a single matrix multiplication.
+///
+/// Larger sizes are split into smaller transforms with the Cooley-Tukey algorithm, unless they
+/// are prime.
+const MAX_DIRECT_SIZE: usize = 64;
+
+/// Computes the one-dimensional discrete Fourier transform of a complex tensor.
+///
+/// Complex tensors are represented by their real and imaginary parts.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the input.
+/// * `im` - The imaginary part of the input.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform, with the same shape as the input.
+pub fn fft<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft_complex::<D>(
+        "FFT",
+        1,
+        &re.shape(),
+        &im.shape()
+    ));
+    check!(TensorCheck::dim_ops::<D>("FFT", dim));
+
+    along_dim(re, im, dim, |re, im| dft(re, im, false))
+}
+
+/// Computes the one-dimensional inverse discrete Fourier transform of a complex tensor.
+///
+/// The output is normalized by `1/n`, so that `ifft(fft(x)) = x`.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the input.
+/// * `im` - The imaginary part of the input.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform, with the same shape as the input.
+pub fn ifft<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft_complex::<D>(
+        "IFFT",
+        1,
+        &re.shape(),
+        &im.shape()
+    ));
+    check!(TensorCheck::dim_ops::<D>("IFFT", dim));
+
+    let n = re.dims()[dim];
+    let (re, im) = along_dim(re, im, dim, |re, im| dft(re, im, true));
+
+    (re.div_scalar(n as f64), im.div_scalar(n as f64))
+}
+
+/// Computes the one-dimensional discrete Fourier transform of a real tensor.
+///
+/// Since the transform of a real signal is Hermitian symmetric, only the `n / 2 + 1`
+/// non-negative frequency bins are returned.
+///
+/// # Arguments
+///
+/// * `signal` - The real input.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform, with the size `n / 2 + 1` along `dim`.
+pub fn rfft<B: Backend, const D: usize>(
+    signal: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::dim_ops::<D>("RFFT", dim));
+
+    let num_bins = signal.dims()[dim] / 2 + 1;
+    let im = signal.zeros_like();
+    let (re, im) = along_dim(signal, im, dim, |re, im| dft(re, im, false));
+
+    (re.narrow(dim, 0, num_bins), im.narrow(dim, 0, num_bins))
+}
+
+/// Computes the inverse of [rfft](rfft), returning a real tensor.
+///
+/// The negative frequency bins are reconstructed from the Hermitian symmetry of the spectrum,
+/// and the imaginary parts of the zero and Nyquist frequency bins are ignored.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the non-negative frequency bins.
+/// * `im` - The imaginary part of the non-negative frequency bins.
+/// * `n` - The length of the output along `dim`, which must satisfy `n / 2 + 1` equal to the
+///   number of frequency bins.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// The real output, with the size `n` along `dim`.
+pub fn irfft<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    n: usize,
+    dim: usize,
+) -> Tensor<B, D> {
+    check!(TensorCheck::fft_complex::<D>(
+        "IRFFT",
+        1,
+        &re.shape(),
+        &im.shape()
+    ));
+    check!(TensorCheck::dim_ops::<D>("IRFFT", dim));
+    check!(TensorCheck::irfft("IRFFT", re.dims()[dim], n));
+
+    let (re, _im) = along_dim(re, im, dim, |re, im| {
+        let (re, im) = hermitian_spectrum(re, im, n);
+        dft(re, im, true)
+    });
+
+    re.div_scalar(n as f64)
+}
+
+/// Computes the two-dimensional discrete Fourier transform of a complex tensor over its last two
+/// dimensions.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform, with the same shape as the input.
+pub fn fft2<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft_complex::<D>(
+        "FFT2",
+        2,
+        &re.shape(),
+        &im.shape()
+    ));
+
+    let (re, im) = fft(re, im, D - 1);
+    fft(re, im, D - 2)
+}
+
+/// Computes the two-dimensional inverse discrete Fourier transform of a complex tensor over its
+/// last two dimensions.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform, with the same shape as the input.
+pub fn ifft2<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft_complex::<D>(
+        "IFFT2",
+        2,
+        &re.shape(),
+        &im.shape()
+    ));
+
+    let (re, im) = ifft(re, im, D - 1);
+    ifft(re, im, D - 2)
+}
+
+/// Computes the two-dimensional discrete Fourier transform of a real tensor over its last two
+/// dimensions.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the transform, with the size `n / 2 + 1` along the last
+/// dimension.
+pub fn rfft2<B: Backend, const D: usize>(signal: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft_complex::<D>(
+        "RFFT2",
+        2,
+        &signal.shape(),
+        &signal.shape()
+    ));
+
+    let (re, im) = rfft(signal, D - 1);
+    fft(re, im, D - 2)
+}
+
+/// Computes the inverse of [rfft2](rfft2), returning a real tensor.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the spectrum.
+/// * `im` - The imaginary part of the spectrum.
+/// * `n` - The length of the output along the last dimension.
+///
+/// # Returns
+///
+/// The real output, with the size `n` along the last dimension.
+pub fn irfft2<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    n: usize,
+) -> Tensor<B, D> {
+    check!(TensorCheck::fft_complex::<D>(
+        "IRFFT2",
+        2,
+        &re.shape(),
+        &im.shape()
+    ));
+
+    let (re, im) = ifft(re, im, D - 2);
+    irfft(re, im, n, D - 1)
+}
+
+/// Applies a transform on the rows of the complex tensor flattened to two dimensions, with the
+/// given dimension moved last.
+fn along_dim<B: Backend, const D: usize, F>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+    transform: F,
+) -> (Tensor<B, D>, Tensor<B, D>)
+where
+    F: FnOnce(Tensor<B, 2>, Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2>),
+{
+    let re = re.swap_dims(dim, D - 1);
+    let im = im.swap_dims(dim, D - 1);
+    let mut dims = re.dims();
+    let n = dims[D - 1];
+    let rows = dims[..D - 1].iter().product::<usize>();
+
+    let (re, im) = transform(re.reshape([rows, n]), im.reshape([rows, n]));
+    dims[D - 1] = re.dims()[1];
+
+    (
+        re.reshape(dims).swap_dims(dim, D - 1),
+        im.reshape(dims).swap_dims(dim, D - 1),
+    )
+}
+
+/// Reconstructs the full spectrum of a real signal of length `n` from its non-negative frequency
+/// bins, using `X[n - k] = conj(X[k])`.
+fn hermitian_spectrum<B: Backend>(
+    re: Tensor<B, 2>,
+    im: Tensor<B, 2>,
+    n: usize,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let num_bins = re.dims()[1];
+    let num_negative = n - num_bins;
+
+    if num_negative == 0 {
+        return (re, im);
+    }
+
+    let re_negative = re.clone().narrow(1, 1, num_negative).flip([1]);
+    let im_negative = im.clone().narrow(1, 1, num_negative).flip([1]).neg();
+
+    (
+        Tensor::cat(vec![re, re_negative], 1),
+        Tensor::cat(vec![im, im_negative], 1),
+    )
+}
+
+/// Computes the discrete Fourier transform of each row, without normalization.
+///
+/// Composite sizes `n = n1 * n2` are decomposed with the Cooley-Tukey algorithm: the input is
+/// viewed as a `[n2, n1]` matrix, the `n1` columns are transformed, multiplied by the twiddle
+/// factors, then the `n2` rows are transformed. This lowers the number of multiplications from
+/// `n^2` to `n * (n1 + n2)`.
+fn dft<B: Backend>(
+    re: Tensor<B, 2>,
+    im: Tensor<B, 2>,
+    inverse: bool,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let [rows, n] = re.dims();
+    let n1 = largest_factor(n);
+
+    if n <= MAX_DIRECT_SIZE || n1 == 1 {
+        return dft_direct(re, im, inverse);
+    }
+
+    let n2 = n / n1;
+    let device = re.device();
+
+    // Transform the columns, indexed by j = j1 + n1 * j2, over j2.
+    let columns = |tensor: Tensor<B, 2>| {
+        tensor
+            .reshape([rows, n2, n1])
+            .swap_dims(1, 2)
+            .reshape([rows * n1, n2])
+    };
+    let (re, im) = dft(columns(re), columns(im), inverse);
+
+    let (twiddle_re, twiddle_im) = twiddle_factors::<B>(n1, n2, inverse, &device);
+    let (re, im) = complex_mul(
+        re.reshape([rows, n]),
+        im.reshape([rows, n]),
+        twiddle_re,
+        twiddle_im,
+    );
+
+    // Transform the rows over j1, and output the bin k = k2 + n2 * k1.
+    let rows_of = |tensor: Tensor<B, 2>| {
+        tensor
+            .reshape([rows, n1, n2])
+            .swap_dims(1, 2)
+            .reshape([rows * n2, n1])
+    };
+    let (re, im) = dft(rows_of(re), rows_of(im), inverse);
+
+    let output = |tensor: Tensor<B, 2>| {
+        tensor
+            .reshape([rows, n2, n1])
+            .swap_dims(1, 2)
+            .reshape([rows, n])
+    };
+
+    (output(re), output(im))
+}
+
+/// Computes the discrete Fourier transform of each row with a matrix multiplication.
+fn dft_direct<B: Backend>(
+    re: Tensor<B, 2>,
+    im: Tensor<B, 2>,
+    inverse: bool,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let n = re.dims()[1];
+    let sign = if inverse { -1.0 } else { 1.0 };
+    let mut cos = Vec::with_capacity(n * n);
+    let mut sin = Vec::with_capacity(n * n);
+
+    for j in 0..n {
+        for k in 0..n {
+            let angle = 2.0 * PI * ((j * k) % n) as f64 / n as f64;
+            cos.push(angle.cos());
+            sin.push(sign * angle.sin());
+        }
+    }
+
+    let device = re.device();
+    let cos = matrix::<B>(cos, [n, n], &device);
+    let sin = matrix::<B>(sin, [n, n], &device);
+
+    // (re + i im) (cos - i sin) = re cos + im sin + i (im cos - re sin)
+    let out_re = re.clone().matmul(cos.clone()) + im.clone().matmul(sin.clone());
+    let out_im = im.matmul(cos) - re.matmul(sin);
+
+    (out_re, out_im)
+}
+
+/// Returns the twiddle factors `exp(-2 pi i j1 k2 / n)` of shape `[1, n1 * n2]`, indexed by
+/// `j1 * n2 + k2`.
+fn twiddle_factors<B: Backend>(
+    n1: usize,
+    n2: usize,
+    inverse: bool,
+    device: &B::Device,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let n = n1 * n2;
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut re = Vec::with_capacity(n);
+    let mut im = Vec::with_capacity(n);
+
+    for j1 in 0..n1 {
+        for k2 in 0..n2 {
+            let angle = 2.0 * PI * ((j1 * k2) % n) as f64 / n as f64;
+            re.push(angle.cos());
+            im.push(sign * angle.sin());
+        }
+    }
+
+    (
+        matrix::<B>(re, [1, n], device),
+        matrix::<B>(im, [1, n], device),
+    )
+}
+
+fn complex_mul<B: Backend>(
+    lhs_re: Tensor<B, 2>,
+    lhs_im: Tensor<B, 2>,
+    rhs_re: Tensor<B, 2>,
+    rhs_im: Tensor<B, 2>,
+) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let re = lhs_re.clone().mul(rhs_re.clone()) - lhs_im.clone().mul(rhs_im.clone());
+    let im = lhs_re.mul(rhs_im) + lhs_im.mul(rhs_re);
+
+    (re, im)
+}
+
+fn matrix<B: Backend>(values: Vec<f64>, shape: [usize; 2], device: &B::Device) -> Tensor<B, 2> {
+    Tensor::from_data(
+        TensorData::new(values, shape).convert::<B::FloatElem>(),
+        device,
+    )
+}
+
+/// Returns the largest factor of `n` that is not greater than its square root.
+fn largest_factor(n: usize) -> usize {
+    let mut factor = 1;
+    let mut candidate = 2;
+
+    while candidate * candidate <= n {
+        if n % candidate == 0 {
+            factor = candidate;
+        }
+        candidate += 1;
+    }
+
+    factor
+}
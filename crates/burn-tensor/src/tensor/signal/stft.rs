@@ -0,0 +1,175 @@
+use super::{irfft, rfft};
+use crate::backend::Backend;
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{Int, Tensor, TensorData};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Computes the short-time Fourier transform of real signals.
+///
+/// The signals are split into overlapping frames of `n_fft` samples every `hop_length` samples,
+/// which are multiplied by the window and transformed with [rfft](super::rfft).
+///
+/// # Arguments
+///
+/// * `signal` - The signals of shape `[batch_size, length]`.
+/// * `n_fft` - The size of the frames.
+/// * `hop_length` - The distance between the start of consecutive frames.
+/// * `window` - The window of size `n_fft`, or a rectangular window if `None`.
+/// * `center` - If true, the signals are padded with `n_fft / 2` reflected samples on both sides,
+///   so that the frame `t` is centered on the sample `t * hop_length`.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the spectrogram, of shape
+/// `[batch_size, n_fft / 2 + 1, num_frames]`.
+pub fn stft<B: Backend>(
+    signal: Tensor<B, 2>,
+    n_fft: usize,
+    hop_length: usize,
+    window: Option<Tensor<B, 1>>,
+    center: bool,
+) -> (Tensor<B, 3>, Tensor<B, 3>) {
+    check!(TensorCheck::stft(
+        "STFT",
+        n_fft,
+        hop_length,
+        window.as_ref().map(|window| window.dims()[0])
+    ));
+
+    let [batch_size, length] = signal.dims();
+    check!(TensorCheck::stft_signal(length, n_fft, center));
+
+    let padding = if center { n_fft / 2 } else { 0 };
+    let num_frames = 1 + (length + 2 * padding - n_fft) / hop_length;
+
+    // The frames and the reflect padding are gathered with a single selection.
+    let mut positions = Vec::with_capacity(num_frames * n_fft);
+    for frame in 0..num_frames {
+        for i in 0..n_fft {
+            let position = (frame * hop_length + i) as i64 - padding as i64;
+            let position = match position {
+                p if p < 0 => -p,
+                p if p >= length as i64 => 2 * (length as i64 - 1) - p,
+                p => p,
+            };
+            positions.push(position);
+        }
+    }
+
+    let device = signal.device();
+    let indices = Tensor::<B, 1, Int>::from_data(
+        TensorData::new(positions, [num_frames * n_fft]).convert::<B::IntElem>(),
+        &device,
+    );
+
+    let frames = signal
+        .select(1, indices)
+        .reshape([batch_size, num_frames, n_fft]);
+    let frames = match window {
+        Some(window) => frames.mul(window.reshape([1, 1, n_fft])),
+        None => frames,
+    };
+
+    let (re, im) = rfft(frames, 2);
+
+    (re.swap_dims(1, 2), im.swap_dims(1, 2))
+}
+
+/// Computes the inverse of the short-time Fourier transform [stft](stft).
+///
+/// The frames are transformed with [irfft](super::irfft), multiplied by the window and
+/// overlap-added, then normalized by the overlap-added squared window.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the spectrogram, of shape `[batch_size, n_fft / 2 + 1, num_frames]`.
+/// * `im` - The imaginary part of the spectrogram, with the same shape.
+/// * `n_fft` - The size of the frames.
+/// * `hop_length` - The distance between the start of consecutive frames.
+/// * `window` - The window of size `n_fft`, or a rectangular window if `None`.
+/// * `center` - If the signals were padded by the forward transform.
+/// * `length` - The length of the output signals, which are trimmed or padded with zeros. If
+///   `None`, the length covered by the frames is used.
+///
+/// # Returns
+///
+/// The real signals of shape `[batch_size, length]`.
+pub fn istft<B: Backend>(
+    re: Tensor<B, 3>,
+    im: Tensor<B, 3>,
+    n_fft: usize,
+    hop_length: usize,
+    window: Option<Tensor<B, 1>>,
+    center: bool,
+    length: Option<usize>,
+) -> Tensor<B, 2> {
+    check!(TensorCheck::fft_complex::<3>(
+        "ISTFT",
+        1,
+        &re.shape(),
+        &im.shape()
+    ));
+    check!(TensorCheck::stft(
+        "ISTFT",
+        n_fft,
+        hop_length,
+        window.as_ref().map(|window| window.dims()[0])
+    ));
+
+    let [batch_size, num_bins, num_frames] = re.dims();
+    check!(TensorCheck::irfft("ISTFT", num_bins, n_fft));
+
+    let device = re.device();
+    let window = window.unwrap_or_else(|| Tensor::ones([n_fft], &device));
+    let frames = irfft(re.swap_dims(1, 2), im.swap_dims(1, 2), n_fft, 2)
+        .mul(window.clone().reshape([1, 1, n_fft]));
+
+    let padded_length = n_fft + hop_length * (num_frames - 1);
+    let positions = (0..num_frames)
+        .flat_map(|frame| (0..n_fft).map(move |i| (frame * hop_length + i) as i64))
+        .collect::<Vec<_>>();
+    let indices = Tensor::<B, 2, Int>::from_data(
+        TensorData::new(positions, [1, num_frames * n_fft]).convert::<B::IntElem>(),
+        &device,
+    );
+
+    let signal = Tensor::zeros([batch_size, padded_length], &device).scatter(
+        1,
+        indices.clone().expand([batch_size, num_frames * n_fft]),
+        frames.reshape([batch_size, num_frames * n_fft]),
+    );
+    let envelope = Tensor::zeros([1, padded_length], &device).scatter(
+        1,
+        indices,
+        window
+            .clone()
+            .mul(window)
+            .reshape([1, 1, n_fft])
+            .expand([1, num_frames, n_fft])
+            .reshape([1, num_frames * n_fft]),
+    );
+    let signal = signal.div(envelope.clamp_min(1e-11));
+
+    let start = if center { n_fft / 2 } else { 0 };
+    let end = if center {
+        padded_length - n_fft / 2
+    } else {
+        padded_length
+    };
+    let length = length.unwrap_or(end - start);
+
+    if start + length <= padded_length {
+        return signal.narrow(1, start, length);
+    }
+
+    let available = padded_length - start;
+    Tensor::cat(
+        vec![
+            signal.narrow(1, start, available),
+            Tensor::zeros([batch_size, length - available], &device),
+        ],
+        1,
+    )
+}
@@ -0,0 +1,34 @@
+use crate::backend::Backend;
+use crate::{Tensor, TensorData};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Creates a Hann window, `0.5 - 0.5 cos(2 pi i / N)`.
+///
+/// # Arguments
+///
+/// * `size` - The size of the window.
+/// * `periodic` - If true, `N = size`, which is the window used for spectral analysis with
+///   [stft](super::stft). Otherwise `N = size - 1`, which gives a symmetric window.
+/// * `device` - The device on which the window is created.
+pub fn hann_window<B: Backend>(size: usize, periodic: bool, device: &B::Device) -> Tensor<B, 1> {
+    let denominator = match periodic {
+        true => size,
+        false => size.saturating_sub(1),
+    };
+
+    let values = (0..size)
+        .map(|i| match denominator {
+            0 => 1.0,
+            _ => 0.5 - 0.5 * (2.0 * PI * i as f64 / denominator as f64).cos(),
+        })
+        .collect::<Vec<_>>();
+
+    Tensor::from_data(
+        TensorData::new(values, [size]).convert::<B::FloatElem>(),
+        device,
+    )
+}
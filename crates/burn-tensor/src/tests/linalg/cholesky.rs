@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(linalg_cholesky)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn should_compute_cholesky() {
+        let tensor = TestTensor::<2>::from([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let output = linalg::cholesky(tensor);
+
+        let expected = TensorData::from([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_compute_cholesky_from_lower_triangle() {
+        // The upper triangle is ignored
+        let tensor = TestTensor::<3>::from([[[4.0, 100.0], [2.0, 3.0]]]);
+
+        let output = linalg::cholesky(tensor);
+
+        let expected = TensorData::from([[[2.0, 0.0], [1.0, 1.414214]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
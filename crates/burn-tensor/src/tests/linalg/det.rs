@@ -0,0 +1,42 @@
+#[burn_tensor_testgen::testgen(linalg_det)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn should_compute_det() {
+        let tensor = TestTensor::<3>::from([
+            [[1.0, 2.0, 0.0], [3.0, 4.0, 0.0], [0.0, 0.0, 1.0]],
+            [[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]],
+        ]);
+
+        let output = linalg::det(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[-2.0]], [[6.0]]]), 3);
+    }
+
+    #[test]
+    fn should_compute_det_of_singular_matrix() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [2.0, 4.0]]);
+
+        let output = linalg::det(tensor);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_logdet() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = linalg::logdet(tensor);
+
+        // ln(|-2|)
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.693147]]), 3);
+    }
+}
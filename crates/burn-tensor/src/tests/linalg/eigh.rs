@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(linalg_eigh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    #[test]
+    fn should_compute_eigh() {
+        let tensor = TestTensor::<2>::from([[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 5.0]]);
+
+        let (w, v) = linalg::eigh(tensor.clone());
+
+        w.clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 3.0, 5.0]]), 3);
+        v.clone()
+            .mul(w)
+            .matmul(v.clone().transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+        v.clone()
+            .transpose()
+            .matmul(v)
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TestBackend, 2>::eye(3, &Default::default()).into_data(),
+                3,
+            );
+    }
+
+    #[test]
+    fn should_compute_eigh_from_lower_triangle() {
+        // The upper triangle is ignored
+        let tensor = TestTensor::<3>::from([[[2.0, 100.0], [1.0, 2.0]]]);
+
+        let (w, _v) = linalg::eigh(tensor);
+
+        w.into_data()
+            .assert_approx_eq(&TensorData::from([[[1.0, 3.0]]]), 3);
+    }
+}
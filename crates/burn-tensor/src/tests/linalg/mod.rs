@@ -0,0 +1,6 @@
+mod cholesky;
+mod det;
+mod eigh;
+mod qr;
+mod solve;
+mod svd;
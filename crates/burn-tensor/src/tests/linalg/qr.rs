@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(linalg_qr)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    fn assert_qr(tensor: TestTensor<2>) {
+        let [m, n] = tensor.dims();
+        let k = usize::min(m, n);
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [m, k]);
+        assert_eq!(r.dims(), [k, n]);
+
+        q.clone()
+            .matmul(r.clone())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+        q.clone()
+            .transpose()
+            .matmul(q)
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TestBackend, 2>::eye(k, &Default::default()).into_data(),
+                3,
+            );
+        r.clone()
+            .tril(-1)
+            .into_data()
+            .assert_approx_eq(&TensorData::zeros::<f32, _>([k, n]), 3);
+    }
+
+    #[test]
+    fn should_compute_qr_of_tall_matrix() {
+        assert_qr(TestTensor::from([
+            [12.0, -51.0, 4.0],
+            [6.0, 167.0, -68.0],
+            [-4.0, 24.0, -41.0],
+            [1.0, 2.0, 3.0],
+        ]));
+    }
+
+    #[test]
+    fn should_compute_qr_of_wide_matrix() {
+        assert_qr(TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+    }
+}
@@ -0,0 +1,54 @@
+#[burn_tensor_testgen::testgen(linalg_solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, TensorData};
+
+    #[test]
+    fn should_solve_batched_linear_systems() {
+        let a = TestTensor::<3>::from([[[3.0, 1.0], [1.0, 2.0]], [[2.0, 0.0], [0.0, 4.0]]]);
+        let b = TestTensor::<3>::from([[[9.0, 1.0], [8.0, 2.0]], [[2.0, 4.0], [8.0, 4.0]]]);
+
+        let x = linalg::solve(a, b);
+
+        let expected = TensorData::from([[[2.0, 0.0], [3.0, 1.0]], [[1.0, 2.0], [2.0, 1.0]]]);
+        x.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_solve_with_pivoting() {
+        let a = TestTensor::<2>::from([[0.0, 1.0], [2.0, 0.0]]);
+        let b = TestTensor::<2>::from([[3.0], [4.0]]);
+
+        let x = linalg::solve(a, b);
+
+        x.into_data()
+            .assert_approx_eq(&TensorData::from([[2.0], [3.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_inverse() {
+        let tensor = TestTensor::<2>::from([[4.0, 7.0], [2.0, 6.0]]);
+
+        let output = linalg::inverse(tensor);
+
+        let expected = TensorData::from([[0.6, -0.7], [-0.2, 0.4]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_matrices_are_not_square() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _output = linalg::inverse(tensor);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_right_hand_side_has_wrong_rows() {
+        let a = TestTensor::<2>::from([[3.0, 1.0], [1.0, 2.0]]);
+        let b = TestTensor::<2>::from([[1.0], [2.0], [3.0]]);
+
+        let _x = linalg::solve(a, b);
+    }
+}
@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(linalg_svd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Tensor, TensorData};
+
+    #[test]
+    fn should_compute_svd() {
+        let tensor = TestTensor::<2>::from([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [2, 2]);
+        assert_eq!(vh.dims(), [2, 3]);
+        s.clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[5.0, 3.0]]), 3);
+        u.clone()
+            .mul(s)
+            .matmul(vh.clone())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+        vh.clone()
+            .matmul(vh.transpose())
+            .into_data()
+            .assert_approx_eq(
+                &Tensor::<TestBackend, 2>::eye(2, &Default::default()).into_data(),
+                3,
+            );
+    }
+
+    #[test]
+    fn should_compute_svd_of_batched_rank_deficient_matrices() {
+        let tensor = TestTensor::<3>::from([
+            [[1.0, 2.0], [2.0, 4.0], [0.0, 0.0]],
+            [[3.0, 0.0], [0.0, -2.0], [0.0, 0.0]],
+        ]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        s.clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[[5.0, 0.0]], [[3.0, 2.0]]]), 3);
+        u.clone()
+            .mul(s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+        u.clone()
+            .transpose()
+            .matmul(u)
+            .into_data()
+            .assert_approx_eq(
+                &TensorData::from([[[1.0, 0.0], [0.0, 1.0]], [[1.0, 0.0], [0.0, 1.0]]]),
+                3,
+            );
+    }
+}
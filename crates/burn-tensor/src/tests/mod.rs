@@ -1,8 +1,10 @@
 mod activation;
 mod clone_invariance;
+mod linalg;
 mod module;
 mod ops;
 mod quantization;
+mod signal;
 mod stats;
 
 #[allow(missing_docs)]
@@ -32,13 +34,19 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_unfold4d!();
         burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
+        burn_tensor::testgen_module_max_pool3d!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
+        burn_tensor::testgen_module_avg_pool3d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_adaptive_avg_pool3d!();
+        burn_tensor::testgen_module_adaptive_max_pool1d!();
+        burn_tensor::testgen_module_adaptive_max_pool2d!();
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_grid_sample!();
 
         // test ops
         burn_tensor::testgen_add!();
@@ -99,6 +107,8 @@ macro_rules! testgen_all {
         burn_tensor::testgen_expand!();
         burn_tensor::testgen_tri_mask!();
         burn_tensor::testgen_sort_argsort!();
+        burn_tensor::testgen_cumulative!();
+        burn_tensor::testgen_einsum!();
         burn_tensor::testgen_topk!();
         burn_tensor::testgen_remainder!();
         burn_tensor::testgen_cartesian_grid!();
@@ -109,6 +119,18 @@ macro_rules! testgen_all {
         burn_tensor::testgen_eye!();
         burn_tensor::testgen_display!();
 
+        // test linalg
+        burn_tensor::testgen_linalg_solve!();
+        burn_tensor::testgen_linalg_det!();
+        burn_tensor::testgen_linalg_cholesky!();
+        burn_tensor::testgen_linalg_qr!();
+        burn_tensor::testgen_linalg_svd!();
+        burn_tensor::testgen_linalg_eigh!();
+
+        // test signal
+        burn_tensor::testgen_signal_fft!();
+        burn_tensor::testgen_signal_stft!();
+
         // test clone invariance
         burn_tensor::testgen_clone_invariance!();
 
@@ -118,5 +140,6 @@ macro_rules! testgen_all {
         // test quantization
         burn_tensor::testgen_calibration!();
         burn_tensor::testgen_scheme!();
+        burn_tensor::testgen_quantized_ops!();
     };
 }
@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(module_adaptive_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_avg_pool3d;
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_adaptive_avg_pool3d_dyn_filter_size() {
+        let test = AdaptiveAvgPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            depth: 3,
+            height: 5,
+            width: 4,
+            output_size: [2, 3, 2],
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [[12.5000, 14.5000], [18.5000, 20.5000], [24.5000, 26.5000]],
+                [[32.5000, 34.5000], [38.5000, 40.5000], [44.5000, 46.5000]],
+            ],
+            [
+                [[72.5000, 74.5000], [78.5000, 80.5000], [84.5000, 86.5000]],
+                [
+                    [92.5000, 94.5000],
+                    [98.5000, 100.5000],
+                    [104.5000, 106.5000],
+                ],
+            ],
+        ]]));
+    }
+
+    struct AdaptiveAvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        depth: usize,
+        height: usize,
+        width: usize,
+        output_size: [usize; 3],
+    }
+
+    impl AdaptiveAvgPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape(shape_x)
+                    .into_data(),
+            );
+            let output = adaptive_avg_pool3d(x, self.output_size);
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
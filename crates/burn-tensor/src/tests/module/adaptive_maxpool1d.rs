@@ -0,0 +1,23 @@
+#[burn_tensor_testgen::testgen(module_adaptive_max_pool1d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{adaptive_max_pool1d, adaptive_max_pool1d_with_indices};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_adaptive_max_pool1d_with_indices() {
+        let x = TestTensor::from([[
+            [0.2380, 0.5442, 0.3700, 0.6039, 0.6257, 0.0655, 0.0132],
+            [0.8375, 0.2594, 0.2343, 0.9956, 0.4703, 0.8365, 0.4764],
+        ]]);
+        let indices = TensorData::from([[[1, 4, 4], [0, 3, 5]]]);
+        let y = TestTensor::<3>::from([[[0.5442, 0.6257, 0.6257], [0.8375, 0.9956, 0.8365]]]);
+
+        let (output, output_indices) = adaptive_max_pool1d_with_indices(x.clone(), 3);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+        y.to_data()
+            .assert_approx_eq(&adaptive_max_pool1d(x, 3).into_data(), 3);
+    }
+}
@@ -0,0 +1,46 @@
+#[burn_tensor_testgen::testgen(module_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{adaptive_max_pool2d, adaptive_max_pool2d_with_indices};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_adaptive_max_pool2d_dyn_filter_size() {
+        let x = TestTensor::from([[[
+            [0.6391, 0.1506, 0.6349, 0.8680, 0.5232, 0.7413, 0.6714],
+            [0.0640, 0.7582, 0.5911, 0.3013, 0.0310, 0.8655, 0.4727],
+            [0.7188, 0.8788, 0.7141, 0.9211, 0.3950, 0.8009, 0.4446],
+            [0.9356, 0.8789, 0.0975, 0.1360, 0.2170, 0.9655, 0.4362],
+            [0.6266, 0.3010, 0.5072, 0.3859, 0.3509, 0.5851, 0.5843],
+        ]]]);
+        let indices = TensorData::from([[[[8, 3, 3, 12], [21, 17, 26, 26], [21, 22, 26, 26]]]]);
+        let y = TestTensor::<4>::from([[[
+            [0.7582, 0.8680, 0.8680, 0.8655],
+            [0.9356, 0.9211, 0.9655, 0.9655],
+            [0.9356, 0.8789, 0.9655, 0.9655],
+        ]]]);
+
+        let (output, output_indices) = adaptive_max_pool2d_with_indices(x.clone(), [3, 4]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+        y.to_data()
+            .assert_approx_eq(&adaptive_max_pool2d(x, [3, 4]).into_data(), 3);
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_bigger_output() {
+        let x = TestTensor::from([[[[0.9042, 0.6820, 0.9289], [0.8564, 0.9910, 0.6713]]]]);
+        let indices = TensorData::from([[[[0, 0, 2, 2], [0, 4, 4, 2], [3, 4, 4, 5]]]]);
+        let y = TestTensor::<4>::from([[[
+            [0.9042, 0.9042, 0.9289, 0.9289],
+            [0.9042, 0.9910, 0.9910, 0.9289],
+            [0.8564, 0.9910, 0.9910, 0.6713],
+        ]]]);
+
+        let (output, output_indices) = adaptive_max_pool2d_with_indices(x, [3, 4]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+    }
+}
@@ -0,0 +1,104 @@
+#[burn_tensor_testgen::testgen(module_avg_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::avg_pool3d;
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_avg_pool3d_count_include_pad() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            kernel_size: [2, 3, 2],
+            padding: [1, 1, 0],
+            stride: [1, 2, 2],
+            depth: 3,
+            height: 4,
+            width: 5,
+            count_include_pad: true,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [[1.0000, 1.6667], [5.2500, 6.2500]],
+                [[8.6667, 10.0000], [20.5000, 22.5000]],
+                [[22.0000, 23.3333], [40.5000, 42.5000]],
+                [[14.3333, 15.0000], [25.2500, 26.2500]],
+            ],
+            [
+                [[21.0000, 21.6667], [35.2500, 36.2500]],
+                [[48.6667, 50.0000], [80.5000, 82.5000]],
+                [[62.0000, 63.3333], [100.5000, 102.5000]],
+                [[34.3333, 35.0000], [55.2500, 56.2500]],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_avg_pool3d_count_exclude_pad() {
+        let test = AvgPool3dTestCase {
+            batch_size: 1,
+            channels: 2,
+            kernel_size: [2, 3, 2],
+            padding: [1, 1, 0],
+            stride: [1, 2, 2],
+            depth: 3,
+            height: 4,
+            width: 5,
+            count_include_pad: false,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [
+                [[3.0000, 5.0000], [10.5000, 12.5000]],
+                [[13.0000, 15.0000], [20.5000, 22.5000]],
+                [[33.0000, 35.0000], [40.5000, 42.5000]],
+                [[43.0000, 45.0000], [50.5000, 52.5000]],
+            ],
+            [
+                [[63.0000, 65.0000], [70.5000, 72.5000]],
+                [[73.0000, 75.0000], [80.5000, 82.5000]],
+                [[93.0000, 95.0000], [100.5000, 102.5000]],
+                [[103.0000, 105.0000], [110.5000, 112.5000]],
+            ],
+        ]]));
+    }
+
+    struct AvgPool3dTestCase {
+        batch_size: usize,
+        channels: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        depth: usize,
+        height: usize,
+        width: usize,
+        count_include_pad: bool,
+    }
+
+    impl AvgPool3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels,
+                self.depth,
+                self.height,
+                self.width,
+            ]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape(shape_x)
+                    .into_data(),
+            );
+            let output = avg_pool3d(
+                x,
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.count_include_pad,
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
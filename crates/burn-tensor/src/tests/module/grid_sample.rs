@@ -0,0 +1,164 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{affine_grid_2d, grid_sample_2d};
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::Shape;
+
+    #[test]
+    fn test_grid_sample_2d_bilinear_zeros() {
+        let test = GridSampleTestCase {
+            mode: GridSampleMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Zeros,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [0.0, 3.9, 1.045],
+            [0.0, 6.1525, 0.77],
+        ]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_2d_bilinear_border_align_corners() {
+        let test = GridSampleTestCase {
+            mode: GridSampleMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Border,
+            align_corners: true,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [0.95, 4.45, 9.8],
+            [5.8, 10.2, 2.025],
+        ]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_2d_bilinear_reflection() {
+        let test = GridSampleTestCase {
+            mode: GridSampleMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Reflection,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from([[[[0.0, 3.9, 11.0], [6.8, 10.7, 2.2]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_2d_bilinear_reflection_align_corners() {
+        let test = GridSampleTestCase {
+            mode: GridSampleMode::Bilinear,
+            padding_mode: GridSamplePaddingMode::Reflection,
+            align_corners: true,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [0.95, 4.45, 9.5],
+            [6.25, 10.2, 2.425],
+        ]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_2d_nearest_zeros() {
+        let test = GridSampleTestCase {
+            mode: GridSampleMode::Nearest,
+            padding_mode: GridSamplePaddingMode::Zeros,
+            align_corners: false,
+        };
+
+        test.assert_output(TestTensor::from([[[[0.0, 6.0, 0.0], [0.0, 11.0, 0.0]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_2d_nearest_border_align_corners() {
+        let test = GridSampleTestCase {
+            mode: GridSampleMode::Nearest,
+            padding_mode: GridSamplePaddingMode::Border,
+            align_corners: true,
+        };
+
+        test.assert_output(TestTensor::from([[[[0.0, 6.0, 11.0], [4.0, 10.0, 2.0]]]]));
+    }
+
+    #[test]
+    fn test_grid_sample_2d_nearest_rounds_half_to_even() {
+        let x = TestTensor::from([[[[0.0, 1.0, 2.0, 3.0, 4.0]]]]);
+        // Sampled at the pixel coordinates 0.5, 1.5, 2.5 and 3.5.
+        let grid =
+            TestTensor::<4>::from([[[[-0.75, 0.0], [-0.25, 0.0], [0.25, 0.0], [0.75, 0.0]]]]);
+
+        let output = grid_sample_2d(
+            x,
+            grid,
+            GridSampleOptions::new(GridSampleMode::Nearest, GridSamplePaddingMode::Zeros, true),
+        );
+
+        let expected = TestTensor::<4>::from([[[[0.0, 2.0, 2.0, 4.0]]]]);
+        expected.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_2d() {
+        let theta = TestTensor::<3>::from([[[0.8, -0.3, 0.1], [0.2, 1.1, -0.4]]]);
+
+        let output = affine_grid_2d(theta, [1, 1, 2, 3], false);
+
+        let expected = TestTensor::<4>::from([[
+            [[-0.2833, -1.0833], [0.25, -0.95], [0.7833, -0.8167]],
+            [[-0.5833, 0.0167], [-0.05, 0.15], [0.4833, 0.2833]],
+        ]]);
+        expected.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_2d_align_corners() {
+        let theta = TestTensor::<3>::from([[[0.8, -0.3, 0.1], [0.2, 1.1, -0.4]]]);
+
+        let output = affine_grid_2d(theta, [1, 1, 2, 3], true);
+
+        let expected = TestTensor::<4>::from([[
+            [[-0.4, -1.7], [0.4, -1.5], [1.2, -1.3]],
+            [[-1.0, 0.5], [-0.2, 0.7], [0.6, 0.9]],
+        ]]);
+        expected.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_affine_grid_2d_identity_sampling() {
+        let x = TestTensor::from([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]]);
+        let theta = TestTensor::<3>::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        let grid = affine_grid_2d(theta, [1, 1, 2, 3], false);
+        let output = grid_sample_2d(x.clone(), grid, GridSampleOptions::default());
+
+        x.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    struct GridSampleTestCase {
+        mode: GridSampleMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    }
+
+    impl GridSampleTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let shape_x = Shape::new([1, 1, 3, 4]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape(shape_x)
+                    .into_data(),
+            );
+            let grid = TestTensor::<4>::from([[
+                [[-0.9, -0.8], [0.1, -0.3], [1.2, 0.7]],
+                [[-1.3, 0.45], [0.6, 0.95], [0.35, -1.1]],
+            ]]);
+            let output = grid_sample_2d(
+                x,
+                grid,
+                GridSampleOptions::new(self.mode, self.padding_mode, self.align_corners),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
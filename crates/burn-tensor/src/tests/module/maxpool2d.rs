@@ -319,4 +319,20 @@ mod tests {
         y.to_data().assert_approx_eq(&output.into_data(), 3);
         output_indices.into_data().assert_eq(&indices, false);
     }
+
+    #[test]
+    fn test_max_pool2d_with_indices_non_square() {
+        let x = TestTensor::from([[[
+            [0.4524, 0.5598, 0.9242, 0.4657, 0.5078],
+            [0.5874, 0.1847, 0.5119, 0.6299, 0.7930],
+            [0.0941, 0.3034, 0.0907, 0.8096, 0.6934],
+        ]]]);
+        let indices = TensorData::from([[[[5, 2, 9], [5, 7, 13]]]]);
+        let y = TestTensor::<4>::from([[[[0.5874, 0.9242, 0.7930], [0.5874, 0.5119, 0.8096]]]]);
+
+        let (output, output_indices) = max_pool2d_with_indices(x, [2, 2], [1, 2], [0, 1], [1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+    }
 }
@@ -0,0 +1,137 @@
+#[burn_tensor_testgen::testgen(module_max_pool3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool3d, max_pool3d_with_indices};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_max_pool3d_simple() {
+        let x = TestTensor::from([
+            [
+                [
+                    [
+                        [0.6804, 0.4276, 0.3141],
+                        [0.5856, 0.4532, 0.2998],
+                        [0.7944, 0.6990, 0.2441],
+                    ],
+                    [
+                        [0.5744, 0.5252, 0.8751],
+                        [0.7294, 0.2879, 0.9802],
+                        [0.1181, 0.4181, 0.7571],
+                    ],
+                ],
+                [
+                    [
+                        [0.1520, 0.4890, 0.0392],
+                        [0.6682, 0.7646, 0.5730],
+                        [0.8755, 0.3137, 0.6953],
+                    ],
+                    [
+                        [0.5944, 0.5799, 0.4562],
+                        [0.8400, 0.9447, 0.4741],
+                        [0.6642, 0.0607, 0.7015],
+                    ],
+                ],
+            ],
+            [
+                [
+                    [
+                        [0.6471, 0.9931, 0.8219],
+                        [0.2846, 0.3858, 0.6687],
+                        [0.0226, 0.4617, 0.1680],
+                    ],
+                    [
+                        [0.1171, 0.0590, 0.7682],
+                        [0.1293, 0.2476, 0.3909],
+                        [0.8714, 0.0806, 0.4492],
+                    ],
+                ],
+                [
+                    [
+                        [0.5494, 0.8834, 0.8193],
+                        [0.8640, 0.2784, 0.4153],
+                        [0.3588, 0.8842, 0.9577],
+                    ],
+                    [
+                        [0.1509, 0.1762, 0.2320],
+                        [0.2333, 0.4850, 0.5891],
+                        [0.2627, 0.0041, 0.4189],
+                    ],
+                ],
+            ],
+        ]);
+        let y = TestTensor::<5>::from([
+            [
+                [[[0.7294, 0.9802], [0.7944, 0.9802]]],
+                [[[0.9447, 0.9447], [0.9447, 0.9447]]],
+            ],
+            [
+                [[[0.9931, 0.9931], [0.8714, 0.6687]]],
+                [[[0.8834, 0.8834], [0.8842, 0.9577]]],
+            ],
+        ]);
+
+        let output = max_pool3d(x, [2, 2, 2], [1, 1, 1], [0, 0, 0], [1, 1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_indices() {
+        let x = TestTensor::from([[[
+            [
+                [0.3238, 0.1508, 0.6509, 0.0724],
+                [0.5359, 0.3657, 0.0580, 0.5074],
+                [0.0375, 0.4336, 0.0699, 0.0907],
+            ],
+            [
+                [0.4245, 0.8269, 0.1238, 0.2232],
+                [0.6274, 0.9477, 0.5771, 0.3967],
+                [0.9763, 0.0466, 0.8585, 0.2896],
+            ],
+            [
+                [0.1443, 0.1178, 0.3085, 0.8161],
+                [0.1807, 0.5816, 0.6389, 0.3724],
+                [0.5477, 0.0628, 0.0596, 0.2060],
+            ],
+        ]]]);
+        let indices = TensorData::from([[[[[4, 2, 7], [4, 9, 7]], [[16, 17, 27], [20, 17, 19]]]]]);
+        let y = TestTensor::<5>::from([[[
+            [[0.5359, 0.6509, 0.5074], [0.5359, 0.4336, 0.5074]],
+            [[0.6274, 0.9477, 0.8161], [0.9763, 0.9477, 0.3967]],
+        ]]]);
+
+        let (output, output_indices) =
+            max_pool3d_with_indices(x, [2, 2, 2], [2, 1, 2], [1, 0, 1], [1, 1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+    }
+
+    #[test]
+    fn test_max_pool3d_with_indices_dilation() {
+        let x = TestTensor::from([[[
+            [
+                [0.3693, 0.5663, 0.9531, 0.6905, 0.5155],
+                [0.6176, 0.6762, 0.0540, 0.8995, 0.7800],
+                [0.8745, 0.7979, 0.3924, 0.3990, 0.1035],
+            ],
+            [
+                [0.6343, 0.0622, 0.0673, 0.2088, 0.1623],
+                [0.3401, 0.0526, 0.0002, 0.1513, 0.1015],
+                [0.3636, 0.0255, 0.8743, 0.6141, 0.1486],
+            ],
+        ]]]);
+        let indices = TensorData::from([[[[[2, 8, 2], [10, 8, 9]], [[15, 18, 19], [27, 28, 27]]]]]);
+        let y = TestTensor::<5>::from([[[
+            [[0.9531, 0.8995, 0.9531], [0.8745, 0.8995, 0.7800]],
+            [[0.6343, 0.2088, 0.1623], [0.8743, 0.6141, 0.8743]],
+        ]]]);
+
+        let (output, output_indices) =
+            max_pool3d_with_indices(x, [1, 2, 2], [1, 1, 1], [0, 0, 0], [1, 1, 2]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+    }
+}
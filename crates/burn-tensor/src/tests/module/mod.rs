@@ -1,7 +1,11 @@
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_avgpool3d;
+mod adaptive_maxpool1d;
+mod adaptive_maxpool2d;
 mod avgpool1d;
 mod avgpool2d;
+mod avgpool3d;
 mod bicubic_interpolate;
 mod bilinear_interpolate;
 mod conv1d;
@@ -11,7 +15,9 @@ mod conv_transpose1d;
 mod conv_transpose2d;
 mod conv_transpose3d;
 mod forward;
+mod grid_sample;
 mod maxpool1d;
 mod maxpool2d;
+mod maxpool3d;
 mod nearest_interpolate;
 mod unfold4d;
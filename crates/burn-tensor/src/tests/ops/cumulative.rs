@@ -0,0 +1,116 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_cumsum_ops() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.clone().cumsum(1);
+        let expected = TensorData::from([[0.0, 1.0, 3.0], [3.0, 7.0, 12.0]]);
+        output.into_data().assert_eq(&expected, false);
+
+        let output = tensor.cumsum(0);
+        let expected = TensorData::from([[0.0, 1.0, 2.0], [3.0, 5.0, 7.0]]);
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_cumsum_ops_int() {
+        let tensor = TestTensorInt::<2>::from([[1, -2, 3], [4, 5, -6]]);
+
+        let output = tensor.cumsum(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, -1, 2], [4, 9, 3]]), false);
+    }
+
+    #[test]
+    fn should_support_cumprod_ops() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.cumprod(1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, 2.0, 6.0], [4.0, 20.0, 120.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_support_cumprod_ops_int() {
+        let tensor = TestTensorInt::<2>::from([[1, -2, 3], [2, 2, -1]]);
+
+        let output = tensor.cumprod(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, -2, -6], [2, 4, -4]]), false);
+    }
+
+    #[test]
+    fn should_support_cummax_with_indices_ops() {
+        let tensor = TestTensor::<2>::from([[1.0, 3.0, 2.0, 3.0, 5.0], [4.0, 1.0, 4.0, 0.0, 2.0]]);
+
+        let (values, indices) = tensor.cummax_with_indices(1);
+
+        // The index of the last occurrence of the maximum is returned
+        values.into_data().assert_eq(
+            &TensorData::from([[1.0, 3.0, 3.0, 3.0, 5.0], [4.0, 4.0, 4.0, 4.0, 4.0]]),
+            false,
+        );
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 1, 3, 4], [0, 0, 2, 2, 2]]), false);
+    }
+
+    #[test]
+    fn should_support_cummin_with_indices_ops() {
+        let tensor = TestTensor::<2>::from([[2.0, 1.0, 3.0, 1.0, 0.0], [4.0, 1.0, 4.0, 0.0, 2.0]]);
+
+        let (values, indices) = tensor.cummin_with_indices(1);
+
+        values.into_data().assert_eq(
+            &TensorData::from([[2.0, 1.0, 1.0, 1.0, 0.0], [4.0, 1.0, 1.0, 0.0, 0.0]]),
+            false,
+        );
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 1, 3, 4], [0, 1, 1, 3, 3]]), false);
+    }
+
+    #[test]
+    fn should_support_cummax_ops_int_first_dim() {
+        let tensor = TestTensorInt::<2>::from([[1, 5], [3, 2], [0, 7]]);
+
+        let (values, indices) = tensor.cummax_with_indices(0);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 5], [3, 5], [3, 7]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0], [1, 0], [1, 2]]), false);
+    }
+
+    #[test]
+    fn should_support_cummin_ops_int() {
+        let tensor = TestTensorInt::<1>::from([3, 4, 1, 2, -1]);
+
+        let output = tensor.cummin(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([3, 3, 1, 1, -1]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_dim_is_out_of_bounds() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output = tensor.cumsum(2);
+    }
+}
@@ -0,0 +1,129 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_einsum_matmul() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+        let output = TestTensor::<2>::einsum("ij,jk->ik", [lhs.clone(), rhs.clone()]);
+        let expected = TensorData::from([[4.0, 5.0], [10.0, 11.0]]);
+        output.into_data().assert_eq(&expected, false);
+
+        let output = TestTensor::<2>::einsum("ij,jk->ki", [lhs, rhs]);
+        let expected = TensorData::from([[4.0, 10.0], [5.0, 11.0]]);
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_einsum_attention_scores() {
+        let device = Default::default();
+        let q = TestTensorInt::<1>::arange(0..48, &device)
+            .float()
+            .reshape([2, 2, 3, 4])
+            .div_scalar(10.0);
+        let k = TestTensorInt::<1>::arange(0..64, &device)
+            .float()
+            .reshape([2, 2, 4, 4])
+            .div_scalar(-10.0);
+
+        let output = TestTensor::<4>::einsum("bhqd,bhkd->bhqk", [q.clone(), k.clone()]);
+        let expected = q.clone().matmul(k.clone().swap_dims(2, 3));
+        assert_eq!(output.dims(), [2, 2, 3, 4]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+
+        let output = TestTensor::<4>::einsum("...qd,...kd->...qk", [q.clone(), k.clone()]);
+        let expected = q.matmul(k.swap_dims(2, 3));
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_einsum_operands_with_different_ranks() {
+        let matrix = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let vector = TestTensor::<1>::from([1.0, 1.0, 2.0]);
+
+        let output = TestTensor::<1>::einsum("ij,j->i", (matrix, vector));
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([9.0, 21.0]), false);
+    }
+
+    #[test]
+    fn should_support_einsum_trace_and_diagonal() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = TestTensor::<1>::einsum("ii", [tensor.clone()]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([5.0]), false);
+
+        let output = TestTensor::<1>::einsum("ii->i", [tensor]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 4.0]), false);
+    }
+
+    #[test]
+    fn should_support_einsum_implicit_output() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = TestTensor::<2>::einsum("ji", [tensor.clone()]);
+        let expected = TensorData::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        output.into_data().assert_eq(&expected, false);
+
+        let output = TestTensor::<1>::einsum("ij->", [tensor]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([21.0]), false);
+    }
+
+    #[test]
+    fn should_support_einsum_contraction_chain() {
+        let a = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let b = TestTensor::<2>::from([[0.0, 1.0, 2.0], [1.0, 0.0, -1.0]]);
+        let c = TestTensor::<2>::from([[1.0], [2.0], [3.0]]);
+
+        let output = TestTensor::<2>::einsum("ij,jk,kl->il", [a.clone(), b.clone(), c.clone()]);
+        let expected = a.matmul(b).matmul(c);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_einsum_permute() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..24, &device)
+            .float()
+            .reshape([2, 3, 4]);
+
+        let output = TestTensor::<3>::einsum("abc->cab", [tensor.clone()]);
+        let expected = tensor.permute([2, 0, 1]);
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_label_sizes_mismatch() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _output = TestTensor::<2>::einsum("ij,jk->ik", [lhs.clone(), lhs]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_output_rank_mismatch() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _output = TestTensor::<3>::einsum("ij->ij", [lhs]);
+    }
+}
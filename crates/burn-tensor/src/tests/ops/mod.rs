@@ -16,7 +16,9 @@ mod clamp;
 mod close;
 mod cos;
 mod create_like;
+mod cumulative;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
 mod expand;
@@ -2,8 +2,11 @@
 mod tests {
     use super::*;
     use burn_tensor::{
-        quantization::{Calibration, MinMaxCalibration, QuantizationType},
-        Tensor, TensorData,
+        quantization::{
+            Calibration, EntropyCalibration, MinMaxCalibration, MovingAverageCalibration,
+            PercentileCalibration, QuantizationType,
+        },
+        ElementConversion, Tensor, TensorData,
     };
 
     #[test]
@@ -23,4 +26,183 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([0.5]), false);
     }
+
+    #[test]
+    fn min_max_calibration_update_range() {
+        let device = Default::default();
+        let calibration = MinMaxCalibration {};
+
+        let range = calibration.update_range(
+            None,
+            &Tensor::<TestBackend, 1>::from_floats([-1.0, 0.5], &device),
+        );
+        let range = calibration.update_range(
+            Some(range),
+            &Tensor::<TestBackend, 1>::from_floats([-0.5, 2.0], &device),
+        );
+
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([-1.0]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([2.0]), false);
+    }
+
+    #[test]
+    fn moving_average_calibration_update_range() {
+        let device = Default::default();
+        let calibration = MovingAverageCalibration { momentum: 0.5 };
+
+        let range = calibration.update_range(
+            None,
+            &Tensor::<TestBackend, 1>::from_floats([-1.0, 1.0], &device),
+        );
+        let range = calibration.update_range(
+            Some(range),
+            &Tensor::<TestBackend, 1>::from_floats([-3.0, 3.0], &device),
+        );
+
+        range
+            .min
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-2.0]), 3);
+        range
+            .max
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.0]), 3);
+    }
+
+    #[test]
+    fn percentile_calibration_range() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats(
+            TensorData::new((0..=100).rev().map(|v| v as f32).collect(), [101]),
+            &Default::default(),
+        );
+        let calibration = PercentileCalibration {
+            percentile: 99.0,
+            momentum: 0.1,
+        };
+
+        let range = calibration.compute_range(&tensor);
+
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([1.0]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([99.0]), false);
+    }
+
+    #[test]
+    fn percentile_calibration_empty_range() {
+        let device = Default::default();
+        let calibration = PercentileCalibration {
+            percentile: 99.0,
+            momentum: 0.1,
+        };
+
+        let range = calibration.compute_range(&Tensor::<TestBackend, 1>::empty([0], &device));
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([0.0]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([0.0]), false);
+
+        let range =
+            calibration.compute_group_range(&Tensor::<TestBackend, 2>::empty([2, 0], &device));
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 0.0]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 0.0]), false);
+    }
+
+    #[test]
+    fn percentile_calibration_group_range() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[-10.0, 0.0, 1.0, 2.0, 3.0], [4.0, -1.0, 100.0, 0.0, 1.0]],
+            &Default::default(),
+        );
+        let calibration = PercentileCalibration {
+            percentile: 75.0,
+            momentum: 0.1,
+        };
+
+        let range = calibration.compute_group_range(&tensor);
+
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 0.0]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 4.0]), false);
+    }
+
+    #[test]
+    fn entropy_calibration_should_clip_outliers() {
+        let mut values = (0..1000)
+            .map(|v| v as f32 / 500.0 - 1.0)
+            .collect::<Vec<_>>();
+        values.push(100.0);
+        let tensor = Tensor::<TestBackend, 1>::from_floats(
+            TensorData::new(values, [1001]),
+            &Default::default(),
+        );
+        let calibration = EntropyCalibration {
+            num_bins: 256,
+            num_quantized_bins: 8,
+            momentum: 0.1,
+        };
+
+        let range = calibration.compute_range(&tensor);
+        let min = range.min.into_scalar().elem::<f32>();
+        let max = range.max.into_scalar().elem::<f32>();
+
+        assert_eq!(min, -1.0);
+        assert!(
+            max > 1.0 && max < 100.0,
+            "Outlier should be clipped, got {max}"
+        );
+    }
+
+    #[test]
+    fn percentile_calibration_update_range_should_average_batches() {
+        let device = Default::default();
+        let calibration = PercentileCalibration {
+            percentile: 75.0,
+            momentum: 0.5,
+        };
+
+        let range = calibration.update_range(
+            None,
+            &Tensor::<TestBackend, 1>::from_floats([-1.0, 0.0, 1.0, 2.0, 100.0], &device),
+        );
+        let range = calibration.update_range(
+            Some(range),
+            &Tensor::<TestBackend, 1>::from_floats([-100.0, -2.0, 0.0, 2.0, 4.0], &device),
+        );
+
+        // The outliers of each batch are clipped instead of widening the running range
+        range
+            .min
+            .into_data()
+            .assert_approx_eq(&TensorData::from([-1.0]), 3);
+        range
+            .max
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.0]), 3);
+    }
 }
@@ -1,2 +1,3 @@
 mod calibration;
+mod ops;
 mod scheme;
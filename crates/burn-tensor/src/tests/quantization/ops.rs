@@ -0,0 +1,231 @@
+#[burn_tensor_testgen::testgen(quantized_ops)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{conv2d, conv2d_requantize};
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::{
+        quantization::{
+            MinMaxCalibration, QuantizationParameters, QuantizationScheme, QuantizationType,
+        },
+        ElementConversion, Int, Tensor,
+    };
+
+    fn quantize<const D: usize>(
+        tensor: Tensor<TestBackend, D>,
+        scheme: QuantizationScheme,
+    ) -> Tensor<TestBackend, D> {
+        let range = scheme.compute_range(&MinMaxCalibration {}, &tensor);
+        tensor.quantize(&scheme, scheme.compute_q_params(range))
+    }
+
+    /// Returns the quantization parameters of the reference output, and the largest scale as the
+    /// tolerance of a single quantization step.
+    fn output_q_params<const D: usize>(
+        tensor: &Tensor<TestBackend, D>,
+        scheme: &QuantizationScheme,
+    ) -> (QuantizationParameters<TestBackend>, f64) {
+        let range = scheme.compute_range(&MinMaxCalibration {}, tensor);
+        let qparams = scheme.compute_q_params(range);
+        let tolerance = qparams.scale.clone().max().into_scalar().elem::<f64>();
+        (qparams, tolerance)
+    }
+
+    #[test]
+    fn should_support_quantized_matmul_per_tensor() {
+        let device = Default::default();
+        let lhs =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 7.0], [2.0, 3.0], [1.0, 5.0]], &device);
+        let rhs =
+            Tensor::<TestBackend, 2>::from_floats([[4.0, 7.0, 5.0], [2.0, -3.0, 5.0]], &device);
+        let lhs = quantize(
+            lhs,
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        );
+        let rhs = quantize(
+            rhs,
+            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8),
+        );
+
+        let expected = lhs.clone().dequantize().matmul(rhs.clone().dequantize());
+        let output = lhs.matmul(rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_quantized_matmul_per_channel_weights() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 3>::from_floats(
+            [[[1.0, 7.0], [2.0, 3.0]], [[-1.0, 0.5], [0.25, 3.0]]],
+            &device,
+        );
+        let rhs =
+            Tensor::<TestBackend, 3>::from_floats([[[4.0, 0.7, 5.0], [2.0, -0.3, 5.0]]], &device);
+        let lhs = quantize(
+            lhs,
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        );
+        let rhs = quantize(
+            rhs,
+            QuantizationScheme::PerChannelSymmetric {
+                dtype: QuantizationType::QInt8,
+                axis: 2,
+            },
+        );
+
+        let expected = lhs.clone().dequantize().matmul(rhs.clone().dequantize());
+        let output = lhs.matmul(rhs);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_quantized_conv2d() {
+        let device = Default::default();
+        let x = Tensor::<TestBackend, 1, Int>::arange(0..32, &device)
+            .float()
+            .reshape([1, 2, 4, 4])
+            .div_scalar(10);
+        let weight = Tensor::<TestBackend, 1, Int>::arange(0..54, &device)
+            .float()
+            .reshape([3, 2, 3, 3])
+            .sub_scalar(27)
+            .div_scalar(20);
+        let bias = Tensor::<TestBackend, 1>::from_floats([0.1, -0.2, 0.3], &device);
+        let options = ConvOptions::new([1, 1], [1, 1], [1, 1], 1);
+        let x = quantize(
+            x,
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        );
+        let weight = quantize(
+            weight,
+            QuantizationScheme::PerChannelSymmetric {
+                dtype: QuantizationType::QInt8,
+                axis: 0,
+            },
+        );
+
+        let expected = conv2d(
+            x.clone().dequantize(),
+            weight.clone().dequantize(),
+            Some(bias.clone()),
+            options.clone(),
+        );
+        let output = conv2d(x, weight, Some(bias), options);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_requantized_matmul_per_tensor() {
+        let device = Default::default();
+        let lhs =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 7.0], [2.0, 3.0], [1.0, 5.0]], &device);
+        let rhs =
+            Tensor::<TestBackend, 2>::from_floats([[4.0, 7.0, 5.0], [2.0, -3.0, 5.0]], &device);
+        let lhs = quantize(
+            lhs,
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        );
+        let rhs = quantize(
+            rhs,
+            QuantizationScheme::PerChannelSymmetric {
+                dtype: QuantizationType::QInt8,
+                axis: 1,
+            },
+        );
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+
+        let reference = lhs.clone().dequantize().matmul(rhs.clone().dequantize());
+        let (qparams, tolerance) = output_q_params(&reference, &scheme);
+        let expected = reference.quantize(&scheme, qparams.clone()).dequantize();
+        let output = lhs.matmul_requantize(rhs, &scheme, qparams);
+
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), tolerance);
+    }
+
+    #[test]
+    fn should_support_requantized_matmul_per_channel() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 3>::from_floats(
+            [[[1.0, 7.0], [2.0, 3.0]], [[-1.0, 0.5], [0.25, 3.0]]],
+            &device,
+        );
+        let rhs =
+            Tensor::<TestBackend, 3>::from_floats([[[4.0, 0.7, 5.0], [2.0, -0.3, 5.0]]], &device);
+        let lhs = quantize(
+            lhs,
+            QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8),
+        );
+        let rhs = quantize(
+            rhs,
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        );
+        let scheme = QuantizationScheme::PerChannelSymmetric {
+            dtype: QuantizationType::QInt8,
+            axis: 2,
+        };
+
+        let reference = lhs.clone().dequantize().matmul(rhs.clone().dequantize());
+        let (qparams, tolerance) = output_q_params(&reference, &scheme);
+        let expected = reference.quantize(&scheme, qparams.clone()).dequantize();
+        let output = lhs.matmul_requantize(rhs, &scheme, qparams);
+
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), tolerance);
+    }
+
+    #[test]
+    fn should_support_requantized_conv2d() {
+        let device = Default::default();
+        let x = Tensor::<TestBackend, 1, Int>::arange(0..32, &device)
+            .float()
+            .reshape([1, 2, 4, 4])
+            .div_scalar(10);
+        let weight = Tensor::<TestBackend, 1, Int>::arange(0..54, &device)
+            .float()
+            .reshape([3, 2, 3, 3])
+            .sub_scalar(27)
+            .div_scalar(20);
+        let bias = Tensor::<TestBackend, 1>::from_floats([0.1, -0.2, 0.3], &device);
+        let options = ConvOptions::new([1, 1], [1, 1], [1, 1], 1);
+        let x = quantize(
+            x,
+            QuantizationScheme::PerTensorAffine(QuantizationType::QInt8),
+        );
+        let weight = quantize(
+            weight,
+            QuantizationScheme::PerChannelSymmetric {
+                dtype: QuantizationType::QInt8,
+                axis: 0,
+            },
+        );
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+
+        let reference = conv2d(
+            x.clone().dequantize(),
+            weight.clone().dequantize(),
+            Some(bias.clone()),
+            options.clone(),
+        );
+        let (qparams, tolerance) = output_q_params(&reference, &scheme);
+        let expected = reference.quantize(&scheme, qparams.clone()).dequantize();
+        let output = conv2d_requantize(x, weight, Some(bias), options, &scheme, qparams);
+
+        output
+            .dequantize()
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), tolerance);
+    }
+}
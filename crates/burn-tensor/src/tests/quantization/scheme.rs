@@ -2,7 +2,10 @@
 mod tests {
     use super::*;
     use burn_tensor::{
-        quantization::{CalibrationRange, QuantizationScheme, QuantizationType},
+        quantization::{
+            AffineQuantization, CalibrationRange, MinMaxCalibration, QuantizationScheme,
+            QuantizationStrategy, QuantizationType,
+        },
         Tensor, TensorData,
     };
 
@@ -28,6 +31,63 @@ mod tests {
             .assert_eq(&TensorData::from([72]), false);
     }
 
+    #[test]
+    fn per_tensor_affine_int8_positive_range() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+        // The range is extended to include zero
+        let range = CalibrationRange {
+            min: Tensor::<TestBackend, 1>::from_floats([0.5], &device),
+            max: Tensor::<TestBackend, 1>::from_floats([2.0], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.007_843_138]), 8);
+        qparams
+            .offset
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([-128]), false);
+    }
+
+    #[test]
+    fn per_tensor_affine_uint8_negative_range() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QUInt8);
+        let range = CalibrationRange {
+            min: Tensor::<TestBackend, 1>::from_floats([-2.0], &device),
+            max: Tensor::<TestBackend, 1>::from_floats([-0.5], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.007_843_138]), 8);
+        qparams
+            .offset
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([255]), false);
+    }
+
+    #[test]
+    fn per_tensor_strategy_clamps_offset() {
+        let scheme = QuantizationScheme::PerTensorAffine(QuantizationType::QInt8);
+
+        let strategy = scheme.per_tensor_strategy(0.1, Some(200)).unwrap();
+
+        assert_eq!(
+            strategy,
+            QuantizationStrategy::PerTensorAffineInt8(AffineQuantization::init(0.1, 127))
+        );
+    }
+
     #[test]
     fn per_tensor_symmetric_int8() {
         let device = Default::default();
@@ -45,4 +105,80 @@ mod tests {
             .assert_approx_eq(&TensorData::from([0.014_173_228]), 8);
         assert!(qparams.offset.is_none());
     }
+
+    #[test]
+    fn per_channel_symmetric_int8() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerChannelSymmetric {
+            dtype: QuantizationType::QInt8,
+            axis: 0,
+        };
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[-1.8, 0.5], [0.25, -0.127]], &device);
+
+        let range = scheme.compute_range(&MinMaxCalibration {}, &tensor);
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.014_173_228, 0.001_968_504]), 8);
+        assert!(qparams.offset.is_none());
+    }
+
+    #[test]
+    fn per_channel_symmetric_int8_constant_channel() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerChannelSymmetric {
+            dtype: QuantizationType::QInt8,
+            axis: 0,
+        };
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.0, 0.0], [0.25, -0.127]], &device);
+
+        let range = scheme.compute_range(&MinMaxCalibration {}, &tensor);
+        let qparams = scheme.compute_q_params(range);
+
+        // The range of the all-zero channel is clamped to avoid a zero scale
+        let scale = qparams.scale.into_data().to_vec::<f32>().unwrap();
+        assert!(scale[0] > 0.0);
+    }
+
+    #[test]
+    fn per_tensor_symmetric_int8_zero_range_is_not_clamped() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::PerTensorSymmetric(QuantizationType::QInt8);
+        let range = CalibrationRange {
+            min: Tensor::<TestBackend, 1>::from_floats([0.0], &device),
+            max: Tensor::<TestBackend, 1>::from_floats([0.0], &device),
+        };
+
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_eq(&TensorData::from([0.0f32]), false);
+    }
+
+    #[test]
+    fn block_affine_int8() {
+        let device = Default::default();
+        let scheme = QuantizationScheme::BlockAffine {
+            dtype: QuantizationType::QInt8,
+            block_size: 2,
+        };
+        let tensor = Tensor::<TestBackend, 1>::from_floats([-1.8, 0.5, -1.0, 0.27], &device);
+
+        let range = scheme.compute_range(&MinMaxCalibration {}, &tensor);
+        let qparams = scheme.compute_q_params(range);
+
+        qparams
+            .scale
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.009_019_608, 0.004_980_392]), 8);
+        qparams
+            .offset
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([72, 73]), false);
+    }
 }
@@ -0,0 +1,108 @@
+#[burn_tensor_testgen::testgen(signal_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{signal, TensorData};
+    use core::f64::consts::PI;
+
+    #[test]
+    fn should_compute_fft() {
+        let re = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0], [1.0, 0.0, -1.0, 0.5]]);
+        let im = TestTensor::<2>::from([[0.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, -1.0]]);
+
+        let (re, im) = signal::fft(re, im, 1);
+
+        re.into_data().assert_approx_eq(
+            &TensorData::from([[10.0, -2.0, -2.0, -2.0], [0.5, 4.0, -0.5, 0.0]]),
+            3,
+        );
+        im.into_data().assert_approx_eq(
+            &TensorData::from([[0.0, 2.0, 0.0, -2.0], [0.0, 0.5, 0.0, -0.5]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_compute_fft_along_dim() {
+        let re = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let im = re.zeros_like();
+
+        let (re, im) = signal::fft(re, im, 0);
+
+        re.into_data()
+            .assert_approx_eq(&TensorData::from([[4.0, 6.0], [-2.0, -2.0]]), 3);
+        im.into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 0.0], [0.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_fft_of_composite_size() {
+        // A cosine with 3 periods has two peaks of n/2 at the frequencies 3 and n - 3.
+        let n = 72;
+        let values = (0..n)
+            .map(|j| (2.0 * PI * 3.0 * j as f64 / n as f64).cos() as f32)
+            .collect::<Vec<_>>();
+        let signal = TestTensor::<1>::from_data(TensorData::new(values, [n]), &Default::default());
+
+        let (re, im) = signal::fft(signal.clone(), signal.zeros_like(), 0);
+
+        let mut expected = vec![0.0f32; n];
+        expected[3] = 36.0;
+        expected[n - 3] = 36.0;
+        re.clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::new(expected, [n]), 3);
+        im.clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::new(vec![0.0f32; n], [n]), 3);
+
+        let (re, im) = signal::ifft(re, im, 0);
+        re.into_data().assert_approx_eq(&signal.into_data(), 3);
+        im.into_data()
+            .assert_approx_eq(&TensorData::new(vec![0.0f32; n], [n]), 3);
+    }
+
+    #[test]
+    fn should_compute_rfft_and_irfft() {
+        let signal = TestTensor::<1>::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        let (re, im) = signal::rfft(signal.clone(), 0);
+
+        re.clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([15.0, -2.5, -2.5]), 3);
+        im.clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 3.440955, 0.812299]), 3);
+
+        let output = signal::irfft(re, im, 5, 0);
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_fft2_and_inverse() {
+        let signal = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let (re, im) = signal::fft2(signal.clone(), signal.zeros_like());
+
+        re.clone()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[10.0, -2.0], [-4.0, 0.0]]), 3);
+
+        let (output, _) = signal::ifft2(re, im);
+        output
+            .into_data()
+            .assert_approx_eq(&signal.clone().into_data(), 3);
+
+        let (re, im) = signal::rfft2(signal.clone());
+        let output = signal::irfft2(re, im, 2);
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_irfft_size_mismatch() {
+        let re = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+
+        let _output = signal::irfft(re.clone(), re, 8, 0);
+    }
+}
@@ -0,0 +1,2 @@
+mod fft;
+mod stft;
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(signal_stft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{signal, TensorData};
+
+    #[test]
+    fn should_compute_stft() {
+        let signal = TestTensor::<2>::from([[1.0, 2.0, 0.0, -1.0, 3.0, 1.0, 0.0, 2.0]]);
+
+        let (re, im) = signal::stft(signal, 4, 2, None, false);
+
+        re.into_data().assert_approx_eq(
+            &TensorData::from([[[2.0, 3.0, 6.0], [1.0, -3.0, 3.0], [0.0, 3.0, 0.0]]]),
+            3,
+        );
+        im.into_data().assert_approx_eq(
+            &TensorData::from([[[0.0, 0.0, 0.0], [-3.0, 2.0, 1.0], [0.0, 0.0, 0.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_reconstruct_signal_with_istft() {
+        let device = Default::default();
+        let signal = TestTensorInt::<1>::arange(0..64, &device)
+            .float()
+            .reshape([2, 32])
+            .div_scalar(8.0)
+            .sin();
+        let window = signal::hann_window(8, true, &device);
+
+        let (re, im) = signal::stft(signal.clone(), 8, 2, Some(window.clone()), true);
+        assert_eq!(re.dims(), [2, 5, 17]);
+
+        let output = signal::istft(re, im, 8, 2, Some(window), true, Some(32));
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+
+    #[test]
+    fn should_create_hann_window() {
+        let device = Default::default();
+
+        let window = signal::hann_window::<TestBackend>(4, true, &device);
+        window
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.5, 1.0, 0.5]), 3);
+
+        let window = signal::hann_window::<TestBackend>(5, false, &device);
+        window
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.5, 1.0, 0.5, 0.0]), 3);
+    }
+}
@@ -4,9 +4,11 @@ use crate::learner::EarlyStoppingStrategy;
 use crate::metric::store::EventStoreClient;
 use crate::LearnerSummaryConfig;
 use burn_core::lr_scheduler::LrScheduler;
-use burn_core::module::Module;
-use burn_core::optim::Optimizer;
-use burn_core::tensor::backend::Backend;
+use burn_core::module::{AutodiffModule, Module};
+use burn_core::optim::{
+    AveragedModule, AveragedModuleRecord, EmaConfig, GradScaler, GradScalerRecord, Optimizer,
+};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
 use burn_core::tensor::Device;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -22,6 +24,8 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) grad_scaler: Option<GradScaler>,
+    pub(crate) model_averaging: Option<ModelAveraging>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
@@ -31,11 +35,45 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) summary: Option<LearnerSummaryConfig>,
 }
 
+/// How the weights of the model are averaged during the training.
+pub(crate) enum ModelAveraging {
+    /// Exponential moving average, updated after each optimizer step.
+    Ema(EmaConfig),
+    /// Stochastic weight averaging, updated at the end of each epoch from the start epoch.
+    Swa { start_epoch: usize },
+}
+
+impl ModelAveraging {
+    pub(crate) fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        model: M,
+    ) -> AveragedModule<B, M> {
+        match self {
+            ModelAveraging::Ema(config) => config.init(model),
+            ModelAveraging::Swa { .. } => AveragedModule::uniform(model),
+        }
+    }
+}
+
+/// The checkpointer used for the averaged model.
+pub(crate) type AveragedModelCheckpointer<LC> = Box<
+    dyn Checkpointer<
+        AveragedModuleRecord<<LC as LearnerComponents>::Backend, <LC as LearnerComponents>::Model>,
+        <LC as LearnerComponents>::Backend,
+    >,
+>;
+
+/// The checkpointer used for the gradient scaler.
+pub(crate) type GradScalerCheckpointer<LC> =
+    Box<dyn Checkpointer<GradScalerRecord, <LC as LearnerComponents>::Backend>>;
+
 #[derive(new)]
 pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     model: LC::CheckpointerModel,
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
+    averaged_model: AveragedModelCheckpointer<LC>,
+    grad_scaler: GradScalerCheckpointer<LC>,
     strategy: LC::CheckpointerStrategy,
 }
 
@@ -45,6 +83,8 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
         model: &LC::Model,
         optim: &LC::Optimizer,
         scheduler: &LC::LrScheduler,
+        averaged_model: Option<&AveragedModule<LC::Backend, LC::Model>>,
+        grad_scaler: Option<&GradScaler>,
         epoch: usize,
         store: &EventStoreClient,
     ) {
@@ -62,6 +102,12 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
                     self.lr_scheduler
                         .delete(epoch)
                         .expect("Can delete learning rate scheduler checkpoint.");
+                    self.averaged_model
+                        .delete(epoch)
+                        .expect("Can delete averaged model checkpoint.");
+                    self.grad_scaler
+                        .delete(epoch)
+                        .expect("Can delete gradient scaler checkpoint.");
                 }
                 CheckpointingAction::Save => {
                     self.model
@@ -73,6 +119,16 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
                     self.lr_scheduler
                         .save(epoch, scheduler.to_record())
                         .expect("Can save learning rate scheduler checkpoint.");
+                    if let Some(averaged_model) = averaged_model {
+                        self.averaged_model
+                            .save(epoch, averaged_model.clone().into_record())
+                            .expect("Can save averaged model checkpoint.");
+                    }
+                    if let Some(grad_scaler) = grad_scaler {
+                        self.grad_scaler
+                            .save(epoch, grad_scaler.to_record())
+                            .expect("Can save gradient scaler checkpoint.");
+                    }
                 }
             }
         }
@@ -106,6 +162,34 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
 
         (model, optim, scheduler)
     }
+
+    pub(crate) fn load_averaged_model(
+        &self,
+        averaged_model: AveragedModule<LC::Backend, LC::Model>,
+        device: &Device<LC::Backend>,
+        epoch: usize,
+    ) -> AveragedModule<LC::Backend, LC::Model> {
+        let record = self
+            .averaged_model
+            .restore(epoch, device)
+            .expect("Can load averaged model checkpoint.");
+
+        averaged_model.load_record(record)
+    }
+
+    pub(crate) fn load_grad_scaler(
+        &self,
+        grad_scaler: GradScaler,
+        device: &Device<LC::Backend>,
+        epoch: usize,
+    ) -> GradScaler {
+        let record = self
+            .grad_scaler
+            .restore(epoch, device)
+            .expect("Can load gradient scaler checkpoint.");
+
+        grad_scaler.load_record(record)
+    }
 }
 
 #[derive(Clone, Default)]
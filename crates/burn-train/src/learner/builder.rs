@@ -8,7 +8,7 @@ use crate::checkpoint::{
     KeepLastNCheckpoints, MetricCheckpointingStrategy,
 };
 use crate::components::LearnerComponentsMarker;
-use crate::learner::base::TrainingInterrupter;
+use crate::learner::base::{ModelAveraging, TrainingInterrupter};
 use crate::learner::EarlyStoppingStrategy;
 use crate::logger::{FileMetricLogger, MetricLogger};
 use crate::metric::processor::{FullEventProcessor, Metrics};
@@ -21,7 +21,10 @@ use crate::{
 };
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::AutodiffModule;
-use burn_core::optim::Optimizer;
+use burn_core::optim::{
+    AveragedModuleRecord, EmaConfig, GradScaler, GradScalerConfig, GradScalerRecord, Optimizer,
+    ParamGroup,
+};
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::AutodiffBackend;
 
@@ -43,11 +46,16 @@ where
         AsyncCheckpointer<M::Record, B>,
         AsyncCheckpointer<O::Record, B>,
         AsyncCheckpointer<S::Record, B>,
+        AsyncCheckpointer<AveragedModuleRecord<B, M>, B>,
+        AsyncCheckpointer<GradScalerRecord, B>,
     )>,
     num_epochs: usize,
     checkpoint: Option<usize>,
     directory: PathBuf,
     grad_accumulation: Option<usize>,
+    grad_scaler: Option<GradScaler>,
+    param_groups: Vec<ParamGroup>,
+    model_averaging: Option<ModelAveraging>,
     devices: Vec<B::Device>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
@@ -84,6 +92,9 @@ where
             checkpointers: None,
             directory,
             grad_accumulation: None,
+            grad_scaler: None,
+            param_groups: Vec::new(),
+            model_averaging: None,
             devices: vec![B::Device::default()],
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
@@ -181,6 +192,64 @@ where
         self
     }
 
+    /// Enable dynamic loss scaling for mixed precision training.
+    ///
+    /// # Notes
+    ///
+    /// The model must scale its loss in [step_scaled](crate::TrainStep::step_scaled) using
+    /// [TrainOutput::scaled](crate::TrainOutput::scaled). The optimizer steps with inf or NaN
+    /// gradients are skipped and the scale is adjusted after each training step. Use an optimizer
+    /// with full precision master weights, e.g.
+    /// [AdamConfig::init_mixed_precision](burn_core::optim::AdamConfig::init_mixed_precision),
+    /// when the model is trained in half precision.
+    pub fn grad_scaler(mut self, config: GradScalerConfig) -> Self {
+        self.grad_scaler = Some(config.init());
+        self
+    }
+
+    /// Add a [parameter group](ParamGroup) to the optimizer, optimized with its own learning rate
+    /// multiplier, weight decay and gradient clipping.
+    ///
+    /// # Notes
+    ///
+    /// The groups are added to the optimizer in order when the learner is built, see
+    /// [Optimizer::with_param_group].
+    ///
+    /// # Panics
+    ///
+    /// When building the learner, if the optimizer doesn't support the parameter group.
+    pub fn param_group(mut self, group: ParamGroup) -> Self {
+        self.param_groups.push(group);
+        self
+    }
+
+    /// Keep an [exponential moving average](EmaConfig) of the weights of the model, updated after
+    /// each optimizer step.
+    ///
+    /// # Notes
+    ///
+    /// The averaged model is used for validation, is saved by the checkpointer along with the
+    /// trained model, and is returned at the end of the training.
+    pub fn ema(mut self, config: EmaConfig) -> Self {
+        self.model_averaging = Some(ModelAveraging::Ema(config));
+        self
+    }
+
+    /// Enable [stochastic weight averaging](burn_core::optim::AveragedModule::uniform), the
+    /// weights of the model being averaged uniformly at the end of each epoch from the given
+    /// start epoch.
+    ///
+    /// # Notes
+    ///
+    /// After each update, the running statistics of the normalization layers of the averaged
+    /// model are re-estimated with a forward pass on the training data. The averaged model is
+    /// used for validation, is saved by the checkpointer along with the trained model, and is
+    /// returned at the end of the training.
+    pub fn swa(mut self, start_epoch: usize) -> Self {
+        self.model_averaging = Some(ModelAveraging::Swa { start_epoch });
+        self
+    }
+
     /// Register a [numeric](crate::metric::Numeric) training [metric](Metric).
     pub fn metric_train_numeric<Me>(mut self, metric: Me) -> Self
     where
@@ -264,12 +333,18 @@ where
         let checkpointer_optimizer =
             FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "optim");
         let checkpointer_scheduler: FileCheckpointer<FR> =
-            FileCheckpointer::new(recorder, &checkpoint_dir, "scheduler");
+            FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "scheduler");
+        let checkpointer_averaged_model: FileCheckpointer<FR> =
+            FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "model-averaged");
+        let checkpointer_grad_scaler: FileCheckpointer<FR> =
+            FileCheckpointer::new(recorder, &checkpoint_dir, "grad-scaler");
 
         self.checkpointers = Some((
             AsyncCheckpointer::new(checkpointer_model),
             AsyncCheckpointer::new(checkpointer_optimizer),
             AsyncCheckpointer::new(checkpointer_scheduler),
+            AsyncCheckpointer::new(checkpointer_averaged_model),
+            AsyncCheckpointer::new(checkpointer_grad_scaler),
         ));
 
         self
@@ -327,12 +402,27 @@ where
                 .register_logger_valid(FileMetricLogger::new(self.directory.join("valid")));
         }
 
+        let optim = self.param_groups.into_iter().fold(optim, |optim, group| {
+            optim
+                .with_param_group(group)
+                .expect("Can add the parameter group to the optimizer.")
+        });
+
         let event_store = Rc::new(EventStoreClient::new(self.event_store));
         let event_processor = FullEventProcessor::new(self.metrics, renderer, event_store.clone());
 
-        let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
-            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointer_strategy)
-        });
+        let checkpointer =
+            self.checkpointers
+                .map(|(model, optim, scheduler, averaged_model, grad_scaler)| {
+                    LearnerCheckpointer::new(
+                        model,
+                        optim,
+                        scheduler,
+                        Box::new(averaged_model),
+                        Box::new(grad_scaler),
+                        self.checkpointer_strategy,
+                    )
+                });
 
         let summary = if self.summary {
             Some(LearnerSummaryConfig {
@@ -353,6 +443,8 @@ where
             event_store,
             checkpoint: self.checkpoint,
             grad_accumulation: self.grad_accumulation,
+            grad_scaler: self.grad_scaler,
+            model_averaging: self.model_averaging,
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
@@ -1,6 +1,9 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    data::dataloader::DataLoader,
+    lr_scheduler::LrScheduler,
+    module::AutodiffModule,
+    optim::{AveragedModule, GradScaler, GradientsAccumulator, GradientsParams},
+    tensor::backend::{AutodiffBackend, Backend},
 };
 use std::sync::Arc;
 
@@ -59,6 +62,7 @@ impl<VI> ValidEpoch<VI> {
                 self.epoch_total,
                 iteration,
                 None,
+                None,
             );
 
             processor.process_valid(Event::ProcessedItem(item));
@@ -80,16 +84,21 @@ impl<TI> TrainEpoch<TI> {
     /// * `model` - The model to train.
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
+    /// * `grad_scaler` - The gradient scaler to use for mixed precision training.
+    /// * `ema` - The moving average of the model to update after each optimizer step.
     /// * `processor` - The event processor to use.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run<LC: LearnerComponents, TO>(
         &self,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
         scheduler: &mut LC::LrScheduler,
+        mut grad_scaler: Option<&mut GradScaler>,
+        mut ema: Option<&mut AveragedModule<LC::Backend, LC::Model>>,
         processor: &mut LC::EventProcessor,
         interrupter: &TrainingInterrupter,
     ) -> (LC::Model, LC::Optimizer)
@@ -110,20 +119,38 @@ impl<TI> TrainEpoch<TI> {
             log::info!("Iteration {}", iteration);
 
             let progress = iterator.progress();
-            let item = model.step(item);
-
-            match self.grad_accumulation {
-                Some(accumulation) => {
-                    accumulator.accumulate(&model, item.grads);
+            let item = match grad_scaler.as_deref() {
+                Some(scaler) => model.step_scaled(item, scaler),
+                None => model.step(item),
+            };
+            let grads = match grad_scaler.as_deref_mut() {
+                Some(scaler) => unscale_grads(scaler, &model, item.grads, item.loss_scale),
+                None => Some(item.grads),
+            };
+
+            match (grads, self.grad_accumulation) {
+                (Some(grads), Some(accumulation)) => {
+                    accumulator.accumulate(&model, grads);
                     accumulation_current += 1;
 
                     if accumulation <= accumulation_current {
                         let grads = accumulator.grads();
                         model = model.optimize(&mut optim, lr, grads);
                         accumulation_current = 0;
+
+                        if let Some(ema) = ema.as_deref_mut() {
+                            ema.update(&model);
+                        }
+                    }
+                }
+                (Some(grads), None) => {
+                    model = model.optimize(&mut optim, lr, grads);
+
+                    if let Some(ema) = ema.as_deref_mut() {
+                        ema.update(&model);
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+                (None, _) => {}
             }
 
             let item = LearnerItem::new(
@@ -133,6 +160,7 @@ impl<TI> TrainEpoch<TI> {
                 self.epoch_total,
                 iteration,
                 Some(lr),
+                optim.grad_norm(),
             );
 
             processor.process_train(Event::ProcessedItem(item));
@@ -156,17 +184,22 @@ impl<TI> TrainEpoch<TI> {
     /// * `model` - The model to train.
     /// * `optim` - The optimizer to use.
     /// * `lr_scheduler` - The learning rate scheduler to use.
+    /// * `grad_scaler` - The gradient scaler to use for mixed precision training.
+    /// * `ema` - The moving average of the model to update after each optimizer step.
     /// * `processor` - The event processor to use.
     /// * `devices` - The devices to use.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run_multi_device<LC: LearnerComponents, TO>(
         &self,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
         lr_scheduler: &mut LC::LrScheduler,
+        mut grad_scaler: Option<&mut GradScaler>,
+        mut ema: Option<&mut AveragedModule<LC::Backend, LC::Model>>,
         processor: &mut LC::EventProcessor,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
@@ -196,7 +229,7 @@ impl<TI> TrainEpoch<TI> {
         let mut interrupted = false;
 
         loop {
-            let items = step.step(&mut iterator, &model);
+            let items = step.step(&mut iterator, &model, grad_scaler.as_deref());
             if items.is_empty() {
                 break;
             }
@@ -207,14 +240,24 @@ impl<TI> TrainEpoch<TI> {
                 let progress = iterator.progress();
 
                 let grads = item.grads.to_device(&device_main, &model);
+                let grads = match grad_scaler.as_deref_mut() {
+                    Some(scaler) => unscale_grads(scaler, &model, grads, item.loss_scale),
+                    None => Some(grads),
+                };
 
-                accumulator.accumulate(&model, grads);
-                accumulation_current += 1;
+                if let Some(grads) = grads {
+                    accumulator.accumulate(&model, grads);
+                    accumulation_current += 1;
 
-                if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
-                    model = model.optimize(&mut optim, lr, grads);
-                    accumulation_current = 0;
+                    if accumulation <= accumulation_current {
+                        let grads = accumulator.grads();
+                        model = model.optimize(&mut optim, lr, grads);
+                        accumulation_current = 0;
+
+                        if let Some(ema) = ema.as_deref_mut() {
+                            ema.update(&model);
+                        }
+                    }
                 }
 
                 let item = LearnerItem::new(
@@ -224,6 +267,7 @@ impl<TI> TrainEpoch<TI> {
                     self.epoch_total,
                     iteration,
                     Some(lr),
+                    optim.grad_norm(),
                 );
 
                 processor.process_train(Event::ProcessedItem(item));
@@ -245,3 +289,64 @@ impl<TI> TrainEpoch<TI> {
         (model, optim)
     }
 }
+
+impl<TI> TrainEpoch<TI> {
+    /// Re-estimates the running statistics of the normalization layers of an averaged model,
+    /// which are reset and then averaged over the [forward passes](TrainStep::forward_step) on
+    /// the training data.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The averaged model.
+    pub fn update_running_stats<LC: LearnerComponents, TO>(
+        &self,
+        model: &AveragedModule<LC::Backend, LC::Model>,
+        interrupter: &TrainingInterrupter,
+    ) where
+        LC::Model: TrainStep<TI, TO>,
+    {
+        log::info!(
+            "Updating the running statistics of the averaged model for epoch {}",
+            self.epoch
+        );
+
+        model.reset_running_stats();
+
+        for item in self.dataloader.iter() {
+            model.module().forward_step(item);
+
+            if interrupter.should_stop() {
+                log::info!("Training interrupted.");
+                break;
+            }
+        }
+    }
+}
+
+/// Check the gradients of a training step and update the gradient scaler.
+///
+/// The gradients are divided by the loss scale during the optimizer step, after they are cast to
+/// the precision of the optimizer. Returns `None` if the gradients contain inf or NaN values, in
+/// which case they are skipped.
+fn unscale_grads<B: AutodiffBackend, M: AutodiffModule<B>>(
+    scaler: &mut GradScaler,
+    model: &M,
+    grads: GradientsParams,
+    loss_scale: Option<f32>,
+) -> Option<GradientsParams> {
+    if !grads.is_finite(model) {
+        log::warn!(
+            "Skipping gradients with inf or NaN values, loss scale {}.",
+            scaler.scale()
+        );
+        scaler.update(true);
+        return None;
+    }
+
+    scaler.update(false);
+
+    match loss_scale {
+        Some(scale) => Some(grads.with_loss_scale(scale)),
+        None => Some(grads),
+    }
+}
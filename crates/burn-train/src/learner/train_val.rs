@@ -1,10 +1,14 @@
 use crate::components::LearnerComponents;
+use crate::learner::base::ModelAveraging;
 use crate::metric::processor::EventProcessor;
+use crate::metric::store::{Aggregate, Split};
 use crate::{Learner, TrainEpoch, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
+use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::{AutodiffModule, Module};
-use burn_core::optim::{GradientsParams, Optimizer};
+use burn_core::optim::{GradScaler, GradientsParams, Optimizer};
 use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::Tensor;
 use std::sync::Arc;
 
 /// A training output.
@@ -14,6 +18,10 @@ pub struct TrainOutput<TO> {
 
     /// The item.
     pub item: TO,
+
+    /// The scale of the loss used to compute the gradients, if it was
+    /// [scaled](GradScaler::scale_loss).
+    pub loss_scale: Option<f32>,
 }
 
 impl<TO> TrainOutput<TO> {
@@ -34,7 +42,39 @@ impl<TO> TrainOutput<TO> {
         item: TO,
     ) -> Self {
         let grads = GradientsParams::from_grads(grads, module);
-        Self { grads, item }
+        Self {
+            grads,
+            item,
+            loss_scale: None,
+        }
+    }
+
+    /// Creates a new training output from a loss scaled by the [gradient scaler](GradScaler),
+    /// used for mixed precision training.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module.
+    /// * `loss` - The loss, which is scaled before the backward pass.
+    /// * `scaler` - The gradient scaler.
+    /// * `item` - The item.
+    ///
+    /// # Returns
+    ///
+    /// A new training output.
+    pub fn scaled<B: AutodiffBackend, M: AutodiffModule<B>, const D: usize>(
+        module: &M,
+        loss: Tensor<B, D>,
+        scaler: &GradScaler,
+        item: TO,
+    ) -> Self {
+        let grads = scaler.scale_loss(loss).backward();
+        let grads = GradientsParams::from_grads(grads, module);
+        Self {
+            grads,
+            item,
+            loss_scale: Some(scaler.scale()),
+        }
     }
 }
 
@@ -62,6 +102,41 @@ pub trait TrainStep<TI, TO> {
     ///
     /// The training output containing the model output and the gradients.
     fn step(&self, item: TI) -> TrainOutput<TO>;
+    /// Runs the training step when the learner uses a [gradient scaler](GradScaler) for mixed
+    /// precision training.
+    ///
+    /// The loss should be scaled before the backward pass with
+    /// [TrainOutput::scaled](TrainOutput::scaled), so that small gradients don't underflow in half
+    /// precision. It must be implemented to train with a gradient scaler.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The training input for the model.
+    /// * `scaler` - The gradient scaler.
+    ///
+    /// # Returns
+    ///
+    /// The training output containing the model output and the gradients.
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics, since the loss can't be scaled by [step](TrainStep::step).
+    fn step_scaled(&self, _item: TI, _scaler: &GradScaler) -> TrainOutput<TO> {
+        panic!("Training with a gradient scaler requires to implement `TrainStep::step_scaled`, which scales the loss before the backward pass.")
+    }
+    /// Runs the forward pass of the training step without the backward pass, which updates the
+    /// running statistics of the normalization layers, e.g. to re-estimate the ones of an
+    /// [averaged model](burn_core::optim::AveragedModule).
+    ///
+    /// The default implementation runs the whole [step](TrainStep::step), implementations
+    /// should only run the forward pass of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The training input for the model.
+    fn forward_step(&self, item: TI) {
+        let _ = self.step(item);
+    }
     /// Optimize the current module with the provided gradients and learning rate.
     ///
     /// # Arguments
@@ -107,7 +182,7 @@ impl<LC: LearnerComponents> Learner<LC> {
     ///
     /// # Returns
     ///
-    /// The fitted model.
+    /// The fitted model, or the averaged model when the weights are averaged during the training.
     pub fn fit<InputTrain, InputValid, OutputTrain, OutputValid>(
         mut self,
         dataloader_train: Arc<dyn DataLoader<InputTrain>>,
@@ -144,6 +219,20 @@ impl<LC: LearnerComponents> Learner<LC> {
             None => 1,
         };
 
+        let mut averaged_model = self
+            .model_averaging
+            .as_ref()
+            .map(|averaging| averaging.init(self.model.clone()));
+
+        if let (Some(checkpoint), Some(checkpointer)) = (self.checkpoint, &self.checkpointer) {
+            averaged_model = averaged_model.map(|averaged_model| {
+                checkpointer.load_averaged_model(averaged_model, &Default::default(), checkpoint)
+            });
+            self.grad_scaler = self.grad_scaler.take().map(|grad_scaler| {
+                checkpointer.load_grad_scaler(grad_scaler, &Default::default(), checkpoint)
+            });
+        }
+
         for epoch in starting_epoch..self.num_epochs + 1 {
             let epoch_train = TrainEpoch::new(
                 dataloader_train.clone(),
@@ -152,11 +241,19 @@ impl<LC: LearnerComponents> Learner<LC> {
                 self.grad_accumulation,
             );
 
+            // The moving average is updated after each optimizer step.
+            let ema = match &self.model_averaging {
+                Some(ModelAveraging::Ema(_)) => averaged_model.as_mut(),
+                _ => None,
+            };
+
             if self.devices.len() > 1 {
                 (self.model, self.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
                     self.model,
                     self.optim,
                     &mut self.lr_scheduler,
+                    self.grad_scaler.as_mut(),
+                    ema,
                     &mut self.event_processor,
                     self.devices.clone(),
                     &self.interrupter,
@@ -166,6 +263,8 @@ impl<LC: LearnerComponents> Learner<LC> {
                     self.model,
                     self.optim,
                     &mut self.lr_scheduler,
+                    self.grad_scaler.as_mut(),
+                    ema,
                     &mut self.event_processor,
                     &self.interrupter,
                 );
@@ -175,18 +274,47 @@ impl<LC: LearnerComponents> Learner<LC> {
                 break;
             }
 
+            if let (Some(ModelAveraging::Swa { start_epoch }), Some(averaged_model)) =
+                (&self.model_averaging, averaged_model.as_mut())
+            {
+                if epoch >= *start_epoch {
+                    averaged_model.update(&self.model);
+                    epoch_train
+                        .update_running_stats::<LC, OutputTrain>(averaged_model, &self.interrupter);
+                }
+            }
+
+            // The averaged model is only used once it has been updated.
+            let model_valid = match &averaged_model {
+                Some(averaged_model) if averaged_model.num_updates() > 0 => averaged_model.module(),
+                _ => &self.model,
+            };
+
             let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
             epoch_valid.run::<LC, OutputValid>(
-                &self.model,
+                model_valid,
                 &mut self.event_processor,
                 &self.interrupter,
             );
 
+            // Schedulers driven by a validation metric are updated before being checkpointed.
+            if let Some(name) = self.lr_scheduler.metric_name().map(str::to_string) {
+                match self
+                    .event_store
+                    .find_metric(&name, epoch, Aggregate::Mean, Split::Valid)
+                {
+                    Some(value) => self.lr_scheduler.report_metric(value),
+                    None => log::warn!("Metric {name} not found for the learning rate scheduler"),
+                }
+            }
+
             if let Some(checkpointer) = &mut self.checkpointer {
                 checkpointer.checkpoint(
                     &self.model,
                     &self.optim,
                     &self.lr_scheduler,
+                    averaged_model.as_ref(),
+                    self.grad_scaler.as_ref(),
                     epoch,
                     &self.event_store,
                 );
@@ -212,6 +340,11 @@ impl<LC: LearnerComponents> Learner<LC> {
             }
         }
 
-        self.model
+        match averaged_model {
+            Some(averaged_model) if averaged_model.num_updates() > 0 => {
+                averaged_model.into_module()
+            }
+            _ => self.model,
+        }
     }
 }
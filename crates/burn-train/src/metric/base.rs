@@ -16,6 +16,9 @@ pub struct MetricMetadata {
 
     /// The current learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The global norm of the gradients before clipping, reported by the optimizer.
+    pub grad_norm: Option<f64>,
 }
 
 impl MetricMetadata {
@@ -30,6 +33,7 @@ impl MetricMetadata {
             epoch_total: 1,
             iteration: 0,
             lr: None,
+            grad_norm: None,
         }
     }
 }
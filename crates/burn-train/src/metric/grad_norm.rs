@@ -0,0 +1,77 @@
+use super::{
+    state::{FormatOptions, NumericMetricState},
+    MetricMetadata, Numeric,
+};
+use crate::metric::{Metric, MetricEntry};
+
+/// Track the global norm of the gradients before clipping across iterations.
+///
+/// The norm is only reported by optimizers clipping the gradients by their
+/// [global norm](burn_core::grad_clipping::GradientClipping::GlobalNorm), the other iterations
+/// are skipped.
+pub struct GradNormMetric {
+    state: NumericMetricState,
+}
+
+impl GradNormMetric {
+    /// Creates a new gradient norm metric.
+    pub fn new() -> Self {
+        Self {
+            state: NumericMetricState::new(),
+        }
+    }
+}
+
+impl Default for GradNormMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for GradNormMetric {
+    const NAME: &'static str = "Gradient Norm";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let format = FormatOptions::new("Gradient Norm").precision(3);
+
+        match metadata.grad_norm {
+            Some(grad_norm) => self.state.update(grad_norm, 1, format),
+            None => self.state.skip(format),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for GradNormMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_skip_iterations_without_grad_norm() {
+        let mut metric = GradNormMetric::new();
+        let mut metadata = MetricMetadata::fake();
+
+        metadata.grad_norm = Some(2.0);
+        metric.update(&(), &metadata);
+
+        metadata.grad_norm = None;
+        let entry = metric.update(&(), &metadata);
+        assert_eq!(entry.serialize, "0,0");
+        assert_eq!(metric.value(), 2.0);
+
+        metadata.grad_norm = Some(4.0);
+        let entry = metric.update(&(), &metadata);
+        assert_eq!(entry.formatted, "epoch 3.000 - batch 4.000");
+    }
+}
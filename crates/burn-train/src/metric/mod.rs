@@ -9,6 +9,7 @@ mod cpu_temp;
 mod cpu_use;
 #[cfg(feature = "metrics")]
 mod cuda;
+mod grad_norm;
 mod hamming;
 mod learning_rate;
 mod loss;
@@ -23,6 +24,7 @@ pub use cpu_temp::*;
 pub use cpu_use::*;
 #[cfg(feature = "metrics")]
 pub use cuda::*;
+pub use grad_norm::*;
 pub use hamming::*;
 pub use learning_rate::*;
 pub use loss::*;
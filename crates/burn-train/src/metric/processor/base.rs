@@ -42,4 +42,7 @@ pub struct LearnerItem<T> {
 
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The global norm of the gradients before clipping.
+    pub grad_norm: Option<f64>,
 }
@@ -65,10 +65,25 @@ impl NumericMetricState {
         self.count += batch_size;
         self.current = value;
 
-        let value_current = value;
+        self.entry(value, batch_size, format)
+    }
+
+    /// Skip the update of the state when no value is available, e.g., the value isn't computed
+    /// for every batch.
+    ///
+    /// The returned entry doesn't contribute to the aggregated value.
+    pub fn skip(&self, format: FormatOptions) -> MetricEntry {
+        self.entry(self.current, 0, format)
+    }
+
+    fn entry(&self, value_current: f64, batch_size: usize, format: FormatOptions) -> MetricEntry {
         let value_running = self.sum / self.count as f64;
-        // Numeric metric state is an aggregated value
-        let serialized = NumericEntry::Aggregated(value_current, batch_size).serialize();
+        // Numeric metric state is an aggregated value, where an empty batch has no weight
+        let value_serialized = match batch_size {
+            0 => 0.0,
+            _ => value_current,
+        };
+        let serialized = NumericEntry::Aggregated(value_serialized, batch_size).serialize();
 
         let (formatted_current, formatted_running) = match format.precision {
             Some(precision) => (
@@ -16,6 +16,7 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::ArgMax => argmax_update_outputs(node),
         NodeType::AveragePool1d => same_as_input(node),
         NodeType::AveragePool2d => same_as_input(node),
+        NodeType::AveragePool3d => same_as_input(node),
         NodeType::BatchNormalization => same_as_input(node),
         NodeType::Cast => cast_update_outputs(node),
         NodeType::Clip => same_as_input(node),
@@ -45,11 +46,13 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Max => same_as_input(node),
         NodeType::MaxPool1d => same_as_input(node),
         NodeType::MaxPool2d => same_as_input(node),
+        NodeType::MaxPool3d => same_as_input(node),
         NodeType::Mul => same_as_input(node),
         NodeType::Neg => same_as_input(node),
         NodeType::Not => same_as_input(node),
         NodeType::Pad => same_as_input(node),
         NodeType::Greater => greater_update_outputs(node),
+        NodeType::GridSample => grid_sample_update_outputs(node),
         NodeType::GreaterOrEqual => greater_or_equal_update_outputs(node),
         NodeType::Less => less_update_outputs(node),
         NodeType::LessOrEqual => less_or_equal_update_outputs(node),
@@ -345,6 +348,28 @@ fn resize_update_outputs(node: &mut Node) {
     });
 }
 
+fn grid_sample_update_outputs(node: &mut Node) {
+    let input = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("GridSample: invalid input type"),
+    };
+
+    let grid = match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("GridSample: invalid grid type"),
+    };
+
+    if input.dim != 4 || grid.dim != 4 {
+        panic!("GridSample: only 4D input and grid tensors are supported");
+    }
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: input.dim,
+        shape: None, // the spatial dimensions are given by the grid
+        ..input
+    });
+}
+
 fn greater_update_outputs(node: &mut Node) {
     match &node.inputs[0].ty {
         ArgType::Tensor(tensor) => {